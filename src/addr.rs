@@ -0,0 +1,22 @@
+use crate::LabelledOutputs;
+use clap::Args;
+
+/// Options of the addr command
+#[derive(Args, Clone, Debug)]
+pub struct AddrOptions {
+    /// The base address
+    pub base: String,
+    /// The address or offset to combine with the base address
+    pub address: String,
+    /// Length of the range starting at the base address, to check whether the address falls inside it
+    #[clap(long)]
+    pub length: Option<String>,
+}
+
+/// The sum and difference of two addresses, and whether the second falls within the range of the first
+#[derive(Debug, PartialEq, Eq)]
+pub struct AddressArithmetic {
+    pub outputs: LabelledOutputs,
+    /// Whether the address is inside the range, when a range length is given
+    pub within: Option<bool>,
+}
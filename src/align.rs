@@ -0,0 +1,180 @@
+use crate::{NumberFormatterError, Value};
+
+/// Clap value parser accepting a positive alignment in decimal or with a 0x, 0o or 0b prefix
+pub(crate) fn parse_alignment(alignment: &str) -> Result<u128, String> {
+    let digits = alignment.replace('_', "");
+    let parsed = match digits.get(..2) {
+        Some("0x" | "0X") => u128::from_str_radix(&digits[2..], 16),
+        Some("0o" | "0O") => u128::from_str_radix(&digits[2..], 8),
+        Some("0b" | "0B") => u128::from_str_radix(&digits[2..], 2),
+        _ => digits.parse::<u128>(),
+    };
+
+    parsed
+        .ok()
+        .filter(|alignment| *alignment > 0)
+        .ok_or_else(|| String::from("alignment must be a positive integer, e.g. 4096 or 0x1000"))
+}
+
+/// Whether a number is a power of two, which negative numbers and zero are not
+pub(crate) fn is_power_of_two(value: Value) -> bool {
+    match value {
+        Value::Signed(num) => num > 0 && num.count_ones() == 1,
+        Value::Unsigned(num) => num.is_power_of_two(),
+    }
+}
+
+/// The smallest power of two greater than or equal to a number
+pub(crate) fn next_power_of_two(value: Value) -> anyhow::Result<Value, NumberFormatterError> {
+    let num = match value {
+        Value::Signed(num) if num < 0 => return Err(NumberFormatterError::TooSmallError),
+        Value::Signed(num) => num as u128,
+        Value::Unsigned(num) => num,
+    };
+
+    num.checked_next_power_of_two()
+        .map(Value::from_unsigned)
+        .ok_or(NumberFormatterError::TooLargeError)
+}
+
+/// Rounds a number down to a multiple of the alignment, towards negative infinity
+pub(crate) fn align_down(
+    value: Value,
+    alignment: u128,
+) -> anyhow::Result<Value, NumberFormatterError> {
+    match value {
+        Value::Signed(num) => {
+            let alignment = signed_alignment(alignment)?;
+            num.checked_sub(num.rem_euclid(alignment))
+                .map(Value::Signed)
+                .ok_or(NumberFormatterError::TooSmallError)
+        }
+        Value::Unsigned(num) => Ok(Value::from_unsigned(num - num % alignment)),
+    }
+}
+
+/// Rounds a number up to a multiple of the alignment, towards positive infinity
+pub(crate) fn align_up(
+    value: Value,
+    alignment: u128,
+) -> anyhow::Result<Value, NumberFormatterError> {
+    match value {
+        Value::Signed(num) => {
+            let remainder = num.rem_euclid(signed_alignment(alignment)?);
+            if remainder == 0 {
+                return Ok(value);
+            }
+            let step = alignment - remainder as u128;
+            num.checked_add(step as i128)
+                .map(Value::Signed)
+                .or_else(|| (num as u128).checked_add(step).map(Value::Unsigned))
+                .ok_or(NumberFormatterError::TooLargeError)
+        }
+        Value::Unsigned(num) => match num % alignment {
+            0 => Ok(value),
+            remainder => num
+                .checked_add(alignment - remainder)
+                .map(Value::Unsigned)
+                .ok_or(NumberFormatterError::TooLargeError),
+        },
+    }
+}
+
+fn signed_alignment(alignment: u128) -> anyhow::Result<i128, NumberFormatterError> {
+    i128::try_from(alignment).map_err(|_| NumberFormatterError::TooLargeError)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("4096", Ok(4096))]
+    #[test_case("0x1000", Ok(4096))]
+    #[test_case("0b100", Ok(4))]
+    #[test_case("0", Err(()))]
+    #[test_case("four", Err(()))]
+    fn parse_alignment_should_read_alignment(alignment: &str, expected: Result<u128, ()>) {
+        // Act
+        let actual = parse_alignment(alignment);
+
+        // Assert
+        assert_eq!(actual.map_err(|_| ()), expected);
+    }
+
+    #[test_case(Value::Signed(64), true)]
+    #[test_case(Value::Signed(1), true)]
+    #[test_case(Value::Signed(0), false)]
+    #[test_case(Value::Signed(96), false)]
+    #[test_case(Value::Signed(i128::MIN), false; "negative")]
+    #[test_case(Value::Unsigned(1 << 127), true; "top bit")]
+    fn is_power_of_two_should_check_single_bit(value: Value, expected: bool) {
+        // Act
+        let actual = is_power_of_two(value);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test_case(Value::Signed(0), Ok(Value::Signed(1)))]
+    #[test_case(Value::Signed(64), Ok(Value::Signed(64)))]
+    #[test_case(Value::Signed(65), Ok(Value::Signed(128)))]
+    #[test_case(Value::Signed(i128::MAX), Ok(Value::Unsigned(1 << 127)); "above i128 max")]
+    #[test_case(Value::Unsigned(u128::MAX), Err(NumberFormatterError::TooLargeError); "too large")]
+    #[test_case(Value::Signed(-1), Err(NumberFormatterError::TooSmallError); "negative")]
+    fn next_power_of_two_should_round_up(
+        value: Value,
+        expected: anyhow::Result<Value, NumberFormatterError>,
+    ) {
+        // Act
+        let actual = next_power_of_two(value);
+
+        // Assert
+        assert_eq!(format!("{:?}", actual), format!("{:?}", expected));
+    }
+
+    #[test_case(
+        Value::Signed(0x1234),
+        0x1000,
+        Value::Signed(0x1000),
+        Value::Signed(0x2000)
+    )]
+    #[test_case(Value::Signed(0x2000), 0x1000, Value::Signed(0x2000), Value::Signed(0x2000); "already aligned")]
+    #[test_case(Value::Signed(10), 3, Value::Signed(9), Value::Signed(12); "not a power of two")]
+    #[test_case(Value::Signed(-5), 4, Value::Signed(-8), Value::Signed(-4); "negative")]
+    #[test_case(Value::Signed(i128::MAX), 16, Value::Signed(i128::MAX - 15), Value::Unsigned(1 << 127); "above i128 max")]
+    #[test_case(Value::Signed(i128::MIN), 4, Value::Signed(i128::MIN), Value::Signed(i128::MIN); "i128 min")]
+    #[test_case(Value::Unsigned(u128::MAX - 1), 2, Value::Unsigned(u128::MAX - 1), Value::Unsigned(u128::MAX - 1); "unsigned")]
+    fn align_should_round_to_multiple(value: Value, alignment: u128, down: Value, up: Value) {
+        // Act
+        let actual_down = align_down(value, alignment);
+        let actual_up = align_up(value, alignment);
+
+        // Assert
+        assert_eq!(actual_down.unwrap(), down);
+        assert_eq!(actual_up.unwrap(), up);
+    }
+
+    #[test]
+    fn align_up_should_reject_overflow() {
+        // Act
+        let actual = align_up(Value::Unsigned(u128::MAX), 16);
+
+        // Assert
+        assert!(matches!(actual, Err(NumberFormatterError::TooLargeError)));
+    }
+
+    #[test]
+    fn align_down_should_reject_underflow() {
+        // Act
+        let actual_down = align_down(Value::Signed(i128::MIN), 3);
+        let actual_up = align_up(Value::Signed(i128::MIN), 3);
+
+        // Assert
+        assert!(matches!(
+            actual_down,
+            Err(NumberFormatterError::TooSmallError)
+        ));
+        assert_eq!(actual_up.unwrap(), Value::Signed(i128::MIN + 2));
+    }
+}
@@ -0,0 +1,219 @@
+use crate::{NumberFormatter, NumberFormatterError};
+
+/// Named alphabets which can be passed to `--alphabet` instead of the digits
+static PRESETS: [(&str, &str); 3] = [
+    (
+        "base58",
+        "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz",
+    ),
+    (
+        "base62",
+        "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz",
+    ),
+    (
+        "base64url",
+        "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_",
+    ),
+];
+
+/// Positional number formatter using a user supplied digit alphabet, where
+/// the first character is the zero digit and the radix is the alphabet length
+pub(crate) struct AlphabetNumberFormatter {
+    digits: Vec<char>,
+}
+
+impl AlphabetNumberFormatter {
+    pub fn new(alphabet: &str) -> Self {
+        let alphabet = PRESETS
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(alphabet))
+            .map_or(alphabet, |(_, digits)| digits);
+
+        Self {
+            digits: alphabet.chars().collect(),
+        }
+    }
+
+    fn validate(&self) -> anyhow::Result<(), NumberFormatterError> {
+        if self.digits.len() < 2 {
+            return Err(NumberFormatterError::InvalidAlphabet);
+        }
+
+        for (index, digit) in self.digits.iter().enumerate() {
+            if self.digits[index + 1..].contains(digit) {
+                return Err(NumberFormatterError::InvalidAlphabet);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl NumberFormatter for AlphabetNumberFormatter {
+    fn read(&self, num: &str) -> anyhow::Result<i128, NumberFormatterError> {
+        self.validate()?;
+
+        let (negative, digits) = match num.strip_prefix('-') {
+            Some(digits) if !self.digits.contains(&'-') => (true, digits),
+            _ => (false, num),
+        };
+
+        if digits.is_empty() {
+            return Err(NumberFormatterError::Empty);
+        }
+
+        let radix = self.digits.len() as i128;
+        let mut value: i128 = 0;
+
+        for c in digits.chars() {
+            let digit = self
+                .digits
+                .iter()
+                .position(|d| *d == c)
+                .ok_or(NumberFormatterError::InvalidDigit)? as i128;
+
+            value = if negative {
+                value
+                    .checked_mul(radix)
+                    .and_then(|v| v.checked_sub(digit))
+                    .ok_or(NumberFormatterError::TooSmallError)?
+            } else {
+                value
+                    .checked_mul(radix)
+                    .and_then(|v| v.checked_add(digit))
+                    .ok_or(NumberFormatterError::TooLargeError)?
+            };
+        }
+
+        Ok(value)
+    }
+
+    fn format(&self, num: i128) -> anyhow::Result<String, NumberFormatterError> {
+        self.format_digits(num.unsigned_abs(), num < 0)
+    }
+
+    fn read_unsigned(&self, num: &str) -> anyhow::Result<u128, NumberFormatterError> {
+        self.validate()?;
+
+        let radix = self.digits.len() as u128;
+        num.chars().try_fold(0u128, |value, c| {
+            let digit = self
+                .digits
+                .iter()
+                .position(|d| *d == c)
+                .ok_or(NumberFormatterError::InvalidDigit)? as u128;
+
+            value
+                .checked_mul(radix)
+                .and_then(|v| v.checked_add(digit))
+                .ok_or(NumberFormatterError::TooLargeError)
+        })
+    }
+
+    fn format_unsigned(&self, num: u128) -> anyhow::Result<String, NumberFormatterError> {
+        self.format_digits(num, false)
+    }
+}
+
+impl AlphabetNumberFormatter {
+    fn format_digits(
+        &self,
+        magnitude: u128,
+        negative: bool,
+    ) -> anyhow::Result<String, NumberFormatterError> {
+        self.validate()?;
+
+        let radix = self.digits.len() as u128;
+        let mut digits = Vec::<char>::new();
+        let mut remainder = magnitude;
+
+        loop {
+            digits.push(self.digits[(remainder % radix) as usize]);
+            remainder /= radix;
+            if remainder == 0 {
+                break;
+            }
+        }
+
+        if negative {
+            digits.push('-');
+        }
+
+        Ok(digits.iter().rev().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("base62", "1C", 74)]
+    #[test_case("base62", "-zz", -3843)]
+    #[test_case("01", "1010", 10)]
+    #[test_case("base64url", "B-", 126)]
+    #[test_case("ab-", "-b", 7)]
+    #[test_case("base58", "11z", 57; "leading zero digits")]
+    fn alphabet_number_formatter_should_read_number(alphabet: &str, input: &str, expected: i128) {
+        // Arrange
+        let formatter = AlphabetNumberFormatter::new(alphabet);
+
+        // Act
+        let actual_number = formatter.read(input);
+
+        // Assert
+        assert!(actual_number.is_ok());
+        assert_eq!(expected, actual_number.unwrap());
+    }
+
+    #[test_case("base62", 74, "1C")]
+    #[test_case("base62", -3843, "-zz")]
+    #[test_case("base62", 0, "0")]
+    #[test_case("base58", 0, "1"; "base58 zero")]
+    #[test_case("base58", 58, "21"; "base58 two digits")]
+    #[test_case("xyz", 5, "yz")]
+    fn alphabet_number_formatter_should_format_i128(alphabet: &str, input: i128, expected: &str) {
+        // Arrange
+        let formatter = AlphabetNumberFormatter::new(alphabet);
+
+        // Act
+        let actual_output = formatter.format(input);
+
+        // Assert
+        assert!(actual_output.is_ok());
+        assert_eq!(expected, actual_output.unwrap());
+    }
+
+    #[test_case("a")]
+    #[test_case("abca")]
+    fn alphabet_number_formatter_should_reject_invalid_alphabet(alphabet: &str) {
+        // Arrange
+        let formatter = AlphabetNumberFormatter::new(alphabet);
+
+        // Act
+        let actual_output = formatter.format(10);
+
+        // Assert
+        assert!(matches!(
+            actual_output,
+            Err(NumberFormatterError::InvalidAlphabet)
+        ));
+    }
+
+    #[test_case("", NumberFormatterError::Empty)]
+    #[test_case("12!", NumberFormatterError::InvalidDigit)]
+    #[test_case("zzzzzzzzzzzzzzzzzzzzzzzzzzz", NumberFormatterError::TooLargeError)]
+    fn alphabet_number_formatter_should_fail_to_read(input: &str, expected: NumberFormatterError) {
+        // Arrange
+        let formatter = AlphabetNumberFormatter::new("base62");
+
+        // Act
+        let actual_number = formatter.read(input);
+
+        // Assert
+        assert_eq!(
+            std::mem::discriminant(&actual_number.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+}
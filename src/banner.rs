@@ -0,0 +1,118 @@
+/// Rows in every glyph of the banner font
+const ROWS: usize = 5;
+
+/// Glyph drawn for characters the font has no glyph for
+static UNKNOWN: [&str; ROWS] = [" ### ", "#   #", "  ## ", "     ", "  #  "];
+
+static GLYPHS: [(char, [&str; ROWS]); 44] = [
+    ('0', [" ### ", "#  ##", "# # #", "##  #", " ### "]),
+    ('1', ["  #  ", " ##  ", "  #  ", "  #  ", " ### "]),
+    ('2', [" ### ", "#   #", "  ## ", " #   ", "#####"]),
+    ('3', ["#### ", "    #", " ### ", "    #", "#### "]),
+    ('4', ["#   #", "#   #", "#####", "    #", "    #"]),
+    ('5', ["#####", "#    ", "#### ", "    #", "#### "]),
+    ('6', [" ### ", "#    ", "#### ", "#   #", " ### "]),
+    ('7', ["#####", "    #", "   # ", "  #  ", "  #  "]),
+    ('8', [" ### ", "#   #", " ### ", "#   #", " ### "]),
+    ('9', [" ### ", "#   #", " ####", "    #", " ### "]),
+    ('A', [" ### ", "#   #", "#####", "#   #", "#   #"]),
+    ('B', ["#### ", "#   #", "#### ", "#   #", "#### "]),
+    ('C', [" ####", "#    ", "#    ", "#    ", " ####"]),
+    ('D', ["#### ", "#   #", "#   #", "#   #", "#### "]),
+    ('E', ["#####", "#    ", "#### ", "#    ", "#####"]),
+    ('F', ["#####", "#    ", "#### ", "#    ", "#    "]),
+    ('G', [" ####", "#    ", "#  ##", "#   #", " ####"]),
+    ('H', ["#   #", "#   #", "#####", "#   #", "#   #"]),
+    ('I', ["#####", "  #  ", "  #  ", "  #  ", "#####"]),
+    ('J', ["#####", "    #", "    #", "#   #", " ### "]),
+    ('K', ["#   #", "#  # ", "###  ", "#  # ", "#   #"]),
+    ('L', ["#    ", "#    ", "#    ", "#    ", "#####"]),
+    ('M', ["#   #", "## ##", "# # #", "#   #", "#   #"]),
+    ('N', ["#   #", "##  #", "# # #", "#  ##", "#   #"]),
+    ('O', [" ### ", "#   #", "#   #", "#   #", " ### "]),
+    ('P', ["#### ", "#   #", "#### ", "#    ", "#    "]),
+    ('Q', [" ### ", "#   #", "# # #", "#  # ", " ## #"]),
+    ('R', ["#### ", "#   #", "#### ", "#  # ", "#   #"]),
+    ('S', [" ####", "#    ", " ### ", "    #", "#### "]),
+    ('T', ["#####", "  #  ", "  #  ", "  #  ", "  #  "]),
+    ('U', ["#   #", "#   #", "#   #", "#   #", " ### "]),
+    ('V', ["#   #", "#   #", "#   #", " # # ", "  #  "]),
+    ('W', ["#   #", "#   #", "# # #", "## ##", "#   #"]),
+    ('X', ["#   #", " # # ", "  #  ", " # # ", "#   #"]),
+    ('Y', ["#   #", " # # ", "  #  ", "  #  ", "  #  "]),
+    ('Z', ["#####", "   # ", "  #  ", " #   ", "#####"]),
+    ('-', ["   ", "   ", "###", "   ", "   "]),
+    ('+', ["     ", "  #  ", "#####", "  #  ", "     "]),
+    (':', [" ", "#", " ", "#", " "]),
+    ('.', [" ", " ", " ", " ", "#"]),
+    (',', ["  ", "  ", "  ", " #", "# "]),
+    ('\'', ["#", "#", " ", " ", " "]),
+    ('_', ["     ", "     ", "     ", "     ", "#####"]),
+    (' ', ["  ", "  ", "  ", "  ", "  "]),
+];
+
+/// Draws text in large letters, five rows high. Letters are drawn in uppercase.
+pub fn banner(text: &str) -> String {
+    let glyphs: Vec<&[&str; ROWS]> = text
+        .chars()
+        .map(|c| {
+            GLYPHS
+                .iter()
+                .find(|(glyph, _)| *glyph == c.to_ascii_uppercase())
+                .map_or(&UNKNOWN, |(_, rows)| rows)
+        })
+        .collect();
+
+    (0..ROWS)
+        .map(|row| {
+            let line: Vec<&str> = glyphs.iter().map(|glyph| glyph[row]).collect();
+            line.join(" ").trim_end().to_string()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test]
+    fn banner_should_draw_digits_side_by_side() {
+        // Act
+        let actual = banner("1-7");
+
+        // Assert
+        assert_eq!(
+            actual,
+            [
+                "  #       #####",
+                " ##           #",
+                "  #   ###    #",
+                "  #         #",
+                " ###        #",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test_case("ff", "FF")]
+    #[test_case("1010", "1010")]
+    #[test_case("1:02:05", "1:02:05")]
+    fn banner_should_draw_uppercase(text: &str, uppercase: &str) {
+        // Act
+        let actual = banner(text);
+
+        // Assert
+        assert_eq!(actual, banner(uppercase));
+    }
+
+    #[test]
+    fn banner_should_draw_unknown_characters() {
+        // Act
+        let actual = banner("↊");
+
+        // Assert
+        assert_eq!(actual, UNKNOWN.map(str::trim_end).join("\n"));
+    }
+}
@@ -0,0 +1,109 @@
+use crate::{NumberFormatter, NumberFormatterError};
+
+/// Packed binary-coded decimal formatter, reading and writing the packed value in
+/// hexadecimal so every nibble holds one decimal digit, e.g. 59 = `0x59`
+pub(crate) struct BcdNumberFormatter;
+
+/// The decimal digits of a packed value, rejecting hexadecimal digits above 9
+fn digits(bcd: &str) -> anyhow::Result<&str, NumberFormatterError> {
+    let without_prefix = bcd
+        .strip_prefix("0x")
+        .or_else(|| bcd.strip_prefix("0X"))
+        .unwrap_or(bcd);
+
+    match without_prefix.chars().find(|c| !c.is_ascii_digit()) {
+        Some(c) if c.is_ascii_hexdigit() => Err(NumberFormatterError::InvalidBcd),
+        Some(_) => Err(NumberFormatterError::InvalidDigit),
+        None => Ok(without_prefix),
+    }
+}
+
+impl NumberFormatter for BcdNumberFormatter {
+    fn read(&self, bcd: &str) -> anyhow::Result<i128, NumberFormatterError> {
+        digits(bcd)?.parse::<i128>().map_err(|op| op.into())
+    }
+
+    fn format(&self, num: i128) -> anyhow::Result<String, NumberFormatterError> {
+        if num < 0 {
+            return Err(NumberFormatterError::TooSmallError);
+        }
+
+        Ok(format!("0x{}", num))
+    }
+
+    fn read_unsigned(&self, bcd: &str) -> anyhow::Result<u128, NumberFormatterError> {
+        digits(bcd)?.parse::<u128>().map_err(|op| op.into())
+    }
+
+    fn format_unsigned(&self, num: u128) -> anyhow::Result<String, NumberFormatterError> {
+        Ok(format!("0x{}", num))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("0x59", 59)]
+    #[test_case("1234", 1234)]
+    #[test_case("0", 0)]
+    fn bcd_number_formatter_should_read_number(input: &str, expected: i128) {
+        // Act
+        let actual_number = BcdNumberFormatter.read(input);
+
+        // Assert
+        assert!(actual_number.is_ok());
+        assert_eq!(expected, actual_number.unwrap());
+    }
+
+    #[test_case(59, "0x59")]
+    #[test_case(2024, "0x2024")]
+    #[test_case(0, "0x0")]
+    fn bcd_number_formatter_should_format_i128(input: i128, expected: &str) {
+        // Act
+        let actual_output = BcdNumberFormatter.format(input);
+
+        // Assert
+        assert!(actual_output.is_ok());
+        assert_eq!(expected, actual_output.unwrap());
+    }
+
+    #[test_case("0x5A", NumberFormatterError::InvalidBcd ; "invalid nibble")]
+    #[test_case("12g", NumberFormatterError::InvalidDigit ; "invalid digit")]
+    #[test_case("", NumberFormatterError::Empty ; "empty")]
+    #[test_case("0x0x12", NumberFormatterError::InvalidDigit ; "repeated prefix")]
+    fn bcd_number_formatter_should_fail_to_read(input: &str, expected: NumberFormatterError) {
+        // Act
+        let actual_number = BcdNumberFormatter.read(input);
+
+        // Assert
+        assert_eq!(
+            std::mem::discriminant(&actual_number.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+
+    #[test]
+    fn bcd_number_formatter_should_round_trip_unsigned() {
+        // Act
+        let bcd = BcdNumberFormatter.format_unsigned(u128::MAX).unwrap();
+        let actual = BcdNumberFormatter.read_unsigned(&bcd);
+
+        // Assert
+        assert_eq!(bcd, "0x340282366920938463463374607431768211455");
+        assert_eq!(actual.unwrap(), u128::MAX);
+    }
+
+    #[test]
+    fn bcd_number_formatter_should_reject_negative() {
+        // Act
+        let actual_output = BcdNumberFormatter.format(-1);
+
+        // Assert
+        assert!(matches!(
+            actual_output,
+            Err(NumberFormatterError::TooSmallError)
+        ));
+    }
+}
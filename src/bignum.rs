@@ -0,0 +1,82 @@
+/// Powers of ten each limb holds digits up to
+const LIMB: u64 = 1_000_000_000;
+
+/// An unsigned integer of any size, stored as base 10^9 limbs with the least significant first so
+/// that writing it in decimal needs no division
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct BigUint {
+    limbs: Vec<u32>,
+}
+
+impl BigUint {
+    pub(crate) fn from_u128(mut num: u128) -> Self {
+        let mut limbs = Vec::new();
+        while num > 0 {
+            limbs.push((num % LIMB as u128) as u32);
+            num /= LIMB as u128;
+        }
+
+        Self { limbs }
+    }
+
+    /// Multiplies the integer by a small factor in place
+    pub(crate) fn mul_small(&mut self, factor: u32) {
+        let mut carry = 0u64;
+        for limb in self.limbs.iter_mut() {
+            let product = *limb as u64 * factor as u64 + carry;
+            *limb = (product % LIMB) as u32;
+            carry = product / LIMB;
+        }
+        while carry > 0 {
+            self.limbs.push((carry % LIMB) as u32);
+            carry /= LIMB;
+        }
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+    }
+
+    /// Multiplies the integer by a power of a small base in place
+    pub(crate) fn mul_pow(&mut self, base: u32, exponent: u32) {
+        for _ in 0..exponent {
+            self.mul_small(base);
+        }
+    }
+}
+
+impl std::fmt::Display for BigUint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.limbs.split_last() {
+            None => write!(f, "0"),
+            Some((most_significant, rest)) => {
+                write!(f, "{}", most_significant)?;
+                for limb in rest.iter().rev() {
+                    write!(f, "{:09}", limb)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(0, 2, 10, "0")]
+    #[test_case(1, 2, 10, "1024"; "power of two")]
+    #[test_case(3, 5, 3, "375"; "power of five")]
+    #[test_case(u128::MAX, 2, 1, "680564733841876926926749214863536422910"; "past u128")]
+    #[test_case(1, 2, 128, "340282366920938463463374607431768211456"; "limbs with leading zeros")]
+    fn big_uint_should_multiply_by_power(num: u128, base: u32, exponent: u32, expected: &str) {
+        // Arrange
+        let mut big = BigUint::from_u128(num);
+
+        // Act
+        big.mul_pow(base, exponent);
+
+        // Assert
+        assert_eq!(big.to_string(), expected);
+    }
+}
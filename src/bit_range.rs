@@ -0,0 +1,107 @@
+use crate::Value;
+use std::fmt;
+
+/// An inclusive range of bit indices, such as `12..15` for the fourth nibble
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct BitRange {
+    pub low: u32,
+    pub high: u32,
+}
+
+/// Clap value parser accepting `LOW..HIGH` in either order, or a single bit index
+pub(crate) fn parse_bit_range(range: &str) -> Result<BitRange, String> {
+    let index = |text: &str| {
+        text.trim()
+            .parse::<u32>()
+            .ok()
+            .filter(|index| *index < 128)
+            .ok_or_else(|| format!("bit index must be between 0 and 127, not '{}'", text))
+    };
+
+    let (first, last) = match range.split_once("..") {
+        Some((first, last)) => (index(first)?, index(last)?),
+        None => (index(range)?, index(range)?),
+    };
+
+    Ok(BitRange {
+        low: first.min(last),
+        high: first.max(last),
+    })
+}
+
+impl BitRange {
+    /// Number of bits in the range
+    pub fn width(&self) -> u32 {
+        self.high - self.low + 1
+    }
+
+    /// The bits of the range set and all others clear
+    pub fn mask(&self) -> u128 {
+        (u128::MAX >> (128 - self.width())) << self.low
+    }
+
+    /// The field held in the range of a number's two's complement bit pattern, shifted down to bit 0
+    pub(crate) fn extract(&self, value: Value) -> Value {
+        let pattern = match value {
+            Value::Signed(num) => num as u128,
+            Value::Unsigned(num) => num,
+        };
+        Value::from_unsigned((pattern & self.mask()) >> self.low)
+    }
+}
+
+impl fmt::Display for BitRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.low == self.high {
+            write!(f, "{}", self.low)
+        } else {
+            write!(f, "{}..{}", self.low, self.high)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("12..15", 12, 15)]
+    #[test_case("15..12", 12, 15; "reversed")]
+    #[test_case("7", 7, 7)]
+    #[test_case("0..127", 0, 127)]
+    fn parse_bit_range_should_accept_range(range: &str, low: u32, high: u32) {
+        // Act
+        let actual = parse_bit_range(range);
+
+        // Assert
+        assert_eq!(actual.unwrap(), BitRange { low, high });
+    }
+
+    #[test_case(""; "empty")]
+    #[test_case("4..")]
+    #[test_case("0..128")]
+    #[test_case("a..b")]
+    fn parse_bit_range_should_reject_invalid_range(range: &str) {
+        // Act
+        let actual = parse_bit_range(range);
+
+        // Assert
+        assert!(actual.is_err());
+    }
+
+    #[test_case(12, 15, Value::Signed(0xa000), Value::Signed(0xa))]
+    #[test_case(4, 7, Value::Signed(0x1234), Value::Signed(0x3))]
+    #[test_case(0, 7, Value::Signed(-1), Value::Signed(0xff); "negative")]
+    #[test_case(0, 127, Value::Signed(-1), Value::Unsigned(u128::MAX); "whole pattern")]
+    #[test_case(127, 127, Value::Unsigned(1 << 127), Value::Signed(1); "top bit")]
+    fn bit_range_should_extract_field(low: u32, high: u32, value: Value, expected: Value) {
+        // Arrange
+        let range = BitRange { low, high };
+
+        // Act
+        let actual = range.extract(value);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+}
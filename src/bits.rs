@@ -0,0 +1,134 @@
+use crate::{align, IntegerSize, Value};
+use clap::Args;
+
+/// Options of the bits command
+#[derive(Args, Clone, Debug)]
+pub struct BitsOptions {
+    /// The number to inspect
+    pub number: String,
+}
+
+/// Bit counts of a number's two's complement bit pattern
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct BitInfo {
+    /// Width in bits the pattern is counted at, 128 unless an integer size is chosen
+    pub width: u32,
+    pub popcount: u32,
+    pub leading_zeros: u32,
+    pub trailing_zeros: u32,
+    /// Index of the highest set bit, none for zero
+    pub highest_bit: Option<u32>,
+    /// Fewest bits holding the number as an unsigned integer, none for negative numbers
+    pub unsigned_width: Option<u32>,
+    /// Fewest bits holding the number as a signed integer, none above i128::MAX
+    pub signed_width: Option<u32>,
+    pub power_of_two: bool,
+}
+
+impl BitInfo {
+    pub(crate) fn new(value: Value, size: Option<IntegerSize>) -> Self {
+        let width = size.map_or(128, |size| size.bits);
+        let pattern = match (value, size) {
+            (Value::Signed(num), Some(size)) => size.bits_of(num),
+            (Value::Signed(num), None) => num as u128,
+            (Value::Unsigned(num), _) => num,
+        };
+        let leading_zeros = pattern.leading_zeros() - (128 - width);
+
+        let (unsigned_width, signed_width) = match value {
+            Value::Signed(num) if num < 0 => (None, Some(129 - (!num).leading_zeros())),
+            Value::Signed(num) => (
+                Some((128 - num.leading_zeros()).max(1)),
+                Some(129 - num.leading_zeros()),
+            ),
+            Value::Unsigned(num) => (Some((128 - num.leading_zeros()).max(1)), None),
+        };
+
+        Self {
+            width,
+            popcount: pattern.count_ones(),
+            leading_zeros,
+            trailing_zeros: pattern.trailing_zeros().min(width),
+            highest_bit: pattern.checked_ilog2(),
+            unsigned_width,
+            signed_width,
+            power_of_two: align::is_power_of_two(value),
+        }
+    }
+
+    /// Labelled lines describing the bit counts, in the order they should be displayed
+    pub fn lines(&self) -> Vec<(&'static str, String)> {
+        let bits = |width: Option<u32>| width.map_or(String::from("none"), |w| w.to_string());
+
+        vec![
+            ("Width", self.width.to_string()),
+            ("Popcount", self.popcount.to_string()),
+            ("Leading zeros", self.leading_zeros.to_string()),
+            ("Trailing zeros", self.trailing_zeros.to_string()),
+            ("Highest set bit", bits(self.highest_bit)),
+            ("Unsigned width", bits(self.unsigned_width)),
+            ("Signed width", bits(self.signed_width)),
+            (
+                "Power of two",
+                String::from(if self.power_of_two { "yes" } else { "no" }),
+            ),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    /// Width, popcount, leading zeros, trailing zeros, highest set bit, unsigned and signed width
+    /// and whether the number is a power of two
+    type Counts = (
+        u32,
+        u32,
+        u32,
+        u32,
+        Option<u32>,
+        Option<u32>,
+        Option<u32>,
+        bool,
+    );
+
+    #[test_case(Value::Signed(0), None, (128, 0, 128, 128, None, Some(1), Some(1), false); "zero")]
+    #[test_case(Value::Signed(0b1011000), None, (128, 3, 121, 3, Some(6), Some(7), Some(8), false); "positive")]
+    #[test_case(Value::Signed(-1), Some(IntegerSize { bits: 8, signed: true }), (8, 8, 0, 0, Some(7), None, Some(1), false); "minus one as i8")]
+    #[test_case(Value::Signed(-128), Some(IntegerSize { bits: 8, signed: true }), (8, 1, 0, 7, Some(7), None, Some(8), false); "i8 min")]
+    #[test_case(Value::Signed(1), Some(IntegerSize { bits: 32, signed: false }), (32, 1, 31, 0, Some(0), Some(1), Some(2), true); "one as u32")]
+    #[test_case(Value::Unsigned(u128::MAX), None, (128, 128, 0, 0, Some(127), Some(128), None, false); "u128 max")]
+    fn bit_info_should_count_bits(value: Value, size: Option<IntegerSize>, expected: Counts) {
+        // Arrange
+        let (
+            width,
+            popcount,
+            leading_zeros,
+            trailing_zeros,
+            highest_bit,
+            unsigned_width,
+            signed_width,
+            power_of_two,
+        ) = expected;
+
+        // Act
+        let actual = BitInfo::new(value, size);
+
+        // Assert
+        assert_eq!(
+            actual,
+            BitInfo {
+                width,
+                popcount,
+                leading_zeros,
+                trailing_zeros,
+                highest_bit,
+                unsigned_width,
+                signed_width,
+                power_of_two,
+            }
+        );
+    }
+}
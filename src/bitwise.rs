@@ -0,0 +1,122 @@
+use crate::LabelledOutputs;
+use clap::Args;
+
+/// Options of the bitwise command
+#[derive(Args, Clone, Debug)]
+pub struct BitwiseOptions {
+    /// The first number, a
+    pub first: String,
+    /// The second number, b
+    pub second: String,
+}
+
+/// The bitwise operations of two bit patterns within a mask, labelled in the order they should be
+/// displayed
+pub(crate) fn table(a: u128, b: u128, mask: u128) -> [(&'static str, u128); 6] {
+    [
+        ("a AND b", a & b),
+        ("a OR b", a | b),
+        ("a XOR b", a ^ b),
+        ("NOT a", !a & mask),
+        ("NOT b", !b & mask),
+        ("a AND NOT b", a & !b & mask),
+    ]
+}
+
+/// Lays out the outputs of the bitwise command as a table with a row for each operation and a
+/// column for each output type
+pub fn bitwise_table(outputs: &LabelledOutputs) -> String {
+    let header = std::iter::once(String::from("Operation")).chain(
+        outputs.first().into_iter().flat_map(|(_, outputs)| {
+            outputs
+                .iter()
+                .map(|(number_type, _)| number_type.to_string())
+        }),
+    );
+    let rows: Vec<Vec<String>> = std::iter::once(header.collect())
+        .chain(outputs.iter().map(|(label, outputs)| {
+            std::iter::once(label.to_string())
+                .chain(outputs.iter().map(|(_, output)| output.clone()))
+                .collect()
+        }))
+        .collect();
+
+    let widths: Vec<usize> = (0..rows[0].len())
+        .map(|column| {
+            rows.iter()
+                .map(|row| row[column].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    rows.iter()
+        .map(|row| {
+            row.iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+                .collect::<Vec<String>>()
+                .join("  ")
+                .trim_end()
+                .to_string()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NumberType;
+
+    #[test]
+    fn table_should_combine_patterns() {
+        // Act
+        let actual = table(0b1100, 0b1010, 0xf);
+
+        // Assert
+        assert_eq!(
+            actual,
+            [
+                ("a AND b", 0b1000),
+                ("a OR b", 0b1110),
+                ("a XOR b", 0b0110),
+                ("NOT a", 0b0011),
+                ("NOT b", 0b0101),
+                ("a AND NOT b", 0b0100),
+            ]
+        );
+    }
+
+    #[test]
+    fn bitwise_table_should_align_columns() {
+        // Arrange
+        let outputs: LabelledOutputs = vec![
+            (
+                "a",
+                vec![
+                    (NumberType::Integer, String::from("12")),
+                    (NumberType::Hexadecimal, String::from("0c")),
+                ],
+            ),
+            (
+                "NOT a",
+                vec![
+                    (NumberType::Integer, String::from("243")),
+                    (NumberType::Hexadecimal, String::from("f3")),
+                ],
+            ),
+        ];
+
+        // Act
+        let actual = bitwise_table(&outputs);
+
+        // Assert
+        assert_eq!(
+            actual,
+            "Operation  Integer  Hexadecimal\n\
+             a          12       0c\n\
+             NOT a      243      f3"
+        );
+    }
+}
@@ -0,0 +1,230 @@
+use crate::bytes::{pattern_of_bytes, value_bytes, Endian};
+use crate::{IntegerSize, NumberFormatter, NumberFormatterError, Value};
+use clap::ArgEnum;
+
+/// Source code syntax byte array literals are written in
+#[derive(ArgEnum, Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum ByteStyle {
+    /// An array such as `[0xde, 0xad]`
+    #[default]
+    Rust,
+    /// An initializer list such as `{0xde, 0xad}`
+    C,
+    /// A bytes literal such as `b"\xde\xad"`
+    Python,
+}
+
+/// Formatter writing the bytes of a number's bit pattern as a byte array literal, at the integer
+/// size or else the narrowest width holding the number
+pub(crate) struct ByteArrayNumberFormatter {
+    pub style: ByteStyle,
+    pub endian: Endian,
+    pub uppercase: bool,
+    pub size: Option<IntegerSize>,
+}
+
+impl ByteArrayNumberFormatter {
+    fn format_value(&self, value: Value) -> String {
+        let size = self.size.unwrap_or_else(|| IntegerSize::holding(value));
+        let hex: Vec<String> = value_bytes(value, size, self.endian)
+            .iter()
+            .map(|byte| {
+                if self.uppercase {
+                    format!("{:02X}", byte)
+                } else {
+                    format!("{:02x}", byte)
+                }
+            })
+            .collect();
+
+        match self.style {
+            ByteStyle::Rust => format!("[0x{}]", hex.join(", 0x")),
+            ByteStyle::C => format!("{{0x{}}}", hex.join(", 0x")),
+            ByteStyle::Python => format!("b\"\\x{}\"", hex.join("\\x")),
+        }
+    }
+
+    /// Reads the bytes of a literal in any of the styles, in the order they are written
+    fn parse_bytes(&self, literal: &str) -> anyhow::Result<Vec<u8>, NumberFormatterError> {
+        let literal = literal.trim();
+
+        if let Some(string) = literal
+            .strip_prefix("b\"")
+            .or_else(|| literal.strip_prefix("b'"))
+        {
+            let string = string
+                .strip_suffix(['"', '\''])
+                .ok_or(NumberFormatterError::InvalidDigit)?;
+            return parse_escaped(string);
+        }
+
+        let elements = literal
+            .strip_prefix('[')
+            .and_then(|literal| literal.strip_suffix(']'))
+            .or_else(|| {
+                literal
+                    .strip_prefix('{')
+                    .and_then(|literal| literal.strip_suffix('}'))
+            })
+            .unwrap_or(literal);
+
+        elements
+            .split(',')
+            .map(str::trim)
+            .filter(|element| !element.is_empty())
+            .map(|element| {
+                let element = element.trim_end_matches("u8");
+                match element.get(..2) {
+                    Some("0x" | "0X") => u8::from_str_radix(&element[2..], 16),
+                    _ => element.parse::<u8>(),
+                }
+                .map_err(|_| NumberFormatterError::InvalidDigit)
+            })
+            .collect()
+    }
+
+    /// Reads the bit pattern held by the bytes of a literal
+    fn read_pattern(&self, literal: &str) -> anyhow::Result<u128, NumberFormatterError> {
+        pattern_of_bytes(self.parse_bytes(literal)?, self.endian)
+    }
+}
+
+/// Reads the bytes of a Python style bytes literal body, made of `\xNN` escapes and ASCII characters
+fn parse_escaped(string: &str) -> anyhow::Result<Vec<u8>, NumberFormatterError> {
+    let mut bytes = Vec::new();
+    let mut chars = string.chars();
+
+    while let Some(c) = chars.next() {
+        let byte = match c {
+            '\\' => match chars.next() {
+                Some('x') => {
+                    let digits: String = chars.by_ref().take(2).collect();
+                    u8::from_str_radix(&digits, 16)
+                        .map_err(|_| NumberFormatterError::InvalidDigit)?
+                }
+                Some('n') => b'\n',
+                Some('r') => b'\r',
+                Some('t') => b'\t',
+                Some('0') => 0,
+                Some(c @ ('\\' | '"' | '\'')) => c as u8,
+                _ => return Err(NumberFormatterError::InvalidDigit),
+            },
+            c if c.is_ascii() => c as u8,
+            _ => return Err(NumberFormatterError::InvalidDigit),
+        };
+        bytes.push(byte);
+    }
+
+    Ok(bytes)
+}
+
+impl NumberFormatter for ByteArrayNumberFormatter {
+    fn read(&self, literal: &str) -> anyhow::Result<i128, NumberFormatterError> {
+        i128::try_from(self.read_pattern(literal)?).map_err(|_| NumberFormatterError::TooLargeError)
+    }
+
+    fn format(&self, num: i128) -> anyhow::Result<String, NumberFormatterError> {
+        Ok(self.format_value(Value::Signed(num)))
+    }
+
+    fn read_unsigned(&self, literal: &str) -> anyhow::Result<u128, NumberFormatterError> {
+        self.read_pattern(literal)
+    }
+
+    fn format_unsigned(&self, num: u128) -> anyhow::Result<String, NumberFormatterError> {
+        Ok(self.format_value(Value::Unsigned(num)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    fn formatter(style: ByteStyle, endian: Endian) -> ByteArrayNumberFormatter {
+        ByteArrayNumberFormatter {
+            style,
+            endian,
+            uppercase: false,
+            size: None,
+        }
+    }
+
+    #[test_case(ByteStyle::Rust, Endian::Big, 0xdeadbeef, "[0xde, 0xad, 0xbe, 0xef]")]
+    #[test_case(ByteStyle::C, Endian::Big, 0xdeadbeef, "{0xde, 0xad, 0xbe, 0xef}")]
+    #[test_case(
+        ByteStyle::Python,
+        Endian::Big,
+        0xdeadbeef,
+        "b\"\\xde\\xad\\xbe\\xef\""
+    )]
+    #[test_case(ByteStyle::Rust, Endian::Little, 0x1234, "[0x34, 0x12]")]
+    #[test_case(ByteStyle::Rust, Endian::Big, -2, "[0xfe]"; "negative")]
+    fn byte_array_number_formatter_should_format_i128(
+        style: ByteStyle,
+        endian: Endian,
+        input: i128,
+        expected: &str,
+    ) {
+        // Act
+        let actual_output = formatter(style, endian).format(input);
+
+        // Assert
+        assert_eq!(actual_output.unwrap(), expected);
+    }
+
+    #[test]
+    fn byte_array_number_formatter_should_pad_to_size() {
+        // Arrange
+        let formatter = ByteArrayNumberFormatter {
+            uppercase: true,
+            size: Some(IntegerSize {
+                bits: 32,
+                signed: false,
+            }),
+            ..formatter(ByteStyle::C, Endian::Big)
+        };
+
+        // Act
+        let actual_output = formatter.format(0xab);
+
+        // Assert
+        assert_eq!(actual_output.unwrap(), "{0x00, 0x00, 0x00, 0xAB}");
+    }
+
+    #[test_case("[0xde, 0xad, 0xbe, 0xef]", Endian::Big, 0xdeadbeef)]
+    #[test_case("{0xde,0xad}", Endian::Big, 0xdead)]
+    #[test_case("[222u8, 173u8]", Endian::Big, 0xdead; "decimal")]
+    #[test_case("b\"\\xde\\xad\"", Endian::Big, 0xdead)]
+    #[test_case("b'AB'", Endian::Big, 0x4142; "ascii")]
+    #[test_case("[0x34, 0x12]", Endian::Little, 0x1234)]
+    fn byte_array_number_formatter_should_read_number(input: &str, endian: Endian, expected: i128) {
+        // Act
+        let actual_number = formatter(ByteStyle::Rust, endian).read(input);
+
+        // Assert
+        assert_eq!(actual_number.unwrap(), expected);
+    }
+
+    #[test_case("[]", NumberFormatterError::Empty; "empty")]
+    #[test_case("[0x100]", NumberFormatterError::InvalidDigit; "not a byte")]
+    #[test_case("b\"\\xzz\"", NumberFormatterError::InvalidDigit; "invalid escape")]
+    #[test_case(
+        "[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17]",
+        NumberFormatterError::TooLargeError;
+        "too many bytes"
+    )]
+    fn byte_array_number_formatter_should_fail_to_read(
+        input: &str,
+        expected: NumberFormatterError,
+    ) {
+        // Act
+        let actual_number = formatter(ByteStyle::Rust, Endian::Big).read(input);
+
+        // Assert
+        assert_eq!(
+            std::mem::discriminant(&actual_number.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+}
@@ -0,0 +1,175 @@
+use crate::{IntegerSize, NumberFormatterError, Value};
+use clap::ArgEnum;
+
+/// Order the bytes of a number are written in
+#[derive(ArgEnum, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Endian {
+    /// Most significant byte first
+    Big,
+    /// Least significant byte first
+    Little,
+}
+
+/// Puts bytes or words given least significant first into the chosen order, or back again
+pub(crate) fn to_endian<T>(mut items: Vec<T>, endian: Endian) -> Vec<T> {
+    if endian == Endian::Big {
+        items.reverse();
+    }
+    items
+}
+
+/// The bytes of a value's bit pattern at a size, in the chosen byte order
+pub(crate) fn value_bytes(value: Value, size: IntegerSize, endian: Endian) -> Vec<u8> {
+    let pattern = size.pattern_of(value);
+    let bytes = (0..size.bits.div_ceil(8))
+        .map(|index| (pattern >> (index * 8)) as u8)
+        .collect();
+
+    to_endian(bytes, endian)
+}
+
+/// The bit pattern held by up to 16 bytes written in the chosen byte order
+pub(crate) fn pattern_of_bytes(
+    bytes: Vec<u8>,
+    endian: Endian,
+) -> anyhow::Result<u128, NumberFormatterError> {
+    if bytes.is_empty() {
+        return Err(NumberFormatterError::Empty);
+    }
+    if bytes.len() > 16 {
+        return Err(NumberFormatterError::TooLargeError);
+    }
+
+    Ok(to_endian(bytes, endian)
+        .iter()
+        .rev()
+        .fold(0, |pattern, byte| pattern << 8 | *byte as u128))
+}
+
+/// Parses hex bytes written in the chosen byte order, returning them least significant first
+pub(crate) fn read_hex_bytes(
+    input: &str,
+    endian: Endian,
+) -> anyhow::Result<Vec<u8>, NumberFormatterError> {
+    Ok(to_endian(parse_hex_bytes(input)?, endian))
+}
+
+/// Formats bytes given least significant first as hex in the chosen byte order
+pub(crate) fn write_hex_bytes(bytes: Vec<u8>, endian: Endian) -> String {
+    format_hex_bytes(&to_endian(bytes, endian))
+}
+
+/// Parses hex bytes such as `e5 8e 26`, `0xe5,0x8e,0x26` or `e58e26` into bytes
+pub(crate) fn parse_hex_bytes(input: &str) -> anyhow::Result<Vec<u8>, NumberFormatterError> {
+    let digits: String = input
+        .split(|c: char| c.is_whitespace() || c == ',' || c == ':')
+        .map(|token| token.trim_start_matches("0x").trim_start_matches("0X"))
+        .collect();
+
+    if digits.is_empty() {
+        return Err(NumberFormatterError::Empty);
+    }
+
+    if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(NumberFormatterError::InvalidDigit);
+    }
+
+    if !digits.len().is_multiple_of(2) {
+        return Err(NumberFormatterError::IncompleteByte);
+    }
+
+    (0..digits.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&digits[i..i + 2], 16).map_err(|op| op.into()))
+        .collect()
+}
+
+/// Formats bytes as space separated lowercase hex, e.g. `e5 8e 26`
+pub(crate) fn format_hex_bytes(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("e5 8e 26", &[0xe5, 0x8e, 0x26] ; "spaces")]
+    #[test_case("0xE5,0x8E,0x26", &[0xe5, 0x8e, 0x26] ; "prefixed")]
+    #[test_case("e58e26", &[0xe5, 0x8e, 0x26] ; "contiguous")]
+    #[test_case("de:ad", &[0xde, 0xad] ; "colons")]
+    fn parse_hex_bytes_should_return_bytes(input: &str, expected: &[u8]) {
+        // Act
+        let actual = parse_hex_bytes(input);
+
+        // Assert
+        assert!(actual.is_ok());
+        assert_eq!(expected, actual.unwrap());
+    }
+
+    #[test_case("e5 8", NumberFormatterError::IncompleteByte ; "odd digits")]
+    #[test_case("zz", NumberFormatterError::InvalidDigit ; "invalid digit")]
+    #[test_case(" ", NumberFormatterError::Empty ; "empty")]
+    fn parse_hex_bytes_should_fail(input: &str, expected: NumberFormatterError) {
+        // Act
+        let actual = parse_hex_bytes(input);
+
+        // Assert
+        assert_eq!(
+            std::mem::discriminant(&actual.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+
+    #[test_case(Endian::Little, "01 02 03")]
+    #[test_case(Endian::Big, "03 02 01")]
+    fn write_hex_bytes_should_order_bytes(endian: Endian, expected: &str) {
+        // Act
+        let actual = write_hex_bytes(vec![0x01, 0x02, 0x03], endian);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test_case(Value::Signed(0x1234), 32, Endian::Big, &[0x00, 0x00, 0x12, 0x34])]
+    #[test_case(Value::Signed(0x1234), 16, Endian::Little, &[0x34, 0x12])]
+    #[test_case(Value::Signed(-2), 16, Endian::Big, &[0xff, 0xfe]; "negative")]
+    fn value_bytes_should_order_pattern_bytes(
+        value: Value,
+        bits: u32,
+        endian: Endian,
+        expected: &[u8],
+    ) {
+        // Arrange
+        let size = IntegerSize { bits, signed: true };
+
+        // Act
+        let actual = value_bytes(value, size, endian);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test_case(Endian::Little, &[0x01, 0x02])]
+    #[test_case(Endian::Big, &[0x02, 0x01])]
+    fn read_hex_bytes_should_return_least_significant_first(endian: Endian, expected: &[u8]) {
+        // Act
+        let actual = read_hex_bytes("01 02", endian);
+
+        // Assert
+        assert_eq!(actual.unwrap(), expected);
+    }
+
+    #[test]
+    fn format_hex_bytes_should_separate_bytes() {
+        // Act
+        let actual = format_hex_bytes(&[0x0e, 0x8e, 0x26]);
+
+        // Assert
+        assert_eq!(actual, "0e 8e 26");
+    }
+}
@@ -0,0 +1,137 @@
+use crate::expression::{self, Operator};
+use crate::{NumberFormatterError, NumberType, Value};
+use clap::{ArgEnum, Args};
+
+/// Options of the calc command
+#[derive(Args, Clone, Debug)]
+pub struct CalcOptions {
+    /// The operation to apply
+    #[clap(arg_enum)]
+    pub operator: CalcOperator,
+    /// The first operand
+    pub first: String,
+    /// The second operand
+    pub second: String,
+}
+
+/// Arithmetic operations of the calc command
+#[derive(ArgEnum, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CalcOperator {
+    /// The sum of the operands
+    Add,
+    /// The first operand minus the second
+    Sub,
+    /// The product of the operands
+    Mul,
+    /// The first operand divided by the second, truncated towards zero
+    Div,
+    /// The remainder of dividing the first operand by the second, with the sign of the first
+    Mod,
+    /// The first operand to the power of the second
+    Pow,
+}
+
+/// The result of the calc command formatted as every output type
+#[derive(Debug, PartialEq, Eq)]
+pub struct Calculation {
+    pub outputs: Vec<(NumberType, String)>,
+    /// Describes how the result was truncated to fit the integer size, if it was
+    pub warning: Option<String>,
+}
+
+/// Raises a value to a whole power by repeated squaring, failing where the result does not fit
+fn power(base: Value, exponent: Value) -> anyhow::Result<Value, NumberFormatterError> {
+    let mut exponent = match exponent {
+        Value::Signed(exponent) if exponent < 0 => return Err(NumberFormatterError::NotAnInteger),
+        Value::Signed(exponent) => exponent as u128,
+        Value::Unsigned(exponent) => exponent,
+    };
+    let (negative, mut magnitude) = base.sign_magnitude();
+    let negative = negative && exponent % 2 == 1;
+    let overflow = || {
+        if negative {
+            NumberFormatterError::TooSmallError
+        } else {
+            NumberFormatterError::TooLargeError
+        }
+    };
+
+    let mut result = 1u128;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result.checked_mul(magnitude).ok_or_else(overflow)?;
+        }
+        exponent >>= 1;
+        if exponent > 0 {
+            magnitude = magnitude.checked_mul(magnitude).ok_or_else(overflow)?;
+        }
+    }
+
+    Value::from_sign_magnitude(negative, result)
+}
+
+/// Applies an operation of the calc command to two operands
+pub(crate) fn calculate(
+    operator: CalcOperator,
+    first: Value,
+    second: Value,
+) -> anyhow::Result<Value, NumberFormatterError> {
+    let operator = match operator {
+        CalcOperator::Add => Operator::Add,
+        CalcOperator::Sub => Operator::Subtract,
+        CalcOperator::Mul => Operator::Multiply,
+        CalcOperator::Div => Operator::Divide,
+        CalcOperator::Mod => Operator::Remainder,
+        CalcOperator::Pow => return power(first, second),
+    };
+
+    expression::apply(operator, first, second, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(CalcOperator::Add, 5, 3, Value::Signed(8))]
+    #[test_case(CalcOperator::Sub, 3, 5, Value::Signed(-2); "subtract")]
+    #[test_case(CalcOperator::Mul, -4, 6, Value::Signed(-24); "multiply")]
+    #[test_case(CalcOperator::Div, 7, -2, Value::Signed(-3); "divide")]
+    #[test_case(CalcOperator::Mod, -7, 3, Value::Signed(-1); "remainder")]
+    #[test_case(CalcOperator::Pow, 2, 10, Value::Signed(1024); "power")]
+    #[test_case(CalcOperator::Pow, -3, 3, Value::Signed(-27); "odd power of negative")]
+    #[test_case(CalcOperator::Pow, 0, 0, Value::Signed(1); "zero to the zero")]
+    #[test_case(CalcOperator::Pow, -2, 127, Value::Signed(i128::MIN); "smallest power")]
+    fn calculate_should_apply_operator(
+        operator: CalcOperator,
+        first: i128,
+        second: i128,
+        expected: Value,
+    ) {
+        // Act
+        let actual = calculate(operator, Value::Signed(first), Value::Signed(second));
+
+        // Assert
+        assert_eq!(actual.unwrap(), expected);
+    }
+
+    #[test_case(CalcOperator::Div, 1, 0, NumberFormatterError::DivisionByZero; "division by zero")]
+    #[test_case(CalcOperator::Pow, 2, 128, NumberFormatterError::TooLargeError; "power overflows")]
+    #[test_case(CalcOperator::Pow, -2, 129, NumberFormatterError::TooSmallError; "negative power overflows")]
+    #[test_case(CalcOperator::Pow, 2, -1, NumberFormatterError::NotAnInteger; "negative exponent")]
+    fn calculate_should_fail(
+        operator: CalcOperator,
+        first: i128,
+        second: i128,
+        expected: NumberFormatterError,
+    ) {
+        // Act
+        let actual = calculate(operator, Value::Signed(first), Value::Signed(second));
+
+        // Assert
+        assert_eq!(
+            std::mem::discriminant(&actual.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+}
@@ -0,0 +1,68 @@
+use clap::Args;
+
+/// Options of the checksum command
+#[derive(Args, Clone, Debug)]
+pub struct ChecksumOptions {
+    /// The number whose bytes are summed, at the integer size or else the fewest bytes holding it,
+    /// in --endian order (big endian by default), an odd last byte being padded with zero. Leading
+    /// zero bytes are only summed at a --width or --size wide enough to hold them
+    pub number: String,
+}
+
+/// The RFC 1071 one's complement sum of bytes taken as big endian 16-bit words, an odd last byte
+/// being padded with zero. Carries out of the top bit are added back in at the bottom.
+pub(crate) fn ones_complement_sum(bytes: &[u8]) -> u16 {
+    let sum = bytes
+        .chunks(2)
+        .map(|word| u16::from_be_bytes([word[0], word.get(1).copied().unwrap_or(0)]) as u32)
+        .fold(0u32, |sum, word| {
+            let sum = sum + word;
+            (sum & 0xffff) + (sum >> 16)
+        });
+
+    sum as u16
+}
+
+/// The internet checksum of bytes as carried in IP, TCP and UDP headers, the complement of their
+/// one's complement sum. Bytes holding their own checksum give zero.
+pub(crate) fn internet_checksum(bytes: &[u8]) -> u16 {
+    !ones_complement_sum(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(&[0x00, 0x01, 0xf2, 0x03, 0xf4, 0xf5, 0xf6, 0xf7], 0xddf2; "rfc 1071 example")]
+    #[test_case(&[0xff, 0xff, 0x00, 0x01], 0x0001; "end around carry")]
+    #[test_case(&[0x12, 0x34, 0x56], 0x6834; "odd length")]
+    #[test_case(&[], 0; "empty")]
+    fn ones_complement_sum_should_fold_carries(bytes: &[u8], expected: u16) {
+        // Act
+        let actual = ones_complement_sum(bytes);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn internet_checksum_should_verify_ipv4_header() {
+        // Arrange
+        let header = [
+            0x45, 0x00, 0x00, 0x73, 0x00, 0x00, 0x40, 0x00, 0x40, 0x11, 0xb8, 0x61, 0xc0, 0xa8,
+            0x00, 0x01, 0xc0, 0xa8, 0x00, 0xc7,
+        ];
+        let mut zeroed = header;
+        zeroed[10] = 0;
+        zeroed[11] = 0;
+
+        // Act
+        let checksum = internet_checksum(&zeroed);
+        let verified = internet_checksum(&header);
+
+        // Assert
+        assert_eq!(checksum, 0xb861);
+        assert_eq!(verified, 0);
+    }
+}
@@ -0,0 +1,98 @@
+use crate::{LabelledOutputs, NumberFormatterError};
+use clap::Args;
+
+/// Options of the cidr command
+#[derive(Args, Clone, Debug)]
+pub struct CidrOptions {
+    /// A prefix length such as /20, or a netmask such as 255.255.240.0 to find the prefix length of
+    pub mask: String,
+}
+
+/// The netmask of a CIDR prefix and the number of hosts it leaves room for
+#[derive(Debug, PartialEq, Eq)]
+pub struct CidrInfo {
+    pub outputs: LabelledOutputs,
+    pub prefix: u32,
+    /// Addresses hosts can be given, none when there are 2^128 of them
+    pub usable_hosts: Option<u128>,
+}
+
+/// Address width the cidr command works at unless an integer size is chosen
+pub(crate) const IPV4_WIDTH: u32 = 32;
+
+/// The netmask with the top `prefix` bits of `width` set
+pub(crate) fn netmask(prefix: u32, width: u32) -> u128 {
+    match prefix {
+        0 => 0,
+        prefix => (u128::MAX << (128 - prefix)) >> (128 - width),
+    }
+}
+
+/// The prefix length of a netmask, which must be a run of set bits followed by clear bits
+pub(crate) fn prefix_length(mask: u128, width: u32) -> anyhow::Result<u32, NumberFormatterError> {
+    let prefix = (mask << (128 - width)).leading_ones().min(width);
+
+    if netmask(prefix, width) == mask {
+        Ok(prefix)
+    } else {
+        Err(NumberFormatterError::InvalidNetmask)
+    }
+}
+
+/// Addresses left for hosts by a prefix. IPv4 networks lose the network and broadcast addresses,
+/// except point-to-point /31 networks (RFC 3021) and single host /32 ones.
+pub(crate) fn usable_hosts(prefix: u32, width: u32) -> Option<u128> {
+    let host_bits = width - prefix;
+    let addresses = 1u128.checked_shl(host_bits)?;
+
+    if width == IPV4_WIDTH && host_bits >= 2 {
+        Some(addresses - 2)
+    } else {
+        Some(addresses)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(20, 32, 0xfffff000)]
+    #[test_case(0, 32, 0)]
+    #[test_case(32, 32, 0xffffffff)]
+    #[test_case(64, 128, 0xffffffff_ffffffff_00000000_00000000; "ipv6")]
+    fn netmask_should_set_prefix_bits(prefix: u32, width: u32, expected: u128) {
+        // Act
+        let actual = netmask(prefix, width);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test_case(0xfffff000, 32, Ok(20))]
+    #[test_case(0xffffffff, 32, Ok(32))]
+    #[test_case(0, 32, Ok(0))]
+    #[test_case(0xffff0f00, 32, Err(()); "not contiguous")]
+    #[test_case(0x00ffffff, 32, Err(()); "host bits set")]
+    fn prefix_length_should_validate_contiguity(mask: u128, width: u32, expected: Result<u32, ()>) {
+        // Act
+        let actual = prefix_length(mask, width);
+
+        // Assert
+        assert_eq!(actual.map_err(|_| ()), expected);
+    }
+
+    #[test_case(24, 32, Some(254))]
+    #[test_case(20, 32, Some(4094))]
+    #[test_case(31, 32, Some(2); "point to point")]
+    #[test_case(32, 32, Some(1); "single host")]
+    #[test_case(64, 128, Some(1 << 64); "ipv6")]
+    #[test_case(0, 128, None; "every ipv6 address")]
+    fn usable_hosts_should_count_addresses(prefix: u32, width: u32, expected: Option<u128>) {
+        // Act
+        let actual = usable_hosts(prefix, width);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,91 @@
+use crate::{LabelledOutputs, NumberFormatterError};
+use clap::Args;
+
+/// Options of the clmul command
+#[derive(Args, Clone, Debug)]
+pub struct ClmulOptions {
+    /// The first polynomial, with bit n as the coefficient of x^n
+    pub first: String,
+    /// The second polynomial, with bit n as the coefficient of x^n
+    pub second: String,
+}
+
+/// The carry-less product of two polynomials over GF(2), with each polynomial written out
+#[derive(Debug, PartialEq, Eq)]
+pub struct ClmulInfo {
+    /// The polynomials and their product, such as `x^3 + x + 1`
+    pub polynomials: Vec<(&'static str, String)>,
+    pub outputs: LabelledOutputs,
+}
+
+/// Multiplies two bit patterns as polynomials over GF(2), adding the shifted copies of the first by
+/// XOR so that no carries are made, failing where the product needs more than 128 bits
+pub(crate) fn carryless_multiply(a: u128, b: u128) -> anyhow::Result<u128, NumberFormatterError> {
+    let degree = |pattern: u128| pattern.checked_ilog2().unwrap_or(0);
+    if a != 0 && b != 0 && degree(a) + degree(b) >= 128 {
+        return Err(NumberFormatterError::TooLargeError);
+    }
+
+    Ok((0..128)
+        .filter(|bit| (b >> bit) & 1 == 1)
+        .fold(0, |product, bit| product ^ a << bit))
+}
+
+/// Writes a bit pattern as a polynomial with the highest power first, such as `x^4 + x + 1`
+pub(crate) fn polynomial(pattern: u128) -> String {
+    match pattern {
+        0 => String::from("0"),
+        pattern => (0..128)
+            .rev()
+            .filter(|bit| (pattern >> bit) & 1 == 1)
+            .map(|bit| match bit {
+                0 => String::from("1"),
+                1 => String::from("x"),
+                bit => format!("x^{}", bit),
+            })
+            .collect::<Vec<String>>()
+            .join(" + "),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(0b11, 0b11, 0b101)]
+    #[test_case(0b1011, 0b110, 0b111010; "no carries")]
+    #[test_case(0, u128::MAX, 0; "zero")]
+    #[test_case(1 << 64, 1 << 63, 1 << 127; "widest")]
+    fn carryless_multiply_should_multiply_polynomials(a: u128, b: u128, expected: u128) {
+        // Act
+        let actual = carryless_multiply(a, b);
+
+        // Assert
+        assert_eq!(actual.unwrap(), expected);
+    }
+
+    #[test]
+    fn carryless_multiply_should_fail_when_too_large() {
+        // Act
+        let actual = carryless_multiply(1 << 64, 1 << 64);
+
+        // Assert
+        assert_eq!(
+            std::mem::discriminant(&actual.unwrap_err()),
+            std::mem::discriminant(&NumberFormatterError::TooLargeError)
+        );
+    }
+
+    #[test_case(0b10011, "x^4 + x + 1")]
+    #[test_case(0b10, "x"; "x")]
+    #[test_case(1, "1"; "one")]
+    #[test_case(0, "0"; "zero")]
+    fn polynomial_should_write_powers(pattern: u128, expected: &str) {
+        // Act
+        let actual = polynomial(pattern);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+}
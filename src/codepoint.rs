@@ -0,0 +1,262 @@
+use crate::{bytes, NumberFormatter, NumberFormatterError};
+
+/// The last codepoint in the Unicode codespace
+const MAX_CODEPOINT: i128 = 0x10FFFF;
+
+#[derive(Default)]
+pub(crate) struct CodepointNumberFormatter {
+    #[cfg(feature = "unicode-info")]
+    pub unicode_info: bool,
+}
+impl NumberFormatter for CodepointNumberFormatter {
+    fn read(&self, number: &str) -> anyhow::Result<i128, NumberFormatterError> {
+        let codepoint = number.split_whitespace().next().unwrap_or_default();
+        let digits = codepoint
+            .strip_prefix("U+")
+            .or_else(|| codepoint.strip_prefix("u+"))
+            .unwrap_or(codepoint);
+
+        if digits.is_empty() {
+            return Err(NumberFormatterError::Empty);
+        }
+
+        let num = i128::from_str_radix(digits, 16)?;
+        if num > MAX_CODEPOINT {
+            return Err(NumberFormatterError::TooLargeError);
+        }
+
+        Ok(num)
+    }
+
+    fn format(&self, num: i128) -> anyhow::Result<String, NumberFormatterError> {
+        if num < 0 {
+            return Err(NumberFormatterError::TooSmallError);
+        }
+        if num > MAX_CODEPOINT {
+            return Err(NumberFormatterError::TooLargeError);
+        }
+
+        let mut codepoint = format!("U+{:04X}", num);
+        // Characters that would change or hide the rest of the line, such as controls and
+        // bidirectional overrides, are left out as the Character formatter escapes them
+        if let Some(c) =
+            char::from_u32(num as u32).filter(|c| !c.is_whitespace() && c.escape_debug().eq([*c]))
+        {
+            codepoint = format!("{} {}", codepoint, c);
+        }
+
+        #[cfg(feature = "unicode-info")]
+        if self.unicode_info {
+            codepoint = format!(
+                "{} {}",
+                codepoint,
+                crate::unicode_info::describe(num as u32)
+            );
+        }
+
+        Ok(codepoint)
+    }
+}
+
+/// Converts a codepoint into its character, checking that it is a Unicode scalar value
+fn to_char(num: i128) -> anyhow::Result<char, NumberFormatterError> {
+    if num < 0 {
+        return Err(NumberFormatterError::TooSmallError);
+    }
+    if num > MAX_CODEPOINT {
+        return Err(NumberFormatterError::TooLargeError);
+    }
+
+    char::from_u32(num as u32).ok_or(NumberFormatterError::InvalidCharacter)
+}
+
+pub(crate) struct CharacterNumberFormatter;
+impl NumberFormatter for CharacterNumberFormatter {
+    fn read(&self, number: &str) -> anyhow::Result<i128, NumberFormatterError> {
+        let character = if number.len() > 2 && number.starts_with('\'') && number.ends_with('\'') {
+            &number[1..number.len() - 1]
+        } else {
+            number
+        };
+
+        let mut chars = character.chars();
+        match (chars.next(), chars.next()) {
+            (None, _) => Err(NumberFormatterError::Empty),
+            (Some(c), None) => Ok(c as i128),
+            _ => Err(NumberFormatterError::InvalidCharacter),
+        }
+    }
+
+    fn format(&self, num: i128) -> anyhow::Result<String, NumberFormatterError> {
+        let c = to_char(num)?;
+        let mut utf8 = [0; 4];
+
+        Ok(format!(
+            "{:?} (UTF-8 {})",
+            c,
+            bytes::format_hex_bytes(c.encode_utf8(&mut utf8).as_bytes())
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("U+0041", 0x41)]
+    #[test_case("u+1f600", 0x1F600)]
+    #[test_case("U+20AC €", 0x20AC; "with character")]
+    #[test_case("10FFFF", 0x10FFFF)]
+    fn codepoint_number_formatter_should_read(input: &str, expected: i128) {
+        // Arrange
+        let formatter = CodepointNumberFormatter::default();
+
+        // Act
+        let actual = formatter.read(input);
+
+        // Assert
+        assert!(actual.is_ok());
+        assert_eq!(actual.unwrap(), expected);
+    }
+
+    #[test_case("U+", NumberFormatterError::Empty)]
+    #[test_case("U+110000", NumberFormatterError::TooLargeError)]
+    #[test_case("U+12G4", NumberFormatterError::InvalidDigit)]
+    fn codepoint_number_formatter_should_not_read(input: &str, expected: NumberFormatterError) {
+        // Arrange
+        let formatter = CodepointNumberFormatter::default();
+
+        // Act
+        let actual = formatter.read(input);
+
+        // Assert
+        assert!(actual.is_err());
+        assert_eq!(
+            std::mem::discriminant(&actual.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+
+    #[test_case(0x41, "U+0041 A")]
+    #[test_case(0x1F600, "U+1F600 😀")]
+    #[test_case(0x0A, "U+000A"; "control character")]
+    #[test_case(0x20, "U+0020"; "space")]
+    #[test_case(0xD800, "U+D800"; "surrogate")]
+    #[test_case(0x202E, "U+202E"; "right to left override")]
+    #[test_case(0x200B, "U+200B"; "zero width space")]
+    #[test_case(0xFEFF, "U+FEFF"; "byte order mark")]
+    #[test_case(0xE000, "U+E000"; "private use")]
+    #[test_case(0x10FFFF, "U+10FFFF"; "noncharacter")]
+    fn codepoint_number_formatter_should_format(num: i128, expected: &str) {
+        // Arrange
+        let formatter = CodepointNumberFormatter::default();
+
+        // Act
+        let actual = formatter.format(num);
+
+        // Assert
+        assert!(actual.is_ok());
+        assert_eq!(actual.unwrap(), expected);
+    }
+
+    #[test_case(-1, NumberFormatterError::TooSmallError)]
+    #[test_case(0x110000, NumberFormatterError::TooLargeError)]
+    fn codepoint_number_formatter_should_not_format(num: i128, expected: NumberFormatterError) {
+        // Arrange
+        let formatter = CodepointNumberFormatter::default();
+
+        // Act
+        let actual = formatter.format(num);
+
+        // Assert
+        assert!(actual.is_err());
+        assert_eq!(
+            std::mem::discriminant(&actual.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+
+    #[test_case("'A'", 0x41)]
+    #[test_case("€", 0x20AC)]
+    #[test_case("'''", 0x27; "quoted quote")]
+    #[test_case("'", 0x27; "quote")]
+    #[test_case("😀", 0x1F600)]
+    fn character_number_formatter_should_read(input: &str, expected: i128) {
+        // Arrange
+        let formatter = CharacterNumberFormatter {};
+
+        // Act
+        let actual = formatter.read(input);
+
+        // Assert
+        assert!(actual.is_ok());
+        assert_eq!(actual.unwrap(), expected);
+    }
+
+    #[test_case("", NumberFormatterError::Empty; "empty")]
+    #[test_case("ab", NumberFormatterError::InvalidCharacter)]
+    #[test_case("'ab'", NumberFormatterError::InvalidCharacter; "quoted ab")]
+    fn character_number_formatter_should_not_read(input: &str, expected: NumberFormatterError) {
+        // Arrange
+        let formatter = CharacterNumberFormatter {};
+
+        // Act
+        let actual = formatter.read(input);
+
+        // Assert
+        assert!(actual.is_err());
+        assert_eq!(
+            std::mem::discriminant(&actual.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+
+    #[test_case(0x41, "'A' (UTF-8 41)")]
+    #[test_case(0x20AC, "'€' (UTF-8 e2 82 ac)")]
+    #[test_case(0x0A, "'\\n' (UTF-8 0a)"; "newline")]
+    fn character_number_formatter_should_format(num: i128, expected: &str) {
+        // Arrange
+        let formatter = CharacterNumberFormatter {};
+
+        // Act
+        let actual = formatter.format(num);
+
+        // Assert
+        assert!(actual.is_ok());
+        assert_eq!(actual.unwrap(), expected);
+    }
+
+    #[test_case(-1, NumberFormatterError::TooSmallError)]
+    #[test_case(0xD800, NumberFormatterError::InvalidCharacter)]
+    #[test_case(0x110000, NumberFormatterError::TooLargeError)]
+    fn character_number_formatter_should_not_format(num: i128, expected: NumberFormatterError) {
+        // Arrange
+        let formatter = CharacterNumberFormatter {};
+
+        // Act
+        let actual = formatter.format(num);
+
+        // Assert
+        assert!(actual.is_err());
+        assert_eq!(
+            std::mem::discriminant(&actual.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+
+    #[cfg(feature = "unicode-info")]
+    #[test_case(0x20AC, "U+20AC € EURO SIGN (Sc, Currency Symbols)")]
+    #[test_case(0x0A, "U+000A <control> (Cc, Basic Latin)"; "control character")]
+    fn codepoint_number_formatter_should_format_unicode_info(num: i128, expected: &str) {
+        // Arrange
+        let formatter = CodepointNumberFormatter { unicode_info: true };
+
+        // Act
+        let actual = formatter.format(num);
+
+        // Assert
+        assert!(actual.is_ok());
+        assert_eq!(actual.unwrap(), expected);
+    }
+}
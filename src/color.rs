@@ -0,0 +1,365 @@
+use crate::{IntegerSize, NumberFormatter, NumberFormatterError};
+
+/// Largest color, with all four channels of RRGGBBAA set
+const MAX_COLOR: i128 = 0xffff_ffff;
+
+/// Largest color without an alpha channel
+const MAX_RGB: i128 = 0xff_ffff;
+
+/// Labels of the channels of a color, in the order they are packed
+const CHANNELS: [&str; 4] = ["R", "G", "B", "A"];
+
+/// Reads the channels of a color written as `#rrggbb` or `#rrggbbaa`, or as decimal `r,g,b` or
+/// `r,g,b,a` components, if it is written as one
+pub(crate) fn parse_color(color: &str) -> Option<anyhow::Result<Vec<u8>, NumberFormatterError>> {
+    if let Some(hex) = color.strip_prefix('#') {
+        return Some(parse_hex_channels(hex));
+    }
+
+    if !color.contains(',') {
+        return None;
+    }
+
+    let components: Vec<&str> = color.split(',').map(str::trim).collect();
+    if components.len() != 3 && components.len() != 4 {
+        return Some(Err(NumberFormatterError::InvalidColor));
+    }
+
+    Some(
+        components
+            .iter()
+            .map(|component| Ok(component.parse::<u8>()?))
+            .collect(),
+    )
+}
+
+/// Reads the channels of a color from its six or eight hex digits
+fn parse_hex_channels(hex: &str) -> anyhow::Result<Vec<u8>, NumberFormatterError> {
+    if (hex.len() != 6 && hex.len() != 8) || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(NumberFormatterError::InvalidColor);
+    }
+
+    Ok(u32::from_str_radix(hex, 16)?.to_be_bytes()[(8 - hex.len()) / 2..].to_vec())
+}
+
+/// Whether the input is written as a `#` color
+pub(crate) fn is_color(input: &str) -> bool {
+    input
+        .strip_prefix('#')
+        .is_some_and(|hex| parse_hex_channels(hex).is_ok())
+}
+
+/// Formatter splitting packed colors into their channels in decimal and hexadecimal, such as
+/// `#1e90ff (R 30 0x1e, G 144 0x90, B 255 0xff)`. Colors above 24 bits or at a 32-bit integer size
+/// are read as RRGGBBAA with an alpha channel.
+pub(crate) struct ColorNumberFormatter {
+    pub uppercase: bool,
+    pub size: Option<IntegerSize>,
+}
+
+impl NumberFormatter for ColorNumberFormatter {
+    fn read(&self, color: &str) -> anyhow::Result<i128, NumberFormatterError> {
+        if color.is_empty() {
+            return Err(NumberFormatterError::Empty);
+        }
+
+        let channels = match color
+            .strip_prefix("0x")
+            .or_else(|| color.strip_prefix("0X"))
+        {
+            Some(hex) => parse_hex_channels(hex)?,
+            None => {
+                let color = if color.starts_with('#') {
+                    color.split_whitespace().next().unwrap_or_default()
+                } else {
+                    color
+                };
+                parse_color(color).ok_or(NumberFormatterError::InvalidColor)??
+            }
+        };
+
+        Ok(channels
+            .iter()
+            .fold(0, |packed, channel| packed << 8 | *channel as i128))
+    }
+
+    fn format(&self, num: i128) -> anyhow::Result<String, NumberFormatterError> {
+        if num < 0 {
+            return Err(NumberFormatterError::TooSmallError);
+        }
+        if num > MAX_COLOR {
+            return Err(NumberFormatterError::TooLargeError);
+        }
+
+        let bytes = (num as u32).to_be_bytes();
+        let channels = if num > MAX_RGB || self.size.is_some_and(|size| size.bits == 32) {
+            &bytes[..]
+        } else {
+            &bytes[1..]
+        };
+
+        let hex = |channel: &u8| {
+            if self.uppercase {
+                format!("{:02X}", channel)
+            } else {
+                format!("{:02x}", channel)
+            }
+        };
+
+        Ok(format!(
+            "#{} ({})",
+            channels.iter().map(hex).collect::<String>(),
+            channels
+                .iter()
+                .zip(CHANNELS)
+                .map(|(channel, label)| format!("{} {} 0x{}", label, channel, hex(channel)))
+                .collect::<Vec<String>>()
+                .join(", ")
+        ))
+    }
+}
+
+/// Channel widths of a 16-bit packed pixel format, from red down to blue, with any bits left above
+/// them unused
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct PixelFormat {
+    widths: [u32; 3],
+}
+
+impl PixelFormat {
+    pub(crate) const RGB565: PixelFormat = PixelFormat { widths: [5, 6, 5] };
+    pub(crate) const RGB555: PixelFormat = PixelFormat { widths: [5, 5, 5] };
+
+    /// Bits above the channels, unused by the color
+    fn unused_bits(&self) -> u32 {
+        u16::BITS - self.widths.iter().sum::<u32>()
+    }
+
+    /// The shift of each channel, from red down to blue
+    fn shifts(&self) -> [u32; 3] {
+        let [_, green, blue] = self.widths;
+        [green + blue, blue, 0]
+    }
+}
+
+/// Widens a channel to 8 bits by repeating its top bits in the bits below, so that full channels
+/// stay full
+fn expand_channel(level: u32, bits: u32) -> u8 {
+    ((level << (8 - bits)) | (level >> (2 * bits - 8))) as u8
+}
+
+/// Formatter writing 16-bit packed pixels as the 8-bit-per-channel colors they show, with the bits
+/// sliced into each channel, such as `#ff0000 (R 11111, G 000000, B 00000)` for RGB565 0xf800
+pub(crate) struct PackedPixelNumberFormatter {
+    pub format: PixelFormat,
+    pub uppercase: bool,
+}
+
+impl NumberFormatter for PackedPixelNumberFormatter {
+    fn read(&self, color: &str) -> anyhow::Result<i128, NumberFormatterError> {
+        if color.is_empty() {
+            return Err(NumberFormatterError::Empty);
+        }
+
+        let color = if color.starts_with('#') {
+            color.split_whitespace().next().unwrap_or_default()
+        } else {
+            color
+        };
+        let channels = parse_color(color).ok_or(NumberFormatterError::InvalidColor)??;
+        if channels.len() != 3 {
+            return Err(NumberFormatterError::InvalidColor);
+        }
+
+        Ok(channels
+            .iter()
+            .zip(self.format.widths)
+            .zip(self.format.shifts())
+            .fold(0, |packed, ((level, bits), shift)| {
+                packed | ((*level as i128) >> (8 - bits)) << shift
+            }))
+    }
+
+    fn format(&self, num: i128) -> anyhow::Result<String, NumberFormatterError> {
+        if num < 0 {
+            return Err(NumberFormatterError::TooSmallError);
+        }
+        if num > u16::MAX as i128 {
+            return Err(NumberFormatterError::TooLargeError);
+        }
+
+        let pixel = num as u32;
+        let mut color = String::from("#");
+        let mut slices = Vec::new();
+
+        let unused = self.format.unused_bits();
+        if unused > 0 {
+            let bits = pixel >> (u16::BITS - unused);
+            slices.push(format!("X {:0width$b}", bits, width = unused as usize));
+        }
+
+        for ((label, bits), shift) in CHANNELS
+            .iter()
+            .zip(self.format.widths)
+            .zip(self.format.shifts())
+        {
+            let level = (pixel >> shift) & ((1 << bits) - 1);
+            let expanded = expand_channel(level, bits);
+            color.push_str(&if self.uppercase {
+                format!("{:02X}", expanded)
+            } else {
+                format!("{:02x}", expanded)
+            });
+            slices.push(format!(
+                "{} {:0width$b}",
+                label,
+                level,
+                width = bits as usize
+            ));
+        }
+
+        Ok(format!("{} ({})", color, slices.join(", ")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("#1e90ff", 0x1e90ff)]
+    #[test_case("#1E90FF80", 0x1e90ff80; "with alpha")]
+    #[test_case("0x1E90FF", 0x1e90ff; "hex prefix")]
+    #[test_case("30, 144, 255", 0x1e90ff; "components")]
+    #[test_case("30,144,255,128", 0x1e90ff80; "components with alpha")]
+    #[test_case("#1e90ff (R 30 0x1e, G 144 0x90, B 255 0xff)", 0x1e90ff; "with channels")]
+    fn color_number_formatter_should_read_color(input: &str, expected: i128) {
+        // Act
+        let actual = ColorNumberFormatter {
+            uppercase: false,
+            size: None,
+        }
+        .read(input);
+
+        // Assert
+        assert_eq!(actual.unwrap(), expected);
+    }
+
+    #[test_case("", NumberFormatterError::Empty; "empty")]
+    #[test_case("#1e90f", NumberFormatterError::InvalidColor; "five digits")]
+    #[test_case("#1e90fg", NumberFormatterError::InvalidColor; "not hex")]
+    #[test_case("30,144", NumberFormatterError::InvalidColor; "two components")]
+    #[test_case("30,144,256", NumberFormatterError::TooLargeError; "component too large")]
+    #[test_case("1e90ff", NumberFormatterError::InvalidColor; "unprefixed")]
+    fn color_number_formatter_should_fail_to_read(input: &str, expected: NumberFormatterError) {
+        // Act
+        let actual = ColorNumberFormatter {
+            uppercase: false,
+            size: None,
+        }
+        .read(input);
+
+        // Assert
+        assert_eq!(
+            std::mem::discriminant(&actual.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+
+    #[test_case(
+        0x1e90ff,
+        false,
+        None,
+        Ok(String::from("#1e90ff (R 30 0x1e, G 144 0x90, B 255 0xff)"))
+    )]
+    #[test_case(0x1e90ff80, true, None, Ok(String::from("#1E90FF80 (R 30 0x1E, G 144 0x90, B 255 0xFF, A 128 0x80)")); "with alpha")]
+    #[test_case(0xff, false, Some(IntegerSize { bits: 32, signed: false }), Ok(String::from("#000000ff (R 0 0x00, G 0 0x00, B 0 0x00, A 255 0xff)")); "32-bit size")]
+    #[test_case(0x100000000, false, None, Err(NumberFormatterError::TooLargeError); "too large")]
+    #[test_case(-1, false, None, Err(NumberFormatterError::TooSmallError); "negative")]
+    fn color_number_formatter_should_format_i128(
+        input: i128,
+        uppercase: bool,
+        size: Option<IntegerSize>,
+        expected: anyhow::Result<String, NumberFormatterError>,
+    ) {
+        // Act
+        let actual = ColorNumberFormatter { uppercase, size }.format(input);
+
+        // Assert
+        assert_eq!(format!("{:?}", actual), format!("{:?}", expected));
+    }
+
+    #[test_case(PixelFormat::RGB565, 0xf800, "#ff0000 (R 11111, G 000000, B 00000)")]
+    #[test_case(PixelFormat::RGB565, 0x07e0, "#00ff00 (R 00000, G 111111, B 00000)"; "green")]
+    #[test_case(PixelFormat::RGB565, 0x1c9f, "#1892ff (R 00011, G 100100, B 11111)"; "dodger blue")]
+    #[test_case(PixelFormat::RGB555, 0x7c00, "#ff0000 (X 0, R 11111, G 00000, B 00000)"; "rgb555")]
+    #[test_case(PixelFormat::RGB555, 0xffff, "#ffffff (X 1, R 11111, G 11111, B 11111)"; "rgb555 white")]
+    fn packed_pixel_number_formatter_should_format_i128(
+        format: PixelFormat,
+        input: i128,
+        expected: &str,
+    ) {
+        // Act
+        let actual = PackedPixelNumberFormatter {
+            format,
+            uppercase: false,
+        }
+        .format(input);
+
+        // Assert
+        assert_eq!(actual.unwrap(), expected);
+    }
+
+    #[test_case(PixelFormat::RGB565, "#1e90ff", 0x1c9f)]
+    #[test_case(PixelFormat::RGB565, "255,255,255", 0xffff; "components")]
+    #[test_case(PixelFormat::RGB565, "#ff0000 (R 11111, G 000000, B 00000)", 0xf800; "with slices")]
+    #[test_case(PixelFormat::RGB555, "#00ff00", 0x03e0; "rgb555")]
+    fn packed_pixel_number_formatter_should_read_color(
+        format: PixelFormat,
+        input: &str,
+        expected: i128,
+    ) {
+        // Act
+        let actual = PackedPixelNumberFormatter {
+            format,
+            uppercase: false,
+        }
+        .read(input);
+
+        // Assert
+        assert_eq!(actual.unwrap(), expected);
+    }
+
+    #[test_case(0x10000, NumberFormatterError::TooLargeError; "too large")]
+    #[test_case(-1, NumberFormatterError::TooSmallError; "negative")]
+    fn packed_pixel_number_formatter_should_fail_to_format(
+        input: i128,
+        expected: NumberFormatterError,
+    ) {
+        // Act
+        let actual = PackedPixelNumberFormatter {
+            format: PixelFormat::RGB565,
+            uppercase: false,
+        }
+        .format(input);
+
+        // Assert
+        assert_eq!(
+            std::mem::discriminant(&actual.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+
+    #[test]
+    fn packed_pixel_number_formatter_should_reject_alpha() {
+        // Act
+        let actual = PackedPixelNumberFormatter {
+            format: PixelFormat::RGB565,
+            uppercase: false,
+        }
+        .read("#1e90ff80");
+
+        // Assert
+        assert!(matches!(actual, Err(NumberFormatterError::InvalidColor)));
+    }
+}
@@ -0,0 +1,133 @@
+use crate::{NumberFormatter, NumberFormatterError};
+
+/// Bijective base-26 formatter used for spreadsheet column names, where there is
+/// no zero digit and `A` = 1, `Z` = 26, `AA` = 27
+pub(crate) struct ColumnNumberFormatter;
+
+impl NumberFormatter for ColumnNumberFormatter {
+    fn read(&self, column: &str) -> anyhow::Result<i128, NumberFormatterError> {
+        i128::try_from(self.read_unsigned(column)?).map_err(|_| NumberFormatterError::TooLargeError)
+    }
+
+    fn format(&self, num: i128) -> anyhow::Result<String, NumberFormatterError> {
+        if num < 1 {
+            return Err(NumberFormatterError::TooSmallError);
+        }
+
+        self.format_unsigned(num as u128)
+    }
+
+    fn read_unsigned(&self, column: &str) -> anyhow::Result<u128, NumberFormatterError> {
+        if column.is_empty() {
+            return Err(NumberFormatterError::Empty);
+        }
+
+        let mut value: u128 = 0;
+
+        for c in column.chars() {
+            if !c.is_ascii_alphabetic() {
+                return Err(NumberFormatterError::InvalidDigit);
+            }
+
+            let digit = (c.to_ascii_uppercase() as u8 - b'A' + 1) as u128;
+            value = value
+                .checked_mul(26)
+                .and_then(|v| v.checked_add(digit))
+                .ok_or(NumberFormatterError::TooLargeError)?;
+        }
+
+        Ok(value)
+    }
+
+    fn format_unsigned(&self, num: u128) -> anyhow::Result<String, NumberFormatterError> {
+        if num < 1 {
+            return Err(NumberFormatterError::TooSmallError);
+        }
+
+        let mut letters = Vec::<char>::new();
+        let mut remainder = num;
+
+        while remainder > 0 {
+            remainder -= 1;
+            letters.push((b'A' + (remainder % 26) as u8) as char);
+            remainder /= 26;
+        }
+
+        Ok(letters.iter().rev().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("A", 1)]
+    #[test_case("z", 26)]
+    #[test_case("AA", 27)]
+    #[test_case("AZ", 52)]
+    #[test_case("XFD", 16384)]
+    fn column_number_formatter_should_read_column(input: &str, expected: i128) {
+        // Act
+        let actual_number = ColumnNumberFormatter.read(input);
+
+        // Assert
+        assert!(actual_number.is_ok());
+        assert_eq!(expected, actual_number.unwrap());
+    }
+
+    #[test_case(1, "A")]
+    #[test_case(26, "Z")]
+    #[test_case(27, "AA")]
+    #[test_case(702, "ZZ")]
+    #[test_case(703, "AAA")]
+    fn column_number_formatter_should_format_i128(input: i128, expected: &str) {
+        // Act
+        let actual_output = ColumnNumberFormatter.format(input);
+
+        // Assert
+        assert!(actual_output.is_ok());
+        assert_eq!(expected, actual_output.unwrap());
+    }
+
+    #[test]
+    fn column_number_formatter_should_round_trip_unsigned() {
+        // Act
+        let column = ColumnNumberFormatter.format_unsigned(u128::MAX).unwrap();
+        let actual = ColumnNumberFormatter.read_unsigned(&column);
+
+        // Assert
+        assert_eq!(actual.unwrap(), u128::MAX);
+        assert!(matches!(
+            ColumnNumberFormatter.read(&column),
+            Err(NumberFormatterError::TooLargeError)
+        ));
+    }
+
+    #[test_case(0)]
+    #[test_case(-5)]
+    fn column_number_formatter_should_reject_non_positive(input: i128) {
+        // Act
+        let actual_output = ColumnNumberFormatter.format(input);
+
+        // Assert
+        assert!(matches!(
+            actual_output,
+            Err(NumberFormatterError::TooSmallError)
+        ));
+    }
+
+    #[test_case("A1", NumberFormatterError::InvalidDigit ; "digit")]
+    #[test_case("", NumberFormatterError::Empty ; "empty")]
+    #[test_case("ZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZ", NumberFormatterError::TooLargeError ; "too large")]
+    fn column_number_formatter_should_fail_to_read(input: &str, expected: NumberFormatterError) {
+        // Act
+        let actual_number = ColumnNumberFormatter.read(input);
+
+        // Assert
+        assert_eq!(
+            std::mem::discriminant(&actual_number.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+}
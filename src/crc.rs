@@ -0,0 +1,135 @@
+use clap::{ArgEnum, Args};
+
+/// Options of the crc command
+#[derive(Args, Clone, Debug)]
+pub struct CrcOptions {
+    /// Comma separated CRC variants to compute, all of them when not given
+    #[clap(long, arg_enum, use_value_delimiter = true)]
+    pub algorithm: Vec<CrcAlgorithm>,
+    /// The number whose bytes are checked, at the integer size or else the fewest bytes holding
+    /// it, in --endian order (big endian by default)
+    pub number: String,
+}
+
+/// Common CRC variants, named as in the catalogue of parametrised CRC algorithms
+#[derive(ArgEnum, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CrcAlgorithm {
+    /// CRC-32 as used by Ethernet, zlib and PNG
+    Crc32,
+    /// CRC-16/CCITT-FALSE, the CCITT polynomial starting from all ones
+    Crc16,
+    /// CRC-8 with the polynomial 0x07, as used by SMBus
+    Crc8,
+}
+
+/// Parameters of a CRC in the Rocksoft model
+struct CrcParameters {
+    width: u32,
+    poly: u32,
+    init: u32,
+    reflected: bool,
+    xor_out: u32,
+}
+
+impl CrcAlgorithm {
+    /// Every variant, in the order they are shown
+    pub fn all() -> Vec<CrcAlgorithm> {
+        vec![CrcAlgorithm::Crc32, CrcAlgorithm::Crc16, CrcAlgorithm::Crc8]
+    }
+
+    /// Label the checksum is shown with
+    pub fn name(&self) -> &'static str {
+        match self {
+            CrcAlgorithm::Crc32 => "CRC-32",
+            CrcAlgorithm::Crc16 => "CRC-16/CCITT",
+            CrcAlgorithm::Crc8 => "CRC-8",
+        }
+    }
+
+    /// Bits in the checksum
+    pub fn width(&self) -> u32 {
+        self.parameters().width
+    }
+
+    fn parameters(&self) -> CrcParameters {
+        match self {
+            CrcAlgorithm::Crc32 => CrcParameters {
+                width: 32,
+                poly: 0x04c11db7,
+                init: 0xffffffff,
+                reflected: true,
+                xor_out: 0xffffffff,
+            },
+            CrcAlgorithm::Crc16 => CrcParameters {
+                width: 16,
+                poly: 0x1021,
+                init: 0xffff,
+                reflected: false,
+                xor_out: 0,
+            },
+            CrcAlgorithm::Crc8 => CrcParameters {
+                width: 8,
+                poly: 0x07,
+                init: 0,
+                reflected: false,
+                xor_out: 0,
+            },
+        }
+    }
+
+    /// Computes the checksum of bytes a bit at a time
+    pub(crate) fn checksum(&self, bytes: &[u8]) -> u32 {
+        let CrcParameters {
+            width,
+            poly,
+            init,
+            reflected,
+            xor_out,
+        } = self.parameters();
+        let top = 1u64 << (width - 1);
+        let mask = (1u64 << width) - 1;
+        let mut crc = init as u64;
+
+        for byte in bytes {
+            let byte = if reflected {
+                byte.reverse_bits()
+            } else {
+                *byte
+            };
+            crc ^= (byte as u64) << (width - 8);
+            for _ in 0..8 {
+                crc = match crc & top {
+                    0 => crc << 1,
+                    _ => (crc << 1) ^ poly as u64,
+                } & mask;
+            }
+        }
+
+        let crc = if reflected {
+            (crc as u32).reverse_bits() >> (32 - width)
+        } else {
+            crc as u32
+        };
+        crc ^ xor_out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(CrcAlgorithm::Crc32, b"123456789", 0xcbf43926)]
+    #[test_case(CrcAlgorithm::Crc16, b"123456789", 0x29b1)]
+    #[test_case(CrcAlgorithm::Crc8, b"123456789", 0xf4)]
+    #[test_case(CrcAlgorithm::Crc32, b"", 0; "crc32 of nothing")]
+    #[test_case(CrcAlgorithm::Crc16, b"", 0xffff; "crc16 of nothing")]
+    #[test_case(CrcAlgorithm::Crc32, &[0xde, 0xad, 0xbe, 0xef], 0x7c9ca35a)]
+    fn checksum_should_match_check_values(algorithm: CrcAlgorithm, bytes: &[u8], expected: u32) {
+        // Act
+        let actual = algorithm.checksum(bytes);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,177 @@
+use crate::NumberType;
+
+/// Lists the number types the input could plausibly be written in, most likely first,
+/// each paired with a note explaining why it was considered
+pub(crate) fn plausible_number_types(input: &str) -> Vec<(NumberType, String)> {
+    let digits = input.strip_prefix('-').unwrap_or(input);
+
+    let detected = NumberType::detect(input);
+    let note = match detected {
+        NumberType::Integer => return digit_readings(digits),
+        NumberType::Character => String::from("is quoted"),
+        NumberType::Text => String::from("is double quoted"),
+        NumberType::Hexdump => String::from("starts with a hexdump offset"),
+        NumberType::Ipv4 => String::from("is a dotted quad"),
+        NumberType::Permissions => String::from("is written as symbolic permissions"),
+        NumberType::Mac => String::from("is grouped like a MAC address"),
+        NumberType::Ipv6 => String::from("is colon separated"),
+        NumberType::Uuid => String::from("is hyphenated like a UUID"),
+        NumberType::Signal => String::from("is a signal name"),
+        NumberType::Errno => String::from("is an errno name"),
+        NumberType::TcpFlags => String::from("lists TCP flags"),
+        NumberType::Color => String::from("has `#` prefix"),
+        NumberType::Time => String::from("is an ISO 8601 date"),
+        NumberType::Frequency => String::from("has Hz unit"),
+        NumberType::Float => String::from("has a fractional part"),
+        NumberType::HexFloat => String::from("has `0x` prefix and a `p` exponent or point"),
+        NumberType::ByteArray => String::from("is a byte array literal"),
+        // Every other type is detected from its prefix, such as 0x or U+
+        _ => return vec![(detected, format!("has `{}` prefix", &digits[..2]))],
+    };
+
+    let mut number_types = vec![(detected, note)];
+
+    if !digits.is_empty() {
+        if digits.chars().all(|c| c.is_ascii_digit()) {
+            number_types.push((NumberType::Integer, String::from("also valid integer")));
+        }
+        if digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            number_types.push((
+                NumberType::Hexadecimal,
+                String::from("also valid hexadecimal"),
+            ));
+        }
+    }
+
+    number_types
+}
+
+/// Lists the number types digits without a prefix or any other telling form could be written in
+fn digit_readings(digits: &str) -> Vec<(NumberType, String)> {
+    if crate::humanize::split_exponent(digits).is_some() {
+        let mut number_types = vec![(
+            NumberType::Integer,
+            String::from("is written in scientific notation"),
+        )];
+        if digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            number_types.push((
+                NumberType::Hexadecimal,
+                String::from("also valid hexadecimal"),
+            ));
+        }
+        return number_types;
+    }
+
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return vec![];
+    }
+
+    if let Some(letter) = digits.chars().find(|c| c.is_ascii_alphabetic()) {
+        return vec![(
+            NumberType::Hexadecimal,
+            format!("contains `{}`, assuming hexadecimal", letter),
+        )];
+    }
+
+    let also_hexadecimal = (
+        NumberType::Hexadecimal,
+        String::from("also valid hexadecimal"),
+    );
+
+    if let Some(digit) = digits.chars().find(|c| *c == '8' || *c == '9') {
+        return vec![
+            (
+                NumberType::Integer,
+                format!("contains `{}`, most likely integer", digit),
+            ),
+            also_hexadecimal,
+        ];
+    }
+
+    if digits.chars().all(|c| c == '0' || c == '1') {
+        return vec![
+            (
+                NumberType::Binary,
+                String::from("only contains 0 and 1, possibly binary"),
+            ),
+            (NumberType::Integer, String::from("also valid integer")),
+            (NumberType::Octal, String::from("also valid octal")),
+            also_hexadecimal,
+        ];
+    }
+
+    if digits.len() > 1 && digits.starts_with('0') {
+        return vec![
+            (
+                NumberType::Octal,
+                String::from("has a leading zero, possibly octal"),
+            ),
+            (NumberType::Integer, String::from("also valid integer")),
+            also_hexadecimal,
+        ];
+    }
+
+    vec![
+        (
+            NumberType::Integer,
+            String::from("only contains decimal digits, most likely integer"),
+        ),
+        (NumberType::Octal, String::from("also valid octal")),
+        also_hexadecimal,
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("0xff", &[NumberType::Hexadecimal] ; "hexadecimal prefix")]
+    #[test_case("-0b11", &[NumberType::Binary] ; "negative binary prefix")]
+    #[test_case("1f", &[NumberType::Hexadecimal] ; "hexadecimal letter")]
+    #[test_case("129", &[NumberType::Integer, NumberType::Hexadecimal] ; "decimal only digit")]
+    #[test_case("0110", &[NumberType::Binary, NumberType::Integer, NumberType::Octal, NumberType::Hexadecimal] ; "binary digits")]
+    #[test_case("0755", &[NumberType::Octal, NumberType::Integer, NumberType::Hexadecimal] ; "leading zero")]
+    #[test_case("755", &[NumberType::Integer, NumberType::Octal, NumberType::Hexadecimal] ; "octal digits")]
+    #[test_case("12z", &[] ; "invalid digit")]
+    #[test_case("1e6", &[NumberType::Integer, NumberType::Hexadecimal] ; "scientific notation")]
+    #[test_case("2.5e3", &[NumberType::Integer] ; "fractional scientific notation")]
+    #[test_case("123e4567-e89b-12d3-a456-426614174000", &[NumberType::Uuid] ; "uuid")]
+    #[test_case("ECE", &[NumberType::TcpFlags, NumberType::Hexadecimal] ; "tcp flags also hexadecimal")]
+    #[test_case("SYN", &[NumberType::TcpFlags] ; "tcp flags")]
+    #[test_case("", &[] ; "empty")]
+    fn plausible_number_types_should_be_ordered_by_likelihood(
+        input: &str,
+        expected: &[NumberType],
+    ) {
+        // Act
+        let actual: Vec<NumberType> = plausible_number_types(input)
+            .into_iter()
+            .map(|(number_type, _)| number_type)
+            .collect();
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn plausible_number_types_should_explain_quoted_character() {
+        // Act
+        let actual = plausible_number_types("'€'");
+
+        // Assert
+        assert_eq!(
+            actual,
+            vec![(NumberType::Character, String::from("is quoted"))]
+        );
+    }
+
+    #[test]
+    fn plausible_number_types_should_explain_hexadecimal_letter() {
+        // Act
+        let actual = plausible_number_types("3f");
+
+        // Assert
+        assert_eq!(actual[0].1, "contains `f`, assuming hexadecimal");
+    }
+}
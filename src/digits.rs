@@ -0,0 +1,90 @@
+use crate::NumberFormatterError;
+
+/// The zero digit of each script whose digits are normalised to ASCII
+static ZEROS: [(&str, char); 10] = [
+    ("ASCII", '0'),
+    ("Arabic-Indic", '\u{0660}'),
+    ("Extended Arabic-Indic", '\u{06F0}'),
+    ("Devanagari", '\u{0966}'),
+    ("Bengali", '\u{09E6}'),
+    ("Gujarati", '\u{0AE6}'),
+    ("Tamil", '\u{0BE6}'),
+    ("Thai", '\u{0E50}'),
+    ("Myanmar", '\u{1040}'),
+    ("Full-width", '\u{FF10}'),
+];
+
+const FULL_WIDTH_UPPERCASE_A: u32 = 0xFF21;
+const FULL_WIDTH_LOWERCASE_A: u32 = 0xFF41;
+
+/// Finds the script of a decimal digit and its value
+fn digit_value(c: char) -> Option<(&'static str, u32)> {
+    ZEROS.iter().find_map(|(script, zero)| {
+        let value = (c as u32).checked_sub(*zero as u32)?;
+        (value < 10).then_some((*script, value))
+    })
+}
+
+/// Replaces digits from other scripts, and full-width letters, with their ASCII equivalents
+pub(crate) fn normalize_digits(number: &str) -> anyhow::Result<String, NumberFormatterError> {
+    let mut digit_script = None;
+
+    number
+        .chars()
+        .map(|c| {
+            if let Some((script, value)) = digit_value(c) {
+                if *digit_script.get_or_insert(script) != script {
+                    return Err(NumberFormatterError::MixedDigitScripts);
+                }
+                return Ok(char::from_digit(value, 10).unwrap());
+            }
+
+            let full_width_letter = [FULL_WIDTH_UPPERCASE_A, FULL_WIDTH_LOWERCASE_A]
+                .iter()
+                .zip([b'A', b'a'])
+                .find_map(|(first, ascii)| {
+                    let offset = (c as u32).checked_sub(*first)?;
+                    (offset < 26).then_some((ascii + offset as u8) as char)
+                });
+
+            Ok(full_width_letter.unwrap_or(c))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("1234", "1234")]
+    #[test_case("١٢٣٤", "1234"; "arabic indic")]
+    #[test_case("۱۲۳۴", "1234"; "extended arabic indic")]
+    #[test_case("१२३४", "1234"; "devanagari")]
+    #[test_case("１２３４", "1234"; "full width")]
+    #[test_case("-٤٢", "-42"; "negative arabic indic")]
+    #[test_case("０ｘ１Ｆ", "0x1F"; "full width hexadecimal")]
+    #[test_case("abc", "abc")]
+    fn normalize_digits_should_be_ascii(number: &str, expected: &str) {
+        // Act
+        let actual = normalize_digits(number);
+
+        // Assert
+        assert_eq!(actual.unwrap(), expected);
+    }
+
+    #[test_case("١2"; "arabic indic and ascii")]
+    #[test_case("१٢"; "devanagari and arabic indic")]
+    #[test_case("１2"; "full width and ascii")]
+    fn normalize_digits_should_reject_mixed_scripts(number: &str) {
+        // Act
+        let actual = normalize_digits(number);
+
+        // Assert
+        assert!(actual.is_err());
+        assert_eq!(
+            std::mem::discriminant(&actual.unwrap_err()),
+            std::mem::discriminant(&NumberFormatterError::MixedDigitScripts)
+        );
+    }
+}
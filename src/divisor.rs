@@ -0,0 +1,108 @@
+use crate::{NumberFormatterError, NumberType};
+use clap::Args;
+
+/// Options of the gcd and lcm commands
+#[derive(Args, Clone, Debug)]
+pub struct DivisorOptions {
+    /// The numbers to combine, two or more
+    #[clap(required = true, min_values = 2)]
+    pub numbers: Vec<String>,
+    /// Show the steps of the Euclidean algorithm for each pair of numbers combined
+    #[clap(short, long)]
+    pub verbose: bool,
+}
+
+/// The greatest common divisor or least common multiple of the numbers given to the gcd or lcm
+/// command, with the steps taken to find it
+#[derive(Debug, PartialEq, Eq)]
+pub struct DivisorInfo {
+    pub outputs: Vec<(NumberType, String)>,
+    /// A line for each division of the Euclidean algorithm, and for each multiple
+    pub steps: Vec<String>,
+}
+
+/// The greatest common divisor of two numbers, with a line such as `1071 = 2 * 462 + 147` for each
+/// division the Euclidean algorithm takes to find it
+pub(crate) fn gcd_steps(a: u128, b: u128) -> (u128, Vec<String>) {
+    let mut steps = vec![format!("gcd({}, {})", a, b)];
+    let (mut a, mut b) = (a, b);
+    while b != 0 {
+        steps.push(format!("{} = {} * {} + {}", a, a / b, b, a % b));
+        (a, b) = (b, a % b);
+    }
+    steps.push(format!("gcd = {}", a));
+
+    (a, steps)
+}
+
+/// The least common multiple of two numbers, which is zero if either of them is, with the steps
+/// taken to find their greatest common divisor first
+pub(crate) fn lcm_steps(
+    a: u128,
+    b: u128,
+) -> anyhow::Result<(u128, Vec<String>), NumberFormatterError> {
+    let (divisor, mut steps) = gcd_steps(a, b);
+    let multiple = match divisor {
+        0 => 0,
+        divisor => (a / divisor)
+            .checked_mul(b)
+            .ok_or(NumberFormatterError::TooLargeError)?,
+    };
+    steps.push(format!(
+        "lcm({}, {}) = {} / {} * {} = {}",
+        a, b, a, divisor, b, multiple
+    ));
+
+    Ok((multiple, steps))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test]
+    fn gcd_steps_should_show_divisions() {
+        // Act
+        let actual = gcd_steps(1071, 462);
+
+        // Assert
+        assert_eq!(
+            actual,
+            (
+                21,
+                vec![
+                    String::from("gcd(1071, 462)"),
+                    String::from("1071 = 2 * 462 + 147"),
+                    String::from("462 = 3 * 147 + 21"),
+                    String::from("147 = 7 * 21 + 0"),
+                    String::from("gcd = 21"),
+                ]
+            )
+        );
+    }
+
+    #[test_case(4, 6, 12)]
+    #[test_case(0, 6, 0; "zero")]
+    #[test_case(0, 0, 0; "both zero")]
+    #[test_case(1 << 64, 3 << 63, 3 << 64; "large")]
+    fn lcm_steps_should_find_multiple(a: u128, b: u128, expected: u128) {
+        // Act
+        let actual = lcm_steps(a, b);
+
+        // Assert
+        assert_eq!(actual.unwrap().0, expected);
+    }
+
+    #[test]
+    fn lcm_steps_should_fail_when_too_large() {
+        // Act
+        let actual = lcm_steps(u128::MAX, u128::MAX - 1);
+
+        // Assert
+        assert_eq!(
+            std::mem::discriminant(&actual.unwrap_err()),
+            std::mem::discriminant(&NumberFormatterError::TooLargeError)
+        );
+    }
+}
@@ -0,0 +1,122 @@
+use crate::alphabet::AlphabetNumberFormatter;
+use crate::{NumberFormatter, NumberFormatterError};
+
+/// The standard dozenal digits for ten (turned two) and eleven (turned three)
+pub(crate) const DOZENAL_DIGITS: &str = "\u{218A}\u{218B}";
+
+/// Clap value parser ensuring exactly two distinct, non decimal digits are given
+pub(crate) fn parse_dozenal_digits(digits: &str) -> Result<String, String> {
+    let chars: Vec<char> = digits.chars().collect();
+
+    match chars.as_slice() {
+        [ten, eleven] if ten != eleven && !ten.is_ascii_digit() && !eleven.is_ascii_digit() => {
+            Ok(digits.to_string())
+        }
+        _ => Err(String::from(
+            "expected two distinct non decimal digits for ten and eleven",
+        )),
+    }
+}
+
+/// Base 12 formatter using `↊` and `↋` (or configured substitutes such as `X` and `E`)
+/// for ten and eleven. Input may use either the standard or the configured digits
+pub(crate) struct DozenalNumberFormatter {
+    ten: char,
+    eleven: char,
+    alphabet: AlphabetNumberFormatter,
+}
+
+impl DozenalNumberFormatter {
+    pub fn new(digits: &str) -> Self {
+        let mut chars = digits.chars();
+        let ten = chars.next().unwrap_or('\u{218A}');
+        let eleven = chars.next().unwrap_or('\u{218B}');
+
+        Self {
+            ten,
+            eleven,
+            alphabet: AlphabetNumberFormatter::new(&format!("0123456789{}{}", ten, eleven)),
+        }
+    }
+
+    fn normalize(&self, c: char) -> char {
+        if c == '\u{218A}' || c.eq_ignore_ascii_case(&self.ten) {
+            self.ten
+        } else if c == '\u{218B}' || c.eq_ignore_ascii_case(&self.eleven) {
+            self.eleven
+        } else {
+            c
+        }
+    }
+}
+
+impl NumberFormatter for DozenalNumberFormatter {
+    fn read(&self, dozenal: &str) -> anyhow::Result<i128, NumberFormatterError> {
+        let normalized: String = dozenal.chars().map(|c| self.normalize(c)).collect();
+        self.alphabet.read(&normalized)
+    }
+
+    fn format(&self, num: i128) -> anyhow::Result<String, NumberFormatterError> {
+        self.alphabet.format(num)
+    }
+
+    fn read_unsigned(&self, dozenal: &str) -> anyhow::Result<u128, NumberFormatterError> {
+        let normalized: String = dozenal.chars().map(|c| self.normalize(c)).collect();
+        self.alphabet.read_unsigned(&normalized)
+    }
+
+    fn format_unsigned(&self, num: u128) -> anyhow::Result<String, NumberFormatterError> {
+        self.alphabet.format_unsigned(num)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(DOZENAL_DIGITS, "\u{218B}\u{218A}", 142 ; "standard digits")]
+    #[test_case(DOZENAL_DIGITS, "-10", -12 ; "negative")]
+    #[test_case("XE", "EX", 142 ; "substitute digits")]
+    #[test_case("XE", "ex", 142 ; "lowercase substitute digits")]
+    #[test_case("XE", "\u{218B}X", 142 ; "mixed digits")]
+    fn dozenal_number_formatter_should_read_number(digits: &str, input: &str, expected: i128) {
+        // Arrange
+        let formatter = DozenalNumberFormatter::new(digits);
+
+        // Act
+        let actual_number = formatter.read(input);
+
+        // Assert
+        assert!(actual_number.is_ok());
+        assert_eq!(expected, actual_number.unwrap());
+    }
+
+    #[test_case(DOZENAL_DIGITS, 142, "\u{218B}\u{218A}" ; "standard digits")]
+    #[test_case("XE", 142, "EX" ; "substitute digits")]
+    #[test_case("XE", 144, "100" ; "gross")]
+    fn dozenal_number_formatter_should_format_i128(digits: &str, input: i128, expected: &str) {
+        // Arrange
+        let formatter = DozenalNumberFormatter::new(digits);
+
+        // Act
+        let actual_output = formatter.format(input);
+
+        // Assert
+        assert!(actual_output.is_ok());
+        assert_eq!(expected, actual_output.unwrap());
+    }
+
+    #[test_case("XE", true)]
+    #[test_case("AB", true)]
+    #[test_case("X", false)]
+    #[test_case("XX", false)]
+    #[test_case("X9", false)]
+    fn parse_dozenal_digits_should_validate(digits: &str, expected_ok: bool) {
+        // Act
+        let actual = parse_dozenal_digits(digits);
+
+        // Assert
+        assert_eq!(actual.is_ok(), expected_ok);
+    }
+}
@@ -0,0 +1,225 @@
+use crate::bytes::{pattern_of_bytes, value_bytes, Endian};
+use crate::{IntegerSize, NumberFormatter, NumberFormatterError, Value};
+
+/// RFC 4648 style encodings of bytes as text
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Encoding {
+    /// Standard Base64 with `=` padding
+    Base64,
+    /// URL and filename safe Base64, without padding
+    Base64Url,
+    /// RFC 4648 Base32 with `=` padding
+    Base32,
+    /// Crockford's Base32, without padding and forgiving of easily confused letters
+    Base32Crockford,
+}
+
+impl Encoding {
+    fn alphabet(&self) -> &'static [u8] {
+        match self {
+            Encoding::Base64 => b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",
+            Encoding::Base64Url => {
+                b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_"
+            }
+            Encoding::Base32 => b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567",
+            Encoding::Base32Crockford => b"0123456789ABCDEFGHJKMNPQRSTVWXYZ",
+        }
+    }
+
+    /// Bits each character encodes
+    fn bits(&self) -> usize {
+        match self {
+            Encoding::Base64 | Encoding::Base64Url => 6,
+            Encoding::Base32 | Encoding::Base32Crockford => 5,
+        }
+    }
+
+    fn padded(&self) -> bool {
+        matches!(self, Encoding::Base64 | Encoding::Base32)
+    }
+
+    fn encode(&self, bytes: &[u8]) -> String {
+        let alphabet = self.alphabet();
+        let bits = self.bits();
+        let mut text = String::new();
+        let mut buffer: u32 = 0;
+        let mut buffered = 0;
+
+        for byte in bytes {
+            buffer = buffer << 8 | *byte as u32;
+            buffered += 8;
+            while buffered >= bits {
+                buffered -= bits;
+                text.push(alphabet[(buffer >> buffered) as usize & ((1 << bits) - 1)] as char);
+            }
+        }
+        if buffered > 0 {
+            text.push(alphabet[(buffer << (bits - buffered)) as usize & ((1 << bits) - 1)] as char);
+        }
+
+        // A group of characters encodes a whole number of bytes: 4 characters hold 3 bytes in
+        // Base64 and 8 characters hold 5 bytes in Base32
+        let group = if bits == 6 { 4 } else { 8 };
+        while self.padded() && !text.len().is_multiple_of(group) {
+            text.push('=');
+        }
+
+        text
+    }
+
+    fn decode(&self, text: &str) -> anyhow::Result<Vec<u8>, NumberFormatterError> {
+        let alphabet = self.alphabet();
+        let bits = self.bits();
+        let mut bytes = Vec::new();
+        let mut buffer: u32 = 0;
+        let mut buffered = 0;
+
+        for c in text.trim().trim_end_matches('=').chars() {
+            let c = match self {
+                Encoding::Base32Crockford => match c.to_ascii_uppercase() {
+                    '-' => continue,
+                    'O' => '0',
+                    'I' | 'L' => '1',
+                    c => c,
+                },
+                Encoding::Base32 => c.to_ascii_uppercase(),
+                _ => c,
+            };
+            let digit = alphabet
+                .iter()
+                .position(|d| *d as char == c)
+                .ok_or(NumberFormatterError::InvalidDigit)?;
+
+            buffer = buffer << bits | digit as u32;
+            buffered += bits;
+            if buffered >= 8 {
+                buffered -= 8;
+                bytes.push((buffer >> buffered) as u8);
+            }
+        }
+
+        if buffered >= bits || buffer & ((1 << buffered) - 1) != 0 {
+            return Err(NumberFormatterError::IncompleteByte);
+        }
+
+        Ok(bytes)
+    }
+}
+
+/// Formatter encoding the bytes of a number's bit pattern, at the integer size or else the
+/// narrowest width holding the number
+pub(crate) struct EncodingNumberFormatter {
+    pub encoding: Encoding,
+    pub endian: Endian,
+    pub size: Option<IntegerSize>,
+}
+
+impl EncodingNumberFormatter {
+    fn format_value(&self, value: Value) -> String {
+        let size = self.size.unwrap_or_else(|| IntegerSize::holding(value));
+        self.encoding.encode(&value_bytes(value, size, self.endian))
+    }
+}
+
+impl NumberFormatter for EncodingNumberFormatter {
+    fn read(&self, text: &str) -> anyhow::Result<i128, NumberFormatterError> {
+        i128::try_from(self.read_unsigned(text)?).map_err(|_| NumberFormatterError::TooLargeError)
+    }
+
+    fn format(&self, num: i128) -> anyhow::Result<String, NumberFormatterError> {
+        Ok(self.format_value(Value::Signed(num)))
+    }
+
+    fn read_unsigned(&self, text: &str) -> anyhow::Result<u128, NumberFormatterError> {
+        pattern_of_bytes(self.encoding.decode(text)?, self.endian)
+    }
+
+    fn format_unsigned(&self, num: u128) -> anyhow::Result<String, NumberFormatterError> {
+        Ok(self.format_value(Value::Unsigned(num)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(Encoding::Base64, b"", "")]
+    #[test_case(Encoding::Base64, b"f", "Zg==")]
+    #[test_case(Encoding::Base64, b"foob", "Zm9vYg==")]
+    #[test_case(Encoding::Base64, b"foobar", "Zm9vYmFy")]
+    #[test_case(Encoding::Base64Url, &[0xfb, 0xff], "-_8")]
+    #[test_case(Encoding::Base32, b"f", "MY======")]
+    #[test_case(Encoding::Base32, b"foobar", "MZXW6YTBOI======")]
+    #[test_case(Encoding::Base32Crockford, b"foobar", "CSQPYRK1E8")]
+    fn encoding_should_encode_rfc4648_test_vectors(
+        encoding: Encoding,
+        bytes: &[u8],
+        expected: &str,
+    ) {
+        // Act
+        let actual = encoding.encode(bytes);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test_case(Encoding::Base64, "Zm9vYg==", b"foob")]
+    #[test_case(Encoding::Base64, "Zm9vYg", b"foob"; "unpadded")]
+    #[test_case(Encoding::Base64Url, "-_8=", &[0xfb, 0xff])]
+    #[test_case(Encoding::Base32, "mzxw6ytboi", b"foobar"; "lowercase")]
+    #[test_case(Encoding::Base32Crockford, "csqp-yrkl-e8", b"foobar"; "confusable letters")]
+    fn encoding_should_decode(encoding: Encoding, text: &str, expected: &[u8]) {
+        // Act
+        let actual = encoding.decode(text);
+
+        // Assert
+        assert_eq!(actual.unwrap(), expected);
+    }
+
+    #[test_case(Encoding::Base64, "Zm9v!", NumberFormatterError::InvalidDigit; "invalid character")]
+    #[test_case(Encoding::Base64, "Z", NumberFormatterError::IncompleteByte; "too short")]
+    #[test_case(Encoding::Base64, "Zh==", NumberFormatterError::IncompleteByte; "leftover bits")]
+    #[test_case(Encoding::Base64, "Zm9v+", NumberFormatterError::IncompleteByte; "plus is standard")]
+    #[test_case(Encoding::Base64Url, "Zm9v+A", NumberFormatterError::InvalidDigit; "plus is not url safe")]
+    fn encoding_should_fail_to_decode(
+        encoding: Encoding,
+        text: &str,
+        expected: NumberFormatterError,
+    ) {
+        // Act
+        let actual = encoding.decode(text);
+
+        // Assert
+        assert_eq!(
+            std::mem::discriminant(&actual.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+
+    #[test_case(Encoding::Base64, 0xdeadbeef, "3q2+7w==", 0xdeadbeef)]
+    #[test_case(Encoding::Base32, 0xdeadbeef, "32W353Y=", 0xdeadbeef)]
+    #[test_case(Encoding::Base32Crockford, 0xdeadbeef, "VTPVXVR", 0xdeadbeef)]
+    #[test_case(Encoding::Base64Url, -1, "_w", 0xff; "negative")]
+    fn encoding_number_formatter_should_format_and_read(
+        encoding: Encoding,
+        number: i128,
+        text: &str,
+        pattern: i128,
+    ) {
+        // Arrange
+        let formatter = EncodingNumberFormatter {
+            encoding,
+            endian: Endian::Big,
+            size: None,
+        };
+
+        // Act
+        let actual_output = formatter.format(number);
+        let actual_number = formatter.read(text);
+
+        // Assert
+        assert_eq!(actual_output.unwrap(), text);
+        assert_eq!(actual_number.unwrap(), pattern);
+    }
+}
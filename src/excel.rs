@@ -0,0 +1,272 @@
+use crate::precision::{self, Precision};
+use crate::time::{self, SECONDS_PER_DAY};
+use crate::NumberFormatterError;
+use clap::Args;
+
+/// Options of the excel command
+#[derive(Args, Clone, Debug)]
+pub struct ExcelOptions {
+    /// A serial date such as 45244.5, or an ISO 8601 date such as 2023-11-14T12:00:00 to find the
+    /// serial date of
+    pub value: String,
+    /// Count from the 1904 date system of old Mac workbooks, where serial 0 is 1904-01-01
+    #[clap(long)]
+    pub date1904: bool,
+}
+
+/// An Excel serial date and the date and time it stands for
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExcelInfo {
+    pub serial: String,
+    pub date: String,
+    /// Whether the serial is 60, the 1900-02-29 that Excel keeps but that never happened
+    pub phantom_leap_day: bool,
+}
+
+/// Serial of the phantom 1900-02-29 in the 1900 date system, kept since Lotus 1-2-3 treated 1900
+/// as a leap year
+const PHANTOM_LEAP_DAY: i128 = 60;
+
+/// The date Excel gives the phantom leap day
+const PHANTOM_LEAP_DATE: &str = "1900-02-29";
+
+/// The real day before the phantom leap day, which it is parsed as
+const DAY_BEFORE_PHANTOM_LEAP_DATE: &str = "1900-02-28";
+
+/// Decimal places serial dates are written with, enough for whole seconds
+const SERIAL_DECIMALS: u32 = 10;
+
+/// Days since 1970-01-01 of serial day 0 of each date system, with the 1900 system's day 0 falling
+/// on 1899-12-30 for every serial after the phantom leap day
+fn serial_zero(date1904: bool) -> i128 {
+    if date1904 {
+        time::days_from_civil(1904, 1, 1)
+    } else {
+        time::days_from_civil(1899, 12, 30)
+    }
+}
+
+/// A date with the phantom 1900-02-29 of the 1900 date system replaced by the real day before it,
+/// so that it can be parsed, and whether it was replaced
+fn real_date(date: &str, date1904: bool) -> (String, bool) {
+    match date.strip_prefix(PHANTOM_LEAP_DATE) {
+        Some(time) if !date1904 => (format!("{}{}", DAY_BEFORE_PHANTOM_LEAP_DATE, time), true),
+        _ => (date.to_string(), false),
+    }
+}
+
+impl ExcelInfo {
+    /// Reads the value given to the excel command as either a serial date or an ISO 8601 date
+    pub(crate) fn new(options: &ExcelOptions) -> anyhow::Result<Self, NumberFormatterError> {
+        let (date, _) = real_date(&options.value, options.date1904);
+        if time::is_datetime(&date) {
+            ExcelInfo::from_date(&options.value, options.date1904)
+        } else {
+            ExcelInfo::from_serial(&options.value, options.date1904)
+        }
+    }
+
+    fn from_serial(serial: &str, date1904: bool) -> anyhow::Result<Self, NumberFormatterError> {
+        if serial.is_empty() {
+            return Err(NumberFormatterError::Empty);
+        }
+        if serial.starts_with('-') {
+            return Err(NumberFormatterError::TooSmallError);
+        }
+
+        let (days, decimals) = serial.split_once('.').unwrap_or((serial, ""));
+        if decimals.len() > 30 || !decimals.chars().all(|c| c.is_ascii_digit()) {
+            return Err(NumberFormatterError::InvalidDigit);
+        }
+
+        let mut days = days.parse::<i128>()?;
+        let mut seconds = if decimals.is_empty() {
+            0
+        } else {
+            let scale = 10i128.pow(decimals.len() as u32);
+            (decimals.parse::<i128>()? * SECONDS_PER_DAY + scale / 2) / scale
+        };
+        if seconds == SECONDS_PER_DAY {
+            days += 1;
+            seconds = 0;
+        }
+
+        let phantom_leap_day = !date1904 && days == PHANTOM_LEAP_DAY;
+        let date = if phantom_leap_day {
+            format!("{}T{}", PHANTOM_LEAP_DATE, format_time_of_day(seconds))
+        } else {
+            // Serials before the phantom leap day count from 1899-12-31 instead
+            let days = if !date1904 && days < PHANTOM_LEAP_DAY {
+                days + 1
+            } else {
+                days
+            };
+            let unix_seconds = (serial_zero(date1904) + days) * SECONDS_PER_DAY + seconds;
+            let datetime = time::format_datetime(unix_seconds, 0, 0, 0)?;
+            datetime.trim_end_matches('Z').to_string()
+        };
+
+        Ok(Self {
+            serial: serial.to_string(),
+            date,
+            phantom_leap_day,
+        })
+    }
+
+    fn from_date(date: &str, date1904: bool) -> anyhow::Result<Self, NumberFormatterError> {
+        let (date, phantom_leap_day) = real_date(date, date1904);
+        let (unix_seconds, _) = time::parse_datetime(&date)?;
+        let mut days = unix_seconds.div_euclid(SECONDS_PER_DAY) - serial_zero(date1904);
+        let seconds = unix_seconds.rem_euclid(SECONDS_PER_DAY);
+
+        // Dates before the phantom leap day count from 1899-12-31, while the phantom leap day
+        // itself is read as the day before, which is one day on from there
+        if !date1904 && days <= PHANTOM_LEAP_DAY && !phantom_leap_day {
+            days -= 1;
+        }
+        if days < if date1904 { 0 } else { 1 } {
+            return Err(NumberFormatterError::TooSmallError);
+        }
+
+        let serial = precision::divide(
+            (days * SECONDS_PER_DAY + seconds) as u128,
+            SECONDS_PER_DAY as u128,
+            Precision::Decimals(SERIAL_DECIMALS),
+        );
+
+        let date = if phantom_leap_day {
+            format!("{}T{}", PHANTOM_LEAP_DATE, format_time_of_day(seconds))
+        } else {
+            time::format_datetime(unix_seconds, 0, 0, 0)?
+                .trim_end_matches('Z')
+                .to_string()
+        };
+
+        Ok(Self {
+            serial,
+            date,
+            phantom_leap_day,
+        })
+    }
+
+    /// Labelled lines describing the serial date, in the order they should be displayed
+    pub fn lines(&self) -> Vec<(&'static str, String)> {
+        let mut lines = vec![("Serial", self.serial.clone()), ("Date", self.date.clone())];
+
+        if self.phantom_leap_day {
+            lines.push((
+                "Note",
+                String::from("1900-02-29 never happened, Excel keeps it for Lotus 1-2-3"),
+            ));
+        }
+
+        lines
+    }
+}
+
+fn format_time_of_day(seconds: i128) -> String {
+    format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("45244", false, "2023-11-14T00:00:00")]
+    #[test_case("45244.5", false, "2023-11-14T12:00:00"; "noon")]
+    #[test_case("45244.925925926", false, "2023-11-14T22:13:20"; "time of day")]
+    #[test_case("1", false, "1900-01-01T00:00:00"; "first day")]
+    #[test_case("59", false, "1900-02-28T00:00:00"; "before phantom leap day")]
+    #[test_case("60", false, "1900-02-29T00:00:00"; "phantom leap day")]
+    #[test_case("61", false, "1900-03-01T00:00:00"; "after phantom leap day")]
+    #[test_case("0.99999999", false, "1900-01-01T00:00:00"; "rounds up to next day")]
+    #[test_case("43782", true, "2023-11-14T00:00:00"; "1904 date system")]
+    fn excel_info_should_convert_serial(serial: &str, date1904: bool, expected: &str) {
+        // Act
+        let actual = ExcelInfo::from_serial(serial, date1904);
+
+        // Assert
+        assert_eq!(actual.unwrap().date, expected);
+    }
+
+    #[test_case("2023-11-14", false, "45244")]
+    #[test_case("2023-11-14T12:00:00", false, "45244.5"; "noon")]
+    #[test_case("2023-11-14T22:13:20", false, "45244.9259259259"; "time of day")]
+    #[test_case("1900-02-28", false, "59"; "before phantom leap day")]
+    #[test_case("1900-02-29", false, "60"; "phantom leap day")]
+    #[test_case("1900-02-29T12:00:00", false, "60.5"; "phantom leap day noon")]
+    #[test_case("1900-03-01", false, "61"; "after phantom leap day")]
+    #[test_case("2023-11-14", true, "43782"; "1904 date system")]
+    fn excel_info_should_convert_date(date: &str, date1904: bool, expected: &str) {
+        // Act
+        let actual = ExcelInfo::from_date(date, date1904);
+
+        // Assert
+        assert_eq!(actual.unwrap().serial, expected);
+    }
+
+    #[test]
+    fn excel_info_should_note_phantom_leap_day() {
+        // Act
+        let actual = ExcelInfo::from_serial("60", false).unwrap();
+
+        // Assert
+        assert!(actual.phantom_leap_day);
+        assert_eq!(actual.lines().len(), 3);
+    }
+
+    #[test]
+    fn excel_info_should_read_phantom_leap_date() {
+        // Arrange
+        let options = ExcelOptions {
+            value: String::from("1900-02-29"),
+            date1904: false,
+        };
+
+        // Act
+        let actual = ExcelInfo::new(&options).unwrap();
+
+        // Assert
+        assert_eq!(actual.serial, "60");
+        assert_eq!(actual.date, "1900-02-29T00:00:00");
+        assert!(actual.phantom_leap_day);
+    }
+
+    #[test]
+    fn excel_info_should_reject_phantom_leap_date_in_1904_date_system() {
+        // Act
+        let actual = ExcelInfo::from_date("1900-02-29", true);
+
+        // Assert
+        assert!(actual.is_err());
+    }
+
+    #[test_case("-1", NumberFormatterError::TooSmallError; "negative")]
+    #[test_case("45244.5x", NumberFormatterError::InvalidDigit; "invalid fraction")]
+    #[test_case("", NumberFormatterError::Empty; "empty")]
+    fn excel_info_should_fail_to_convert_serial(serial: &str, expected: NumberFormatterError) {
+        // Act
+        let actual = ExcelInfo::from_serial(serial, false);
+
+        // Assert
+        assert_eq!(
+            std::mem::discriminant(&actual.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+
+    #[test]
+    fn excel_info_should_reject_date_before_1900() {
+        // Act
+        let actual = ExcelInfo::from_date("1899-12-31", false);
+
+        // Assert
+        assert!(matches!(actual, Err(NumberFormatterError::TooSmallError)));
+    }
+}
@@ -0,0 +1,106 @@
+use crate::{read_prefixed_number, NumberFormatter, NumberFormatterError};
+
+/// Excess-K (biased) formatter where the stored value is the number plus the bias,
+/// e.g. excess-127 stores an f32 exponent of 3 as 130. The stored value is written in
+/// decimal and may be read with a `0x`, `0b` or `0o` prefix
+pub(crate) struct ExcessNumberFormatter {
+    pub bias: i128,
+}
+
+impl NumberFormatter for ExcessNumberFormatter {
+    fn read(&self, stored: &str) -> anyhow::Result<i128, NumberFormatterError> {
+        let stored = read_prefixed_number(stored)?;
+        if stored < 0 {
+            return Err(NumberFormatterError::TooSmallError);
+        }
+
+        stored
+            .checked_sub(self.bias)
+            .ok_or(NumberFormatterError::TooSmallError)
+    }
+
+    fn format(&self, num: i128) -> anyhow::Result<String, NumberFormatterError> {
+        let stored = num
+            .checked_add(self.bias)
+            .ok_or(NumberFormatterError::TooLargeError)?;
+
+        if stored < 0 {
+            return Err(NumberFormatterError::TooSmallError);
+        }
+
+        Ok(stored.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(127, "130", 3)]
+    #[test_case(127, "0b10000001", 2)]
+    #[test_case(127, "0x7f", 0)]
+    #[test_case(1023, "0", -1023)]
+    #[test_case(-5, "0", 5)]
+    fn excess_number_formatter_should_read_number(bias: i128, input: &str, expected: i128) {
+        // Arrange
+        let formatter = ExcessNumberFormatter { bias };
+
+        // Act
+        let actual_number = formatter.read(input);
+
+        // Assert
+        assert!(actual_number.is_ok());
+        assert_eq!(expected, actual_number.unwrap());
+    }
+
+    #[test_case(127, 3, "130")]
+    #[test_case(127, -126, "1")]
+    #[test_case(0, 42, "42")]
+    fn excess_number_formatter_should_format_i128(bias: i128, input: i128, expected: &str) {
+        // Arrange
+        let formatter = ExcessNumberFormatter { bias };
+
+        // Act
+        let actual_output = formatter.format(input);
+
+        // Assert
+        assert!(actual_output.is_ok());
+        assert_eq!(expected, actual_output.unwrap());
+    }
+
+    #[test_case(127, -128, NumberFormatterError::TooSmallError ; "below bias")]
+    #[test_case(1, i128::MAX, NumberFormatterError::TooLargeError ; "overflow")]
+    fn excess_number_formatter_should_fail_to_format(
+        bias: i128,
+        input: i128,
+        expected: NumberFormatterError,
+    ) {
+        // Arrange
+        let formatter = ExcessNumberFormatter { bias };
+
+        // Act
+        let actual_output = formatter.format(input);
+
+        // Assert
+        assert_eq!(
+            std::mem::discriminant(&actual_output.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+
+    #[test]
+    fn excess_number_formatter_should_reject_negative_stored_value() {
+        // Arrange
+        let formatter = ExcessNumberFormatter { bias: 127 };
+
+        // Act
+        let actual_number = formatter.read("-1");
+
+        // Assert
+        assert!(matches!(
+            actual_number,
+            Err(NumberFormatterError::TooSmallError)
+        ));
+    }
+}
@@ -0,0 +1,436 @@
+use crate::{IntegerSize, NumberFormatterError, Value};
+
+/// Binary operators of expressions, with the precedence they bind with
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Operator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Remainder,
+    ShiftLeft,
+    ShiftRight,
+    And,
+    Xor,
+    Or,
+}
+
+impl Operator {
+    fn from_symbol(symbol: &str) -> Option<Operator> {
+        match symbol {
+            "+" => Some(Operator::Add),
+            "-" => Some(Operator::Subtract),
+            "*" => Some(Operator::Multiply),
+            "/" => Some(Operator::Divide),
+            "%" => Some(Operator::Remainder),
+            "<<" => Some(Operator::ShiftLeft),
+            ">>" => Some(Operator::ShiftRight),
+            "&" => Some(Operator::And),
+            "^" => Some(Operator::Xor),
+            "|" => Some(Operator::Or),
+            _ => None,
+        }
+    }
+
+    /// How tightly the operator binds, with higher binding tighter as in C
+    fn precedence(self) -> u8 {
+        match self {
+            Operator::Or => 1,
+            Operator::Xor => 2,
+            Operator::And => 3,
+            Operator::ShiftLeft | Operator::ShiftRight => 4,
+            Operator::Add | Operator::Subtract => 5,
+            Operator::Multiply | Operator::Divide | Operator::Remainder => 6,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Token<'a> {
+    Literal(&'a str),
+    Operator(&'a str),
+    Open,
+    Close,
+}
+
+/// A parsed expression, with its literals left as written to be read by number types
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Expression<'a> {
+    Literal(&'a str),
+    Negate(Box<Expression<'a>>),
+    Not(Box<Expression<'a>>),
+    Binary(Operator, Box<Expression<'a>>, Box<Expression<'a>>),
+}
+
+/// Splits an expression into literals, operators and parentheses. Parentheses and the operators
+/// `* % & ^ ~ << >>` split anything, but `+`, `-`, `/` and `|` are only operators standing apart
+/// between spaces, as number types use them within literals such as dates, base64, negative
+/// numbers and hexdumps.
+fn lex(expression: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+
+    for word in expression.split_whitespace() {
+        let bytes = word.as_bytes();
+        let mut start = 0;
+        let mut index = 0;
+        while index < bytes.len() {
+            let length = match (bytes[index], bytes.get(index + 1)) {
+                (b'(' | b')' | b'*' | b'%' | b'&' | b'^' | b'~', _) => 1,
+                (b'<', Some(b'<')) | (b'>', Some(b'>')) => 2,
+                _ => {
+                    index += 1;
+                    continue;
+                }
+            };
+            if start < index {
+                tokens.push(Token::Literal(&word[start..index]));
+            }
+            tokens.push(match &word[index..index + length] {
+                "(" => Token::Open,
+                ")" => Token::Close,
+                symbol => Token::Operator(symbol),
+            });
+            index += length;
+            start = index;
+        }
+
+        match &word[start..] {
+            "" => {}
+            symbol if Operator::from_symbol(symbol).is_some() => {
+                tokens.push(Token::Operator(symbol))
+            }
+            literal => tokens.push(Token::Literal(literal)),
+        }
+    }
+
+    tokens
+}
+
+/// Whether the input is an expression rather than a single number, having an operator or
+/// parentheses
+pub(crate) fn is_expression(input: &str) -> bool {
+    lex(input)
+        .iter()
+        .any(|token| !matches!(token, Token::Literal(_)))
+}
+
+/// Recursive descent parser reading binary operators by precedence climbing
+struct Parser<'a> {
+    tokens: Vec<Token<'a>>,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn next(&mut self) -> Option<Token<'a>> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn peek_operator(&self) -> Option<Operator> {
+        match self.tokens.get(self.position) {
+            Some(Token::Operator(symbol)) => Operator::from_symbol(symbol),
+            _ => None,
+        }
+    }
+
+    /// Parses operands joined by operators binding at least as tightly as a precedence, with
+    /// operators of equal precedence grouping from the left
+    fn binary(
+        &mut self,
+        min_precedence: u8,
+    ) -> anyhow::Result<Expression<'a>, NumberFormatterError> {
+        let mut left = self.unary()?;
+
+        while let Some(operator) = self
+            .peek_operator()
+            .filter(|operator| operator.precedence() >= min_precedence)
+        {
+            self.position += 1;
+            let right = self.binary(operator.precedence() + 1)?;
+            left = Expression::Binary(operator, Box::new(left), Box::new(right));
+        }
+
+        Ok(left)
+    }
+
+    fn unary(&mut self) -> anyhow::Result<Expression<'a>, NumberFormatterError> {
+        match self.next() {
+            Some(Token::Literal(literal)) => Ok(Expression::Literal(literal)),
+            Some(Token::Operator("-")) => Ok(Expression::Negate(Box::new(self.unary()?))),
+            Some(Token::Operator("+")) => self.unary(),
+            Some(Token::Operator("~")) => Ok(Expression::Not(Box::new(self.unary()?))),
+            Some(Token::Open) => {
+                let inner = self.binary(0)?;
+                match self.next() {
+                    Some(Token::Close) => Ok(inner),
+                    _ => Err(NumberFormatterError::InvalidExpression),
+                }
+            }
+            _ => Err(NumberFormatterError::InvalidExpression),
+        }
+    }
+}
+
+fn parse(expression: &str) -> anyhow::Result<Expression<'_>, NumberFormatterError> {
+    let mut parser = Parser {
+        tokens: lex(expression),
+        position: 0,
+    };
+
+    let parsed = parser.binary(0)?;
+    if parser.position == parser.tokens.len() {
+        Ok(parsed)
+    } else {
+        Err(NumberFormatterError::InvalidExpression)
+    }
+}
+
+/// The two's complement bit pattern of a value, with the bits above it all ones for negative values
+fn twos_complement(value: Value) -> (bool, u128) {
+    match value {
+        Value::Signed(num) => (num < 0, num as u128),
+        Value::Unsigned(num) => (false, num),
+    }
+}
+
+/// The value of a bit pattern with the bits above it all ones if it is negative
+fn from_twos_complement(negative: bool, bits: u128) -> Value {
+    if negative {
+        Value::Signed(bits as i128)
+    } else {
+        Value::from_unsigned(bits)
+    }
+}
+
+/// Flips every bit of a value, within the integer size if one is chosen and otherwise as an
+/// integer of unlimited width, where the complement of x is -x - 1
+fn not(value: Value, size: Option<IntegerSize>) -> anyhow::Result<Value, NumberFormatterError> {
+    match size {
+        Some(size) => Ok(size.value_of(!size.pattern_of(value))),
+        None => Value::Signed(-1).checked_sub(value),
+    }
+}
+
+/// Shifts a value by a number of bits, within the integer size if one is chosen so that bits
+/// shifted out are lost. Without one, shifting left must not overflow and shifting right rounds
+/// down, as shifting an integer of unlimited width would. Right shifts of signed values are
+/// arithmetic and of unsigned values logical.
+fn shift(
+    operator: Operator,
+    value: Value,
+    count: Value,
+    size: Option<IntegerSize>,
+) -> anyhow::Result<Value, NumberFormatterError> {
+    let count = match count {
+        Value::Signed(count) if count < 0 => return Err(NumberFormatterError::TooSmallError),
+        Value::Signed(count) => u32::try_from(count).unwrap_or(u32::MAX),
+        Value::Unsigned(_) => u32::MAX,
+    };
+
+    match (operator, size) {
+        (Operator::ShiftLeft, Some(size)) => Ok(size.value_of(
+            size.pattern_of(value)
+                .checked_shl(count)
+                .unwrap_or_default(),
+        )),
+        (Operator::ShiftLeft, None) => {
+            let (negative, magnitude) = value.sign_magnitude();
+            let shifted = if count < 128 {
+                magnitude.checked_mul(1 << count)
+            } else {
+                Some(0).filter(|_| magnitude == 0)
+            };
+            match shifted {
+                Some(shifted) => Value::from_sign_magnitude(negative, shifted),
+                None if negative => Err(NumberFormatterError::TooSmallError),
+                None => Err(NumberFormatterError::TooLargeError),
+            }
+        }
+        (_, size) => {
+            let value = match size {
+                Some(size) => size.value_of(size.pattern_of(value)),
+                None => value,
+            };
+            Ok(match value {
+                Value::Signed(num) => Value::Signed(num >> count.min(127)),
+                Value::Unsigned(num) => Value::from_unsigned(num.checked_shr(count).unwrap_or(0)),
+            })
+        }
+    }
+}
+
+/// Applies an operator to two values, failing where the result does not fit. Division truncates
+/// towards zero, and remainders take the sign of the dividend, as in C and Rust.
+pub(crate) fn apply(
+    operator: Operator,
+    left: Value,
+    right: Value,
+    size: Option<IntegerSize>,
+) -> anyhow::Result<Value, NumberFormatterError> {
+    let (left_sign, left_bits) = twos_complement(left);
+    let (right_sign, right_bits) = twos_complement(right);
+    let (left_negative, left_magnitude) = left.sign_magnitude();
+    let (right_negative, right_magnitude) = right.sign_magnitude();
+    let negative = left_negative != right_negative;
+    let overflow = if negative {
+        NumberFormatterError::TooSmallError
+    } else {
+        NumberFormatterError::TooLargeError
+    };
+
+    match operator {
+        Operator::Add => left.checked_add(right),
+        Operator::Subtract => left.checked_sub(right),
+        Operator::Multiply => Value::from_sign_magnitude(
+            negative,
+            left_magnitude
+                .checked_mul(right_magnitude)
+                .ok_or(overflow)?,
+        ),
+        Operator::Divide | Operator::Remainder if right_magnitude == 0 => {
+            Err(NumberFormatterError::DivisionByZero)
+        }
+        Operator::Divide => Value::from_sign_magnitude(negative, left_magnitude / right_magnitude),
+        Operator::Remainder => {
+            Value::from_sign_magnitude(left_negative, left_magnitude % right_magnitude)
+        }
+        Operator::ShiftLeft | Operator::ShiftRight => shift(operator, left, right, size),
+        Operator::And => Ok(from_twos_complement(
+            left_sign && right_sign,
+            left_bits & right_bits,
+        )),
+        Operator::Xor => Ok(from_twos_complement(
+            left_sign != right_sign,
+            left_bits ^ right_bits,
+        )),
+        Operator::Or => Ok(from_twos_complement(
+            left_sign || right_sign,
+            left_bits | right_bits,
+        )),
+    }
+}
+
+fn evaluate_parsed(
+    expression: &Expression,
+    size: Option<IntegerSize>,
+    read_literal: &impl Fn(&str) -> anyhow::Result<Value, NumberFormatterError>,
+) -> anyhow::Result<Value, NumberFormatterError> {
+    match expression {
+        Expression::Literal(literal) => read_literal(literal),
+        Expression::Negate(operand) => {
+            Value::Signed(0).checked_sub(evaluate_parsed(operand, size, read_literal)?)
+        }
+        Expression::Not(operand) => not(evaluate_parsed(operand, size, read_literal)?, size),
+        Expression::Binary(operator, left, right) => apply(
+            *operator,
+            evaluate_parsed(left, size, read_literal)?,
+            evaluate_parsed(right, size, read_literal)?,
+            size,
+        ),
+    }
+}
+
+/// Evaluates an expression such as `0xff + 0b1010 * 3`, with each literal read by a number type of
+/// its own so that bases can be mixed. Complements and shifts work within the integer size if one
+/// is given.
+pub(crate) fn evaluate(
+    expression: &str,
+    size: Option<IntegerSize>,
+    read_literal: impl Fn(&str) -> anyhow::Result<Value, NumberFormatterError>,
+) -> anyhow::Result<Value, NumberFormatterError> {
+    evaluate_parsed(&parse(expression)?, size, &read_literal)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    fn read_literal(literal: &str) -> anyhow::Result<Value, NumberFormatterError> {
+        Ok(Value::Signed(literal.parse::<i128>()?))
+    }
+
+    #[test_case("1 + 2", true)]
+    #[test_case("2*3", true; "multiply without spaces")]
+    #[test_case("(5)", true; "parentheses")]
+    #[test_case("-5", false; "negative number")]
+    #[test_case("2023-11-14", false; "date")]
+    #[test_case("de ad be ef", false; "spaced bytes")]
+    #[test_case("a+b/c=", false; "base64")]
+    #[test_case("1<<4", true; "shift without spaces")]
+    #[test_case("~5", true; "complement")]
+    #[test_case("00000000  2b 2d  |+-|", false; "hexdump")]
+    fn is_expression_should_find_operators(input: &str, expected: bool) {
+        // Act
+        let actual = is_expression(input);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test_case("1 + 2 * 3", Value::Signed(7))]
+    #[test_case("(1 + 2) * 3", Value::Signed(9); "parentheses")]
+    #[test_case("10 - 4 - 3", Value::Signed(3); "left to right")]
+    #[test_case("- (2 + 3)", Value::Signed(-5); "negated")]
+    #[test_case("-7 / 2", Value::Signed(-3); "truncating division")]
+    #[test_case("-7 % 2", Value::Signed(-1); "remainder takes sign of dividend")]
+    #[test_case("2*3%4", Value::Signed(2); "without spaces")]
+    #[test_case("170141183460469231731687303715884105727 * 2", Value::Unsigned(u128::MAX - 1); "past i128")]
+    fn evaluate_should_follow_precedence(expression: &str, expected: Value) {
+        // Act
+        let actual = evaluate(expression, None, read_literal);
+
+        // Assert
+        assert_eq!(actual.unwrap(), expected);
+    }
+
+    #[test_case("12 & 10", None, Value::Signed(8))]
+    #[test_case("12 | 10", None, Value::Signed(14); "or")]
+    #[test_case("12 ^ 10", None, Value::Signed(6); "xor")]
+    #[test_case("1 | 2 ^ 3 & 4", None, Value::Signed(3); "precedence")]
+    #[test_case("1 + 1 << 4", None, Value::Signed(32); "shift binds looser than addition")]
+    #[test_case("(1<<4) | 0", None, Value::Signed(16); "parentheses")]
+    #[test_case("~5", None, Value::Signed(-6); "complement")]
+    #[test_case("-1 & 255", None, Value::Signed(255); "negative and")]
+    #[test_case("-16 >> 2", None, Value::Signed(-4); "arithmetic shift")]
+    #[test_case("1 >> 200", None, Value::Signed(0); "shifted out")]
+    #[test_case("~15", Some(IntegerSize { bits: 8, signed: false }), Value::Signed(240); "sized complement")]
+    #[test_case("~15", Some(IntegerSize { bits: 8, signed: true }), Value::Signed(-16); "signed sized complement")]
+    #[test_case("255 << 4", Some(IntegerSize { bits: 8, signed: false }), Value::Signed(240); "sized shift drops bits")]
+    #[test_case("128 >> 1", Some(IntegerSize { bits: 8, signed: true }), Value::Signed(-64); "sized arithmetic shift")]
+    #[test_case("128 >> 1", Some(IntegerSize { bits: 8, signed: false }), Value::Signed(64); "sized logical shift")]
+    #[test_case("1 << 8", Some(IntegerSize { bits: 8, signed: false }), Value::Signed(0); "sized shift past width")]
+    fn evaluate_should_apply_bitwise_operators(
+        expression: &str,
+        size: Option<IntegerSize>,
+        expected: Value,
+    ) {
+        // Act
+        let actual = evaluate(expression, size, read_literal);
+
+        // Assert
+        assert_eq!(actual.unwrap(), expected);
+    }
+
+    #[test_case("1 / 0", NumberFormatterError::DivisionByZero; "division by zero")]
+    #[test_case("1 +", NumberFormatterError::InvalidExpression; "missing operand")]
+    #[test_case("(1 + 2", NumberFormatterError::InvalidExpression; "unclosed parenthesis")]
+    #[test_case("1 2 + 3", NumberFormatterError::InvalidExpression; "missing operator")]
+    #[test_case("-1 * 340282366920938463463374607431768211455", NumberFormatterError::TooSmallError; "too small")]
+    #[test_case("1 << 128", NumberFormatterError::TooLargeError; "shift overflows")]
+    #[test_case("1 << -1", NumberFormatterError::TooSmallError; "negative shift")]
+    fn evaluate_should_fail(expression: &str, expected: NumberFormatterError) {
+        // Act
+        let actual = evaluate(expression, None, |literal| match literal.parse::<i128>() {
+            Ok(num) => Ok(Value::Signed(num)),
+            Err(_) => Ok(Value::Unsigned(literal.parse::<u128>()?)),
+        });
+
+        // Assert
+        assert_eq!(
+            std::mem::discriminant(&actual.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+}
@@ -0,0 +1,196 @@
+use crate::modular;
+use crate::rational::gcd;
+use clap::Args;
+
+/// Options of the factor command
+#[derive(Args, Clone, Debug)]
+pub struct FactorOptions {
+    /// The positive number to factor
+    pub number: String,
+}
+
+/// Primes below this are found by trial division, leaving larger factors to Pollard's rho
+const TRIAL_LIMIT: u128 = 1000;
+
+/// Bases for the Miller-Rabin test, which between them never pass a composite below 3.3 * 10^24
+static WITNESSES: [u128; 13] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41];
+
+/// The prime factorization of a positive number
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FactorInfo {
+    /// Each prime factor and its exponent, smallest first
+    pub factors: Vec<(u128, u32)>,
+}
+
+/// Whether a number is prime, by the Miller-Rabin test. Certain below 3.3 * 10^24, and wrong for
+/// larger numbers only for composites built to fool every witness.
+pub(crate) fn is_prime(number: u128) -> bool {
+    if number < 2 {
+        return false;
+    }
+    if let Some(witness) = WITNESSES
+        .iter()
+        .find(|witness| number.is_multiple_of(**witness))
+    {
+        return number == *witness;
+    }
+
+    let shift = (number - 1).trailing_zeros();
+    let odd = (number - 1) >> shift;
+    WITNESSES.iter().all(|witness| {
+        let mut x = modular::power(*witness, odd, number);
+        if x == 1 || x == number - 1 {
+            return true;
+        }
+        (1..shift).any(|_| {
+            x = modular::multiply(x, x, number);
+            x == number - 1
+        })
+    })
+}
+
+/// A factor of a composite number other than one and itself, by Pollard's rho with the sequence
+/// x^2 + c, none when the sequence cycles without finding one
+fn rho(number: u128, c: u128) -> Option<u128> {
+    let next = |x: u128| modular::add(modular::multiply(x, x, number), c, number);
+    let (mut slow, mut fast) = (2, 2);
+
+    loop {
+        slow = next(slow);
+        fast = next(next(fast));
+        match gcd(slow.abs_diff(fast), number) {
+            1 => continue,
+            divisor if divisor == number => return None,
+            divisor => return Some(divisor),
+        }
+    }
+}
+
+/// Adds the prime factors of a number without small factors, splitting composites with Pollard's
+/// rho and trying further sequences when one cycles
+fn split(number: u128, primes: &mut Vec<u128>) {
+    if number == 1 {
+        return;
+    }
+    if is_prime(number) {
+        primes.push(number);
+        return;
+    }
+
+    let divisor = (1..)
+        .find_map(|c| rho(number, c))
+        .expect("every composite has a factor rho finds for some c");
+    split(divisor, primes);
+    split(number / divisor, primes);
+}
+
+impl FactorInfo {
+    pub(crate) fn new(mut number: u128) -> Self {
+        let mut primes = vec![];
+        // Composite divisors never divide what is left, as their prime factors are already gone
+        for divisor in 2..TRIAL_LIMIT {
+            if divisor * divisor > number {
+                break;
+            }
+            while number.is_multiple_of(divisor) {
+                primes.push(divisor);
+                number /= divisor;
+            }
+        }
+        split(number, &mut primes);
+        primes.sort_unstable();
+
+        let mut factors: Vec<(u128, u32)> = vec![];
+        for prime in primes {
+            match factors.last_mut() {
+                Some((last, exponent)) if *last == prime => *exponent += 1,
+                _ => factors.push((prime, 1)),
+            }
+        }
+
+        Self { factors }
+    }
+
+    /// How many numbers divide the number, one and itself included
+    pub fn divisor_count(&self) -> u128 {
+        self.factors
+            .iter()
+            .map(|(_, exponent)| u128::from(exponent + 1))
+            .product()
+    }
+
+    /// Labelled lines describing the factorization, in the order they should be displayed
+    pub fn lines(&self) -> Vec<(&'static str, String)> {
+        let factors = if self.factors.is_empty() {
+            String::from("none")
+        } else {
+            self.factors
+                .iter()
+                .map(|(prime, exponent)| match exponent {
+                    1 => prime.to_string(),
+                    exponent => format!("{}^{}", prime, exponent),
+                })
+                .collect::<Vec<String>>()
+                .join(" * ")
+        };
+        let prime = matches!(self.factors[..], [(_, 1)]);
+
+        vec![
+            ("Factors", factors),
+            ("Divisors", self.divisor_count().to_string()),
+            ("Prime", String::from(if prime { "yes" } else { "no" })),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(2, true)]
+    #[test_case(1, false; "one")]
+    #[test_case(561, false; "carmichael number")]
+    #[test_case(18_446_744_073_709_551_557, true; "largest 64-bit prime")]
+    #[test_case(3_825_123_056_546_413_051, false; "strong pseudoprime to small bases")]
+    #[test_case(170_141_183_460_469_231_731_687_303_715_884_105_727, true; "mersenne prime")]
+    fn is_prime_should_test_primality(number: u128, expected: bool) {
+        // Act
+        let actual = is_prime(number);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test_case(360, &[(2, 3), (3, 2), (5, 1)])]
+    #[test_case(1, &[]; "one")]
+    #[test_case(997 * 997, &[(997, 2)]; "square of trial prime")]
+    #[test_case(4_294_967_291 * 4_294_967_279, &[(4_294_967_279, 1), (4_294_967_291, 1)]; "product of 32-bit primes")]
+    #[test_case(u64::MAX as u128, &[(3, 1), (5, 1), (17, 1), (257, 1), (641, 1), (65537, 1), (6_700_417, 1)]; "largest 64-bit number")]
+    fn factor_info_should_factor_number(number: u128, expected: &[(u128, u32)]) {
+        // Act
+        let actual = FactorInfo::new(number);
+
+        // Assert
+        assert_eq!(actual.factors, expected);
+    }
+
+    #[test]
+    fn factor_info_should_describe_factors() {
+        // Arrange
+        let info = FactorInfo::new(360);
+
+        // Act
+        let actual = info.lines();
+
+        // Assert
+        assert_eq!(
+            actual,
+            vec![
+                ("Factors", String::from("2^3 * 3^2 * 5")),
+                ("Divisors", String::from("24")),
+                ("Prime", String::from("no")),
+            ]
+        );
+    }
+}
@@ -0,0 +1,304 @@
+use crate::{NumberFormatter, NumberFormatterError};
+
+/// The distinct Fibonacci numbers 1, 2, 3, 5, 8, … that fit in a u128
+fn fibonacci_numbers() -> Vec<u128> {
+    let mut numbers: Vec<u128> = vec![1, 2];
+
+    while let Some(next) = numbers[numbers.len() - 2].checked_add(numbers[numbers.len() - 1]) {
+        numbers.push(next);
+    }
+
+    numbers
+}
+
+/// Indices into `fibonacci_numbers` of the greedy Zeckendorf terms, largest first
+fn zeckendorf_indices(num: u128) -> Vec<usize> {
+    let numbers = fibonacci_numbers();
+    let mut indices = Vec::<usize>::new();
+    let mut remainder = num;
+
+    for (index, fibonacci) in numbers.iter().enumerate().rev() {
+        if *fibonacci <= remainder {
+            indices.push(index);
+            remainder -= fibonacci;
+        }
+    }
+
+    indices
+}
+
+/// Formats a number as its sum of non-consecutive Fibonacci numbers, e.g. 100 = `89 + 8 + 3`
+pub(crate) struct ZeckendorfNumberFormatter;
+
+impl NumberFormatter for ZeckendorfNumberFormatter {
+    fn read(&self, zeckendorf: &str) -> anyhow::Result<i128, NumberFormatterError> {
+        i128::try_from(self.read_unsigned(zeckendorf)?)
+            .map_err(|_| NumberFormatterError::TooLargeError)
+    }
+
+    fn format(&self, num: i128) -> anyhow::Result<String, NumberFormatterError> {
+        if num < 0 {
+            return Err(NumberFormatterError::TooSmallError);
+        }
+
+        self.format_unsigned(num as u128)
+    }
+
+    fn read_unsigned(&self, zeckendorf: &str) -> anyhow::Result<u128, NumberFormatterError> {
+        let numbers = fibonacci_numbers();
+        let mut previous: Option<usize> = None;
+        let mut value: u128 = 0;
+
+        for term in zeckendorf.split('+').map(str::trim) {
+            let term = term.parse::<u128>()?;
+            if term == 0 && previous.is_none() && !zeckendorf.contains('+') {
+                return Ok(0);
+            }
+
+            let index = numbers
+                .iter()
+                .position(|fibonacci| *fibonacci == term)
+                .ok_or(NumberFormatterError::InvalidZeckendorf)?;
+
+            if previous.is_some_and(|previous| index + 1 >= previous) {
+                return Err(NumberFormatterError::InvalidZeckendorf);
+            }
+
+            previous = Some(index);
+            value = value
+                .checked_add(term)
+                .ok_or(NumberFormatterError::TooLargeError)?;
+        }
+
+        Ok(value)
+    }
+
+    fn format_unsigned(&self, num: u128) -> anyhow::Result<String, NumberFormatterError> {
+        if num == 0 {
+            return Ok(String::from("0"));
+        }
+
+        let numbers = fibonacci_numbers();
+        Ok(zeckendorf_indices(num)
+            .iter()
+            .map(|index| numbers[*index].to_string())
+            .collect::<Vec<String>>()
+            .join(" + "))
+    }
+}
+
+/// Formats a positive number as its Fibonacci code, the Zeckendorf terms as bits from
+/// the smallest Fibonacci number up followed by a terminating `1`, e.g. 4 = `1011`
+pub(crate) struct FibonacciCodeNumberFormatter;
+
+impl NumberFormatter for FibonacciCodeNumberFormatter {
+    fn read(&self, code: &str) -> anyhow::Result<i128, NumberFormatterError> {
+        i128::try_from(self.read_unsigned(code)?).map_err(|_| NumberFormatterError::TooLargeError)
+    }
+
+    fn format(&self, num: i128) -> anyhow::Result<String, NumberFormatterError> {
+        if num < 1 {
+            return Err(NumberFormatterError::TooSmallError);
+        }
+
+        self.format_unsigned(num as u128)
+    }
+
+    fn read_unsigned(&self, code: &str) -> anyhow::Result<u128, NumberFormatterError> {
+        if code.is_empty() {
+            return Err(NumberFormatterError::Empty);
+        }
+
+        if !code.chars().all(|c| c == '0' || c == '1') {
+            return Err(NumberFormatterError::InvalidDigit);
+        }
+
+        let bits = code
+            .strip_suffix('1')
+            .filter(|bits| bits.ends_with('1') && !bits.contains("11"))
+            .ok_or(NumberFormatterError::InvalidZeckendorf)?;
+
+        let numbers = fibonacci_numbers();
+        let mut value: u128 = 0;
+
+        for (index, bit) in bits.chars().enumerate() {
+            if bit == '1' {
+                let fibonacci = numbers
+                    .get(index)
+                    .ok_or(NumberFormatterError::TooLargeError)?;
+                value = value
+                    .checked_add(*fibonacci)
+                    .ok_or(NumberFormatterError::TooLargeError)?;
+            }
+        }
+
+        Ok(value)
+    }
+
+    fn format_unsigned(&self, num: u128) -> anyhow::Result<String, NumberFormatterError> {
+        if num < 1 {
+            return Err(NumberFormatterError::TooSmallError);
+        }
+
+        let indices = zeckendorf_indices(num);
+        let mut bits = vec!['0'; indices[0] + 1];
+
+        for index in indices {
+            bits[index] = '1';
+        }
+
+        bits.push('1');
+        Ok(bits.iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("89 + 8 + 3", 100)]
+    #[test_case("1", 1)]
+    #[test_case("0", 0)]
+    #[test_case("8+2", 10)]
+    fn zeckendorf_number_formatter_should_read_number(input: &str, expected: i128) {
+        // Act
+        let actual_number = ZeckendorfNumberFormatter.read(input);
+
+        // Assert
+        assert!(actual_number.is_ok());
+        assert_eq!(expected, actual_number.unwrap());
+    }
+
+    #[test_case(100, "89 + 8 + 3")]
+    #[test_case(0, "0")]
+    #[test_case(4, "3 + 1")]
+    fn zeckendorf_number_formatter_should_format_i128(input: i128, expected: &str) {
+        // Act
+        let actual_output = ZeckendorfNumberFormatter.format(input);
+
+        // Assert
+        assert!(actual_output.is_ok());
+        assert_eq!(expected, actual_output.unwrap());
+    }
+
+    #[test]
+    fn zeckendorf_number_formatter_should_round_trip_max() {
+        // Act
+        let zeckendorf = ZeckendorfNumberFormatter.format(i128::MAX).unwrap();
+        let actual_number = ZeckendorfNumberFormatter.read(&zeckendorf);
+
+        // Assert
+        assert_eq!(i128::MAX, actual_number.unwrap());
+    }
+
+    #[test]
+    fn zeckendorf_number_formatter_should_round_trip_unsigned() {
+        // Act
+        let zeckendorf = ZeckendorfNumberFormatter
+            .format_unsigned(u128::MAX)
+            .unwrap();
+        let actual_number = ZeckendorfNumberFormatter.read_unsigned(&zeckendorf);
+
+        // Assert
+        assert_eq!(u128::MAX, actual_number.unwrap());
+    }
+
+    #[test_case("89 + 7", NumberFormatterError::InvalidZeckendorf ; "not fibonacci")]
+    #[test_case("8 + 5", NumberFormatterError::InvalidZeckendorf ; "consecutive")]
+    #[test_case("3 + 8", NumberFormatterError::InvalidZeckendorf ; "ascending")]
+    #[test_case("3 + 3", NumberFormatterError::InvalidZeckendorf ; "repeated")]
+    #[test_case("3 + x", NumberFormatterError::InvalidDigit ; "invalid digit")]
+    fn zeckendorf_number_formatter_should_fail_to_read(
+        input: &str,
+        expected: NumberFormatterError,
+    ) {
+        // Act
+        let actual_number = ZeckendorfNumberFormatter.read(input);
+
+        // Assert
+        assert_eq!(
+            std::mem::discriminant(&actual_number.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+
+    #[test_case("11", 1)]
+    #[test_case("011", 2)]
+    #[test_case("1011", 4)]
+    #[test_case("00101000011", 100 ; "hundred")]
+    fn fibonacci_code_number_formatter_should_read_number(input: &str, expected: i128) {
+        // Act
+        let actual_number = FibonacciCodeNumberFormatter.read(input);
+
+        // Assert
+        assert!(actual_number.is_ok());
+        assert_eq!(expected, actual_number.unwrap());
+    }
+
+    #[test_case(1, "11")]
+    #[test_case(3, "0011")]
+    #[test_case(4, "1011")]
+    #[test_case(100, "00101000011")]
+    fn fibonacci_code_number_formatter_should_format_i128(input: i128, expected: &str) {
+        // Act
+        let actual_output = FibonacciCodeNumberFormatter.format(input);
+
+        // Assert
+        assert!(actual_output.is_ok());
+        assert_eq!(expected, actual_output.unwrap());
+    }
+
+    #[test]
+    fn fibonacci_code_number_formatter_should_round_trip_max() {
+        // Act
+        let code = FibonacciCodeNumberFormatter.format(i128::MAX).unwrap();
+        let actual_number = FibonacciCodeNumberFormatter.read(&code);
+
+        // Assert
+        assert_eq!(i128::MAX, actual_number.unwrap());
+    }
+
+    #[test]
+    fn fibonacci_code_number_formatter_should_round_trip_unsigned() {
+        // Act
+        let code = FibonacciCodeNumberFormatter
+            .format_unsigned(u128::MAX)
+            .unwrap();
+        let actual_number = FibonacciCodeNumberFormatter.read_unsigned(&code);
+
+        // Assert
+        assert_eq!(u128::MAX, actual_number.unwrap());
+    }
+
+    #[test_case("1101", NumberFormatterError::InvalidZeckendorf ; "early terminator")]
+    #[test_case("0101", NumberFormatterError::InvalidZeckendorf ; "missing terminator")]
+    #[test_case("1", NumberFormatterError::InvalidZeckendorf ; "only terminator")]
+    #[test_case("0121", NumberFormatterError::InvalidDigit ; "invalid digit")]
+    #[test_case("", NumberFormatterError::Empty ; "empty")]
+    fn fibonacci_code_number_formatter_should_fail_to_read(
+        input: &str,
+        expected: NumberFormatterError,
+    ) {
+        // Act
+        let actual_number = FibonacciCodeNumberFormatter.read(input);
+
+        // Assert
+        assert_eq!(
+            std::mem::discriminant(&actual_number.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+
+    #[test]
+    fn fibonacci_code_number_formatter_should_reject_non_positive() {
+        // Act
+        let actual_output = FibonacciCodeNumberFormatter.format(0);
+
+        // Assert
+        assert!(matches!(
+            actual_output,
+            Err(NumberFormatterError::TooSmallError)
+        ));
+    }
+}
@@ -0,0 +1,456 @@
+use crate::precision::{self, Precision};
+use crate::{ratio, NumberFormatter, NumberFormatterError, Value};
+use clap::Args;
+
+/// Options of the fixed-point command
+#[derive(Args, Clone, Debug)]
+pub struct FixedPointOptions {
+    /// A raw value such as 0x4000, or a real number with a point such as 0.5 to find the raw value
+    /// of
+    pub value: String,
+}
+
+/// A fixed-point format in Q notation, such as Q15, Q8.8 or UQ16.16
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct QFormat {
+    pub signed: bool,
+    /// Bits before the binary point, which for signed formats include the sign bit
+    pub integer_bits: u32,
+    /// Bits after the binary point
+    pub fraction_bits: u32,
+}
+
+impl Default for QFormat {
+    fn default() -> Self {
+        Self {
+            signed: true,
+            integer_bits: 1,
+            fraction_bits: 15,
+        }
+    }
+}
+
+impl std::fmt::Display for QFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let unsigned = if self.signed { "" } else { "U" };
+        write!(
+            f,
+            "{}Q{}.{}",
+            unsigned, self.integer_bits, self.fraction_bits
+        )
+    }
+}
+
+/// Clap value parser accepting Q notation such as `Q15`, `Q8.8` or `UQ16.16`, with the integer
+/// bits of signed formats counting the sign bit as TI writes them, so that Q15 is short for Q1.15
+pub(crate) fn parse_q_format(format: &str) -> Result<QFormat, String> {
+    let error =
+        || String::from("format must be Q notation such as Q15, Q8.8 or UQ16.16, at most 64 bits");
+
+    let (signed, bits) = match format.strip_prefix(['U', 'u']) {
+        Some(unsigned) => (false, unsigned),
+        None => (true, format),
+    };
+    let bits = bits.strip_prefix(['Q', 'q']).ok_or_else(error)?;
+    let (integer_bits, fraction_bits) = match bits.split_once('.') {
+        Some((integer_bits, fraction_bits)) => {
+            (integer_bits.parse::<u32>(), fraction_bits.parse::<u32>())
+        }
+        None => (Ok(if signed { 1 } else { 0 }), bits.parse::<u32>()),
+    };
+    let (Ok(integer_bits), Ok(fraction_bits)) = (integer_bits, fraction_bits) else {
+        return Err(error());
+    };
+
+    let width = integer_bits + fraction_bits;
+    if (signed && integer_bits == 0) || width == 0 || width > 64 {
+        return Err(error());
+    }
+
+    Ok(QFormat {
+        signed,
+        integer_bits,
+        fraction_bits,
+    })
+}
+
+/// How a real number was rounded to the nearest raw value of a format
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Rounding {
+    /// The raw value holds the real number exactly
+    Exact,
+    /// The raw value is off by this much from the real number
+    Rounded(f64),
+    /// The real number is beyond the range of the format, so the raw value is its nearest end
+    Saturated,
+}
+
+impl QFormat {
+    /// Width of the format's raw values
+    pub fn bits(self) -> u32 {
+        self.integer_bits + self.fraction_bits
+    }
+
+    /// The smallest and largest raw values, as raw values are read as two's complement integers
+    /// by signed formats
+    fn raw_range(self) -> (i128, i128) {
+        if self.signed {
+            (-(1 << (self.bits() - 1)), (1 << (self.bits() - 1)) - 1)
+        } else {
+            (0, (1 << self.bits()) - 1)
+        }
+    }
+
+    /// The raw value of a bit pattern, which may be negative when read as a signed integer of the
+    /// format's width such as with -w 16
+    fn raw_of(self, num: i128) -> anyhow::Result<i128, NumberFormatterError> {
+        let bits = self.bits();
+        if num < -(1 << (bits - 1)) || (num < 0 && !self.signed) {
+            return Err(NumberFormatterError::TooSmallError);
+        }
+        if num >= 1 << bits {
+            return Err(NumberFormatterError::TooLargeError);
+        }
+
+        Ok(if self.signed && num >= 1 << (bits - 1) {
+            num - (1 << bits)
+        } else {
+            num
+        })
+    }
+
+    /// The bit pattern of a raw value, which is unsigned within the format's width
+    fn pattern_of(self, raw: i128) -> u128 {
+        raw as u128 & ((1 << self.bits()) - 1)
+    }
+
+    /// Every digit of the real numbers of the format, as a fraction over a power of two has as
+    /// many decimal places as the power
+    pub fn exact(self) -> Precision {
+        Precision::Decimals(self.fraction_bits)
+    }
+
+    /// Writes a raw value as the real number it stands for
+    pub(crate) fn write(self, raw: i128, precision: Precision) -> String {
+        let magnitude = precision::divide(raw.unsigned_abs(), 1 << self.fraction_bits, precision);
+
+        if raw < 0 {
+            format!("-{}", magnitude)
+        } else {
+            magnitude
+        }
+    }
+
+    /// Reads a real number as the nearest raw value of the format, rounding half away from zero
+    /// and saturating at the ends of its range
+    pub(crate) fn round(
+        self,
+        real: &str,
+    ) -> anyhow::Result<(i128, Rounding), NumberFormatterError> {
+        let (negative, numerator, denominator) = ratio::read_decimal(real)?;
+        let (min, max) = self.raw_range();
+        let saturated = || {
+            if negative {
+                Ok((min, Rounding::Saturated))
+            } else {
+                Ok((max, Rounding::Saturated))
+            }
+        };
+
+        let whole = numerator / denominator;
+        if whole >> self.integer_bits != 0 {
+            return saturated();
+        }
+
+        // The fraction bits are found one at a time by long division so that nothing overflows
+        let mut magnitude = whole;
+        let mut remainder = numerator % denominator;
+        for _ in 0..self.fraction_bits {
+            remainder *= 2;
+            magnitude = magnitude << 1 | (remainder >= denominator) as u128;
+            if remainder >= denominator {
+                remainder -= denominator;
+            }
+        }
+        let round_up = remainder >= denominator - remainder;
+        if round_up {
+            magnitude += 1;
+        }
+
+        let raw = if negative {
+            -(magnitude as i128)
+        } else {
+            magnitude as i128
+        };
+        if raw < min || raw > max {
+            return saturated();
+        }
+
+        let rounding = match (remainder, round_up) {
+            (0, _) => Rounding::Exact,
+            (remainder, round_up) => {
+                // Only a few figures are shown, which f64 keeps however small the error is
+                let offset = if round_up {
+                    (denominator - remainder) as f64
+                } else {
+                    -(remainder as f64)
+                };
+                let error = offset / denominator as f64 / 2f64.powi(self.fraction_bits as i32);
+                Rounding::Rounded(if negative { -error } else { error })
+            }
+        };
+
+        Ok((raw, rounding))
+    }
+}
+
+/// A raw fixed-point value and the real number it stands for, with how a real number given
+/// instead was rounded to it
+#[derive(Clone, Debug, PartialEq)]
+pub struct FixedPointInfo {
+    pub format: QFormat,
+    pub raw: i128,
+    /// How the real number was rounded, if one was given rather than a raw value
+    pub rounding: Option<Rounding>,
+    /// How real numbers are written
+    pub precision: Precision,
+}
+
+impl FixedPointInfo {
+    /// Rounds a real number given to the fixed-point command to the nearest raw value
+    pub(crate) fn from_real(
+        real: &str,
+        format: QFormat,
+        precision: Precision,
+    ) -> anyhow::Result<Self, NumberFormatterError> {
+        let (raw, rounding) = format.round(real)?;
+
+        Ok(Self {
+            format,
+            raw,
+            rounding: Some(rounding),
+            precision,
+        })
+    }
+
+    /// Reads a raw value given to the fixed-point command, either as a bit pattern or as a signed
+    /// integer
+    pub(crate) fn from_raw(
+        value: Value,
+        format: QFormat,
+        precision: Precision,
+    ) -> anyhow::Result<Self, NumberFormatterError> {
+        let raw = match value {
+            Value::Signed(num) => format.raw_of(num)?,
+            Value::Unsigned(_) => return Err(NumberFormatterError::TooLargeError),
+        };
+
+        Ok(Self {
+            format,
+            raw,
+            rounding: None,
+            precision,
+        })
+    }
+
+    /// Labelled lines describing the value, in the order they should be displayed
+    pub fn lines(&self) -> Vec<(&'static str, String)> {
+        let (min, max) = self.format.raw_range();
+        let signedness = if self.format.signed {
+            "signed"
+        } else {
+            "unsigned"
+        };
+        let hex_digits = self.format.bits().div_ceil(4) as usize;
+
+        let mut lines = vec![
+            (
+                "Format",
+                format!(
+                    "{} ({} bits, {})",
+                    self.format,
+                    self.format.bits(),
+                    signedness
+                ),
+            ),
+            (
+                "Range",
+                format!(
+                    "{} to {}",
+                    self.format.write(min, self.format.exact()),
+                    self.format.write(max, self.format.exact())
+                ),
+            ),
+            (
+                "Resolution",
+                format!(
+                    "{} (2^-{})",
+                    self.format.write(1, self.format.exact()),
+                    self.format.fraction_bits
+                ),
+            ),
+            (
+                "Raw",
+                format!(
+                    "0x{:0width$x} ({})",
+                    self.format.pattern_of(self.raw),
+                    self.raw,
+                    width = hex_digits
+                ),
+            ),
+            ("Value", self.format.write(self.raw, self.precision)),
+        ];
+
+        if let Some(rounding) = self.rounding {
+            let rounding = match rounding {
+                Rounding::Exact => String::from("exact"),
+                Rounding::Rounded(error) => format!("rounded, error {:+.3e}", error),
+                Rounding::Saturated => String::from("saturated, out of range"),
+            };
+            lines.push(("Rounding", rounding));
+        }
+
+        lines
+    }
+}
+
+/// Formatter writing raw fixed-point values as the real numbers they stand for in the --q-format
+/// format, such as 0.5 for 0x4000 in Q15
+pub(crate) struct FixedPointNumberFormatter {
+    pub format: QFormat,
+    pub precision: Precision,
+}
+
+impl NumberFormatter for FixedPointNumberFormatter {
+    fn read(&self, real: &str) -> anyhow::Result<i128, NumberFormatterError> {
+        match self.format.round(real)? {
+            (_, Rounding::Saturated) if real.starts_with('-') => {
+                Err(NumberFormatterError::TooSmallError)
+            }
+            (_, Rounding::Saturated) => Err(NumberFormatterError::TooLargeError),
+            (raw, _) => Ok(self.format.pattern_of(raw) as i128),
+        }
+    }
+
+    fn format(&self, num: i128) -> anyhow::Result<String, NumberFormatterError> {
+        Ok(self.format.write(self.format.raw_of(num)?, self.precision))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("Q15", true, 1, 15)]
+    #[test_case("Q8.8", true, 8, 8; "integer bits")]
+    #[test_case("UQ16.16", false, 16, 16; "unsigned")]
+    #[test_case("uq8", false, 0, 8; "unsigned fraction")]
+    fn parse_q_format_should_read_q_notation(
+        format: &str,
+        signed: bool,
+        integer_bits: u32,
+        fraction_bits: u32,
+    ) {
+        // Act
+        let actual = parse_q_format(format);
+
+        // Assert
+        assert_eq!(
+            actual,
+            Ok(QFormat {
+                signed,
+                integer_bits,
+                fraction_bits
+            })
+        );
+    }
+
+    #[test_case("Q0.15"; "signed without sign bit")]
+    #[test_case("Q32.33"; "too wide")]
+    #[test_case("15"; "without q")]
+    #[test_case("Q1.x"; "invalid fraction bits")]
+    fn parse_q_format_should_reject_invalid_formats(format: &str) {
+        // Act
+        let actual = parse_q_format(format);
+
+        // Assert
+        assert!(actual.is_err());
+    }
+
+    #[test_case("Q15", "0.5", 0x4000, Rounding::Exact)]
+    #[test_case("Q15", "-1.0", -0x8000, Rounding::Exact; "most negative")]
+    #[test_case("Q15", "1.0", 0x7fff, Rounding::Saturated; "saturates")]
+    #[test_case("Q15", "-1.5", -0x8000, Rounding::Saturated; "saturates negative")]
+    #[test_case("Q15", "0.1", 0xccd, Rounding::Rounded(3277.0 / 32768.0 - 0.1); "rounds up")]
+    #[test_case("Q8.8", "1.2345", 0x13c, Rounding::Rounded(1.234375 - 1.2345); "rounds down")]
+    #[test_case("Q8.8", "-0.001", 0, Rounding::Rounded(0.001); "rounds to zero")]
+    #[test_case("UQ16.16", "65535.5", 0xffff8000, Rounding::Exact; "unsigned")]
+    #[test_case("UQ8", "-0.5", 0, Rounding::Saturated; "unsigned negative")]
+    fn q_format_should_round_real(format: &str, real: &str, raw: i128, rounding: Rounding) {
+        // Act
+        let actual = parse_q_format(format).unwrap().round(real).unwrap();
+
+        // Assert
+        assert_eq!(actual.0, raw);
+        match (actual.1, rounding) {
+            (Rounding::Rounded(actual), Rounding::Rounded(expected)) => {
+                assert!((actual - expected).abs() < 1e-12)
+            }
+            (actual, expected) => assert_eq!(actual, expected),
+        }
+    }
+
+    #[test_case("Q15", 0x4000, "0.5")]
+    #[test_case("Q15", 0xc000, "-0.5"; "register value")]
+    #[test_case("Q15", -0x4000, "-0.5"; "negative")]
+    #[test_case("Q15", 1, "0.000030517578125"; "resolution")]
+    #[test_case("Q8.8", 0x0324, "3.140625"; "integer bits")]
+    #[test_case("UQ16.16", 0xffff8000, "65535.5"; "unsigned")]
+    fn fixed_point_number_formatter_should_format_i128(format: &str, input: i128, expected: &str) {
+        // Act
+        let actual = FixedPointNumberFormatter {
+            format: parse_q_format(format).unwrap(),
+            precision: Precision::Decimals(20),
+        }
+        .format(input);
+
+        // Assert
+        assert_eq!(actual.unwrap(), expected);
+    }
+
+    #[test_case("Q15", "-0.5", 0xc000)]
+    #[test_case("Q8.8", "1.5", 0x180; "integer bits")]
+    fn fixed_point_number_formatter_should_read_real(format: &str, input: &str, expected: i128) {
+        // Act
+        let actual = FixedPointNumberFormatter {
+            format: parse_q_format(format).unwrap(),
+            precision: Precision::Decimals(20),
+        }
+        .read(input);
+
+        // Assert
+        assert_eq!(actual.unwrap(), expected);
+    }
+
+    #[test_case("Q15", 0x10000, NumberFormatterError::TooLargeError; "too wide")]
+    #[test_case("UQ8", -1, NumberFormatterError::TooSmallError; "unsigned negative")]
+    fn fixed_point_number_formatter_should_fail_to_format(
+        format: &str,
+        input: i128,
+        expected: NumberFormatterError,
+    ) {
+        // Act
+        let actual = FixedPointNumberFormatter {
+            format: parse_q_format(format).unwrap(),
+            precision: Precision::Decimals(20),
+        }
+        .format(input);
+
+        // Assert
+        assert_eq!(
+            std::mem::discriminant(&actual.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+}
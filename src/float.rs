@@ -0,0 +1,931 @@
+use crate::bignum::BigUint;
+use crate::humanize;
+use crate::precision::{self, FloatDigits, FloatPrecision};
+use crate::{NumberFormatter, NumberFormatterError, Value};
+use clap::{ArgEnum, Args};
+
+/// Options of the float-bits command
+#[derive(Args, Clone, Debug)]
+pub struct FloatBitsOptions {
+    /// The float to split, as a decimal float such as 1.5 or a bit pattern such as 0x3ff8000000000000
+    pub number: String,
+}
+
+/// Options of the ulp command
+#[derive(Args, Clone, Debug)]
+pub struct UlpOptions {
+    /// The first float, as a decimal float with a point such as 1.0, or a bit pattern such as 1
+    pub first: String,
+    /// The second float, as a decimal float with a point such as 1.0, or a bit pattern such as 1
+    pub second: String,
+}
+
+/// IEEE 754 binary floating-point formats the bit patterns of floats are read and written in
+#[derive(ArgEnum, Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum FloatFormat {
+    /// Double precision, 64 bits
+    #[default]
+    F64,
+    /// Single precision, 32 bits
+    F32,
+    /// IEEE 754 half precision, 16 bits
+    F16,
+    /// Brain floating point, the top 16 bits of an f32
+    Bf16,
+}
+
+impl FloatFormat {
+    /// Width of the format's bit patterns
+    pub fn bits(self) -> u32 {
+        match self {
+            FloatFormat::F64 => 64,
+            FloatFormat::F32 => 32,
+            FloatFormat::F16 | FloatFormat::Bf16 => 16,
+        }
+    }
+
+    /// Width of the format's exponent field
+    pub fn exponent_bits(self) -> u32 {
+        match self {
+            FloatFormat::F64 => 11,
+            FloatFormat::F32 | FloatFormat::Bf16 => 8,
+            FloatFormat::F16 => 5,
+        }
+    }
+
+    /// Width of the format's mantissa field, which leaves out the implicit leading bit
+    pub fn mantissa_bits(self) -> u32 {
+        self.bits() - self.exponent_bits() - 1
+    }
+
+    /// What is subtracted from the exponent field to find the power of two it stands for
+    pub fn bias(self) -> i32 {
+        (1 << (self.exponent_bits() - 1)) - 1
+    }
+
+    /// The bit pattern of the float of this format nearest to `mantissa * 2^exponent`, rounding
+    /// half to even. `sticky` tells whether nonzero bits were dropped below the mantissa, which
+    /// breaks ties upwards.
+    pub(crate) fn encode(
+        self,
+        negative: bool,
+        mantissa: u128,
+        exponent: i64,
+        sticky: bool,
+    ) -> u128 {
+        let sign = (negative as u128) << (self.bits() - 1);
+        if mantissa == 0 {
+            return sign;
+        }
+
+        let precision = self.mantissa_bits() as i64;
+        let min_exponent = 1 - self.bias() as i64;
+        let top_exponent = exponent + 127 - mantissa.leading_zeros() as i64;
+
+        // The power of two of the last mantissa bit, which is fixed for subnormals
+        let mut lsb_exponent = top_exponent.max(min_exponent) - precision;
+        let shift = lsb_exponent - exponent;
+        let mut rounded = match shift {
+            ..=0 => mantissa << -shift,
+            1..=127 => {
+                let rounded = mantissa >> shift;
+                let dropped = mantissa & ((1 << shift) - 1);
+                let half = 1 << (shift - 1);
+                if dropped > half || (dropped == half && (sticky || rounded & 1 == 1)) {
+                    rounded + 1
+                } else {
+                    rounded
+                }
+            }
+            _ => 0,
+        };
+
+        // Rounding up can carry into another bit
+        if rounded >> (precision + 1) == 1 {
+            rounded >>= 1;
+            lsb_exponent += 1;
+        }
+
+        let implicit_bit = 1 << precision;
+        let biased = if rounded >= implicit_bit {
+            lsb_exponent + precision + self.bias() as i64
+        } else {
+            0
+        };
+        let all_ones = (1 << self.exponent_bits()) - 1;
+        if biased >= all_ones {
+            return sign | (all_ones as u128) << precision;
+        }
+
+        sign | (biased as u128) << precision | (rounded & (implicit_bit - 1))
+    }
+
+    /// The bit pattern of the float of this format nearest to an f64, with NaNs becoming the quiet
+    /// NaN of the same sign
+    fn narrow(self, float: f64) -> u128 {
+        let bits = FloatBits::from_pattern(float.to_bits() as u128, FloatFormat::F64);
+        let sign = (bits.negative as u128) << (self.bits() - 1);
+        let infinity = ((1 << self.exponent_bits()) - 1) << self.mantissa_bits();
+
+        match bits.unbiased_exponent() {
+            None if float.is_nan() => sign | infinity | 1 << (self.mantissa_bits() - 1),
+            None => sign | infinity,
+            Some(exponent) => {
+                let implicit_bit = if bits.exponent == 0 { 0 } else { 1 << 52 };
+                self.encode(
+                    bits.negative,
+                    implicit_bit | bits.mantissa,
+                    exponent as i64 - 52,
+                    false,
+                )
+            }
+        }
+    }
+
+    /// The f64 holding the same value as a bit pattern of this format, which every format here
+    /// narrower than f64 converts to exactly
+    fn widen(self, pattern: u128) -> f64 {
+        let bits = FloatBits::from_pattern(pattern, self);
+
+        let widened = match bits.unbiased_exponent() {
+            None => {
+                let mantissa = (bits.mantissa << (52 - self.mantissa_bits())) | 1 << 51;
+                let quiet_or_infinity = if bits.mantissa == 0 { 0 } else { mantissa };
+                (bits.negative as u128) << 63 | 0x7ff << 52 | quiet_or_infinity
+            }
+            Some(exponent) => {
+                let implicit_bit = if bits.exponent == 0 {
+                    0
+                } else {
+                    1 << self.mantissa_bits()
+                };
+                FloatFormat::F64.encode(
+                    bits.negative,
+                    implicit_bit | bits.mantissa,
+                    exponent as i64 - self.mantissa_bits() as i64,
+                    false,
+                )
+            }
+        };
+
+        f64::from_bits(widened as u64)
+    }
+
+    /// Reads a decimal float as the bit pattern of the nearest float of this format. Half precision
+    /// floats are rounded through f64, which only differs from rounding once for decimals with
+    /// more than 17 digits lying within a hair of half way between two of them.
+    pub(crate) fn parse(self, float: &str) -> anyhow::Result<u128, NumberFormatterError> {
+        let parsed = match self {
+            FloatFormat::F64 => float.parse::<f64>().map(|float| float.to_bits() as u128),
+            FloatFormat::F32 => float.parse::<f32>().map(|float| float.to_bits() as u128),
+            FloatFormat::F16 | FloatFormat::Bf16 => {
+                float.parse::<f64>().map(|float| self.narrow(float))
+            }
+        };
+
+        parsed.map_err(|_| NumberFormatterError::InvalidFloat)
+    }
+
+    /// Writes a bit pattern of this format as the shortest decimal that reads back as it
+    pub(crate) fn write(self, pattern: u128) -> String {
+        match self {
+            FloatFormat::F64 => format!("{:?}", f64::from_bits(pattern as u64)),
+            FloatFormat::F32 => format!("{:?}", f32::from_bits(pattern as u32)),
+            FloatFormat::F16 | FloatFormat::Bf16 => {
+                let float = self.widen(pattern);
+                if !float.is_finite() {
+                    return format!("{:?}", float);
+                }
+
+                // The fewest significant digits that read back as the same pattern, which 17 always do
+                (1..=17)
+                    .filter_map(|digits| format!("{:.*e}", digits - 1, float).parse::<f64>().ok())
+                    .find(|shortest| self.narrow(*shortest) == pattern)
+                    .map_or_else(
+                        || format!("{:?}", float),
+                        |shortest| format!("{:?}", shortest),
+                    )
+            }
+        }
+    }
+
+    /// Writes a bit pattern of this format as every digit of the decimal value it holds, which is
+    /// always finite as floats are fractions over powers of two
+    pub(crate) fn write_exact(self, pattern: u128) -> String {
+        let bits = FloatBits::from_pattern(pattern, self);
+        let Some((mantissa, exponent)) = bits.exact_value() else {
+            return self.write(pattern);
+        };
+        let sign = if bits.negative { "-" } else { "" };
+        if mantissa == 0 {
+            return format!("{}0", sign);
+        }
+
+        let mut digits = BigUint::from_u128(mantissa);
+        if exponent >= 0 {
+            digits.mul_pow(2, exponent as u32);
+            return format!("{}{}", sign, digits);
+        }
+
+        // m / 2^k has the same digits as m * 5^k, with k of them after the point
+        let places = exponent.unsigned_abs() as usize;
+        digits.mul_pow(5, places as u32);
+        let digits = format!("{:0>width$}", digits.to_string(), width = places + 1);
+        let (whole, fraction) = digits.split_at(digits.len() - places);
+
+        format!("{}{}.{}", sign, whole, fraction)
+    }
+
+    /// The bit pattern of a number, which may be negative when read as a signed integer of the
+    /// format's width such as with -w 64
+    pub(crate) fn pattern_of(self, num: i128) -> anyhow::Result<u128, NumberFormatterError> {
+        let bits = self.bits();
+        if num < -(1 << (bits - 1)) {
+            return Err(NumberFormatterError::TooSmallError);
+        }
+        if num >= 1 << bits {
+            return Err(NumberFormatterError::TooLargeError);
+        }
+
+        Ok(num as u128 & ((1 << bits) - 1))
+    }
+
+    /// The bit pattern of a value read from the command line
+    fn pattern_of_value(self, value: Value) -> anyhow::Result<u128, NumberFormatterError> {
+        match value {
+            Value::Signed(num) => self.pattern_of(num),
+            Value::Unsigned(_) => Err(NumberFormatterError::TooLargeError),
+        }
+    }
+}
+
+impl std::fmt::Display for FloatFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FloatFormat::F64 => write!(f, "f64"),
+            FloatFormat::F32 => write!(f, "f32"),
+            FloatFormat::F16 => write!(f, "f16"),
+            FloatFormat::Bf16 => write!(f, "bf16"),
+        }
+    }
+}
+
+/// Kinds of values a float's bit pattern can hold
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FloatClass {
+    Zero,
+    /// Below the smallest normal float, with no implicit leading bit and less precision
+    Subnormal,
+    Normal,
+    Infinity,
+    /// A NaN with the top mantissa bit set, which passes through arithmetic quietly
+    QuietNan {
+        payload: u128,
+    },
+    /// A NaN with the top mantissa bit clear, which raises an invalid operation exception when used
+    SignalingNan {
+        payload: u128,
+    },
+}
+
+impl std::fmt::Display for FloatClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FloatClass::Zero => write!(f, "zero"),
+            FloatClass::Subnormal => write!(f, "subnormal"),
+            FloatClass::Normal => write!(f, "normal"),
+            FloatClass::Infinity => write!(f, "infinity"),
+            FloatClass::QuietNan { payload } => write!(f, "quiet NaN, payload 0x{:x}", payload),
+            FloatClass::SignalingNan { payload } => {
+                write!(f, "signaling NaN, payload 0x{:x}", payload)
+            }
+        }
+    }
+}
+
+/// The sign, exponent and mantissa fields of a float's bit pattern
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FloatBits {
+    pub format: FloatFormat,
+    pub pattern: u128,
+    pub negative: bool,
+    /// The biased exponent field as stored
+    pub exponent: u32,
+    /// The mantissa field as stored, without the implicit leading bit
+    pub mantissa: u128,
+}
+
+impl FloatBits {
+    pub(crate) fn new(
+        value: Value,
+        format: FloatFormat,
+    ) -> anyhow::Result<Self, NumberFormatterError> {
+        Ok(FloatBits::from_pattern(
+            format.pattern_of_value(value)?,
+            format,
+        ))
+    }
+
+    pub(crate) fn from_pattern(pattern: u128, format: FloatFormat) -> Self {
+        let mantissa_bits = format.mantissa_bits();
+
+        Self {
+            format,
+            pattern,
+            negative: pattern >> (format.bits() - 1) == 1,
+            exponent: ((pattern >> mantissa_bits) & ((1 << format.exponent_bits()) - 1)) as u32,
+            mantissa: pattern & ((1 << mantissa_bits) - 1),
+        }
+    }
+
+    /// The power of two the exponent field stands for, which for subnormals and zero is the
+    /// smallest normal exponent, and none for infinities and NaNs
+    pub fn unbiased_exponent(&self) -> Option<i32> {
+        let all_ones = (1 << self.format.exponent_bits()) - 1;
+
+        match self.exponent {
+            0 => Some(1 - self.format.bias()),
+            exponent if exponent == all_ones => None,
+            exponent => Some(exponent as i32 - self.format.bias()),
+        }
+    }
+
+    /// The magnitude of a finite float as a whole number times a power of two, with the whole
+    /// number odd unless the power is positive, and none for infinities and NaNs
+    pub(crate) fn exact_value(&self) -> Option<(u128, i64)> {
+        let unbiased_exponent = self.unbiased_exponent()?;
+
+        let implicit_bit = if self.exponent == 0 {
+            0
+        } else {
+            1 << self.format.mantissa_bits()
+        };
+        let mut mantissa = implicit_bit | self.mantissa;
+        let mut exponent = unbiased_exponent as i64 - self.format.mantissa_bits() as i64;
+        while mantissa != 0 && mantissa & 1 == 0 && exponent < 0 {
+            mantissa >>= 1;
+            exponent += 1;
+        }
+
+        Some((mantissa, exponent))
+    }
+
+    /// The kind of value the fields hold
+    pub fn class(&self) -> FloatClass {
+        let all_ones = (1 << self.format.exponent_bits()) - 1;
+        let quiet_bit = 1 << (self.format.mantissa_bits() - 1);
+
+        match (self.exponent, self.mantissa) {
+            (0, 0) => FloatClass::Zero,
+            (0, _) => FloatClass::Subnormal,
+            (exponent, 0) if exponent == all_ones => FloatClass::Infinity,
+            (exponent, mantissa) if exponent == all_ones && mantissa & quiet_bit != 0 => {
+                FloatClass::QuietNan {
+                    payload: mantissa & !quiet_bit,
+                }
+            }
+            (exponent, mantissa) if exponent == all_ones => {
+                FloatClass::SignalingNan { payload: mantissa }
+            }
+            _ => FloatClass::Normal,
+        }
+    }
+
+    /// The float written as a decimal to some precision, followed by its class when that is
+    /// unusual enough to be easily missed and by the digits chosen, such as `1e-45 (subnormal)` or
+    /// `0.1 (shortest round-trip)`
+    pub(crate) fn classified(&self, precision: FloatPrecision) -> String {
+        let class = self.class();
+        let (float, digits_note) = match (class, precision) {
+            (FloatClass::Infinity, _)
+            | (FloatClass::QuietNan { .. }, _)
+            | (FloatClass::SignalingNan { .. }, _)
+            | (_, FloatPrecision::Shortest) => (self.format.write(self.pattern), None),
+            (_, FloatPrecision::Digits(FloatDigits::Shortest)) => {
+                (self.format.write(self.pattern), Some("shortest round-trip"))
+            }
+            (_, FloatPrecision::Digits(FloatDigits::Exact)) => {
+                (self.format.write_exact(self.pattern), Some("exact"))
+            }
+            (_, FloatPrecision::Rounded(precision)) => (
+                precision::round_decimal(&self.format.write_exact(self.pattern), precision),
+                None,
+            ),
+        };
+
+        let (sign, class_note) = match class {
+            FloatClass::Subnormal => ("", Some(class.to_string())),
+            FloatClass::QuietNan { .. } | FloatClass::SignalingNan { .. } => (
+                if self.negative { "-" } else { "" },
+                Some(class.to_string()),
+            ),
+            _ => ("", None),
+        };
+
+        let notes: Vec<String> = class_note
+            .into_iter()
+            .chain(digits_note.map(String::from))
+            .collect();
+        if notes.is_empty() {
+            format!("{}{}", sign, float)
+        } else {
+            format!("{}{} ({})", sign, float, notes.join(", "))
+        }
+    }
+
+    /// Labelled lines describing the fields, in the order they should be displayed
+    pub fn lines(&self) -> Vec<(&'static str, String)> {
+        let field = |field: u128, bits: u32| {
+            format!(
+                "{:0bin_width$b} (0x{:0hex_width$x})",
+                field,
+                field,
+                bin_width = bits as usize,
+                hex_width = bits.div_ceil(4) as usize
+            )
+        };
+
+        vec![
+            ("Format", self.format.to_string()),
+            ("Float", self.format.write(self.pattern)),
+            ("Class", self.class().to_string()),
+            (
+                "Sign",
+                String::from(if self.negative {
+                    "1 (negative)"
+                } else {
+                    "0 (positive)"
+                }),
+            ),
+            (
+                "Exponent",
+                field(self.exponent as u128, self.format.exponent_bits()),
+            ),
+            ("Biased exponent", self.exponent.to_string()),
+            (
+                "Unbiased exponent",
+                self.unbiased_exponent()
+                    .map_or(String::from("none"), |exponent| exponent.to_string()),
+            ),
+            (
+                "Mantissa",
+                field(self.mantissa, self.format.mantissa_bits()),
+            ),
+        ]
+    }
+}
+
+/// How many floats apart two floats are, counting in units in the last place
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct UlpInfo {
+    pub format: FloatFormat,
+    pub first: u128,
+    pub second: u128,
+    /// Floats from the first to the second, negative when the second is smaller
+    pub distance: i128,
+}
+
+impl UlpInfo {
+    pub(crate) fn new(
+        first: Value,
+        second: Value,
+        format: FloatFormat,
+    ) -> anyhow::Result<Self, NumberFormatterError> {
+        let first = format.pattern_of_value(first)?;
+        let second = format.pattern_of_value(second)?;
+
+        Ok(Self {
+            format,
+            first,
+            second,
+            distance: UlpInfo::ordinal(second, format)? - UlpInfo::ordinal(first, format)?,
+        })
+    }
+
+    /// The position of a float among all floats of its format counting up from zero, so that
+    /// neighboring floats are one apart and both zeros are at 0
+    fn ordinal(pattern: u128, format: FloatFormat) -> anyhow::Result<i128, NumberFormatterError> {
+        let bits = FloatBits::from_pattern(pattern, format);
+        if let FloatClass::QuietNan { .. } | FloatClass::SignalingNan { .. } = bits.class() {
+            return Err(NumberFormatterError::InvalidFloat);
+        }
+
+        let magnitude = (pattern & ((1 << (format.bits() - 1)) - 1)) as i128;
+        Ok(if bits.negative { -magnitude } else { magnitude })
+    }
+
+    /// Labelled lines describing the distance, in the order they should be displayed
+    pub fn lines(&self) -> Vec<(&'static str, String)> {
+        let larger = match self.distance {
+            0 => "neither",
+            distance if distance > 0 => "second",
+            _ => "first",
+        };
+        let difference = self.format.widen(self.second) - self.format.widen(self.first);
+
+        vec![
+            ("First", self.format.write(self.first)),
+            ("Second", self.format.write(self.second)),
+            ("ULP distance", self.distance.unsigned_abs().to_string()),
+            ("Larger", String::from(larger)),
+            ("Difference", format!("{:?}", difference)),
+        ]
+    }
+}
+
+/// Whether the input is written as a decimal float such as `1.5`, `inf` or `NaN`. Scientific
+/// notation such as `2.5e3` is left to be read as an integer, which fails when it is not whole, so
+/// floats with an exponent must be chosen with `-n float`.
+pub(crate) fn is_float(input: &str) -> bool {
+    let unsigned = input.trim_start_matches(['-', '+']);
+    if ["inf", "infinity", "nan"]
+        .iter()
+        .any(|name| unsigned.eq_ignore_ascii_case(name))
+    {
+        return true;
+    }
+
+    if input.parse::<f64>().is_err() {
+        return false;
+    }
+
+    humanize::split_exponent(input).is_none() && input.contains('.')
+}
+
+/// Formatter writing bit patterns as the IEEE 754 floats they encode, such as `1.5` for
+/// 0x3ff8000000000000
+pub(crate) struct FloatNumberFormatter {
+    pub format: FloatFormat,
+    pub precision: FloatPrecision,
+}
+
+impl NumberFormatter for FloatNumberFormatter {
+    fn read(&self, float: &str) -> anyhow::Result<i128, NumberFormatterError> {
+        // Any class written after the float is left out
+        let float = float.split(" (").next().unwrap_or_default();
+        Ok(self.format.parse(float)? as i128)
+    }
+
+    fn format(&self, num: i128) -> anyhow::Result<String, NumberFormatterError> {
+        let pattern = self.format.pattern_of(num)?;
+        Ok(FloatBits::from_pattern(pattern, self.format).classified(self.precision))
+    }
+}
+
+/// Formatter writing bit patterns as every digit of the decimal value of the IEEE 754 float they
+/// encode, such as `0.1000000000000000055511151231257827021181583404541015625` for 0x3fb999999999999a
+pub(crate) struct ExactFloatNumberFormatter {
+    pub format: FloatFormat,
+}
+
+impl NumberFormatter for ExactFloatNumberFormatter {
+    fn read(&self, float: &str) -> anyhow::Result<i128, NumberFormatterError> {
+        Ok(self.format.parse(float)? as i128)
+    }
+
+    fn format(&self, num: i128) -> anyhow::Result<String, NumberFormatterError> {
+        Ok(self.format.write_exact(self.format.pattern_of(num)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::precision::Precision;
+    use test_case::test_case;
+
+    #[test_case(false, 3, -1, false, FloatFormat::F64, 0x3ff8000000000000)]
+    #[test_case(true, 1, 0, false, FloatFormat::F32, 0xbf800000; "negative")]
+    #[test_case(false, 0, 5, false, FloatFormat::F32, 0; "zero")]
+    #[test_case(false, 0x1000001, 0, false, FloatFormat::F32, 0x4b800000; "tie to even")]
+    #[test_case(false, 0x1000003, 0, false, FloatFormat::F32, 0x4b800002; "tie to even upwards")]
+    #[test_case(false, 0x1000001, 0, true, FloatFormat::F32, 0x4b800001; "sticky tie")]
+    #[test_case(false, 1, -149, false, FloatFormat::F32, 1; "smallest subnormal")]
+    #[test_case(false, 1, -150, true, FloatFormat::F32, 1; "rounds up to subnormal")]
+    #[test_case(false, 1, -151, false, FloatFormat::F32, 0; "underflows")]
+    #[test_case(false, 1, 128, false, FloatFormat::F32, 0x7f800000; "overflows")]
+    #[test_case(false, u128::MAX, 0, false, FloatFormat::F64, 0x47f0000000000000; "carries")]
+    fn float_format_should_encode_nearest_float(
+        negative: bool,
+        mantissa: u128,
+        exponent: i64,
+        sticky: bool,
+        format: FloatFormat,
+        expected: u128,
+    ) {
+        // Act
+        let actual = format.encode(negative, mantissa, exponent, sticky);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test_case("1.5", true)]
+    #[test_case("-0.1", true; "negative")]
+    #[test_case("2.5e-3", false; "fractional scientific notation")]
+    #[test_case("1e6", false; "whole scientific notation")]
+    #[test_case("1e300", false; "too large for an integer")]
+    #[test_case("-inf", true; "infinity")]
+    #[test_case("NaN", true; "nan")]
+    #[test_case("42", false; "integer")]
+    #[test_case("1.2.3", false; "version")]
+    fn is_float_should_detect_float(input: &str, expected: bool) {
+        // Act
+        let actual = is_float(input);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test_case(FloatFormat::F64, "1.5", 0x3ff8000000000000)]
+    #[test_case(FloatFormat::F64, "0.1", 0x3fb999999999999a; "inexact")]
+    #[test_case(FloatFormat::F64, "-0.0", 0x8000000000000000; "negative zero")]
+    #[test_case(FloatFormat::F64, "inf", 0x7ff0000000000000; "infinity")]
+    #[test_case(FloatFormat::F32, "1.5", 0x3fc00000; "single precision")]
+    #[test_case(FloatFormat::F32, "0.1", 0x3dcccccd; "inexact single precision")]
+    #[test_case(FloatFormat::F16, "1.5", 0x3e00; "half precision")]
+    #[test_case(FloatFormat::F16, "0.1", 0x2e66; "inexact half precision")]
+    #[test_case(FloatFormat::F16, "65504", 0x7bff; "largest half precision")]
+    #[test_case(FloatFormat::F16, "65520", 0x7c00; "half precision overflow")]
+    #[test_case(FloatFormat::F16, "6e-8", 0x0001; "half precision subnormal")]
+    #[test_case(FloatFormat::F16, "-nan", 0xfe00; "half precision nan")]
+    #[test_case(FloatFormat::Bf16, "1.5", 0x3fc0; "bfloat16")]
+    #[test_case(FloatFormat::Bf16, "3.14159", 0x4049; "inexact bfloat16")]
+    fn float_number_formatter_should_read_float(format: FloatFormat, input: &str, expected: i128) {
+        // Act
+        let actual = FloatNumberFormatter {
+            format,
+            precision: FloatPrecision::Shortest,
+        }
+        .read(input);
+
+        // Assert
+        assert_eq!(actual.unwrap(), expected);
+    }
+
+    #[test_case(FloatFormat::F64, 0x3ff8000000000000, "1.5")]
+    #[test_case(FloatFormat::F64, 0x3fb999999999999a, "0.1"; "shortest")]
+    #[test_case(FloatFormat::F64, 0x4000000000000000, "2.0"; "whole")]
+    #[test_case(FloatFormat::F64, -0x4000000000000000, "-2.0"; "negative signed pattern")]
+    #[test_case(FloatFormat::F64, 0x7ff8000000000000, "NaN (quiet NaN, payload 0x0)"; "nan")]
+    #[test_case(FloatFormat::F64, 0xfff0000000000001, "-NaN (signaling NaN, payload 0x1)"; "signaling nan")]
+    #[test_case(FloatFormat::F64, 1, "5e-324 (subnormal)"; "subnormal")]
+    #[test_case(FloatFormat::F32, 0x3dcccccd, "0.1"; "single precision")]
+    #[test_case(FloatFormat::F32, 0x7f800000, "inf"; "single precision infinity")]
+    #[test_case(FloatFormat::F16, 0x2e66, "0.1"; "half precision")]
+    #[test_case(FloatFormat::F16, 0x7bff, "65500.0"; "largest half precision")]
+    #[test_case(FloatFormat::F16, 0x0001, "6e-8 (subnormal)"; "half precision subnormal")]
+    #[test_case(FloatFormat::F16, 0xfc00, "-inf"; "half precision infinity")]
+    #[test_case(FloatFormat::F16, 0x7e00, "NaN (quiet NaN, payload 0x0)"; "half precision nan")]
+    #[test_case(FloatFormat::Bf16, 0x4049, "3.14"; "bfloat16")]
+    #[test_case(FloatFormat::Bf16, -0x4040, "-1.5"; "negative bfloat16")]
+    fn float_number_formatter_should_format_i128(format: FloatFormat, input: i128, expected: &str) {
+        // Act
+        let actual = FloatNumberFormatter {
+            format,
+            precision: FloatPrecision::Shortest,
+        }
+        .format(input);
+
+        // Assert
+        assert_eq!(actual.unwrap(), expected);
+    }
+
+    #[test_case(FloatFormat::F32, 0x1_0000_0000, NumberFormatterError::TooLargeError)]
+    #[test_case(FloatFormat::F32, -0x8000_0001, NumberFormatterError::TooSmallError; "too small")]
+    fn float_number_formatter_should_fail_to_format(
+        format: FloatFormat,
+        input: i128,
+        expected: NumberFormatterError,
+    ) {
+        // Act
+        let actual = FloatNumberFormatter {
+            format,
+            precision: FloatPrecision::Shortest,
+        }
+        .format(input);
+
+        // Assert
+        assert_eq!(
+            std::mem::discriminant(&actual.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+
+    #[test_case(Value::Signed(0x3ff8000000000000), FloatFormat::F64, (false, 1023, Some(0), 0x8000000000000))]
+    #[test_case(Value::Signed(-0x3ff8000000000000), FloatFormat::F64, (true, 0x400, Some(1), 0x8000000000000); "negative pattern")]
+    #[test_case(Value::Signed(0x3fc00000), FloatFormat::F32, (false, 127, Some(0), 0x400000); "single precision")]
+    #[test_case(Value::Signed(1), FloatFormat::F32, (false, 0, Some(-126), 1); "subnormal")]
+    #[test_case(Value::Signed(0x7f800000), FloatFormat::F32, (false, 255, None, 0); "infinity")]
+    fn float_bits_should_split_fields(
+        value: Value,
+        format: FloatFormat,
+        expected: (bool, u32, Option<i32>, u128),
+    ) {
+        // Act
+        let actual = FloatBits::new(value, format).unwrap();
+
+        // Assert
+        assert_eq!(
+            (
+                actual.negative,
+                actual.exponent,
+                actual.unbiased_exponent(),
+                actual.mantissa
+            ),
+            expected
+        );
+    }
+
+    #[test_case(0x0000, FloatFormat::F16, FloatClass::Zero)]
+    #[test_case(0x8000, FloatFormat::F16, FloatClass::Zero; "negative zero")]
+    #[test_case(0x0001, FloatFormat::F16, FloatClass::Subnormal; "subnormal")]
+    #[test_case(0x3c00, FloatFormat::F16, FloatClass::Normal; "normal")]
+    #[test_case(0xfc00, FloatFormat::F16, FloatClass::Infinity; "negative infinity")]
+    #[test_case(0x7e01, FloatFormat::F16, FloatClass::QuietNan { payload: 1 }; "quiet nan")]
+    #[test_case(0x7c01, FloatFormat::F16, FloatClass::SignalingNan { payload: 1 }; "signaling nan")]
+    #[test_case(0x7fa00000, FloatFormat::F32, FloatClass::SignalingNan { payload: 0x200000 }; "single precision signaling nan")]
+    fn float_bits_should_classify_pattern(
+        pattern: u128,
+        format: FloatFormat,
+        expected: FloatClass,
+    ) {
+        // Act
+        let actual = FloatBits::from_pattern(pattern, format).class();
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test_case(0x3ff0000000000000, 0x3ff0000000000001, FloatFormat::F64, 1)]
+    #[test_case(0x3ff0000000000001, 0x3ff0000000000000, FloatFormat::F64, -1; "second smaller")]
+    #[test_case(0x0000, 0x8000, FloatFormat::F16, 0; "zeros")]
+    #[test_case(0x8001, 0x0001, FloatFormat::F16, 2; "across zero")]
+    #[test_case(0x7bff, 0x7c00, FloatFormat::F16, 1; "to infinity")]
+    fn ulp_info_should_count_floats_between(
+        first: i128,
+        second: i128,
+        format: FloatFormat,
+        expected: i128,
+    ) {
+        // Act
+        let actual = UlpInfo::new(Value::Signed(first), Value::Signed(second), format);
+
+        // Assert
+        assert_eq!(actual.unwrap().distance, expected);
+    }
+
+    #[test]
+    fn ulp_info_should_reject_nan() {
+        // Act
+        let actual = UlpInfo::new(
+            Value::Signed(0x3c00),
+            Value::Signed(0x7e00),
+            FloatFormat::F16,
+        );
+
+        // Assert
+        assert!(matches!(actual, Err(NumberFormatterError::InvalidFloat)));
+    }
+
+    #[test]
+    fn ulp_info_should_show_larger_and_difference() {
+        // Act
+        let actual = UlpInfo::new(
+            Value::Signed(0x3ff0000000000001),
+            Value::Signed(0x3ff0000000000000),
+            FloatFormat::F64,
+        );
+
+        // Assert
+        assert_eq!(
+            actual.unwrap().lines(),
+            vec![
+                ("First", String::from("1.0000000000000002")),
+                ("Second", String::from("1.0")),
+                ("ULP distance", String::from("1")),
+                ("Larger", String::from("first")),
+                ("Difference", String::from("-2.220446049250313e-16")),
+            ]
+        );
+    }
+
+    #[test]
+    fn float_bits_should_show_fields_in_binary_and_hexadecimal() {
+        // Act
+        let actual = FloatBits::new(Value::Signed(0x3fc00000), FloatFormat::F32).unwrap();
+
+        // Assert
+        assert_eq!(
+            actual.lines(),
+            vec![
+                ("Format", String::from("f32")),
+                ("Float", String::from("1.5")),
+                ("Class", String::from("normal")),
+                ("Sign", String::from("0 (positive)")),
+                ("Exponent", String::from("01111111 (0x7f)")),
+                ("Biased exponent", String::from("127")),
+                ("Unbiased exponent", String::from("0")),
+                (
+                    "Mantissa",
+                    String::from("10000000000000000000000 (0x400000)")
+                ),
+            ]
+        );
+    }
+
+    #[test_case(
+        FloatFormat::F64,
+        0x3fb999999999999a,
+        "0.1000000000000000055511151231257827021181583404541015625"
+    )]
+    #[test_case(FloatFormat::F64, 0x3ff8000000000000, "1.5"; "exact")]
+    #[test_case(FloatFormat::F64, 0x4340000000000000, "9007199254740992"; "whole")]
+    #[test_case(FloatFormat::F64, 0x8000000000000000, "-0"; "negative zero")]
+    #[test_case(FloatFormat::F32, 0x3dcccccd, "0.100000001490116119384765625"; "single precision")]
+    #[test_case(FloatFormat::F32, 0x7f7fffff, "340282346638528859811704183484516925440"; "largest single precision")]
+    #[test_case(FloatFormat::F16, 0x0001, "0.000000059604644775390625"; "half precision subnormal")]
+    #[test_case(FloatFormat::F64, 0x7ff0000000000000, "inf"; "infinity")]
+    fn exact_float_number_formatter_should_format_i128(
+        format: FloatFormat,
+        input: i128,
+        expected: &str,
+    ) {
+        // Act
+        let actual = ExactFloatNumberFormatter { format }.format(input);
+
+        // Assert
+        assert_eq!(actual.unwrap(), expected);
+    }
+
+    #[test]
+    fn exact_float_number_formatter_should_write_smallest_subnormal() {
+        // Act
+        let actual = ExactFloatNumberFormatter {
+            format: FloatFormat::F64,
+        }
+        .format(1)
+        .unwrap();
+
+        // Assert
+        assert_eq!(actual.len(), 2 + 1074);
+        assert!(actual[2..].starts_with(&format!(
+            "{}49406564584124654417656879286822137236505980",
+            "0".repeat(323)
+        )));
+        assert!(actual.ends_with('5'));
+        assert_eq!(actual.parse::<f64>().unwrap().to_bits(), 1);
+    }
+
+    #[test_case(
+        0x3fb999999999999a,
+        FloatPrecision::Digits(FloatDigits::Shortest),
+        "0.1 (shortest round-trip)"
+    )]
+    #[test_case(0x3fb999999999999a, FloatPrecision::Digits(FloatDigits::Exact), "0.1000000000000000055511151231257827021181583404541015625 (exact)"; "exact")]
+    #[test_case(0x3fb999999999999a, FloatPrecision::Rounded(Precision::SignificantFigures(20)), "0.10000000000000000555"; "significant figures")]
+    #[test_case(0x400921fb54442d18, FloatPrecision::Rounded(Precision::Decimals(3)), "3.142"; "decimals")]
+    #[test_case(1, FloatPrecision::Digits(FloatDigits::Shortest), "5e-324 (subnormal, shortest round-trip)"; "subnormal")]
+    #[test_case(0x7ff0000000000000, FloatPrecision::Rounded(Precision::Decimals(3)), "inf"; "infinity")]
+    fn float_number_formatter_should_format_to_precision(
+        input: i128,
+        precision: FloatPrecision,
+        expected: &str,
+    ) {
+        // Act
+        let actual = FloatNumberFormatter {
+            format: FloatFormat::F64,
+            precision,
+        }
+        .format(input);
+
+        // Assert
+        assert_eq!(actual.unwrap(), expected);
+    }
+
+    #[test]
+    fn float_number_formatter_should_read_classified_float() {
+        // Act
+        let actual = FloatNumberFormatter {
+            format: FloatFormat::F64,
+            precision: FloatPrecision::Shortest,
+        }
+        .read("5e-324 (subnormal)");
+
+        // Assert
+        assert_eq!(actual.unwrap(), 1);
+    }
+
+    #[test]
+    fn float_number_formatter_should_fail_to_read_invalid_float() {
+        // Act
+        let actual = FloatNumberFormatter {
+            format: FloatFormat::F64,
+            precision: FloatPrecision::Shortest,
+        }
+        .read("1.5x");
+
+        // Assert
+        assert!(matches!(actual, Err(NumberFormatterError::InvalidFloat)));
+    }
+}
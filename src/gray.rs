@@ -0,0 +1,85 @@
+use crate::{NumberFormatter, NumberFormatterError};
+
+/// Reflected binary Gray code formatter, reading and writing the Gray coded bits in
+/// binary. Negative numbers are coded from their 128 bit two's complement pattern
+pub(crate) struct GrayNumberFormatter;
+
+impl NumberFormatter for GrayNumberFormatter {
+    fn read(&self, gray: &str) -> anyhow::Result<i128, NumberFormatterError> {
+        let without_prefix = gray.trim_start_matches("0b").trim_start_matches("0B");
+        let mut bits = u128::from_str_radix(without_prefix, 2)?;
+
+        let mut shift = 1;
+        while shift < u128::BITS {
+            bits ^= bits >> shift;
+            shift <<= 1;
+        }
+
+        Ok(bits as i128)
+    }
+
+    fn format(&self, num: i128) -> anyhow::Result<String, NumberFormatterError> {
+        self.format_unsigned(num as u128)
+    }
+
+    fn format_unsigned(&self, bits: u128) -> anyhow::Result<String, NumberFormatterError> {
+        Ok(format!("{:b}", bits ^ (bits >> 1)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("0", 0)]
+    #[test_case("1", 1)]
+    #[test_case("11", 2)]
+    #[test_case("10", 3)]
+    #[test_case("0b1101", 9)]
+    #[test_case("1000", 15)]
+    fn gray_number_formatter_should_read_number(input: &str, expected: i128) {
+        // Act
+        let actual_number = GrayNumberFormatter.read(input);
+
+        // Assert
+        assert!(actual_number.is_ok());
+        assert_eq!(expected, actual_number.unwrap());
+    }
+
+    #[test_case(0, "0")]
+    #[test_case(2, "11")]
+    #[test_case(9, "1101")]
+    #[test_case(15, "1000")]
+    fn gray_number_formatter_should_format_i128(input: i128, expected: &str) {
+        // Act
+        let actual_output = GrayNumberFormatter.format(input);
+
+        // Assert
+        assert!(actual_output.is_ok());
+        assert_eq!(expected, actual_output.unwrap());
+    }
+
+    #[test_case(-1)]
+    #[test_case(i128::MIN)]
+    #[test_case(i128::MAX)]
+    fn gray_number_formatter_should_round_trip(input: i128) {
+        // Act
+        let actual_number = GrayNumberFormatter.read(&GrayNumberFormatter.format(input).unwrap());
+
+        // Assert
+        assert_eq!(input, actual_number.unwrap());
+    }
+
+    #[test]
+    fn gray_number_formatter_should_reject_invalid_digit() {
+        // Act
+        let actual_number = GrayNumberFormatter.read("1021");
+
+        // Assert
+        assert!(matches!(
+            actual_number,
+            Err(NumberFormatterError::InvalidDigit)
+        ));
+    }
+}
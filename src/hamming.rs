@@ -0,0 +1,93 @@
+use clap::Args;
+
+/// Options of the hamming command
+#[derive(Args, Clone, Debug)]
+pub struct HammingOptions {
+    /// The first number
+    pub first: String,
+    /// The second number
+    pub second: String,
+}
+
+/// The bits that differ between two bit patterns of the same width
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HammingInfo {
+    pub width: u32,
+    /// Indices of the differing bits, lowest first
+    pub positions: Vec<u32>,
+}
+
+impl HammingInfo {
+    pub(crate) fn new(a: u128, b: u128, width: u32) -> Self {
+        let difference = a ^ b;
+
+        Self {
+            width,
+            positions: (0..width)
+                .filter(|bit| (difference >> bit) & 1 == 1)
+                .collect(),
+        }
+    }
+
+    /// The Hamming distance, the number of bits that differ
+    pub fn distance(&self) -> usize {
+        self.positions.len()
+    }
+
+    /// Labelled lines describing the differing bits, in the order they should be displayed
+    pub fn lines(&self) -> Vec<(&'static str, String)> {
+        let positions = if self.positions.is_empty() {
+            String::from("none")
+        } else {
+            self.positions
+                .iter()
+                .map(u32::to_string)
+                .collect::<Vec<String>>()
+                .join(", ")
+        };
+
+        vec![
+            ("Width", self.width.to_string()),
+            ("Distance", self.distance().to_string()),
+            ("Differing bits", positions),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(0b1011, 0b1110, 8, &[0, 2])]
+    #[test_case(0x55, 0x55, 8, &[]; "equal")]
+    #[test_case(0, u128::MAX, 128, &(0..128).collect::<Vec<u32>>(); "every bit")]
+    fn hamming_info_should_find_differing_bits(a: u128, b: u128, width: u32, expected: &[u32]) {
+        // Act
+        let actual = HammingInfo::new(a, b, width);
+
+        // Assert
+        assert_eq!(actual.positions, expected);
+        assert_eq!(actual.distance(), expected.len());
+    }
+
+    #[test_case(0b1011, 0b1110, "2", "0, 2")]
+    #[test_case(7, 7, "0", "none"; "equal")]
+    fn hamming_info_should_describe_distance(a: u128, b: u128, distance: &str, positions: &str) {
+        // Arrange
+        let info = HammingInfo::new(a, b, 8);
+
+        // Act
+        let actual = info.lines();
+
+        // Assert
+        assert_eq!(
+            actual,
+            vec![
+                ("Width", String::from("8")),
+                ("Distance", distance.to_string()),
+                ("Differing bits", positions.to_string()),
+            ]
+        );
+    }
+}
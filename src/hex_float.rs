@@ -0,0 +1,215 @@
+use crate::float::{FloatBits, FloatFormat};
+use crate::{NumberFormatter, NumberFormatterError};
+
+/// A hexadecimal float literal such as `-0x1.8p3` split into its parts, with the value
+/// `mantissa * 2^exponent`
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+struct HexFloatLiteral {
+    negative: bool,
+    mantissa: u128,
+    exponent: i64,
+    /// Whether nonzero digits were dropped from the end of a mantissa too long for 128 bits
+    sticky: bool,
+}
+
+/// Splits a hexadecimal float literal as written in C99 and later, `0x` then hex digits with an
+/// optional point and a `p` exponent of two written in decimal, which is optional here
+fn lex(literal: &str) -> anyhow::Result<HexFloatLiteral, NumberFormatterError> {
+    if literal.is_empty() {
+        return Err(NumberFormatterError::Empty);
+    }
+
+    let (negative, unsigned) = match literal.strip_prefix('-') {
+        Some(unsigned) => (true, unsigned),
+        None => (false, literal.strip_prefix('+').unwrap_or(literal)),
+    };
+    let digits = unsigned
+        .strip_prefix("0x")
+        .or_else(|| unsigned.strip_prefix("0X"))
+        .ok_or(NumberFormatterError::InvalidHexFloat)?;
+
+    let (digits, power) = match digits.split_once(['p', 'P']) {
+        Some((digits, power)) => (
+            digits,
+            power
+                .parse::<i32>()
+                .map_err(|_| NumberFormatterError::InvalidHexFloat)?,
+        ),
+        None => (digits, 0),
+    };
+    let (whole, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+    if whole.is_empty() && fraction.is_empty() {
+        return Err(NumberFormatterError::InvalidHexFloat);
+    }
+
+    let mut mantissa = 0u128;
+    let mut exponent = power as i64;
+    let mut sticky = false;
+    for (index, digit) in whole.chars().chain(fraction.chars()).enumerate() {
+        let digit = digit
+            .to_digit(16)
+            .ok_or(NumberFormatterError::InvalidHexFloat)? as u128;
+
+        // Digits past 128 bits only matter for rounding, as long as they are counted
+        let in_fraction = index >= whole.len();
+        if mantissa.leading_zeros() >= 4 {
+            mantissa = mantissa << 4 | digit;
+            if in_fraction {
+                exponent -= 4;
+            }
+        } else {
+            sticky |= digit != 0;
+            if !in_fraction {
+                exponent += 4;
+            }
+        }
+    }
+
+    Ok(HexFloatLiteral {
+        negative,
+        mantissa,
+        exponent,
+        sticky,
+    })
+}
+
+/// Whether the input is a hexadecimal float literal such as `0x1.8p3`, which has a point or an
+/// exponent unlike a hexadecimal integer
+pub(crate) fn is_hex_float(input: &str) -> bool {
+    let digits = input.trim_start_matches(['-', '+']);
+    let digits = digits.get(2..).unwrap_or_default();
+
+    digits.contains(['.', 'p', 'P']) && lex(input).is_ok()
+}
+
+/// Writes the bit pattern of a float as a hexadecimal float literal, with a leading 1 for normal
+/// floats and a leading 0 for subnormal ones as the `%a` format of C does
+pub(crate) fn write_hex_float(pattern: u128, format: FloatFormat) -> String {
+    let bits = FloatBits::from_pattern(pattern, format);
+    let sign = if bits.negative { "-" } else { "" };
+    let mantissa_bits = format.mantissa_bits();
+
+    let exponent = match bits.unbiased_exponent() {
+        None if bits.mantissa == 0 => return format!("{}inf", sign),
+        None => return String::from("nan"),
+        Some(_) if bits.exponent == 0 && bits.mantissa == 0 => return format!("{}0x0p+0", sign),
+        Some(exponent) => exponent,
+    };
+    let leading = if bits.exponent == 0 { 0 } else { 1 };
+
+    // The mantissa is shifted to end on a whole hex digit
+    let hex_digits = mantissa_bits.div_ceil(4);
+    let fraction = format!(
+        "{:0width$x}",
+        bits.mantissa << (hex_digits * 4 - mantissa_bits),
+        width = hex_digits as usize
+    );
+    let fraction = fraction.trim_end_matches('0');
+
+    if fraction.is_empty() {
+        format!("{}0x{}p{:+}", sign, leading, exponent)
+    } else {
+        format!("{}0x{}.{}p{:+}", sign, leading, fraction, exponent)
+    }
+}
+
+/// Formatter writing bit patterns as the IEEE 754 floats they encode in hexadecimal float literals,
+/// such as `0x1.8p+0` for 0x3ff8000000000000, which unlike decimal floats are always exact
+pub(crate) struct HexFloatNumberFormatter {
+    pub format: FloatFormat,
+}
+
+impl NumberFormatter for HexFloatNumberFormatter {
+    fn read(&self, literal: &str) -> anyhow::Result<i128, NumberFormatterError> {
+        let literal = lex(literal)?;
+        Ok(self.format.encode(
+            literal.negative,
+            literal.mantissa,
+            literal.exponent,
+            literal.sticky,
+        ) as i128)
+    }
+
+    fn format(&self, num: i128) -> anyhow::Result<String, NumberFormatterError> {
+        Ok(write_hex_float(self.format.pattern_of(num)?, self.format))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("0x1.8p3", true)]
+    #[test_case("-0X1P-2", true; "uppercase")]
+    #[test_case("0x.8", true; "without exponent")]
+    #[test_case("0x18", false; "hexadecimal integer")]
+    #[test_case("0x1.8q3", false; "invalid exponent")]
+    #[test_case("1.8p3", false; "without prefix")]
+    fn is_hex_float_should_detect_hex_float(input: &str, expected: bool) {
+        // Act
+        let actual = is_hex_float(input);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test_case("0x1.8p3", FloatFormat::F64, 0x4028000000000000)]
+    #[test_case("0x1.8p+0", FloatFormat::F64, 0x3ff8000000000000; "signed exponent")]
+    #[test_case("-0x1p-1", FloatFormat::F64, 0xbfe0000000000000; "negative")]
+    #[test_case("0x1.999999999999ap-4", FloatFormat::F64, 0x3fb999999999999a; "tenth")]
+    #[test_case("0x1p-1074", FloatFormat::F64, 1; "smallest subnormal")]
+    #[test_case("0x0p+0", FloatFormat::F64, 0; "zero")]
+    #[test_case("0x1.8p0", FloatFormat::F32, 0x3fc00000; "single precision")]
+    #[test_case("0x1.0000010000000000000000000000000001p0", FloatFormat::F32, 0x3f800001; "long mantissa tie")]
+    #[test_case("0x1p1024", FloatFormat::F64, 0x7ff0000000000000; "overflows")]
+    fn hex_float_number_formatter_should_read_literal(
+        input: &str,
+        format: FloatFormat,
+        expected: i128,
+    ) {
+        // Act
+        let actual = HexFloatNumberFormatter { format }.read(input);
+
+        // Assert
+        assert_eq!(actual.unwrap(), expected);
+    }
+
+    #[test_case(0x4028000000000000, FloatFormat::F64, "0x1.8p+3")]
+    #[test_case(0x3fb999999999999a, FloatFormat::F64, "0x1.999999999999ap-4"; "tenth")]
+    #[test_case(0x3ff0000000000000, FloatFormat::F64, "0x1p+0"; "one")]
+    #[test_case(1, FloatFormat::F64, "0x0.0000000000001p-1022"; "subnormal")]
+    #[test_case(0x8000000000000000, FloatFormat::F64, "-0x0p+0"; "negative zero")]
+    #[test_case(0x7ff0000000000000, FloatFormat::F64, "inf"; "infinity")]
+    #[test_case(0x7ff8000000000000, FloatFormat::F64, "nan"; "nan")]
+    #[test_case(0x3dcccccd, FloatFormat::F32, "0x1.99999ap-4"; "single precision")]
+    fn hex_float_number_formatter_should_format_i128(
+        input: i128,
+        format: FloatFormat,
+        expected: &str,
+    ) {
+        // Act
+        let actual = HexFloatNumberFormatter { format }.format(input);
+
+        // Assert
+        assert_eq!(actual.unwrap(), expected);
+    }
+
+    #[test_case("", NumberFormatterError::Empty; "empty")]
+    #[test_case("1.5", NumberFormatterError::InvalidHexFloat; "decimal")]
+    #[test_case("0x1.8p", NumberFormatterError::InvalidHexFloat; "missing exponent")]
+    #[test_case("0x1.g", NumberFormatterError::InvalidHexFloat; "invalid digit")]
+    fn hex_float_number_formatter_should_fail_to_read(input: &str, expected: NumberFormatterError) {
+        // Act
+        let actual = HexFloatNumberFormatter {
+            format: FloatFormat::F64,
+        }
+        .read(input);
+
+        // Assert
+        assert_eq!(
+            std::mem::discriminant(&actual.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+}
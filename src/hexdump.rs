@@ -0,0 +1,290 @@
+use crate::bytes::{self, pattern_of_bytes, value_bytes, Endian};
+use crate::{IntegerSize, NumberFormatter, NumberFormatterError, Value};
+
+/// Bytes shown on each line of a hexdump
+const BYTES_PER_LINE: usize = 16;
+
+/// Shortest offset `od` and `hexdump` print without a colon, telling it apart from a byte group
+const MIN_OFFSET_LEN: usize = 7;
+
+/// Lays out bytes as `xxd` does, with the offset, the bytes in hex in pairs and the printable ASCII
+/// characters, sixteen bytes to a line
+pub fn hexdump(bytes: &[u8]) -> String {
+    bytes
+        .chunks(BYTES_PER_LINE)
+        .enumerate()
+        .map(|(line, chunk)| {
+            let hex = chunk
+                .chunks(2)
+                .map(|pair| pair.iter().map(|byte| format!("{:02x}", byte)).collect())
+                .collect::<Vec<String>>()
+                .join(" ");
+            let ascii: String = chunk
+                .iter()
+                .map(|byte| match byte {
+                    0x20..=0x7e => *byte as char,
+                    _ => '.',
+                })
+                .collect();
+
+            format!(
+                "{:08x}: {:<width$}  {}",
+                line * BYTES_PER_LINE,
+                hex,
+                ascii,
+                width = BYTES_PER_LINE / 2 * 5 - 1
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Whether the input starts like a hexdump line, either an `xxd` line with an offset followed by a
+/// colon, or an `od` or `hexdump -C` line with an offset followed by byte or word groups
+pub(crate) fn is_hexdump(input: &str) -> bool {
+    let is_offset = |offset: &str| {
+        offset.len() >= MIN_OFFSET_LEN && offset.chars().all(|c| c.is_ascii_hexdigit())
+    };
+
+    if input
+        .split_once(": ")
+        .is_some_and(|(offset, _)| is_offset(offset))
+    {
+        return true;
+    }
+
+    let line = input.lines().next().unwrap_or_default();
+    let (line, fenced) = match line.split_once('|') {
+        Some((line, _)) => (line, true),
+        None => (line, false),
+    };
+    let mut tokens = line.split_whitespace();
+    let groups: Vec<&str> = match tokens.next() {
+        Some(offset) if is_offset(offset) => tokens.collect(),
+        _ => return false,
+    };
+
+    !groups.is_empty()
+        && groups.iter().all(|group| {
+            if fenced {
+                group.len() == 2 && group.chars().all(|c| c.is_ascii_hexdigit())
+            } else {
+                read_word_group(group).is_ok()
+            }
+        })
+}
+
+/// Reads the bytes of a group on an `od` or `hexdump` line. Those tools write words in the host's
+/// byte order, taken to be little endian, so a group of four hex digits such as `4241` holds the
+/// bytes `41 42`. Groups with an odd number of digits are octal, as in `od`'s default `041101`.
+fn read_word_group(group: &str) -> anyhow::Result<Vec<String>, NumberFormatterError> {
+    let (radix, size) = match group.len() {
+        3 => (8, 1),
+        6 => (8, 2),
+        11 => (8, 4),
+        22 => (8, 8),
+        2 | 4 | 8 | 16 => (16, group.len() / 2),
+        _ => return Err(NumberFormatterError::InvalidDigit),
+    };
+
+    if !group.chars().all(|c| c.is_digit(radix)) {
+        return Err(NumberFormatterError::InvalidDigit);
+    }
+
+    let word =
+        u64::from_str_radix(group, radix).map_err(|_| NumberFormatterError::TooLargeError)?;
+    if size < 8 && word >> (size * 8) != 0 {
+        return Err(NumberFormatterError::TooLargeError);
+    }
+
+    Ok(word.to_le_bytes()[..size]
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect())
+}
+
+/// Reads the bytes of pasted `xxd`, `hexdump -C` or `od` lines, skipping their offsets and ASCII
+/// gutters. Byte groups are read in the order they are written, while the words `od` and
+/// `hexdump` write after an offset without a colon are read as little endian.
+pub(crate) fn parse_hexdump(dump: &str) -> anyhow::Result<Vec<u8>, NumberFormatterError> {
+    let mut hex = Vec::<String>::new();
+
+    for (index, line) in dump.lines().enumerate() {
+        // hexdump -C fences the gutter with bars, xxd puts two spaces before it
+        let (line, fenced) = match line.split_once('|') {
+            Some((line, _)) => (line, true),
+            None => (line, false),
+        };
+        let mut tokens: Vec<&str> = line.split_whitespace().collect();
+
+        match tokens.first() {
+            None => continue,
+            // od ends its dump with a line holding just the length
+            Some(_) if tokens.len() == 1 && index > 0 => continue,
+            Some(offset) if offset.ends_with(':') => {
+                let data = line.split_once(':').map_or(line, |(_, data)| data);
+                let data = data.trim_start();
+                let data = if fenced {
+                    data
+                } else {
+                    data.split_once("  ").map_or(data, |(data, _)| data)
+                };
+                tokens = data.split_whitespace().collect();
+            }
+            Some(offset) if tokens.len() > 1 && offset.len() >= MIN_OFFSET_LEN => {
+                tokens.remove(0);
+                if !fenced {
+                    for group in tokens {
+                        hex.extend(read_word_group(group)?);
+                    }
+                    continue;
+                }
+            }
+            Some(_) => {}
+        }
+
+        hex.extend(tokens.into_iter().map(String::from));
+    }
+
+    bytes::parse_hex_bytes(&hex.join(" "))
+}
+
+/// Formatter writing the bytes of a number's bit pattern as an `xxd` line, at the integer size or
+/// else the narrowest width holding the number, and reading pasted hexdumps back
+pub(crate) struct HexdumpNumberFormatter {
+    pub endian: Endian,
+    pub size: Option<IntegerSize>,
+}
+
+impl HexdumpNumberFormatter {
+    fn format_value(&self, value: Value) -> String {
+        let size = self.size.unwrap_or_else(|| IntegerSize::holding(value));
+        hexdump(&value_bytes(value, size, self.endian))
+    }
+}
+
+impl NumberFormatter for HexdumpNumberFormatter {
+    fn read(&self, dump: &str) -> anyhow::Result<i128, NumberFormatterError> {
+        i128::try_from(self.read_unsigned(dump)?).map_err(|_| NumberFormatterError::TooLargeError)
+    }
+
+    fn format(&self, num: i128) -> anyhow::Result<String, NumberFormatterError> {
+        Ok(self.format_value(Value::Signed(num)))
+    }
+
+    fn read_unsigned(&self, dump: &str) -> anyhow::Result<u128, NumberFormatterError> {
+        pattern_of_bytes(parse_hexdump(dump)?, self.endian)
+    }
+
+    fn format_unsigned(&self, num: u128) -> anyhow::Result<String, NumberFormatterError> {
+        Ok(self.format_value(Value::Unsigned(num)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(
+        &[0xde, 0xad, 0xbe, 0xef],
+        "00000000: dead beef                                ...."
+    )]
+    #[test_case(
+        &[0x48, 0x69, 0x21],
+        "00000000: 4869 21                                  Hi!"
+    )]
+    #[test_case(
+        b"0123456789abcdefXY",
+        "00000000: 3031 3233 3435 3637 3839 6162 6364 6566  0123456789abcdef\n\
+         00000010: 5859                                     XY"
+    )]
+    fn hexdump_should_lay_out_bytes_like_xxd(bytes: &[u8], expected: &str) {
+        // Act
+        let actual = hexdump(bytes);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test_case("00000000: dead beef                                ....", &[0xde, 0xad, 0xbe, 0xef]; "xxd")]
+    #[test_case("00000000: 3031 3233  0123\n", &[0x30, 0x31, 0x32, 0x33]; "xxd gutter of hex digits")]
+    #[test_case(
+        "00000000: 3031 3233 3435 3637 3839 6162 6364 6566  0123456789abcdef\n\
+         00000010: 5859                                     XY",
+        b"0123456789abcdefXY";
+        "xxd lines"
+    )]
+    #[test_case("0000000 de ad be ef\n0000004", &[0xde, 0xad, 0xbe, 0xef]; "od")]
+    #[test_case("0000000 041101 042103\n0000004", b"ABCD"; "od octal words")]
+    #[test_case("0000000 4241 4443\n0000004", b"ABCD"; "od hex words")]
+    #[test_case("0000000 44434241\n0000004", b"ABCD"; "od hex double words")]
+    #[test_case("0000000 101 102 103 104\n0000004", b"ABCD"; "od octal bytes")]
+    #[test_case(" de ad be ef", &[0xde, 0xad, 0xbe, 0xef]; "od without offsets")]
+    #[test_case("00000000  de ad be ef 41  |....A|", &[0xde, 0xad, 0xbe, 0xef, 0x41]; "hexdump canonical")]
+    #[test_case("deadbeef", &[0xde, 0xad, 0xbe, 0xef]; "plain")]
+    fn parse_hexdump_should_skip_offsets_and_gutters(dump: &str, expected: &[u8]) {
+        // Act
+        let actual = parse_hexdump(dump);
+
+        // Assert
+        assert_eq!(actual.unwrap(), expected);
+    }
+
+    #[test_case("00000000: dead beef  ....", true)]
+    #[test_case("0000000 de ad be ef\n0000004", true; "od")]
+    #[test_case("0000000 041101 042103", true; "od octal words")]
+    #[test_case("0000000 4241 4443", true; "od hex words")]
+    #[test_case("00000000  de ad be ef 41  |....A|", true; "hexdump canonical")]
+    #[test_case("00000000  dead  |..|", false; "hexdump canonical word")]
+    #[test_case("0000000 16961 17475", false; "od decimal words")]
+    #[test_case("0000000", false; "offset only")]
+    #[test_case("10: 12", false; "short offset")]
+    fn is_hexdump_should_check_offset(input: &str, expected: bool) {
+        // Act
+        let actual = is_hexdump(input);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test_case("", NumberFormatterError::Empty; "empty")]
+    #[test_case("00000000:", NumberFormatterError::Empty; "offset only")]
+    #[test_case("00000000: dea", NumberFormatterError::IncompleteByte; "odd digits")]
+    #[test_case("0000000 16961 17475", NumberFormatterError::InvalidDigit; "od decimal words")]
+    #[test_case("0000000 041109", NumberFormatterError::InvalidDigit; "od octal word with invalid digit")]
+    #[test_case("0000000 777", NumberFormatterError::TooLargeError; "od octal byte too large")]
+    fn hexdump_number_formatter_should_fail_to_read(dump: &str, expected: NumberFormatterError) {
+        // Arrange
+        let formatter = HexdumpNumberFormatter {
+            endian: Endian::Big,
+            size: None,
+        };
+
+        // Act
+        let actual = formatter.read(dump);
+
+        // Assert
+        assert_eq!(
+            std::mem::discriminant(&actual.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+
+    #[test_case(
+        Endian::Big,
+        "00000000: dead beef                                ....",
+        0xdeadbeef
+    )]
+    #[test_case(Endian::Little, "0000000 ef be ad de\n0000004", 0xdeadbeef; "little endian")]
+    fn hexdump_number_formatter_should_read_number(endian: Endian, dump: &str, expected: i128) {
+        // Arrange
+        let formatter = HexdumpNumberFormatter { endian, size: None };
+
+        // Act
+        let actual = formatter.read(dump);
+
+        // Assert
+        assert_eq!(actual.unwrap(), expected);
+    }
+}
@@ -0,0 +1,643 @@
+use crate::precision::{self, Precision};
+use crate::{NumberFormatter, NumberFormatterError, Value};
+use clap::ArgEnum;
+
+/// Unit systems human-readable sizes are written in
+#[derive(ArgEnum, Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Units {
+    /// Both binary and decimal units, binary first
+    #[default]
+    Both,
+    /// Binary IEC units, powers of 1024 such as KiB and MiB
+    Iec,
+    /// Decimal SI units, powers of 1000 such as kB and MB
+    Si,
+}
+
+/// Byte units from bytes up, each 1024 times the last
+static IEC_UNITS: [&str; 9] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB", "ZiB", "YiB"];
+
+/// Byte units from bytes up, each 1000 times the last
+static SI_UNITS: [&str; 11] = [
+    "B", "kB", "MB", "GB", "TB", "PB", "EB", "ZB", "YB", "RB", "QB",
+];
+
+/// Frequency units from hertz up, each 1000 times the last
+static FREQUENCY_UNITS: [&str; 5] = ["Hz", "kHz", "MHz", "GHz", "THz"];
+
+/// SI prefixes from none up, each 1000 times the last
+static SI_PREFIXES: [&str; 11] = ["", "k", "M", "G", "T", "P", "E", "Z", "Y", "R", "Q"];
+
+/// Magnitude suffixes from kilo up, each a power of 1000 or 1024 above the last
+static SUFFIXES: [char; 8] = ['k', 'M', 'G', 'T', 'P', 'E', 'Z', 'Y'];
+
+/// Decimal places human-readable sizes are rounded to without a --precision or --sig-figs
+pub(crate) const SIZE_DECIMALS: u32 = 2;
+
+/// Decimal places frequencies are rounded to by default, enough for a 32.768 kHz crystal
+pub(crate) const FREQUENCY_DECIMALS: u32 = 3;
+
+/// Decimal places the mantissas of engineering notation and SI prefixes are rounded to by default
+pub(crate) const ENGINEERING_DECIMALS: u32 = 3;
+
+/// Writes a magnitude in the largest unit it is at least one of, each unit `base` times the last
+pub(crate) fn scale(magnitude: u128, base: u128, units: &[&str], precision: Precision) -> String {
+    let (quotient, unit) = scale_parts(magnitude, base, units.len(), precision);
+    format!("{} {}", quotient, units[unit])
+}
+
+/// Divides a magnitude by the largest power of `base` below `unit_count` that it is at least, and
+/// returns the quotient with the power
+fn scale_parts(
+    magnitude: u128,
+    base: u128,
+    unit_count: usize,
+    precision: Precision,
+) -> (String, usize) {
+    let mut unit = 0;
+    let mut den = 1u128;
+
+    while unit + 1 < unit_count {
+        match den.checked_mul(base) {
+            Some(next) if magnitude >= next => {
+                den = next;
+                unit += 1;
+            }
+            _ => break,
+        }
+    }
+
+    let mut quotient = precision::divide(magnitude, den, precision);
+
+    // Rounding can reach the next unit, such as 1023.999 KiB rounding to 1024 KiB
+    if unit + 1 < unit_count && quotient == base.to_string() {
+        unit += 1;
+        quotient = String::from("1");
+    }
+
+    (quotient, unit)
+}
+
+/// Reads a decimal number such as `1.5` as its digits and the number of them after the point
+pub(crate) fn parse_decimal(decimal: &str) -> anyhow::Result<(u128, u32), NumberFormatterError> {
+    let (whole, fraction) = decimal.split_once('.').unwrap_or((decimal, ""));
+
+    if whole.is_empty() && fraction.is_empty() {
+        return Err(NumberFormatterError::Empty);
+    }
+    if !whole
+        .chars()
+        .chain(fraction.chars())
+        .all(|c| c.is_ascii_digit())
+    {
+        return Err(NumberFormatterError::InvalidDigit);
+    }
+
+    let digits = format!("{}{}", whole, fraction).parse::<u128>()?;
+    let scale = u32::try_from(fraction.len()).map_err(|_| NumberFormatterError::TooLargeError)?;
+
+    Ok((digits, scale))
+}
+
+/// Multiplies a decimal number's digits by a whole number and divides out its decimal places,
+/// which must leave a whole number
+pub(crate) fn exact_product(
+    digits: u128,
+    scale: u32,
+    multiplier: u128,
+) -> anyhow::Result<u128, NumberFormatterError> {
+    let mut product = digits
+        .checked_mul(multiplier)
+        .ok_or(NumberFormatterError::TooLargeError)?;
+
+    // Dividing out one zero at a time works for more decimal places than a u128 power of ten has
+    for _ in 0..scale {
+        if product % 10 != 0 {
+            return Err(NumberFormatterError::NotAnInteger);
+        }
+        product /= 10;
+    }
+
+    Ok(product)
+}
+
+/// Reads a decimal number followed by an optional unit, such as `1.5 GiB` or `25MHz`, as a whole
+/// number of the smallest unit
+fn read_with_unit(
+    text: &str,
+    unit_multiplier: impl Fn(&str) -> Option<u128>,
+) -> anyhow::Result<u128, NumberFormatterError> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Err(NumberFormatterError::Empty);
+    }
+
+    let unit_start = text
+        .find(|c: char| c.is_ascii_alphabetic())
+        .unwrap_or(text.len());
+    let (decimal, unit) = text.split_at(unit_start);
+    let multiplier = match unit {
+        "" => 1,
+        unit => unit_multiplier(unit).ok_or(NumberFormatterError::UnknownUnit)?,
+    };
+
+    let (digits, scale) = parse_decimal(decimal.trim())?;
+    exact_product(digits, scale, multiplier)
+}
+
+/// Whether the input is a frequency such as `25MHz` or `480 MHz`
+pub(crate) fn is_frequency(input: &str) -> bool {
+    let input = input.to_ascii_lowercase();
+    let Some(decimal) = input.strip_suffix("hz") else {
+        return false;
+    };
+    let decimal = decimal.trim_end_matches(['k', 'm', 'g', 't']).trim_end();
+
+    decimal.starts_with(|c: char| c.is_ascii_digit())
+        && decimal.chars().all(|c| c.is_ascii_digit() || c == '.')
+}
+
+/// Splits a magnitude suffix such as `k`, `M` or `Gi` off the end of a number, with the multiplier it
+/// stands for. Suffixes ending in `i` are powers of 1024, as are bare ones unless `si` is set.
+pub(crate) fn split_suffix(number: &str, si: bool) -> Option<(&str, u128)> {
+    let (number, binary) = match number.strip_suffix('i') {
+        Some(number) => (number, true),
+        None => (number, !si),
+    };
+
+    let suffix = number.chars().last()?;
+    let power = SUFFIXES
+        .iter()
+        .position(|known| known.eq_ignore_ascii_case(&suffix))?;
+    let base: u128 = if binary { 1024 } else { 1000 };
+
+    Some((&number[..number.len() - 1], base.pow(power as u32 + 1)))
+}
+
+/// Reads a possibly negative decimal number such as `-1.5` multiplied by a whole number, which must
+/// leave a whole number, as its sign and magnitude
+pub(crate) fn read_scaled(
+    number: &str,
+    multiplier: u128,
+) -> anyhow::Result<(bool, u128), NumberFormatterError> {
+    let (negative, decimal) = match number.strip_prefix('-') {
+        Some(decimal) => (true, decimal),
+        None => (false, number),
+    };
+
+    let (digits, scale) = parse_decimal(decimal)?;
+    Ok((negative, exact_product(digits, scale, multiplier)?))
+}
+
+/// Splits a number in scientific notation such as `2.5e3` into its mantissa and power of ten
+/// exponent
+pub(crate) fn split_exponent(number: &str) -> Option<(&str, i32)> {
+    let (mantissa, exponent) = number.split_once(['e', 'E'])?;
+
+    if mantissa.is_empty() || mantissa.contains(|c: char| c.is_alphabetic()) {
+        None
+    } else {
+        Some((mantissa, exponent.parse::<i32>().ok()?))
+    }
+}
+
+/// Reads a possibly negative decimal mantissa such as `-2.5` times a power of ten, which must leave
+/// a whole number, as its sign and magnitude
+pub(crate) fn read_scientific(
+    mantissa: &str,
+    exponent: i32,
+) -> anyhow::Result<(bool, u128), NumberFormatterError> {
+    let (negative, decimal) = match mantissa.strip_prefix('-') {
+        Some(decimal) => (true, decimal),
+        None => (false, mantissa),
+    };
+    let (digits, scale) = parse_decimal(decimal)?;
+
+    let magnitude = match u32::try_from(exponent) {
+        Ok(exponent) => match digits {
+            0 => 0,
+            digits => {
+                let multiplier = 10u128
+                    .checked_pow(exponent)
+                    .ok_or(NumberFormatterError::TooLargeError)?;
+                exact_product(digits, scale, multiplier)?
+            }
+        },
+        Err(_) => exact_product(digits, scale + exponent.unsigned_abs(), 1)?,
+    };
+
+    Ok((negative, magnitude))
+}
+
+/// Formatter writing byte counts as human-readable sizes in binary and decimal units, such as
+/// `1.5 GiB (1.61 GB)`
+pub(crate) struct SizeNumberFormatter {
+    pub units: Units,
+    pub precision: Precision,
+}
+
+impl SizeNumberFormatter {
+    fn unit_multiplier(unit: &str) -> Option<u128> {
+        let iec = IEC_UNITS
+            .iter()
+            .position(|known| known.eq_ignore_ascii_case(unit))
+            .map(|power| 1024u128.pow(power as u32));
+        let si = SI_UNITS
+            .iter()
+            .position(|known| known.eq_ignore_ascii_case(unit))
+            .map(|power| 1000u128.pow(power as u32));
+
+        iec.or(si)
+    }
+
+    /// Writes a byte count in the chosen units, with the sign in front of every figure
+    fn format_size(&self, sign: &str, num: u128) -> String {
+        let iec = format!("{}{}", sign, scale(num, 1024, &IEC_UNITS, self.precision));
+        let si = format!("{}{}", sign, scale(num, 1000, &SI_UNITS, self.precision));
+
+        match self.units {
+            Units::Both if iec == si => iec,
+            Units::Both => format!("{} ({})", iec, si),
+            Units::Iec => iec,
+            Units::Si => si,
+        }
+    }
+}
+
+impl NumberFormatter for SizeNumberFormatter {
+    fn read(&self, size: &str) -> anyhow::Result<i128, NumberFormatterError> {
+        i128::try_from(self.read_unsigned(size)?).map_err(|_| NumberFormatterError::TooLargeError)
+    }
+
+    fn format(&self, num: i128) -> anyhow::Result<String, NumberFormatterError> {
+        let sign = if num < 0 { "-" } else { "" };
+        Ok(self.format_size(sign, num.unsigned_abs()))
+    }
+
+    fn read_unsigned(&self, size: &str) -> anyhow::Result<u128, NumberFormatterError> {
+        // Only the first of the binary and decimal sizes is read back
+        let size = size.split(" (").next().unwrap_or_default();
+        read_with_unit(size, SizeNumberFormatter::unit_multiplier)
+    }
+
+    fn format_unsigned(&self, num: u128) -> anyhow::Result<String, NumberFormatterError> {
+        Ok(self.format_size("", num))
+    }
+}
+
+/// Formatter writing frequencies in hertz as human-readable frequencies, such as `480 MHz`
+pub(crate) struct FrequencyNumberFormatter {
+    pub precision: Precision,
+}
+
+impl FrequencyNumberFormatter {
+    fn unit_multiplier(unit: &str) -> Option<u128> {
+        FREQUENCY_UNITS
+            .iter()
+            .position(|known| known.eq_ignore_ascii_case(unit))
+            .map(|power| 1000u128.pow(power as u32))
+    }
+}
+
+impl NumberFormatter for FrequencyNumberFormatter {
+    fn read(&self, frequency: &str) -> anyhow::Result<i128, NumberFormatterError> {
+        i128::try_from(self.read_unsigned(frequency)?)
+            .map_err(|_| NumberFormatterError::TooLargeError)
+    }
+
+    fn format(&self, num: i128) -> anyhow::Result<String, NumberFormatterError> {
+        let frequency = self.format_unsigned(num.unsigned_abs())?;
+        Ok(if num < 0 {
+            format!("-{}", frequency)
+        } else {
+            frequency
+        })
+    }
+
+    fn read_unsigned(&self, frequency: &str) -> anyhow::Result<u128, NumberFormatterError> {
+        read_with_unit(frequency, FrequencyNumberFormatter::unit_multiplier)
+    }
+
+    fn format_unsigned(&self, num: u128) -> anyhow::Result<String, NumberFormatterError> {
+        Ok(scale(num, 1000, &FREQUENCY_UNITS, self.precision))
+    }
+}
+
+/// Ways of writing a number as a mantissa scaled by a power of 1000
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Notation {
+    /// A power of ten exponent that is a multiple of 3, such as `480e6`
+    Engineering,
+    /// An SI prefix, such as `480M`
+    SiPrefix,
+}
+
+/// Formatter writing numbers in engineering notation or with SI prefixes, rounding the mantissa to
+/// some precision
+pub(crate) struct EngineeringNumberFormatter {
+    pub notation: Notation,
+    pub precision: Precision,
+}
+
+impl NumberFormatter for EngineeringNumberFormatter {
+    fn read(&self, number: &str) -> anyhow::Result<i128, NumberFormatterError> {
+        let (negative, decimal) = match number.strip_prefix('-') {
+            Some(decimal) => (true, decimal),
+            None => (false, number),
+        };
+
+        let magnitude = self.read_unsigned(decimal)?;
+        match Value::from_sign_magnitude(negative, magnitude)? {
+            Value::Signed(num) => Ok(num),
+            Value::Unsigned(_) => Err(NumberFormatterError::TooLargeError),
+        }
+    }
+
+    fn format(&self, num: i128) -> anyhow::Result<String, NumberFormatterError> {
+        let number = self.format_unsigned(num.unsigned_abs())?;
+        Ok(if num < 0 {
+            format!("-{}", number)
+        } else {
+            number
+        })
+    }
+
+    fn read_unsigned(&self, number: &str) -> anyhow::Result<u128, NumberFormatterError> {
+        let (decimal, multiplier) = match self.notation {
+            Notation::Engineering => match number.split_once(['e', 'E']) {
+                Some((decimal, exponent)) => {
+                    let exponent = exponent.parse::<u32>()?;
+                    let multiplier = 10u128
+                        .checked_pow(exponent)
+                        .ok_or(NumberFormatterError::TooLargeError)?;
+                    (decimal, multiplier)
+                }
+                None => (number, 1),
+            },
+            Notation::SiPrefix => {
+                let decimal = number.trim_end_matches(|c: char| c.is_ascii_alphabetic());
+                let prefix = number[decimal.len()..].trim();
+                let power = SI_PREFIXES
+                    .iter()
+                    .position(|known| *known == prefix)
+                    .ok_or(NumberFormatterError::UnknownUnit)?;
+                (decimal, 1000u128.pow(power as u32))
+            }
+        };
+
+        let (digits, scale) = parse_decimal(decimal.trim())?;
+        exact_product(digits, scale, multiplier)
+    }
+
+    fn format_unsigned(&self, num: u128) -> anyhow::Result<String, NumberFormatterError> {
+        Ok(match self.notation {
+            Notation::Engineering => {
+                // Every power of 1000 a u128 holds, up to 1e36
+                let (mantissa, power) = scale_parts(num, 1000, 13, self.precision);
+                format!("{}e{}", mantissa, power * 3)
+            }
+            Notation::SiPrefix => {
+                let (mantissa, power) = scale_parts(num, 1000, SI_PREFIXES.len(), self.precision);
+                format!("{}{}", mantissa, SI_PREFIXES[power])
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("4k", false, Some(("4", 1024)))]
+    #[test_case("16M", true, Some(("16", 1000000)); "si")]
+    #[test_case("1.5Gi", true, Some(("1.5", 1073741824)); "binary despite si")]
+    #[test_case("42", false, None; "no suffix")]
+    #[test_case("0x10", false, None; "hexadecimal")]
+    fn split_suffix_should_find_multiplier(input: &str, si: bool, expected: Option<(&str, u128)>) {
+        // Act
+        let actual = split_suffix(input, si);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test_case("1.5", 1024, Ok((false, 1536)))]
+    #[test_case("-2", 1000, Ok((true, 2000)); "negative")]
+    #[test_case("1.3", 1024, Err(NumberFormatterError::NotAnInteger); "fractional")]
+    fn read_scaled_should_expand_exactly(
+        input: &str,
+        multiplier: u128,
+        expected: anyhow::Result<(bool, u128), NumberFormatterError>,
+    ) {
+        // Act
+        let actual = read_scaled(input, multiplier);
+
+        // Assert
+        assert_eq!(format!("{:?}", actual), format!("{:?}", expected));
+    }
+
+    #[test_case("1e6", Some(("1", 6)))]
+    #[test_case("2.5E3", Some(("2.5", 3)); "uppercase")]
+    #[test_case("-4e+2", Some(("-4", 2)); "signed")]
+    #[test_case("25e-1", Some(("25", -1)); "negative exponent")]
+    #[test_case("dead", None; "hexadecimal")]
+    #[test_case("1e", None; "missing exponent")]
+    fn split_exponent_should_find_exponent(input: &str, expected: Option<(&str, i32)>) {
+        // Act
+        let actual = split_exponent(input);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test_case("1", 6, Ok((false, 1000000)))]
+    #[test_case("2.5", 3, Ok((false, 2500)); "fractional mantissa")]
+    #[test_case("-2500", -2, Ok((true, 25)); "negative exponent")]
+    #[test_case("0", 100, Ok((false, 0)); "zero")]
+    #[test_case("2.5", 0, Err(NumberFormatterError::NotAnInteger); "fractional")]
+    #[test_case("15", -1, Err(NumberFormatterError::NotAnInteger); "fractional negative exponent")]
+    #[test_case("1", 39, Err(NumberFormatterError::TooLargeError); "too large")]
+    fn read_scientific_should_expand_exactly(
+        mantissa: &str,
+        exponent: i32,
+        expected: anyhow::Result<(bool, u128), NumberFormatterError>,
+    ) {
+        // Act
+        let actual = read_scientific(mantissa, exponent);
+
+        // Assert
+        assert_eq!(format!("{:?}", actual), format!("{:?}", expected));
+    }
+
+    #[test_case(0, Units::Both, "0 B")]
+    #[test_case(512, Units::Both, "512 B"; "bytes")]
+    #[test_case(1536, Units::Both, "1.5 KiB (1.54 kB)"; "kilobytes")]
+    #[test_case(1610612736, Units::Both, "1.5 GiB (1.61 GB)"; "gigabytes")]
+    #[test_case(1048575, Units::Iec, "1 MiB"; "rounds to next unit")]
+    #[test_case(1000000, Units::Si, "1 MB"; "si")]
+    #[test_case(u128::MAX, Units::Iec, "281474976710656 YiB"; "largest unit")]
+    fn size_number_formatter_should_format_u128(input: u128, units: Units, expected: &str) {
+        // Act
+        let actual = SizeNumberFormatter {
+            units,
+            precision: Precision::Decimals(SIZE_DECIMALS),
+        }
+        .format_unsigned(input);
+
+        // Assert
+        assert_eq!(actual.unwrap(), expected);
+    }
+
+    #[test_case(-1536, Units::Both, "-1.5 KiB (-1.54 kB)")]
+    #[test_case(-512, Units::Both, "-512 B"; "bytes")]
+    #[test_case(i128::MIN, Units::Si, "-170141183.46 QB"; "smallest")]
+    fn size_number_formatter_should_format_negative_i128(
+        input: i128,
+        units: Units,
+        expected: &str,
+    ) {
+        // Act
+        let actual = SizeNumberFormatter {
+            units,
+            precision: Precision::Decimals(SIZE_DECIMALS),
+        }
+        .format(input);
+
+        // Assert
+        assert_eq!(actual.unwrap(), expected);
+    }
+
+    #[test_case("1.5 GiB", 1610612736)]
+    #[test_case("2MB", 2000000; "decimal unit")]
+    #[test_case("4 kib", 4096; "lowercase")]
+    #[test_case("512", 512; "without unit")]
+    #[test_case("1.5 KiB (1.54 kB)", 1536; "with decimal size")]
+    fn size_number_formatter_should_read_size(input: &str, expected: u128) {
+        // Act
+        let actual = SizeNumberFormatter {
+            units: Units::Both,
+            precision: Precision::Decimals(SIZE_DECIMALS),
+        }
+        .read_unsigned(input);
+
+        // Assert
+        assert_eq!(actual.unwrap(), expected);
+    }
+
+    #[test_case("25MHz", true)]
+    #[test_case("480 MHz", true; "with space")]
+    #[test_case("32.768 khz", true; "lowercase")]
+    #[test_case("50 Hz", true; "hertz")]
+    #[test_case("MHz", false; "without number")]
+    #[test_case("25 MB", false; "size")]
+    fn is_frequency_should_detect_frequency(input: &str, expected: bool) {
+        // Act
+        let actual = is_frequency(input);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test_case(480000000, "480 MHz")]
+    #[test_case(32768, "32.768 kHz"; "crystal")]
+    #[test_case(999, "999 Hz"; "hertz")]
+    #[test_case(2400000000, "2.4 GHz"; "gigahertz")]
+    #[test_case(999999999, "1 GHz"; "rounds to next unit")]
+    fn frequency_number_formatter_should_format_u128(input: u128, expected: &str) {
+        // Act
+        let actual = FrequencyNumberFormatter {
+            precision: Precision::Decimals(FREQUENCY_DECIMALS),
+        }
+        .format_unsigned(input);
+
+        // Assert
+        assert_eq!(actual.unwrap(), expected);
+    }
+
+    #[test_case("25MHz", 25000000)]
+    #[test_case("32.768 kHz", 32768; "crystal")]
+    #[test_case("1.2 ghz", 1200000000; "lowercase")]
+    #[test_case("50", 50; "without unit")]
+    fn frequency_number_formatter_should_read_frequency(input: &str, expected: u128) {
+        // Act
+        let actual = FrequencyNumberFormatter {
+            precision: Precision::Decimals(FREQUENCY_DECIMALS),
+        }
+        .read_unsigned(input);
+
+        // Assert
+        assert_eq!(actual.unwrap(), expected);
+    }
+
+    #[test_case(480000000, Notation::Engineering, "480e6")]
+    #[test_case(1500, Notation::Engineering, "1.5e3"; "fractional mantissa")]
+    #[test_case(12, Notation::Engineering, "12e0"; "no exponent")]
+    #[test_case(1234567, Notation::Engineering, "1.235e6"; "rounded mantissa")]
+    #[test_case(999999999, Notation::Engineering, "1e9"; "rounds to next exponent")]
+    #[test_case(u128::MAX, Notation::Engineering, "340.282e36"; "largest")]
+    #[test_case(480000000, Notation::SiPrefix, "480M"; "prefix")]
+    #[test_case(512, Notation::SiPrefix, "512"; "no prefix")]
+    #[test_case(u128::MAX, Notation::SiPrefix, "340282366.921Q"; "largest prefix")]
+    fn engineering_number_formatter_should_format_u128(
+        input: u128,
+        notation: Notation,
+        expected: &str,
+    ) {
+        // Arrange
+        let formatter = EngineeringNumberFormatter {
+            notation,
+            precision: Precision::Decimals(ENGINEERING_DECIMALS),
+        };
+
+        // Act
+        let actual = formatter.format_unsigned(input);
+
+        // Assert
+        assert_eq!(actual.unwrap(), expected);
+    }
+
+    #[test_case("480e6", Notation::Engineering, Ok(480000000))]
+    #[test_case("1.5E3", Notation::Engineering, Ok(1500); "uppercase exponent")]
+    #[test_case("1.2345e3", Notation::Engineering, Err(NumberFormatterError::NotAnInteger); "fractional")]
+    #[test_case("480M", Notation::SiPrefix, Ok(480000000); "prefix")]
+    #[test_case("1.5 k", Notation::SiPrefix, Ok(1500); "prefix with space")]
+    #[test_case("7", Notation::SiPrefix, Ok(7); "no prefix")]
+    #[test_case("2X", Notation::SiPrefix, Err(NumberFormatterError::UnknownUnit); "unknown prefix")]
+    fn engineering_number_formatter_should_read(
+        input: &str,
+        notation: Notation,
+        expected: anyhow::Result<u128, NumberFormatterError>,
+    ) {
+        // Arrange
+        let formatter = EngineeringNumberFormatter {
+            notation,
+            precision: Precision::Decimals(ENGINEERING_DECIMALS),
+        };
+
+        // Act
+        let actual = formatter.read_unsigned(input);
+
+        // Assert
+        assert_eq!(format!("{:?}", actual), format!("{:?}", expected));
+    }
+
+    #[test_case("", NumberFormatterError::Empty; "empty")]
+    #[test_case("1.5 B", NumberFormatterError::NotAnInteger; "fractional")]
+    #[test_case("2 XB", NumberFormatterError::UnknownUnit; "unknown unit")]
+    #[test_case("1e3 B", NumberFormatterError::UnknownUnit; "exponent")]
+    fn size_number_formatter_should_fail_to_read(input: &str, expected: NumberFormatterError) {
+        // Act
+        let actual = SizeNumberFormatter {
+            units: Units::Both,
+            precision: Precision::Decimals(SIZE_DECIMALS),
+        }
+        .read_unsigned(input);
+
+        // Assert
+        assert_eq!(
+            std::mem::discriminant(&actual.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+}
@@ -0,0 +1,151 @@
+use crate::factor::{self, FactorInfo};
+use crate::{align, Value};
+use clap::Args;
+
+/// Options of the info command
+#[derive(Args, Clone, Debug)]
+pub struct InfoOptions {
+    /// The number to describe
+    pub number: String,
+}
+
+/// Largest magnitude whose divisors are counted, as factoring larger numbers can take too long
+const DIVISOR_LIMIT: u128 = u64::MAX as u128;
+
+/// Properties of a number for an overview, taken of its magnitude where negative numbers have none
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct NumberInfo {
+    pub negative: bool,
+    pub magnitude: u128,
+    pub prime: bool,
+    pub perfect_square: bool,
+    pub power_of_two: bool,
+    /// Sum of the decimal digits of the magnitude
+    pub digit_sum: u32,
+    /// The digit sum summed again until a single digit is left
+    pub digital_root: u32,
+    /// How many positive numbers divide the magnitude, none for zero and above the divisor limit
+    pub divisors: Option<u128>,
+}
+
+fn digit_sum(number: u128) -> u32 {
+    number
+        .to_string()
+        .bytes()
+        .map(|digit| u32::from(digit - b'0'))
+        .sum()
+}
+
+impl NumberInfo {
+    pub(crate) fn new(value: Value) -> Self {
+        let (negative, magnitude) = value.sign_magnitude();
+        let square_root = magnitude.isqrt();
+        let digital_root = match magnitude {
+            0 => 0,
+            magnitude => ((magnitude - 1) % 9) as u32 + 1,
+        };
+
+        Self {
+            negative,
+            magnitude,
+            prime: !negative && factor::is_prime(magnitude),
+            perfect_square: !negative && square_root * square_root == magnitude,
+            power_of_two: align::is_power_of_two(value),
+            digit_sum: digit_sum(magnitude),
+            digital_root,
+            divisors: (magnitude != 0 && magnitude <= DIVISOR_LIMIT)
+                .then(|| FactorInfo::new(magnitude).divisor_count()),
+        }
+    }
+
+    /// Labelled lines describing the properties, in the order they should be displayed
+    pub fn lines(&self) -> Vec<(&'static str, String)> {
+        let yes_no = |property: bool| String::from(if property { "yes" } else { "no" });
+        let sign = match (self.negative, self.magnitude) {
+            (_, 0) => "zero",
+            (true, _) => "negative",
+            (false, _) => "positive",
+        };
+        let divisors = match (self.divisors, self.magnitude) {
+            (Some(divisors), _) => divisors.to_string(),
+            (None, 0) => String::from("infinitely many"),
+            (None, _) => String::from("not counted above 2^64"),
+        };
+
+        vec![
+            ("Sign", String::from(sign)),
+            (
+                "Parity",
+                String::from(if self.magnitude.is_multiple_of(2) {
+                    "even"
+                } else {
+                    "odd"
+                }),
+            ),
+            ("Prime", yes_no(self.prime)),
+            ("Perfect square", yes_no(self.perfect_square)),
+            ("Power of two", yes_no(self.power_of_two)),
+            ("Digit sum", self.digit_sum.to_string()),
+            ("Digital root", self.digital_root.to_string()),
+            ("Divisors", divisors),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test]
+    fn number_info_should_describe_number() {
+        // Arrange
+        let info = NumberInfo::new(Value::Signed(144));
+
+        // Act
+        let actual = info.lines();
+
+        // Assert
+        assert_eq!(
+            actual,
+            vec![
+                ("Sign", String::from("positive")),
+                ("Parity", String::from("even")),
+                ("Prime", String::from("no")),
+                ("Perfect square", String::from("yes")),
+                ("Power of two", String::from("no")),
+                ("Digit sum", String::from("9")),
+                ("Digital root", String::from("9")),
+                ("Divisors", String::from("15")),
+            ]
+        );
+    }
+
+    #[test_case(Value::Signed(0), "zero", "even", "infinitely many")]
+    #[test_case(Value::Signed(-7), "negative", "odd", "2"; "negative")]
+    #[test_case(Value::Unsigned(u128::MAX), "positive", "odd", "not counted above 2^64"; "largest")]
+    fn number_info_should_describe_sign(value: Value, sign: &str, parity: &str, divisors: &str) {
+        // Arrange
+        let info = NumberInfo::new(value);
+
+        // Act
+        let actual = info.lines();
+
+        // Assert
+        assert_eq!(actual[0].1, sign);
+        assert_eq!(actual[1].1, parity);
+        assert_eq!(actual[7].1, divisors);
+    }
+
+    #[test_case(987_654_321, 45, 9)]
+    #[test_case(1999, 28, 1; "sums again")]
+    #[test_case(0, 0, 0; "zero")]
+    fn number_info_should_sum_digits(number: i128, sum: u32, root: u32) {
+        // Act
+        let actual = NumberInfo::new(Value::Signed(number));
+
+        // Assert
+        assert_eq!(actual.digit_sum, sum);
+        assert_eq!(actual.digital_root, root);
+    }
+}
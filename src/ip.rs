@@ -0,0 +1,152 @@
+use crate::{NumberFormatter, NumberFormatterError};
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+/// Formatter writing 32-bit numbers as dotted-quad IPv4 addresses, such as `192.168.0.1`
+pub(crate) struct Ipv4NumberFormatter;
+
+impl NumberFormatter for Ipv4NumberFormatter {
+    fn read(&self, address: &str) -> anyhow::Result<i128, NumberFormatterError> {
+        if address.is_empty() {
+            return Err(NumberFormatterError::Empty);
+        }
+
+        let address: Ipv4Addr = address
+            .parse()
+            .map_err(|_| NumberFormatterError::InvalidIpAddress)?;
+
+        Ok(u32::from(address) as i128)
+    }
+
+    fn format(&self, num: i128) -> anyhow::Result<String, NumberFormatterError> {
+        if num < 0 {
+            return Err(NumberFormatterError::TooSmallError);
+        }
+
+        let address = u32::try_from(num).map_err(|_| NumberFormatterError::TooLargeError)?;
+        Ok(Ipv4Addr::from(address).to_string())
+    }
+}
+
+/// Formatter writing 128-bit patterns as IPv6 addresses in their canonical compressed form, such
+/// as `2001:db8::1`
+pub(crate) struct Ipv6NumberFormatter;
+
+impl NumberFormatter for Ipv6NumberFormatter {
+    fn read(&self, address: &str) -> anyhow::Result<i128, NumberFormatterError> {
+        i128::try_from(self.read_unsigned(address)?)
+            .map_err(|_| NumberFormatterError::TooLargeError)
+    }
+
+    fn format(&self, num: i128) -> anyhow::Result<String, NumberFormatterError> {
+        if num < 0 {
+            return Err(NumberFormatterError::TooSmallError);
+        }
+
+        self.format_unsigned(num as u128)
+    }
+
+    fn read_unsigned(&self, address: &str) -> anyhow::Result<u128, NumberFormatterError> {
+        if address.is_empty() {
+            return Err(NumberFormatterError::Empty);
+        }
+
+        // Addresses are often written in brackets, as in URLs
+        let address = address
+            .strip_prefix('[')
+            .and_then(|address| address.strip_suffix(']'))
+            .unwrap_or(address);
+        let address: Ipv6Addr = address
+            .parse()
+            .map_err(|_| NumberFormatterError::InvalidIpAddress)?;
+
+        Ok(u128::from(address))
+    }
+
+    fn format_unsigned(&self, num: u128) -> anyhow::Result<String, NumberFormatterError> {
+        Ok(Ipv6Addr::from(num).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("192.168.0.1", 0xc0a80001)]
+    #[test_case("0.0.0.0", 0)]
+    #[test_case("255.255.255.255", 0xffffffff)]
+    fn ipv4_number_formatter_should_read_address(input: &str, expected: i128) {
+        // Act
+        let actual = Ipv4NumberFormatter.read(input);
+
+        // Assert
+        assert_eq!(actual.unwrap(), expected);
+    }
+
+    #[test_case("", NumberFormatterError::Empty; "empty")]
+    #[test_case("192.168.0", NumberFormatterError::InvalidIpAddress; "three octets")]
+    #[test_case("192.168.0.256", NumberFormatterError::InvalidIpAddress; "octet too large")]
+    fn ipv4_number_formatter_should_fail_to_read(input: &str, expected: NumberFormatterError) {
+        // Act
+        let actual = Ipv4NumberFormatter.read(input);
+
+        // Assert
+        assert_eq!(
+            std::mem::discriminant(&actual.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+
+    #[test_case(0xc0a80001, Ok(String::from("192.168.0.1")))]
+    #[test_case(0x7f000001, Ok(String::from("127.0.0.1")))]
+    #[test_case(0x100000000, Err(NumberFormatterError::TooLargeError); "too large")]
+    #[test_case(-1, Err(NumberFormatterError::TooSmallError); "negative")]
+    fn ipv4_number_formatter_should_format_i128(
+        input: i128,
+        expected: anyhow::Result<String, NumberFormatterError>,
+    ) {
+        // Act
+        let actual = Ipv4NumberFormatter.format(input);
+
+        // Assert
+        assert_eq!(format!("{:?}", actual), format!("{:?}", expected));
+    }
+
+    #[test_case("2001:db8::1", 0x2001_0db8_0000_0000_0000_0000_0000_0001)]
+    #[test_case("[::1]", 1; "bracketed")]
+    #[test_case("::ffff:192.168.0.1", 0xffff_c0a8_0001; "ipv4 mapped")]
+    #[test_case("ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff", u128::MAX; "max")]
+    fn ipv6_number_formatter_should_read_address(input: &str, expected: u128) {
+        // Act
+        let actual = Ipv6NumberFormatter.read_unsigned(input);
+
+        // Assert
+        assert_eq!(actual.unwrap(), expected);
+    }
+
+    #[test_case("", NumberFormatterError::Empty; "empty")]
+    #[test_case("2001:db8::1::2", NumberFormatterError::InvalidIpAddress; "two gaps")]
+    #[test_case("2001:db8:0:0:0:0:0:0:1", NumberFormatterError::InvalidIpAddress; "nine groups")]
+    fn ipv6_number_formatter_should_fail_to_read(input: &str, expected: NumberFormatterError) {
+        // Act
+        let actual = Ipv6NumberFormatter.read_unsigned(input);
+
+        // Assert
+        assert_eq!(
+            std::mem::discriminant(&actual.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+
+    #[test_case(0x2001_0db8_0000_0000_0000_0000_0000_0001, "2001:db8::1")]
+    #[test_case(0x2001_0db8_0000_0001_0000_0000_0000_0001, "2001:db8:0:1::1"; "longest run")]
+    #[test_case(0, "::"; "unspecified")]
+    #[test_case(u128::MAX, "ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff"; "max")]
+    fn ipv6_number_formatter_should_format_u128(input: u128, expected: &str) {
+        // Act
+        let actual = Ipv6NumberFormatter.format_unsigned(input);
+
+        // Assert
+        assert_eq!(actual.unwrap(), expected);
+    }
+}
@@ -0,0 +1,267 @@
+use crate::bytes::{read_hex_bytes, write_hex_bytes, Endian};
+use crate::{NumberFormatter, NumberFormatterError};
+
+/// Little endian base 128 varint formatter, reading and writing the encoded bytes
+/// in hex. Unsigned (ULEB128) rejects negative numbers, signed (SLEB128) does not
+pub(crate) struct Leb128NumberFormatter {
+    pub signed: bool,
+    /// Order the encoded bytes are written in, little endian being the order they are encoded in
+    pub endian: Endian,
+}
+
+impl Leb128NumberFormatter {
+    /// Whether a 7 bit group only has `bits` significant bits left, allowing the
+    /// bits above them to be a sign extension when signed
+    fn fits(&self, group: u128, bits: u32) -> bool {
+        if bits >= 7 {
+            return true;
+        }
+
+        let extra = group >> bits;
+        let negative = self.signed && (group >> (bits - 1)) & 1 == 1;
+        let extension = if negative { (1 << (7 - bits)) - 1 } else { 0 };
+
+        extra == extension
+    }
+
+    /// Decodes the bit pattern of a varint, sign extended to 128 bits when signed
+    fn decode(&self, leb128: &str) -> anyhow::Result<u128, NumberFormatterError> {
+        let bytes = read_hex_bytes(leb128, self.endian)?;
+
+        let mut value: u128 = 0;
+        let mut shift: u32 = 0;
+
+        for (index, byte) in bytes.iter().enumerate() {
+            let last = byte & 0x80 == 0;
+            if last != (index == bytes.len() - 1) {
+                return Err(NumberFormatterError::InvalidVarint);
+            }
+
+            let group = (byte & 0x7f) as u128;
+            if shift >= u128::BITS || !self.fits(group, u128::BITS - shift) {
+                return Err(NumberFormatterError::TooLargeError);
+            }
+
+            value |= group << shift;
+            shift += 7;
+        }
+
+        if self.signed && shift < u128::BITS && bytes[bytes.len() - 1] & 0x40 != 0 {
+            value |= u128::MAX << shift;
+        }
+
+        Ok(value)
+    }
+}
+
+impl NumberFormatter for Leb128NumberFormatter {
+    fn read(&self, leb128: &str) -> anyhow::Result<i128, NumberFormatterError> {
+        let value = self.decode(leb128)?;
+
+        if self.signed {
+            return Ok(value as i128);
+        }
+
+        i128::try_from(value).map_err(|_| NumberFormatterError::TooLargeError)
+    }
+
+    fn format(&self, num: i128) -> anyhow::Result<String, NumberFormatterError> {
+        if !self.signed {
+            return match u128::try_from(num) {
+                Ok(num) => self.format_unsigned(num),
+                Err(_) => Err(NumberFormatterError::TooSmallError),
+            };
+        }
+
+        let mut bytes = Vec::<u8>::new();
+        let mut remainder = num;
+
+        loop {
+            let group = (remainder & 0x7f) as u8;
+            remainder >>= 7;
+
+            if (remainder == 0 && group & 0x40 == 0) || (remainder == -1 && group & 0x40 != 0) {
+                bytes.push(group);
+                break;
+            }
+            bytes.push(group | 0x80);
+        }
+
+        Ok(write_hex_bytes(bytes, self.endian))
+    }
+
+    fn read_unsigned(&self, leb128: &str) -> anyhow::Result<u128, NumberFormatterError> {
+        if self.signed {
+            return Err(NumberFormatterError::TooLargeError);
+        }
+
+        self.decode(leb128)
+    }
+
+    fn format_unsigned(&self, num: u128) -> anyhow::Result<String, NumberFormatterError> {
+        if self.signed {
+            return i128::try_from(num)
+                .map_err(|_| NumberFormatterError::TooLargeError)
+                .and_then(|num| self.format(num));
+        }
+
+        let mut bytes = Vec::<u8>::new();
+        let mut remainder = num;
+
+        loop {
+            let group = (remainder & 0x7f) as u8;
+            remainder >>= 7;
+
+            if remainder == 0 {
+                bytes.push(group);
+                break;
+            }
+            bytes.push(group | 0x80);
+        }
+
+        Ok(write_hex_bytes(bytes, self.endian))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(false, "e5 8e 26", 624485)]
+    #[test_case(false, "00", 0)]
+    #[test_case(false, "7f", 127)]
+    #[test_case(true, "c0 bb 78", -123456)]
+    #[test_case(true, "7f", -1)]
+    #[test_case(true, "3f", 63)]
+    fn leb128_number_formatter_should_read_number(signed: bool, input: &str, expected: i128) {
+        // Arrange
+        let formatter = Leb128NumberFormatter {
+            signed,
+            endian: Endian::Little,
+        };
+
+        // Act
+        let actual_number = formatter.read(input);
+
+        // Assert
+        assert!(actual_number.is_ok());
+        assert_eq!(expected, actual_number.unwrap());
+    }
+
+    #[test_case(false, 624485, "e5 8e 26")]
+    #[test_case(false, 128, "80 01")]
+    #[test_case(true, -123456, "c0 bb 78")]
+    #[test_case(true, 64, "c0 00")]
+    #[test_case(true, -1, "7f")]
+    fn leb128_number_formatter_should_format_i128(signed: bool, input: i128, expected: &str) {
+        // Arrange
+        let formatter = Leb128NumberFormatter {
+            signed,
+            endian: Endian::Little,
+        };
+
+        // Act
+        let actual_output = formatter.format(input);
+
+        // Assert
+        assert!(actual_output.is_ok());
+        assert_eq!(expected, actual_output.unwrap());
+    }
+
+    #[test_case(false, "26 8e e5", 624485)]
+    #[test_case(true, "78 bb c0", -123456)]
+    fn big_endian_leb128_number_formatter_should_reverse_bytes(
+        signed: bool,
+        bytes: &str,
+        number: i128,
+    ) {
+        // Arrange
+        let formatter = Leb128NumberFormatter {
+            signed,
+            endian: Endian::Big,
+        };
+
+        // Act
+        let actual_output = formatter.format(number);
+        let actual_number = formatter.read(bytes);
+
+        // Assert
+        assert_eq!(actual_output.unwrap(), bytes);
+        assert_eq!(actual_number.unwrap(), number);
+    }
+
+    #[test_case(i128::MIN)]
+    #[test_case(i128::MAX)]
+    fn leb128_number_formatter_should_round_trip_signed(input: i128) {
+        // Arrange
+        let formatter = Leb128NumberFormatter {
+            signed: true,
+            endian: Endian::Little,
+        };
+
+        // Act
+        let actual_number = formatter.read(&formatter.format(input).unwrap());
+
+        // Assert
+        assert_eq!(input, actual_number.unwrap());
+    }
+
+    #[test]
+    fn unsigned_leb128_number_formatter_should_round_trip_unsigned() {
+        // Arrange
+        let formatter = Leb128NumberFormatter {
+            signed: false,
+            endian: Endian::Little,
+        };
+
+        // Act
+        let leb128 = formatter.format_unsigned(u128::MAX).unwrap();
+        let actual_number = formatter.read_unsigned(&leb128);
+
+        // Assert
+        assert_eq!(
+            leb128,
+            "ff ff ff ff ff ff ff ff ff ff ff ff ff ff ff ff ff ff 03"
+        );
+        assert_eq!(actual_number.unwrap(), u128::MAX);
+    }
+
+    #[test_case("e5 8e", NumberFormatterError::InvalidVarint ; "unterminated")]
+    #[test_case("05 8e 26", NumberFormatterError::InvalidVarint ; "terminated early")]
+    #[test_case("ff ff ff ff ff ff ff ff ff ff ff ff ff ff ff ff ff ff ff 7f", NumberFormatterError::TooLargeError ; "too large")]
+    fn leb128_number_formatter_should_fail_to_read(input: &str, expected: NumberFormatterError) {
+        // Arrange
+        let formatter = Leb128NumberFormatter {
+            signed: false,
+            endian: Endian::Little,
+        };
+
+        // Act
+        let actual_number = formatter.read(input);
+
+        // Assert
+        assert_eq!(
+            std::mem::discriminant(&actual_number.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+
+    #[test]
+    fn unsigned_leb128_number_formatter_should_reject_negative() {
+        // Arrange
+        let formatter = Leb128NumberFormatter {
+            signed: false,
+            endian: Endian::Little,
+        };
+
+        // Act
+        let actual_output = formatter.format(-1);
+
+        // Assert
+        assert!(matches!(
+            actual_output,
+            Err(NumberFormatterError::TooSmallError)
+        ));
+    }
+}
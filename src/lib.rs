@@ -1,24 +1,407 @@
-use clap::{ArgEnum, Parser};
+use clap::{ArgEnum, Parser, Subcommand};
 use core::fmt;
 use std::num::ParseIntError;
+use std::path::PathBuf;
 use thiserror::Error;
 
-#[derive(ArgEnum, Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+mod addr;
+mod align;
+mod alphabet;
+mod banner;
+mod bcd;
+mod bignum;
+mod bit_range;
+mod bits;
+mod bitwise;
+mod byte_array;
+mod bytes;
+mod calc;
+mod checksum;
+mod cidr;
+mod clmul;
+mod codepoint;
+mod color;
+mod column;
+mod crc;
+mod detect;
+mod digits;
+mod divisor;
+mod dozenal;
+mod encoding;
+mod excel;
+mod excess;
+mod expression;
+mod factor;
+mod fibonacci;
+mod fixed_point;
+mod float;
+mod gray;
+mod hamming;
+mod hex_float;
+mod hexdump;
+mod humanize;
+mod info;
+mod ip;
+mod leb128;
+mod locale;
+mod luhn;
+mod mac;
+mod mask;
+mod modular;
+mod negabase;
+mod ones_complement;
+mod page;
+mod perm;
+mod posix;
+mod precision;
+mod random;
+mod range;
+mod ratio;
+mod rational;
+mod register;
+mod roman;
+mod root;
+mod seven_segment;
+mod sexagesimal;
+mod sext;
+mod shift;
+mod size;
+mod swap;
+mod tcp;
+mod text;
+mod time;
+#[cfg(feature = "unicode-info")]
+mod unicode_info;
+mod uuid;
+mod visualize;
+mod words;
+mod xterm;
+mod zigzag;
+
+use alphabet::AlphabetNumberFormatter;
+use bcd::BcdNumberFormatter;
+use byte_array::ByteArrayNumberFormatter;
+use codepoint::{CharacterNumberFormatter, CodepointNumberFormatter};
+use color::{ColorNumberFormatter, PackedPixelNumberFormatter, PixelFormat};
+use column::ColumnNumberFormatter;
+use dozenal::DozenalNumberFormatter;
+use encoding::{Encoding, EncodingNumberFormatter};
+use excess::ExcessNumberFormatter;
+use fibonacci::{FibonacciCodeNumberFormatter, ZeckendorfNumberFormatter};
+use fixed_point::FixedPointNumberFormatter;
+use float::{ExactFloatNumberFormatter, FloatNumberFormatter};
+use gray::GrayNumberFormatter;
+use hex_float::HexFloatNumberFormatter;
+use hexdump::HexdumpNumberFormatter;
+use humanize::{
+    EngineeringNumberFormatter, FrequencyNumberFormatter, Notation, SizeNumberFormatter,
+};
+use ip::{Ipv4NumberFormatter, Ipv6NumberFormatter};
+use leb128::Leb128NumberFormatter;
+use mac::MacNumberFormatter;
+use negabase::NegativeBaseNumberFormatter;
+use ones_complement::OnesComplementNumberFormatter;
+use perm::PermissionsNumberFormatter;
+use posix::{ErrnoNumberFormatter, SignalNumberFormatter};
+use precision::FloatPrecision;
+use roman::RomanNumberFormatter;
+use sexagesimal::SexagesimalNumberFormatter;
+use tcp::TcpFlagsNumberFormatter;
+use text::TextNumberFormatter;
+use time::TimeNumberFormatter;
+use uuid::UuidNumberFormatter;
+use words::WordsNumberFormatter;
+use zigzag::ZigZagNumberFormatter;
+
+pub use addr::{AddrOptions, AddressArithmetic};
+pub use banner::banner;
+pub use bit_range::BitRange;
+pub use bits::{BitInfo, BitsOptions};
+pub use bitwise::{bitwise_table, BitwiseOptions};
+pub use byte_array::ByteStyle;
+pub use bytes::Endian;
+pub use calc::{CalcOperator, CalcOptions, Calculation};
+pub use checksum::ChecksumOptions;
+pub use cidr::{CidrInfo, CidrOptions};
+pub use clmul::{ClmulInfo, ClmulOptions};
+pub use crc::{CrcAlgorithm, CrcOptions};
+pub use divisor::{DivisorInfo, DivisorOptions};
+pub use excel::{ExcelInfo, ExcelOptions};
+pub use factor::{FactorInfo, FactorOptions};
+pub use fixed_point::{FixedPointInfo, FixedPointOptions, QFormat, Rounding};
+pub use float::{FloatBits, FloatBitsOptions, FloatClass, FloatFormat, UlpInfo, UlpOptions};
+pub use hamming::{HammingInfo, HammingOptions};
+pub use hexdump::hexdump;
+pub use humanize::Units;
+pub use info::{InfoOptions, NumberInfo};
+pub use locale::{Grouping, Locale};
+pub use luhn::{LuhnInfo, LuhnOptions};
+pub use mask::MaskOptions;
+pub use modular::{ModOptions, ModularInfo};
+pub use page::PageOptions;
+pub use precision::{FloatDigits, Precision};
+pub use random::RandomOptions;
+pub use range::RangeOptions;
+pub use ratio::{Approximation, ApproximationKind, RatioInfo, RatioOptions};
+pub use rational::{Decimal, FractionOptions, Rational};
+pub use register::{bitfield_table, DecodedField, Field, Register};
+pub use root::{RootInfo, RootOptions};
+pub use seven_segment::seven_segment;
+pub use sext::SextOptions;
+pub use shift::{ShiftOperation, ShiftOptions};
+pub use size::{IntegerSize, Truncate};
+pub use swap::SwapOptions;
+pub use time::{Epoch, TimeUnit};
+pub use visualize::visualize;
+pub use words::Lang;
+pub use xterm::{XtermInfo, XtermOptions};
+
+#[derive(ArgEnum, Copy, Clone, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub enum NumberType {
+    #[default]
     Integer,
     Hexadecimal,
     Binary,
+    Octal,
+    Radix,
+    Alphabet,
+    Base36,
+    Column,
+    Sexagesimal,
+    Dozenal,
+    Negabinary,
+    Negadecimal,
+    Gray,
+    Bcd,
+    OnesComplement,
+    #[clap(name = "zigzag")]
+    ZigZag,
+    Uleb128,
+    Sleb128,
+    Zeckendorf,
+    FibonacciCode,
+    Excess,
+    Roman,
+    Words,
+    Codepoint,
+    Character,
+    ByteArray,
+    Base64,
+    Base64Url,
+    Base32,
+    Base32Crockford,
+    Base58,
+    Uuid,
+    Text,
+    Hexdump,
+    Ipv4,
+    Ipv6,
+    Mac,
+    #[clap(name = "perm")]
+    Permissions,
+    Signal,
+    Errno,
+    TcpFlags,
+    Color,
+    Rgb565,
+    Rgb555,
+    Time,
+    Size,
+    Frequency,
+    Engineering,
+    SiPrefix,
+    Float,
+    HexFloat,
+    ExactFloat,
+    FixedPoint,
 }
 
 impl NumberType {
     pub fn iter() -> std::slice::Iter<'static, NumberType> {
-        static NUMBERTYPES: [NumberType; 3] = [
+        static NUMBERTYPES: [NumberType; 53] = [
             NumberType::Integer,
             NumberType::Hexadecimal,
             NumberType::Binary,
+            NumberType::Octal,
+            NumberType::Radix,
+            NumberType::Alphabet,
+            NumberType::Base36,
+            NumberType::Column,
+            NumberType::Sexagesimal,
+            NumberType::Dozenal,
+            NumberType::Negabinary,
+            NumberType::Negadecimal,
+            NumberType::Gray,
+            NumberType::Bcd,
+            NumberType::OnesComplement,
+            NumberType::ZigZag,
+            NumberType::Uleb128,
+            NumberType::Sleb128,
+            NumberType::Zeckendorf,
+            NumberType::FibonacciCode,
+            NumberType::Excess,
+            NumberType::Roman,
+            NumberType::Words,
+            NumberType::Codepoint,
+            NumberType::Character,
+            NumberType::ByteArray,
+            NumberType::Base64,
+            NumberType::Base64Url,
+            NumberType::Base32,
+            NumberType::Base32Crockford,
+            NumberType::Base58,
+            NumberType::Uuid,
+            NumberType::Text,
+            NumberType::Hexdump,
+            NumberType::Ipv4,
+            NumberType::Ipv6,
+            NumberType::Mac,
+            NumberType::Permissions,
+            NumberType::Signal,
+            NumberType::Errno,
+            NumberType::TcpFlags,
+            NumberType::Color,
+            NumberType::Rgb565,
+            NumberType::Rgb555,
+            NumberType::Time,
+            NumberType::Size,
+            NumberType::Frequency,
+            NumberType::Engineering,
+            NumberType::SiPrefix,
+            NumberType::Float,
+            NumberType::HexFloat,
+            NumberType::ExactFloat,
+            NumberType::FixedPoint,
         ];
         NUMBERTYPES.iter()
     }
+
+    /// The number types that are always part of the output
+    pub fn defaults() -> std::slice::Iter<'static, NumberType> {
+        static DEFAULTS: [NumberType; 3] = [
+            NumberType::Integer,
+            NumberType::Hexadecimal,
+            NumberType::Binary,
+        ];
+        DEFAULTS.iter()
+    }
+
+    /// Detects the number type from a `0x`, `0b`, `0o` or `#` prefix, quotes, brackets, UUID
+    /// hyphenation, a hexdump offset, an IP or MAC address, symbolic permissions, a signal or
+    /// errno name, TCP flags or an ISO 8601 date, falling back to integer
+    pub fn detect(input: &str) -> NumberType {
+        if input.len() > 2 && input.starts_with('\'') && input.ends_with('\'') {
+            return NumberType::Character;
+        }
+
+        if input.len() >= 2 && input.starts_with('"') && input.ends_with('"') {
+            return NumberType::Text;
+        }
+
+        if hexdump::is_hexdump(input) {
+            return NumberType::Hexdump;
+        }
+
+        if input.parse::<std::net::Ipv4Addr>().is_ok() {
+            return NumberType::Ipv4;
+        }
+
+        if perm::is_permissions(input) {
+            return NumberType::Permissions;
+        }
+
+        if mac::is_mac(input) {
+            return NumberType::Mac;
+        }
+
+        if input.parse::<std::net::Ipv6Addr>().is_ok() {
+            return NumberType::Ipv6;
+        }
+
+        if uuid::is_uuid(input) {
+            return NumberType::Uuid;
+        }
+
+        if posix::is_signal(input) {
+            return NumberType::Signal;
+        }
+
+        if posix::is_errno(input) {
+            return NumberType::Errno;
+        }
+
+        if tcp::is_tcp_flags(input) {
+            return NumberType::TcpFlags;
+        }
+
+        if color::is_color(input) {
+            return NumberType::Color;
+        }
+
+        if time::is_datetime(input) {
+            return NumberType::Time;
+        }
+
+        if humanize::is_frequency(input) {
+            return NumberType::Frequency;
+        }
+
+        if float::is_float(input) {
+            return NumberType::Float;
+        }
+
+        if hex_float::is_hex_float(input) {
+            return NumberType::HexFloat;
+        }
+
+        if input.starts_with(['[', '{']) || input.starts_with("b\"") || input.starts_with("b'") {
+            return NumberType::ByteArray;
+        }
+
+        let digits = input.strip_prefix('-').unwrap_or(input);
+        let prefix = digits.get(..2).map(str::to_ascii_lowercase);
+
+        match prefix.as_deref() {
+            Some("0x") => NumberType::Hexadecimal,
+            Some("0b") => NumberType::Binary,
+            Some("0o") => NumberType::Octal,
+            Some("u+") => NumberType::Codepoint,
+            _ => NumberType::Integer,
+        }
+    }
+
+    /// Whether numbers of this type are integers written in some notation, as the literals of an
+    /// expression must be, rather than bytes, addresses, names, dates, quantities or floats
+    fn is_integer(&self) -> bool {
+        matches!(
+            self,
+            NumberType::Integer
+                | NumberType::Hexadecimal
+                | NumberType::Binary
+                | NumberType::Octal
+                | NumberType::Radix
+                | NumberType::Alphabet
+                | NumberType::Base36
+                | NumberType::Column
+                | NumberType::Sexagesimal
+                | NumberType::Dozenal
+                | NumberType::Negabinary
+                | NumberType::Negadecimal
+                | NumberType::Gray
+                | NumberType::Bcd
+                | NumberType::OnesComplement
+                | NumberType::ZigZag
+                | NumberType::Uleb128
+                | NumberType::Sleb128
+                | NumberType::Zeckendorf
+                | NumberType::FibonacciCode
+                | NumberType::Excess
+                | NumberType::Roman
+                | NumberType::Words
+                | NumberType::Codepoint
+                | NumberType::Character
+        )
+    }
 }
 
 impl fmt::Display for NumberType {
@@ -27,251 +410,4477 @@ impl fmt::Display for NumberType {
             NumberType::Integer => write!(f, "Integer"),
             NumberType::Hexadecimal => write!(f, "Hexadecimal"),
             NumberType::Binary => write!(f, "Binary"),
+            NumberType::Octal => write!(f, "Octal"),
+            NumberType::Radix => write!(f, "Radix"),
+            NumberType::Alphabet => write!(f, "Alphabet"),
+            NumberType::Base36 => write!(f, "Base36"),
+            NumberType::Column => write!(f, "Column"),
+            NumberType::Sexagesimal => write!(f, "Sexagesimal"),
+            NumberType::Dozenal => write!(f, "Dozenal"),
+            NumberType::Negabinary => write!(f, "Negabinary"),
+            NumberType::Negadecimal => write!(f, "Negadecimal"),
+            NumberType::Gray => write!(f, "Gray"),
+            NumberType::Bcd => write!(f, "BCD"),
+            NumberType::OnesComplement => write!(f, "One's complement"),
+            NumberType::ZigZag => write!(f, "ZigZag"),
+            NumberType::Uleb128 => write!(f, "ULEB128"),
+            NumberType::Sleb128 => write!(f, "SLEB128"),
+            NumberType::Zeckendorf => write!(f, "Zeckendorf"),
+            NumberType::FibonacciCode => write!(f, "Fibonacci code"),
+            NumberType::Excess => write!(f, "Excess"),
+            NumberType::Roman => write!(f, "Roman"),
+            NumberType::Words => write!(f, "Words"),
+            NumberType::Codepoint => write!(f, "Codepoint"),
+            NumberType::Character => write!(f, "Character"),
+            NumberType::ByteArray => write!(f, "Byte array"),
+            NumberType::Base64 => write!(f, "Base64"),
+            NumberType::Base64Url => write!(f, "Base64 URL"),
+            NumberType::Base32 => write!(f, "Base32"),
+            NumberType::Base32Crockford => write!(f, "Crockford Base32"),
+            NumberType::Base58 => write!(f, "Base58"),
+            NumberType::Uuid => write!(f, "UUID"),
+            NumberType::Text => write!(f, "Text"),
+            NumberType::Hexdump => write!(f, "Hexdump"),
+            NumberType::Ipv4 => write!(f, "IPv4"),
+            NumberType::Ipv6 => write!(f, "IPv6"),
+            NumberType::Mac => write!(f, "MAC"),
+            NumberType::Permissions => write!(f, "Permissions"),
+            NumberType::Signal => write!(f, "Signal"),
+            NumberType::Errno => write!(f, "Errno"),
+            NumberType::TcpFlags => write!(f, "TCP flags"),
+            NumberType::Color => write!(f, "Color"),
+            NumberType::Rgb565 => write!(f, "RGB565"),
+            NumberType::Rgb555 => write!(f, "RGB555"),
+            NumberType::Time => write!(f, "Time"),
+            NumberType::Size => write!(f, "Size"),
+            NumberType::Frequency => write!(f, "Frequency"),
+            NumberType::Engineering => write!(f, "Engineering"),
+            NumberType::SiPrefix => write!(f, "SI prefix"),
+            NumberType::Float => write!(f, "Float"),
+            NumberType::HexFloat => write!(f, "Hex float"),
+            NumberType::ExactFloat => write!(f, "Exact float"),
+            NumberType::FixedPoint => write!(f, "Fixed point"),
         }
     }
 }
 
-#[derive(Debug, Parser)]
-#[clap(version, about)]
+#[derive(Clone, Debug, Default, Parser)]
+#[clap(version, about, subcommand_negates_reqs = true)]
 pub struct CommandOptions {
-    /// Type of number that is being passed to the <NUMBER> argument
-    #[clap(short, long, arg_enum, default_value_t=NumberType::Integer)]
-    pub number_type: NumberType,
-    /// The number to format
+    /// Type of number that is being passed to the <NUMBER> argument, detected when not given from
+    /// its prefix (0x, 0b or 0o) or its form, such as a decimal point for floats or the shape of an
+    /// address, date or color, otherwise integer
+    #[clap(global = true, short, long, arg_enum)]
+    pub number_type: Option<NumberType>,
+    /// Base (2-36) used by the radix number type, also adds it to the output
+    #[clap(
+        global = true,
+        short,
+        long,
+        value_parser = clap::value_parser!(u32).range(2..=36),
+        required_if_eq("number-type", "radix")
+    )]
+    pub radix: Option<u32>,
+    /// Digits used by the alphabet number type, lowest first (or a preset: base58, base62, base64url),
+    /// also adds it to the output
+    #[clap(global = true, short, long, required_if_eq("number-type", "alphabet"))]
+    pub alphabet: Option<String>,
+    /// Guess the type of an unprefixed <NUMBER> and output every plausible interpretation
+    #[clap(global = true, short, long, conflicts_with = "number-type")]
+    pub detect: bool,
+    /// Read <NUMBER> as a character, converting it to its codepoint
+    #[clap(global = true, short, long = "char", conflicts_with_all = &["number-type", "detect"])]
+    pub character: bool,
+    /// Number types to output instead of the defaults
+    #[clap(global = true, short, long, arg_enum, value_delimiter = ',')]
+    pub output: Vec<NumberType>,
+    /// Use uppercase letters for digits above 9 in hexadecimal, radix and base36 output
+    #[clap(global = true, short, long)]
+    pub uppercase: bool,
+    /// Digits used for ten and eleven by the dozenal number type
+    #[clap(global = true, long, value_parser = dozenal::parse_dozenal_digits, default_value = dozenal::DOZENAL_DIGITS)]
+    pub dozenal_digits: String,
+    /// Bias K used by the excess number type (e.g. 127 for f32 exponents), also adds it to the
+    /// output
+    #[clap(global = true, short, long, required_if_eq("number-type", "excess"))]
+    pub bias: Option<i128>,
+    /// Allow Roman numerals up to 3999999 by overlining the thousands
+    #[clap(global = true, long)]
+    pub roman_extended: bool,
+    /// Interpret numbers as signed two's complement integers of this many bits
+    #[clap(global = true, short, long, value_parser = size::parse_width)]
+    pub width: Option<u32>,
+    /// Interpret numbers as an integer type such as u8, i32 or u64, zero-padding bit pattern output
+    #[clap(global = true, short, long, value_parser = size::parse_size, conflicts_with = "width")]
+    pub size: Option<IntegerSize>,
+    /// Show negative hexadecimal, binary and octal output as the two's complement bit pattern of
+    /// this many bits, without changing how <NUMBER> is read
+    #[clap(
+        global = true,
+        long,
+        value_parser = size::parse_width,
+        conflicts_with_all = &["width", "size"]
+    )]
+    pub twos_complement: Option<u32>,
+    /// Extract the field held in an inclusive range of bits, e.g. 12..15, and format it instead of <NUMBER>
+    #[clap(global = true, long, value_parser = bit_range::parse_bit_range)]
+    pub bits: Option<BitRange>,
+    /// Decode the number field by field against a TOML register definition with a [[field]] table per
+    /// field giving its name, bits and optionally the names of its values
+    #[clap(global = true, long, value_parser, value_name = "FILE")]
+    pub bitfields: Option<PathBuf>,
+    /// Decode the number field by field against a register of a CMSIS-SVD device description
+    #[clap(
+        global = true,
+        long,
+        value_parser,
+        value_name = "FILE",
+        requires = "register",
+        conflicts_with = "bitfields"
+    )]
+    pub svd: Option<PathBuf>,
+    /// The register --svd decodes against, named PERIPHERAL.REGISTER, e.g. GPIOA.MODER
+    #[clap(global = true, long, requires = "svd")]
+    pub register: Option<String>,
+    /// Round the number down to a multiple of N, e.g. 0x1000
+    #[clap(global = true, long, value_parser = align::parse_alignment, value_name = "N")]
+    pub align_down: Option<u128>,
+    /// Round the number up to a multiple of N, e.g. 0x1000
+    #[clap(
+        global = true,
+        long,
+        value_parser = align::parse_alignment,
+        value_name = "N",
+        conflicts_with = "align-down"
+    )]
+    pub align_up: Option<u128>,
+    /// Round the number up to the next power of two
+    #[clap(global = true, long, conflicts_with_all = &["align-down", "align-up"])]
+    pub next_power_of_two: bool,
+    /// Also print the number split into words of this many bits, e.g. to program it into 16 or 32 bit
+    /// registers, ordered by --endian with the most significant word first by default
+    #[clap(global = true, long, value_parser = size::parse_width, value_name = "BITS")]
+    pub split: Option<u32>,
+    /// Also output the bytes of the number as a byte array literal in the syntax of this language
+    #[clap(global = true, long, arg_enum)]
+    pub style: Option<ByteStyle>,
+    /// Also print the bytes of the number as xxd would, at the integer size or else the narrowest width
+    /// holding the number
+    #[clap(global = true, long)]
+    pub hexdump: bool,
+    /// Byte order of outputs made of bytes, such as LEB128, which otherwise use their usual order
+    #[clap(global = true, long, arg_enum)]
+    pub endian: Option<Endian>,
+    /// Unit the time number type counts Unix timestamps in, guessed from their magnitude when not
+    /// given, also adds it to the output
+    #[clap(global = true, long, arg_enum)]
+    pub time_unit: Option<TimeUnit>,
+    /// Epoch the time number type counts timestamps from, Unix time when not given, also adds it to
+    /// the output
+    #[clap(global = true, long, arg_enum)]
+    pub epoch: Option<Epoch>,
+    /// Read bare k, M, G and larger suffixes on integer input, such as 4k, as powers of 1000 rather
+    /// than 1024. Suffixes ending in i, such as 1.5Gi, are always powers of 1024.
+    #[clap(global = true, long)]
+    pub si: bool,
+    /// Units the size number type writes byte counts in, both binary and decimal ones when not
+    /// given, also adds it to the output
+    #[clap(global = true, long, arg_enum)]
+    pub units: Option<Units>,
+    /// Floating-point format the float number type reads and writes bit patterns in, f64 when not
+    /// given, also adds it to the output
+    #[clap(global = true, long, arg_enum, value_name = "FORMAT")]
+    pub float: Option<FloatFormat>,
+    /// Fixed-point format in Q notation the fixed point number type reads and writes raw values
+    /// in, such as Q15, Q8.8 or UQ16.16, Q15 when not given, also adds it to the output
+    #[clap(global = true, long, value_parser = fixed_point::parse_q_format, value_name = "FORMAT")]
+    pub q_format: Option<QFormat>,
+    /// Decimal places fractional output such as sizes, frequencies, engineering notation and floats
+    /// is rounded to, which otherwise depend on the number type
+    #[clap(global = true, long, value_name = "DECIMALS")]
+    pub precision: Option<u32>,
+    /// Significant figures fractional output such as sizes, frequencies, engineering notation and
+    /// floats is rounded to, instead of decimal places
+    #[clap(
+        global = true,
+        long,
+        value_parser = clap::value_parser!(u32).range(1..),
+        value_name = "FIGURES",
+        conflicts_with = "precision"
+    )]
+    pub sig_figs: Option<u32>,
+    /// Digits the float number type writes floats with, unrounded and noting which were chosen,
+    /// the shortest that read back as the same float when not given
+    #[clap(
+        global = true,
+        long,
+        arg_enum,
+        conflicts_with_all = &["precision", "sig-figs"]
+    )]
+    pub float_digits: Option<FloatDigits>,
+    /// What to do with numbers that do not fit in the chosen --width or --size
+    #[clap(global = true, long, arg_enum, default_value_t = Truncate::Error)]
+    pub truncate: Truncate,
+    /// Language used by the words number type
+    #[clap(global = true, short, long, arg_enum, default_value_t = Lang::En)]
+    pub lang: Lang,
+    /// Group the digits of integer input and output per a locale, e.g. en-US, de-DE or en-IN
+    #[clap(global = true, long, value_parser = locale::parse_locale)]
+    pub locale: Option<Locale>,
+    /// Print each output in large ASCII-art letters
+    #[clap(global = true, long)]
+    pub banner: bool,
+    /// Describe codepoints by their Unicode 14.0 general category, and by their name and block
+    /// where known. Coverage is partial: names are only bundled for commonly looked up ranges and
+    /// blocks only for the more common blocks, so other codepoints show their category alone
+    #[cfg(feature = "unicode-info")]
+    #[clap(global = true, long)]
+    pub unicode_info: bool,
+    /// Print each output as digits on a seven-segment display
+    #[clap(global = true, long, conflicts_with = "banner")]
+    pub seven_segment: bool,
+    /// Print the binary output a byte at a time under a ruler of bit indices, adding it to the
+    /// outputs if --output leaves it out
+    #[clap(global = true, long, conflicts_with_all = &["banner", "seven-segment"])]
+    pub visualize: bool,
+    #[clap(subcommand)]
+    pub command: Option<Command>,
+    /// The number to format, or an expression of numbers in any base joined by + - * / % << >> & ^
+    /// | ~ and parentheses, such as "0xff + 0b1010 * 3". + - / and | need spaces around them, and
+    /// ~ and shifts work within the integer size if one is chosen.
+    #[clap(required = true, default_value = "", hide_default_value = true)]
     pub number: String,
 }
 
+/// Operations run on numbers before they are formatted
+#[derive(Clone, Debug, Subcommand)]
+pub enum Command {
+    /// Sign-extend <NUMBER> from one width to another, e.g. 0xff as i8 to 0xffffffff as i32
+    Sext(SextOptions),
+    /// Count the bits of <NUMBER>: popcount, leading and trailing zeros, highest set bit and minimal width
+    Bits(BitsOptions),
+    /// Build a bitmask from bits and bit ranges, e.g. 4..7,31, and its inverse
+    Mask(MaskOptions),
+    /// Split an address into its page number and the offset within the page
+    Page(PageOptions),
+    /// Add and subtract two addresses, and check whether the second falls within a range of the first
+    Addr(AddrOptions),
+    /// Reverse the order of the bytes of <NUMBER> within its width, e.g. 0x12345678 to 0x78563412
+    Bswap(SwapOptions),
+    /// Reverse the order of the nibbles of <NUMBER> within its width, e.g. 0x12345678 to 0x87654321
+    Nswap(SwapOptions),
+    /// Shift or rotate <NUMBER> by <COUNT> bits within its width, showing both the logical and
+    /// arithmetic right shifts and the bits shifted out
+    Shift(ShiftOptions),
+    /// Combine two numbers bit by bit, showing AND, OR, XOR, NOT of each and AND-NOT in one table
+    Bitwise(BitwiseOptions),
+    /// Compute CRC-32, CRC-16/CCITT and CRC-8 checksums of the bytes of <NUMBER>
+    Crc(CrcOptions),
+    /// Validate the Luhn check digit of <NUMBER>, as used by card numbers and IMEIs
+    Luhn(LuhnOptions),
+    /// Compute the RFC 1071 internet checksum of the 16-bit words of <NUMBER>, as used by IP, TCP
+    /// and UDP headers
+    Checksum(ChecksumOptions),
+    /// Convert between a CIDR prefix length and its netmask, counting the usable hosts. Works on
+    /// IPv4 addresses unless an integer size is chosen, such as -w 128 for IPv6.
+    Cidr(CidrOptions),
+    /// Convert an xterm 256-color palette index to its RGB color, or find the index nearest to a
+    /// color
+    Xterm(XtermOptions),
+    /// Convert between an Excel serial date, counting days since 1900 with the time of day as a
+    /// fraction, and an ISO 8601 date
+    Excel(ExcelOptions),
+    /// Split a float, given as a decimal float or its bit pattern, into its sign, exponent and
+    /// mantissa fields, in the --float format
+    FloatBits(FloatBitsOptions),
+    /// Count the units in the last place between two floats, each given as a decimal float or its
+    /// bit pattern, in the --float format
+    Ulp(UlpOptions),
+    /// Find the best fractions approximating a decimal or float with denominators up to a bound,
+    /// from its continued fraction, e.g. 355/113 for 3.14159
+    Ratio(RatioOptions),
+    /// Convert a raw fixed-point value to the real number it stands for in the --q-format format,
+    /// or a real number to the nearest raw value, noting any rounding or saturation
+    FixedPoint(FixedPointOptions),
+    /// Reduce a fraction such as 6/14 to lowest terms and write it as a mixed number and a decimal
+    /// with its repetend in brackets, e.g. 0.[428571], or find the fraction of such a decimal
+    Fraction(FractionOptions),
+    /// Apply an arithmetic operation to two numbers, e.g. calc mul 0x10 3, failing if the result
+    /// does not fit the integer size unless --truncate is chosen
+    Calc(CalcOptions),
+    /// Reduce <NUMBER> modulo <MODULUS> and find its modular inverse, and with --power raise it to a
+    /// power modulo <MODULUS>
+    Mod(ModOptions),
+    /// Find the greatest common divisor of two or more numbers, with --verbose showing the steps of
+    /// the Euclidean algorithm
+    Gcd(DivisorOptions),
+    /// Find the least common multiple of two or more numbers, with --verbose showing the steps of
+    /// the Euclidean algorithm
+    Lcm(DivisorOptions),
+    /// Take the integer square root of <NUMBER>, and with --degree its root of another degree,
+    /// along with its base 2 logarithm rounded down and up, noting which are exact
+    Root(RootOptions),
+    /// Find the prime factors of <NUMBER> with their exponents, and how many divisors it has
+    Factor(FactorOptions),
+    /// Summarize the properties of <NUMBER>: its sign, parity, primality, whether it is a perfect
+    /// square or a power of two, its digit sum and digital root, and how many divisors it has
+    Info(InfoOptions),
+    /// Count the bits that differ between two numbers within their width, listing where they are
+    Hamming(HammingOptions),
+    /// Multiply two numbers as polynomials over GF(2) without carries, as CRCs and GCM do, writing
+    /// out each polynomial
+    Clmul(ClmulOptions),
+    /// Generate random numbers across the integer size, u64 unless chosen, or from --min to --max
+    Random(RandomOptions),
+    /// Generate every number from START to END, such as 0x1000..0x2000 or 1..=10, a line each so
+    /// that they can be piped into other tools
+    Range(RangeOptions),
+}
+
 impl CommandOptions {
     pub fn new(number_type: NumberType, input: &str) -> Self {
         Self {
-            number_type,
+            number_type: Some(number_type),
             number: input.to_string(),
+            ..Default::default()
         }
     }
-}
 
-pub struct CommandContext {
-    command_options: CommandOptions,
-}
+    /// The type of the <NUMBER> argument, either as given or detected from its prefix, and integer
+    /// for expressions whose literals are detected one at a time
+    pub fn input_type(&self) -> NumberType {
+        if self.character {
+            return NumberType::Character;
+        }
+        if self.number_type.is_none() && expression::is_expression(&self.number) {
+            return NumberType::Integer;
+        }
+        if self.number_type.is_none() && self.is_locale_grouped() {
+            return NumberType::Integer;
+        }
+        if self.number_type.is_none()
+            && self.output.contains(&NumberType::Permissions)
+            && perm::is_octal_mode(&self.number)
+        {
+            return NumberType::Permissions;
+        }
 
-impl CommandContext {
-    pub fn new(command_options: CommandOptions) -> Self {
-        Self { command_options }
+        self.number_type
+            .unwrap_or_else(|| NumberType::detect(&self.number))
     }
 
-    pub fn format_all_number_types(
-        &self,
-    ) -> anyhow::Result<Vec<(NumberType, String)>, NumberFormatterError> {
-        let mut vec = Vec::<(NumberType, String)>::new();
+    /// Whether the <NUMBER> argument is an integer grouped per the chosen locale, such as 1.234 in
+    /// de-DE, which would otherwise be detected as a float
+    fn is_locale_grouped(&self) -> bool {
+        self.locale.as_ref().is_some_and(|locale| {
+            locale
+                .ungroup(&self.number)
+                .is_ok_and(|number| number.trim_start_matches('-').parse::<u128>().is_ok())
+        })
+    }
 
-        let formatter =
-            NumberFormatterFactory::new_number_formatter(&self.command_options.number_type);
+    /// Whether the <NUMBER> argument is an expression such as `0xff + 0b1010 * 3`, whose literals
+    /// are read as the chosen number type or else detected one at a time, and must be integers
+    /// rather than floats, dates or the like. Zeckendorf input is itself written as a sum, so is
+    /// never taken for an expression.
+    pub fn is_expression(&self) -> bool {
+        !self.character
+            && !matches!(
+                self.number_type,
+                Some(
+                    NumberType::Alphabet
+                        | NumberType::Character
+                        | NumberType::Text
+                        | NumberType::Zeckendorf
+                )
+            )
+            && expression::is_expression(&self.number)
+    }
 
-        let num = formatter.read(&self.command_options.number)?;
+    /// The size integers are interpreted and formatted at, if one was chosen
+    pub fn integer_size(&self) -> Option<IntegerSize> {
+        self.size
+            .or_else(|| self.width.map(|bits| IntegerSize { bits, signed: true }))
+    }
 
-        for number_type in NumberType::iter() {
-            let formatter = NumberFormatterFactory::new_number_formatter(number_type);
-            let output = formatter.format(num)?;
-            vec.push((*number_type, output));
+    /// How far fractional output is rounded, as chosen by --precision or --sig-figs or else to the
+    /// decimal places a number type uses by default
+    pub fn precision(&self, default_decimals: u32) -> Precision {
+        match (self.precision, self.sig_figs) {
+            (_, Some(figures)) => Precision::SignificantFigures(figures),
+            (Some(decimals), None) => Precision::Decimals(decimals),
+            (None, None) => Precision::Decimals(default_decimals),
         }
+    }
 
-        Ok(vec)
+    /// How floats are written, as chosen by --float-digits, rounded by --precision or --sig-figs,
+    /// or else with the shortest digits that read back as the same float
+    pub(crate) fn float_precision(&self) -> FloatPrecision {
+        match (self.float_digits, self.precision, self.sig_figs) {
+            (Some(digits), _, _) => FloatPrecision::Digits(digits),
+            (None, None, None) => FloatPrecision::Shortest,
+            _ => FloatPrecision::Rounded(self.precision(0)),
+        }
     }
-}
 
-#[derive(Error, Debug)]
-pub enum NumberFormatterError {
-    #[error("Unknown error occurred")]
-    Unknown,
-    #[error("No value was entered")]
-    Empty,
-    #[error("Number too large")]
-    TooLargeError,
-    #[error("Number too small")]
-    TooSmallError,
-    #[error("Number contains an invalid digit")]
-    InvalidDigit,
-}
+    /// The size bit pattern output is shown at, either the integer size or the --twos-complement width
+    pub fn pattern_size(&self) -> Option<IntegerSize> {
+        self.integer_size().or_else(|| {
+            self.twos_complement
+                .map(|bits| IntegerSize { bits, signed: true })
+        })
+    }
 
-impl From<ParseIntError> for NumberFormatterError {
-    fn from(e: ParseIntError) -> Self {
-        match &e.kind() {
-            std::num::IntErrorKind::Empty => NumberFormatterError::Empty,
-            std::num::IntErrorKind::InvalidDigit => NumberFormatterError::InvalidDigit,
-            std::num::IntErrorKind::PosOverflow => NumberFormatterError::TooLargeError,
-            std::num::IntErrorKind::NegOverflow => NumberFormatterError::TooSmallError,
-            _ => NumberFormatterError::Unknown,
+    /// The number types to output, in the order they should be displayed
+    pub fn output_types(&self) -> Vec<NumberType> {
+        if !self.output.is_empty() {
+            let mut output_types = self.output.clone();
+            // --visualize lays out the binary output, so it is shown even when not asked for
+            if self.visualize && !output_types.contains(&NumberType::Binary) {
+                output_types.push(NumberType::Binary);
+            }
+            return output_types;
         }
+
+        let mut output_types: Vec<NumberType> = NumberType::defaults().copied().collect();
+
+        if self.radix.is_some() {
+            output_types.push(NumberType::Radix);
+        }
+
+        if self.alphabet.is_some() {
+            output_types.push(NumberType::Alphabet);
+        }
+
+        if self.bias.is_some() {
+            output_types.push(NumberType::Excess);
+        }
+
+        if self.style.is_some() {
+            output_types.push(NumberType::ByteArray);
+        }
+
+        if self.time_unit.is_some() || self.epoch.is_some() {
+            output_types.push(NumberType::Time);
+        }
+
+        if self.units.is_some() {
+            output_types.push(NumberType::Size);
+        }
+
+        if self.float.is_some() {
+            output_types.push(NumberType::Float);
+        }
+
+        if self.q_format.is_some() {
+            output_types.push(NumberType::FixedPoint);
+        }
+
+        if self.visualize && !output_types.contains(&NumberType::Binary) {
+            output_types.push(NumberType::Binary);
+        }
+
+        let input_type = self.input_type();
+        if input_type == NumberType::Character && !output_types.contains(&NumberType::Codepoint) {
+            output_types.push(NumberType::Codepoint);
+        }
+
+        if !output_types.contains(&input_type) {
+            output_types.push(input_type);
+        }
+
+        output_types
     }
 }
 
-trait NumberFormatter {
-    fn read(&self, num: &str) -> anyhow::Result<i128, NumberFormatterError>;
-    fn format(&self, num: i128) -> anyhow::Result<String, NumberFormatterError>;
+/// A number read from the input, unsigned when it is above i128::MAX. As unsigned values are always
+/// above every signed value, the derived ordering is the numeric one.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Value {
+    Signed(i128),
+    Unsigned(u128),
 }
 
-struct IntegerNumberFormatter;
-impl NumberFormatter for IntegerNumberFormatter {
-    fn read(&self, integer: &str) -> anyhow::Result<i128, NumberFormatterError> {
-        integer.parse::<i128>().map_err(|op| op.into())
-    }
+/// Groups of outputs under a label, for commands which format several numbers
+pub type LabelledOutputs = Vec<(&'static str, Vec<(NumberType, String)>)>;
 
-    fn format(&self, num: i128) -> anyhow::Result<String, NumberFormatterError> {
-        Ok(num.to_string())
+impl Value {
+    /// The value of an unsigned number, kept signed where it fits in an i128
+    fn from_unsigned(num: u128) -> Value {
+        match i128::try_from(num) {
+            Ok(num) => Value::Signed(num),
+            Err(_) => Value::Unsigned(num),
+        }
     }
-}
 
-struct HexadecimalNumberFormatter;
-impl NumberFormatter for HexadecimalNumberFormatter {
-    fn read(&self, hexadecimal: &str) -> anyhow::Result<i128, NumberFormatterError> {
-        let without_prefix = hexadecimal.trim_start_matches("0x");
-        i128::from_str_radix(without_prefix, 16).map_err(|op| op.into())
+    /// Whether the value is negative, and its magnitude
+    fn sign_magnitude(self) -> (bool, u128) {
+        match self {
+            Value::Signed(num) => (num < 0, num.unsigned_abs()),
+            Value::Unsigned(num) => (false, num),
+        }
     }
 
-    fn format(&self, num: i128) -> anyhow::Result<String, NumberFormatterError> {
-        Ok(format!("{:x}", &num))
+    fn from_sign_magnitude(
+        negative: bool,
+        magnitude: u128,
+    ) -> anyhow::Result<Value, NumberFormatterError> {
+        if !negative {
+            Ok(Value::from_unsigned(magnitude))
+        } else if magnitude <= i128::MIN.unsigned_abs() {
+            Ok(Value::Signed((magnitude as i128).wrapping_neg()))
+        } else {
+            Err(NumberFormatterError::TooSmallError)
+        }
     }
-}
 
-struct BinaryNumberFormatter;
-impl NumberFormatter for BinaryNumberFormatter {
-    fn read(&self, binary_num: &str) -> anyhow::Result<i128, NumberFormatterError> {
-        let without_prefix = binary_num.trim_start_matches("0b");
-        i128::from_str_radix(without_prefix, 2).map_err(|op| op.into())
+    fn checked_add(self, other: Value) -> anyhow::Result<Value, NumberFormatterError> {
+        Value::add_sign_magnitude(self.sign_magnitude(), other.sign_magnitude())
     }
 
-    fn format(&self, num: i128) -> anyhow::Result<String, NumberFormatterError> {
-        Ok(format!("{:b}", num))
+    fn checked_sub(self, other: Value) -> anyhow::Result<Value, NumberFormatterError> {
+        let (negative, magnitude) = other.sign_magnitude();
+        Value::add_sign_magnitude(
+            self.sign_magnitude(),
+            (!negative && magnitude != 0, magnitude),
+        )
     }
-}
 
-struct NumberFormatterFactory;
-impl NumberFormatterFactory {
-    pub fn new_number_formatter(number_type: &NumberType) -> Box<dyn NumberFormatter> {
-        match number_type {
-            NumberType::Integer => Box::new(IntegerNumberFormatter {}),
-            NumberType::Hexadecimal => Box::new(HexadecimalNumberFormatter {}),
-            NumberType::Binary => Box::new(BinaryNumberFormatter {}),
+    fn add_sign_magnitude(
+        (negative, magnitude): (bool, u128),
+        (other_negative, other_magnitude): (bool, u128),
+    ) -> anyhow::Result<Value, NumberFormatterError> {
+        if negative == other_negative {
+            return match magnitude.checked_add(other_magnitude) {
+                Some(sum) => Value::from_sign_magnitude(negative, sum),
+                None if negative => Err(NumberFormatterError::TooSmallError),
+                None => Err(NumberFormatterError::TooLargeError),
+            };
+        }
+
+        if magnitude >= other_magnitude {
+            Value::from_sign_magnitude(negative, magnitude - other_magnitude)
+        } else {
+            Value::from_sign_magnitude(other_negative, other_magnitude - magnitude)
         }
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use test_case::test_case;
+/// One possible reading of the <NUMBER> argument found by `--detect`
+#[derive(Debug, PartialEq, Eq)]
+pub struct Interpretation {
+    pub number_type: NumberType,
+    pub note: String,
+    pub outputs: Vec<(NumberType, String)>,
+}
 
-    #[test_case(NumberType::Integer, "Integer")]
-    #[test_case(NumberType::Binary, "Binary")]
-    #[test_case(NumberType::Hexadecimal, "Hexadecimal")]
-    fn number_type_fmt_should_be_expected_value(number_type: NumberType, expected_value: &str) {
-        // Act
-        let actual_value = format!("{}", number_type);
+pub struct CommandContext {
+    command_options: CommandOptions,
+}
 
-        // Assert
-        assert_eq!(actual_value, expected_value);
+impl CommandContext {
+    pub fn new(command_options: CommandOptions) -> Self {
+        Self { command_options }
     }
 
-    #[test]
-    fn new_command_options_should_return_expected_value() {
-        // Arrange
-        let expected = CommandOptions {
-            number_type: NumberType::Binary,
-            number: String::from("input"),
+    pub fn format_all_number_types(
+        &self,
+    ) -> anyhow::Result<Vec<(NumberType, String)>, NumberFormatterError> {
+        let value = self.read_number()?;
+        self.format_value(value)
+    }
+
+    /// Reads the <NUMBER> argument, evaluating it first if it is an expression, interpreting it at
+    /// the chosen integer size, extracting the chosen range of bits and rounding it as asked
+    fn read_number(&self) -> anyhow::Result<Value, NumberFormatterError> {
+        let command_options = self.normalized_command_options()?;
+
+        let value = if command_options.is_expression() {
+            expression::evaluate(
+                &command_options.number,
+                command_options.integer_size(),
+                |literal| {
+                    let literal_options = CommandOptions {
+                        number: literal.to_string(),
+                        ..command_options.clone()
+                    };
+                    if !literal_options.input_type().is_integer() {
+                        return Err(NumberFormatterError::InvalidExpression);
+                    }
+                    CommandContext::read_value(&literal_options)
+                },
+            )?
+        } else {
+            CommandContext::read_value(&command_options)?
         };
 
-        // Act
-        let actual = CommandOptions::new(expected.number_type, &expected.number);
+        let value = match command_options.integer_size() {
+            Some(size) => size.fit(value, command_options.truncate)?,
+            None => value,
+        };
 
-        // Assert
-        assert_eq!(actual.number_type, expected.number_type);
-        assert_eq!(actual.number, expected.number);
+        let value = match command_options.bits {
+            Some(range) => range.extract(value),
+            None => value,
+        };
+
+        match command_options {
+            CommandOptions {
+                align_down: Some(alignment),
+                ..
+            } => align::align_down(value, alignment),
+            CommandOptions {
+                align_up: Some(alignment),
+                ..
+            } => align::align_up(value, alignment),
+            CommandOptions {
+                next_power_of_two: true,
+                ..
+            } => align::next_power_of_two(value),
+            _ => Ok(value),
+        }
     }
 
-    #[test_case(NumberType::Integer)]
-    #[test_case(NumberType::Hexadecimal)]
-    #[test_case(NumberType::Binary)]
-    fn new_number_formatter_should_match_number_type(number_type: NumberType) {
-        let _ = *NumberFormatterFactory::new_number_formatter(&number_type);
+    /// Reads a number as its number type, unsigned if it is too large to be signed
+    fn read_value(command_options: &CommandOptions) -> anyhow::Result<Value, NumberFormatterError> {
+        let formatter = NumberFormatterFactory::new_number_formatter(
+            &command_options.input_type(),
+            command_options,
+        );
+
+        match formatter.read(&command_options.number) {
+            Err(NumberFormatterError::TooLargeError) => Ok(Value::Unsigned(
+                formatter.read_unsigned(&command_options.number)?,
+            )),
+            num => Ok(Value::Signed(num?)),
+        }
     }
 
-    #[test_case(NumberType::Integer, "10", 10)]
-    #[test_case(NumberType::Hexadecimal, "FFFF", 65535)]
-    #[test_case(NumberType::Binary, "0000110", 6)]
-    fn new_number_formatter_should_read_number_type(
-        number_type: NumberType,
-        input_number: &str,
-        expected_number: i128,
-    ) {
-        // Arrange
-        let reader = NumberFormatterFactory::new_number_formatter(&number_type);
+    /// Describes how the <NUMBER> argument was truncated to fit the chosen integer size, if it was
+    pub fn truncation_warning(&self) -> Option<String> {
+        let size = self.command_options.integer_size()?;
+        let action = match self.command_options.truncate {
+            Truncate::Error => return None,
+            Truncate::Wrap => "wrapped",
+            Truncate::Saturate => "saturated",
+        };
 
-        // Act
-        let actual_number = reader.read(input_number);
+        let exact = CommandContext::new(CommandOptions {
+            truncate: Truncate::Error,
+            ..self.command_options.clone()
+        });
 
-        // Assert
-        assert!(actual_number.is_ok());
-        assert_eq!(expected_number, actual_number.unwrap());
+        match exact.read_number() {
+            Err(NumberFormatterError::TooLargeError | NumberFormatterError::TooSmallError) => {
+                Some(format!(
+                    "{} does not fit in {}, {}",
+                    self.command_options.number, size, action
+                ))
+            }
+            _ => None,
+        }
     }
 
-    #[test_case(NumberType::Integer, 907823, "907823")]
-    #[test_case(NumberType::Hexadecimal, 65451, "ffab")]
-    #[test_case(NumberType::Binary, 9543, "10010101000111")]
-    fn new_number_formatter_should_format_i128(
-        number_type: NumberType,
-        input_number: i128,
-        expected_output: &str,
-    ) {
-        // Arrange
-        let reader = NumberFormatterFactory::new_number_formatter(&number_type);
+    /// Formats a value once for every output number type
+    fn format_value(
+        &self,
+        value: Value,
+    ) -> anyhow::Result<Vec<(NumberType, String)>, NumberFormatterError> {
+        self.command_options
+            .output_types()
+            .into_iter()
+            .map(|number_type| {
+                let formatter = NumberFormatterFactory::new_number_formatter(
+                    &number_type,
+                    &self.command_options,
+                );
+                let output = match value {
+                    Value::Signed(num) => formatter.format(num)?,
+                    Value::Unsigned(num) => formatter.format_unsigned(num)?,
+                };
+                Ok((number_type, output))
+            })
+            .collect()
+    }
 
-        // Act
-        let actual_number = reader.format(input_number);
+    /// Sign-extends the number given to the sext command and formats it at the destination width
+    pub fn sign_extend(
+        &self,
+        sext: &SextOptions,
+    ) -> anyhow::Result<Vec<(NumberType, String)>, NumberFormatterError> {
+        if sext.to < sext.from {
+            return Err(NumberFormatterError::NarrowingExtension);
+        }
 
-        // Assert
-        assert!(actual_number.is_ok());
-        assert_eq!(expected_output, actual_number.unwrap());
+        let source = CommandContext::new(CommandOptions {
+            number: sext.number.clone(),
+            width: Some(sext.from),
+            size: None,
+            ..self.command_options.clone()
+        });
+        let destination = CommandContext::new(CommandOptions {
+            width: Some(sext.to),
+            size: None,
+            ..self.command_options.clone()
+        });
+
+        destination.format_value(source.read_number()?)
     }
 
-    #[test]
-    fn command_context_should_format_all_types_correctly() {
-        // Arrange
-        let command_options = CommandOptions {
-            number_type: NumberType::Binary,
-            number: String::from("0b1101011"),
+    /// Applies the operation of the calc command to its operands, read at the integer size if one is
+    /// chosen, and fits the result to the size as --truncate chooses
+    pub fn calc(&self, options: &CalcOptions) -> anyhow::Result<Calculation, NumberFormatterError> {
+        let result = calc::calculate(
+            options.operator,
+            self.read_argument(&options.first)?,
+            self.read_argument(&options.second)?,
+        )?;
+
+        let (fitted, warning) = match self.command_options.integer_size() {
+            Some(size) => {
+                let fitted = size.fit_result(result, self.command_options.truncate)?;
+                let action = match self.command_options.truncate {
+                    Truncate::Wrap => "wrapped",
+                    _ => "saturated",
+                };
+                let warning = (fitted != result)
+                    .then(|| format!("result does not fit in {}, {}", size, action));
+                (fitted, warning)
+            }
+            None => (result, None),
         };
 
-        let command_context = CommandContext::new(command_options);
+        Ok(Calculation {
+            outputs: self.format_value(fitted)?,
+            warning,
+        })
+    }
 
-        // Act
-        let output = command_context.format_all_number_types();
+    /// Reduces the number given to the mod command modulo its modulus, finding its inverse and, when
+    /// an exponent is given, its power
+    pub fn modular(
+        &self,
+        options: &ModOptions,
+    ) -> anyhow::Result<ModularInfo, NumberFormatterError> {
+        let modulus = modular::modulus(self.read_argument(&options.modulus)?)?;
+        let residue = modular::residue(self.read_argument(&options.number)?, modulus);
+        let inverse = modular::inverse(residue, modulus);
+        let format = |residue: u128| self.format_value(Value::from_unsigned(residue));
 
-        // Assert
-        assert!(output.is_ok());
-        let vec = output.unwrap();
+        let mut outputs = vec![("a mod n", format(residue)?)];
+        if let Ok(inverse) = inverse {
+            outputs.push(("Inverse", format(inverse)?));
+        }
+        if let Some(exponent) = &options.power {
+            let (base, exponent) = match self.read_argument(exponent)?.sign_magnitude() {
+                (true, exponent) => (
+                    inverse.map_err(|_| NumberFormatterError::NoInverse)?,
+                    exponent,
+                ),
+                (false, exponent) => (residue, exponent),
+            };
+            outputs.push((
+                "a^b mod n",
+                format(modular::power(base, exponent, modulus))?,
+            ));
+        }
 
-        assert!(vec.contains(&(NumberType::Integer, String::from("107"))));
-        assert!(vec.contains(&(NumberType::Binary, String::from("1101011"))));
-        assert!(vec.contains(&(NumberType::Hexadecimal, String::from("6b"))));
+        Ok(ModularInfo {
+            outputs,
+            common_factor: inverse.err(),
+        })
     }
 
-    #[test_case(CommandOptions { number_type: NumberType::Integer, number: String::from("12") })]
-    #[test_case(CommandOptions { number_type: NumberType::Binary, number: String::from("100001") })]
-    #[test_case(CommandOptions { number_type: NumberType::Hexadecimal, number: String::from("0xAbC3f09") })]
-    fn command_context_should_format_all_types_in_expected_order(command_options: CommandOptions) {
-        // Arrange
-        let command_context = CommandContext::new(command_options);
+    /// Finds the greatest common divisor of the numbers given to the gcd command
+    pub fn gcd(
+        &self,
+        options: &DivisorOptions,
+    ) -> anyhow::Result<DivisorInfo, NumberFormatterError> {
+        self.divisor(options, false)
+    }
 
-        // Act
-        let output = command_context.format_all_number_types();
+    /// Finds the least common multiple of the numbers given to the lcm command
+    pub fn lcm(
+        &self,
+        options: &DivisorOptions,
+    ) -> anyhow::Result<DivisorInfo, NumberFormatterError> {
+        self.divisor(options, true)
+    }
 
-        // Assert
+    /// Combines the magnitudes of numbers a pair at a time into their greatest common divisor or
+    /// least common multiple, keeping the steps of each pair
+    fn divisor(
+        &self,
+        options: &DivisorOptions,
+        multiple: bool,
+    ) -> anyhow::Result<DivisorInfo, NumberFormatterError> {
+        let mut numbers = options.numbers.iter();
+        let (_, mut result) = match numbers.next() {
+            Some(number) => self.read_argument(number)?.sign_magnitude(),
+            None => (false, 0),
+        };
+        let mut steps = vec![];
+
+        for number in numbers {
+            let (_, magnitude) = self.read_argument(number)?.sign_magnitude();
+            let (combined, pair_steps) = if multiple {
+                divisor::lcm_steps(result, magnitude)?
+            } else {
+                divisor::gcd_steps(result, magnitude)
+            };
+            result = combined;
+            steps.extend(pair_steps);
+        }
+
+        Ok(DivisorInfo {
+            outputs: self.format_value(Value::from_unsigned(result))?,
+            steps,
+        })
+    }
+
+    /// Takes the integer roots and logarithms of the number given to the root command, which must
+    /// not be negative
+    pub fn root(&self, options: &RootOptions) -> anyhow::Result<RootInfo, NumberFormatterError> {
+        match self.read_argument(&options.number)?.sign_magnitude() {
+            (true, _) => Err(NumberFormatterError::TooSmallError),
+            (false, number) => Ok(RootInfo::new(number, options.degree)),
+        }
+    }
+
+    /// Factors the number given to the factor command, which must be positive
+    pub fn factor(
+        &self,
+        options: &FactorOptions,
+    ) -> anyhow::Result<FactorInfo, NumberFormatterError> {
+        match self.read_argument(&options.number)?.sign_magnitude() {
+            (true, _) | (_, 0) => Err(NumberFormatterError::TooSmallError),
+            (false, number) => Ok(FactorInfo::new(number)),
+        }
+    }
+
+    /// Describes the properties of the number given to the info command
+    pub fn info(&self, options: &InfoOptions) -> anyhow::Result<NumberInfo, NumberFormatterError> {
+        Ok(NumberInfo::new(self.read_argument(&options.number)?))
+    }
+
+    /// Counts the bits of the number given to the bits command, at the integer size if one is chosen
+    pub fn bit_info(&self, bits: &BitsOptions) -> anyhow::Result<BitInfo, NumberFormatterError> {
+        Ok(BitInfo::new(
+            self.read_argument(&bits.number)?,
+            self.command_options.integer_size(),
+        ))
+    }
+
+    /// Looks up the palette color of the index given to the xterm command, or the nearest index to
+    /// the color given to it
+    pub fn xterm(&self, options: &XtermOptions) -> anyhow::Result<XtermInfo, NumberFormatterError> {
+        match xterm::parse_rgb(&options.color) {
+            Some(rgb) => Ok(XtermInfo::nearest_to(rgb?)),
+            None => XtermInfo::from_index(self.read_argument(&options.color)?),
+        }
+    }
+
+    /// Converts the serial date given to the excel command to a date, or the date given to it to a
+    /// serial date
+    pub fn excel(&self, options: &ExcelOptions) -> anyhow::Result<ExcelInfo, NumberFormatterError> {
+        ExcelInfo::new(options)
+    }
+
+    /// Splits the float given to the float-bits command into its fields
+    pub fn float_bits(
+        &self,
+        options: &FloatBitsOptions,
+    ) -> anyhow::Result<FloatBits, NumberFormatterError> {
+        FloatBits::new(
+            self.read_argument(&options.number)?,
+            self.command_options.float.unwrap_or_default(),
+        )
+    }
+
+    /// Counts the floats between the two floats given to the ulp command
+    pub fn ulp(&self, options: &UlpOptions) -> anyhow::Result<UlpInfo, NumberFormatterError> {
+        UlpInfo::new(
+            self.read_argument(&options.first)?,
+            self.read_argument(&options.second)?,
+            self.command_options.float.unwrap_or_default(),
+        )
+    }
+
+    /// Finds the best rational approximations of the value given to the ratio command
+    pub fn ratio(&self, options: &RatioOptions) -> anyhow::Result<RatioInfo, NumberFormatterError> {
+        RatioInfo::new(
+            options,
+            options
+                .as_float
+                .then(|| self.command_options.float.unwrap_or_default()),
+            self.command_options.precision(ratio::RATIO_DECIMALS),
+        )
+    }
+
+    /// Converts the raw value given to the fixed-point command to a real number, or the real number
+    /// with a point given to it to a raw value
+    pub fn fixed_point(
+        &self,
+        options: &FixedPointOptions,
+    ) -> anyhow::Result<FixedPointInfo, NumberFormatterError> {
+        let format = self.command_options.q_format.unwrap_or_default();
+        let precision = self.command_options.precision(format.fraction_bits);
+
+        if options.value.contains('.') {
+            FixedPointInfo::from_real(&options.value, format, precision)
+        } else {
+            FixedPointInfo::from_raw(self.read_argument(&options.value)?, format, precision)
+        }
+    }
+
+    /// Reduces the fraction given to the fraction command, or finds the fraction of the decimal
+    /// given to it
+    pub fn fraction(
+        &self,
+        options: &FractionOptions,
+    ) -> anyhow::Result<Rational, NumberFormatterError> {
+        Rational::parse(&options.value)
+    }
+
+    /// Checks the Luhn check digit of the number given to the luhn command
+    pub fn luhn(&self, options: &LuhnOptions) -> anyhow::Result<LuhnInfo, NumberFormatterError> {
+        LuhnInfo::new(self.read_argument(&luhn::ungroup(&options.number))?)
+    }
+
+    /// Builds the mask of the mask command and its inverse, both formatted unsigned at the integer size
+    /// or else the narrowest width holding the mask
+    pub fn mask(
+        &self,
+        options: &MaskOptions,
+    ) -> anyhow::Result<LabelledOutputs, NumberFormatterError> {
+        let mask = mask::build_mask(&options.ranges);
+        let bits = match self.command_options.integer_size() {
+            Some(size) => size.bits,
+            None => size::width_holding(128 - mask.leading_zeros()),
+        };
+        let size = IntegerSize {
+            bits,
+            signed: false,
+        };
+
+        if mask & !size.mask() != 0 {
+            return Err(NumberFormatterError::TooLargeError);
+        }
+
+        let context = CommandContext::new(CommandOptions {
+            size: Some(size),
+            width: None,
+            ..self.command_options.clone()
+        });
+
+        Ok(vec![
+            ("Mask", context.format_value(Value::from_unsigned(mask))?),
+            (
+                "Inverse",
+                context.format_value(Value::from_unsigned(!mask & size.mask()))?,
+            ),
+        ])
+    }
+
+    /// Splits the address given to the page command into a page number and offset, formatted in
+    /// integer and hexadecimal unless other output types are chosen
+    pub fn page(
+        &self,
+        options: &PageOptions,
+    ) -> anyhow::Result<LabelledOutputs, NumberFormatterError> {
+        let address = match self.read_argument(&options.number)? {
+            Value::Signed(num) if num < 0 => return Err(NumberFormatterError::TooSmallError),
+            Value::Signed(num) => num as u128,
+            Value::Unsigned(num) => num,
+        };
+
+        let context = self.address_context();
+
+        Ok(vec![
+            (
+                "Page",
+                context.format_value(Value::from_unsigned(address / options.page_size))?,
+            ),
+            (
+                "Offset",
+                context.format_value(Value::from_unsigned(address % options.page_size))?,
+            ),
+        ])
+    }
+
+    /// Adds and subtracts the addresses given to the addr command, formatted in integer and
+    /// hexadecimal unless other output types are chosen
+    pub fn address_arithmetic(
+        &self,
+        options: &AddrOptions,
+    ) -> anyhow::Result<AddressArithmetic, NumberFormatterError> {
+        let base = self.read_argument(&options.base)?;
+        let address = self.read_argument(&options.address)?;
+        let context = self.address_context();
+
+        let mut outputs = vec![
+            ("Sum", context.format_value(base.checked_add(address)?)?),
+            (
+                "Difference",
+                context.format_value(address.checked_sub(base)?)?,
+            ),
+        ];
+
+        let within = match &options.length {
+            Some(length) => {
+                let end = base.checked_add(self.read_argument(length)?)?;
+                outputs.push(("End", context.format_value(end)?));
+                Some(base <= address && address < end)
+            }
+            None => None,
+        };
+
+        Ok(AddressArithmetic { outputs, within })
+    }
+
+    /// Finds the netmask of the prefix length given to the cidr command, or the prefix length of the
+    /// netmask, formatting the netmask and its inverse as addresses too unless other output types
+    /// are chosen
+    pub fn cidr(&self, options: &CidrOptions) -> anyhow::Result<CidrInfo, NumberFormatterError> {
+        let width = self
+            .command_options
+            .integer_size()
+            .map_or(cidr::IPV4_WIDTH, |size| size.bits);
+        let size = IntegerSize {
+            bits: width,
+            signed: false,
+        };
+
+        let (prefix, mask) = match options.mask.strip_prefix('/') {
+            Some(prefix) => {
+                let prefix = prefix.parse::<u32>()?;
+                if prefix > width {
+                    return Err(NumberFormatterError::TooLargeError);
+                }
+                (prefix, cidr::netmask(prefix, width))
+            }
+            None => {
+                let mask = size.pattern_of(self.read_argument(&options.mask)?);
+                (cidr::prefix_length(mask, width)?, mask)
+            }
+        };
+
+        let output = if self.command_options.output.is_empty() {
+            let mut output: Vec<NumberType> = NumberType::defaults().copied().collect();
+            match width {
+                32 => output.push(NumberType::Ipv4),
+                128 => output.push(NumberType::Ipv6),
+                _ => {}
+            }
+            output
+        } else {
+            self.command_options.output.clone()
+        };
+        let context = CommandContext::new(CommandOptions {
+            output,
+            ..self.sized_context(size).command_options
+        });
+
+        Ok(CidrInfo {
+            outputs: vec![
+                ("Netmask", context.format_value(size.value_of(mask))?),
+                (
+                    "Wildcard",
+                    context.format_value(size.value_of(!mask & size.mask()))?,
+                ),
+            ],
+            prefix,
+            usable_hosts: cidr::usable_hosts(prefix, width),
+        })
+    }
+
+    /// Reverses the bytes of the number given to the bswap command, formatting it before and after
+    pub fn byte_swap(
+        &self,
+        options: &SwapOptions,
+    ) -> anyhow::Result<LabelledOutputs, NumberFormatterError> {
+        self.swap(options, swap::swap_bytes)
+    }
+
+    /// Reverses the nibbles of the number given to the nswap command, formatting it before and after
+    pub fn nibble_swap(
+        &self,
+        options: &SwapOptions,
+    ) -> anyhow::Result<LabelledOutputs, NumberFormatterError> {
+        self.swap(options, swap::swap_nibbles)
+    }
+
+    /// Swaps the bit pattern of a number at the integer size, or else the narrowest width holding it
+    fn swap(
+        &self,
+        options: &SwapOptions,
+        swap: fn(u128, u32) -> u128,
+    ) -> anyhow::Result<LabelledOutputs, NumberFormatterError> {
+        let value = self.read_argument(&options.number)?;
+        let size = self
+            .command_options
+            .integer_size()
+            .unwrap_or_else(|| IntegerSize::holding(value));
+        let pattern = size.pattern_of(value);
+
+        let context = self.sized_context(size);
+
+        Ok(vec![
+            ("Original", context.format_value(size.value_of(pattern))?),
+            (
+                "Swapped",
+                context.format_value(size.value_of(swap(pattern, size.bits)))?,
+            ),
+        ])
+    }
+
+    /// Shifts or rotates the number given to the shift command at the integer size, or else the
+    /// narrowest width holding it. Right shifts are shown both logical and arithmetic, and shifts
+    /// show the bits they dropped as a number of their own.
+    pub fn shift(
+        &self,
+        options: &ShiftOptions,
+    ) -> anyhow::Result<LabelledOutputs, NumberFormatterError> {
+        let value = self.read_argument(&options.number)?;
+        let size = self
+            .command_options
+            .integer_size()
+            .unwrap_or_else(|| IntegerSize::holding(value));
+        let pattern = size.pattern_of(value);
+        let (bits, count) = (size.bits, options.count);
+
+        let context = self.sized_context(size);
+        let format = |pattern: u128| context.format_value(size.value_of(pattern));
+        let logical = || format(shift::shift_right(pattern, count));
+        let arithmetic = || format(shift::shift_right_arithmetic(pattern, count, bits));
+
+        let mut outputs = vec![("Original", format(pattern)?)];
+        match options.operation {
+            ShiftOperation::Shl => {
+                outputs.push(("Shifted", format(shift::shift_left(pattern, count, bits))?))
+            }
+            ShiftOperation::Shr => {
+                outputs.push(("Logical", logical()?));
+                outputs.push(("Arithmetic", arithmetic()?));
+            }
+            ShiftOperation::Sar => {
+                outputs.push(("Arithmetic", arithmetic()?));
+                outputs.push(("Logical", logical()?));
+            }
+            ShiftOperation::Rol => {
+                outputs.push(("Rotated", format(shift::rotate_left(pattern, count, bits))?))
+            }
+            ShiftOperation::Ror => outputs.push((
+                "Rotated",
+                format(shift::rotate_right(pattern, count, bits))?,
+            )),
+        }
+
+        if !matches!(options.operation, ShiftOperation::Rol | ShiftOperation::Ror) {
+            let out_size = IntegerSize {
+                bits: size::width_holding(count.min(bits)),
+                signed: false,
+            };
+            let shifted_out = shift::shifted_out(options.operation, pattern, count, bits);
+            outputs.push((
+                "Shifted out",
+                self.sized_context(out_size)
+                    .format_value(out_size.value_of(shifted_out))?,
+            ));
+        }
+
+        Ok(outputs)
+    }
+
+    /// Combines the numbers given to the bitwise command at the integer size, or else the narrowest
+    /// width holding both, formatting them and each of their bitwise operations
+    pub fn bitwise(
+        &self,
+        options: &BitwiseOptions,
+    ) -> anyhow::Result<LabelledOutputs, NumberFormatterError> {
+        let a = self.read_argument(&options.first)?;
+        let b = self.read_argument(&options.second)?;
+        let size = self.size_holding_both(a, b);
+        let context = self.sized_context(size);
+        let format = |pattern: u128| context.format_value(size.value_of(pattern));
+
+        let (a, b) = (size.pattern_of(a), size.pattern_of(b));
+        let mut outputs = vec![("a", format(a)?), ("b", format(b)?)];
+        for (label, pattern) in bitwise::table(a, b, size.mask()) {
+            outputs.push((label, format(pattern)?));
+        }
+
+        Ok(outputs)
+    }
+
+    /// Compares the numbers given to the hamming command bit by bit at the integer size, or else the
+    /// narrowest width holding both
+    pub fn hamming(
+        &self,
+        options: &HammingOptions,
+    ) -> anyhow::Result<HammingInfo, NumberFormatterError> {
+        let a = self.read_argument(&options.first)?;
+        let b = self.read_argument(&options.second)?;
+        let size = self.size_holding_both(a, b);
+
+        Ok(HammingInfo::new(
+            size.pattern_of(a),
+            size.pattern_of(b),
+            size.bits,
+        ))
+    }
+
+    /// Multiplies the numbers given to the clmul command as polynomials over GF(2), which must not be
+    /// negative
+    pub fn clmul(&self, options: &ClmulOptions) -> anyhow::Result<ClmulInfo, NumberFormatterError> {
+        let polynomial = |number: &str| match self.read_argument(number)?.sign_magnitude() {
+            (true, _) => Err(NumberFormatterError::TooSmallError),
+            (false, pattern) => Ok(pattern),
+        };
+        let a = polynomial(&options.first)?;
+        let b = polynomial(&options.second)?;
+        let product = clmul::carryless_multiply(a, b)?;
+
+        Ok(ClmulInfo {
+            polynomials: vec![
+                ("a", clmul::polynomial(a)),
+                ("b", clmul::polynomial(b)),
+                ("a * b", clmul::polynomial(product)),
+            ],
+            outputs: vec![("Product", self.format_value(Value::from_unsigned(product))?)],
+        })
+    }
+
+    /// Generates the numbers asked for by the random command, formatting each. Numbers are spread
+    /// evenly from --min to --max when given, or else across the integer size, zero-padded to it.
+    pub fn random(
+        &self,
+        options: &RandomOptions,
+    ) -> anyhow::Result<Vec<Vec<(NumberType, String)>>, NumberFormatterError> {
+        let mut rng = options
+            .seed
+            .map_or_else(random::Rng::from_entropy, random::Rng::from_seed);
+
+        if let (Some(min), Some(max)) = (&options.min, &options.max) {
+            let min = self.read_argument(min)?;
+            let max = self.read_argument(max)?;
+            let (_, span) = match max.checked_sub(min)?.sign_magnitude() {
+                (true, _) => return Err(NumberFormatterError::EmptyRange),
+                span => span,
+            };
+
+            return (0..options.count)
+                .map(|_| {
+                    let offset = Value::from_unsigned(rng.up_to(span));
+                    self.format_value(min.checked_add(offset)?)
+                })
+                .collect();
+        }
+
+        let size = self.command_options.integer_size().unwrap_or(IntegerSize {
+            bits: 64,
+            signed: false,
+        });
+        let context = self.sized_context(size);
+        (0..options.count)
+            .map(|_| context.format_value(size.value_of(rng.next_u128() & size.mask())))
+            .collect()
+    }
+
+    /// Generates the numbers of the range given to the range command, formatting each only as it is
+    /// reached so that large ranges stream out. Ends early should a step overflow.
+    pub fn range(
+        &self,
+        options: &RangeOptions,
+    ) -> anyhow::Result<
+        impl Iterator<Item = anyhow::Result<Vec<(NumberType, String)>, NumberFormatterError>> + '_,
+        NumberFormatterError,
+    > {
+        let (start, end, inclusive) = range::parse_range(&options.range)?;
+        let start = self.read_argument(start)?;
+        let end = self.read_argument(end)?;
+        let step = match self.read_argument(&options.step)?.sign_magnitude() {
+            (false, step) if step > 0 => Value::from_unsigned(step),
+            _ => return Err(NumberFormatterError::TooSmallError),
+        };
+        if end < start {
+            return Err(NumberFormatterError::EmptyRange);
+        }
+
+        Ok(
+            std::iter::successors(Some(start), move |value| value.checked_add(step).ok())
+                .take_while(move |value| {
+                    if inclusive {
+                        *value <= end
+                    } else {
+                        *value < end
+                    }
+                })
+                .map(|value| self.format_value(value)),
+        )
+    }
+
+    /// The integer size, or else the narrowest width holding both numbers, signed if either is
+    fn size_holding_both(&self, a: Value, b: Value) -> IntegerSize {
+        self.command_options.integer_size().unwrap_or_else(|| {
+            let (a, b) = (IntegerSize::holding(a), IntegerSize::holding(b));
+            IntegerSize {
+                bits: a.bits.max(b.bits),
+                signed: a.signed || b.signed,
+            }
+        })
+    }
+
+    /// Computes the checksums of the bytes of the number given to the crc command, formatting each
+    /// at its own width
+    pub fn crc(
+        &self,
+        options: &CrcOptions,
+    ) -> anyhow::Result<LabelledOutputs, NumberFormatterError> {
+        let bytes = self.argument_bytes(&options.number)?;
+        let algorithms = if options.algorithm.is_empty() {
+            CrcAlgorithm::all()
+        } else {
+            options.algorithm.clone()
+        };
+
+        algorithms
+            .iter()
+            .map(|algorithm| {
+                let context = self.sized_context(IntegerSize {
+                    bits: algorithm.width(),
+                    signed: false,
+                });
+                let checksum = Value::Signed(algorithm.checksum(&bytes) as i128);
+
+                Ok((algorithm.name(), context.format_value(checksum)?))
+            })
+            .collect()
+    }
+
+    /// Computes the internet checksum of the bytes of the number given to the checksum command,
+    /// along with the one's complement sum it is the complement of
+    pub fn checksum(
+        &self,
+        options: &ChecksumOptions,
+    ) -> anyhow::Result<LabelledOutputs, NumberFormatterError> {
+        let bytes = self.argument_bytes(&options.number)?;
+        let context = self.sized_context(IntegerSize {
+            bits: 16,
+            signed: false,
+        });
+
+        Ok(vec![
+            (
+                "Sum",
+                context
+                    .format_value(Value::Signed(checksum::ones_complement_sum(&bytes) as i128))?,
+            ),
+            (
+                "Checksum",
+                context.format_value(Value::Signed(checksum::internet_checksum(&bytes) as i128))?,
+            ),
+        ])
+    }
+
+    /// Splits the <NUMBER> argument into --split words, as hexadecimal labelled with each word's index
+    /// counting from the least significant word
+    pub fn split_words(&self) -> anyhow::Result<Option<Vec<(u32, String)>>, NumberFormatterError> {
+        let word_bits = match self.command_options.split {
+            Some(bits) => bits,
+            None => return Ok(None),
+        };
+
+        let value = self.read_number()?;
+        let size = self
+            .command_options
+            .pattern_size()
+            .unwrap_or_else(|| IntegerSize::holding(value));
+        let bits = size.bits.max(word_bits);
+        let pattern = IntegerSize {
+            bits,
+            signed: false,
+        }
+        .pattern_of(value);
+
+        let word_size = IntegerSize {
+            bits: word_bits,
+            signed: false,
+        };
+        let formatter = HexadecimalNumberFormatter {
+            uppercase: self.command_options.uppercase,
+            size: Some(word_size),
+        };
+
+        let words = (0..bits / word_bits)
+            .map(|index| {
+                let word = word_size.pattern_of(Value::Unsigned(pattern >> (index * word_bits)));
+                Ok((index, formatter.format_unsigned(word)?))
+            })
+            .collect::<anyhow::Result<Vec<(u32, String)>, NumberFormatterError>>()?;
+
+        Ok(Some(bytes::to_endian(
+            words,
+            self.command_options.endian.unwrap_or(Endian::Big),
+        )))
+    }
+
+    /// The bytes of the <NUMBER> argument to draw a --hexdump of, in the chosen byte order
+    pub fn hexdump_bytes(&self) -> anyhow::Result<Option<Vec<u8>>, NumberFormatterError> {
+        if !self.command_options.hexdump {
+            return Ok(None);
+        }
+
+        let value = self.read_number()?;
+        let size = self
+            .command_options
+            .pattern_size()
+            .unwrap_or_else(|| IntegerSize::holding(value));
+
+        Ok(Some(bytes::value_bytes(
+            value,
+            size,
+            self.command_options.endian.unwrap_or(Endian::Big),
+        )))
+    }
+
+    /// The bytes of a number given to a command, at the integer size or else the fewest bytes
+    /// holding it, in the chosen byte order
+    fn argument_bytes(&self, number: &str) -> anyhow::Result<Vec<u8>, NumberFormatterError> {
+        let value = self.read_argument(number)?;
+        let size = self
+            .command_options
+            .pattern_size()
+            .unwrap_or_else(|| IntegerSize::bytes_holding(value));
+
+        Ok(bytes::value_bytes(
+            value,
+            size,
+            self.command_options.endian.unwrap_or(Endian::Big),
+        ))
+    }
+
+    /// A context formatting results at a size of their own rather than the chosen one
+    fn sized_context(&self, size: IntegerSize) -> CommandContext {
+        CommandContext::new(CommandOptions {
+            size: Some(size),
+            width: None,
+            twos_complement: None,
+            ..self.command_options.clone()
+        })
+    }
+
+    /// Reads a number given to a command in place of the <NUMBER> argument
+    fn read_argument(&self, number: &str) -> anyhow::Result<Value, NumberFormatterError> {
+        CommandContext::new(CommandOptions {
+            number: number.to_string(),
+            ..self.command_options.clone()
+        })
+        .read_number()
+    }
+
+    /// A context formatting addresses, in integer and hexadecimal unless other output types are chosen
+    fn address_context(&self) -> CommandContext {
+        let output = if self.command_options.output.is_empty() {
+            vec![NumberType::Integer, NumberType::Hexadecimal]
+        } else {
+            self.command_options.output.clone()
+        };
+
+        CommandContext::new(CommandOptions {
+            output,
+            ..self.command_options.clone()
+        })
+    }
+
+    /// Decodes the <NUMBER> argument against the --bitfields or --svd register, if one is given
+    pub fn decode_bitfields(
+        &self,
+    ) -> anyhow::Result<Option<Vec<DecodedField>>, NumberFormatterError> {
+        let register = match &self.command_options {
+            CommandOptions {
+                bitfields: Some(path),
+                ..
+            } => Register::from_file(path)?,
+            CommandOptions {
+                svd: Some(path),
+                register: Some(name),
+                ..
+            } => Register::from_svd_file(path, name)?,
+            _ => return Ok(None),
+        };
+
+        Ok(Some(register.decode(self.read_number()?)))
+    }
+
+    /// Formats the <NUMBER> argument once for every number type it could plausibly be written in
+    pub fn format_all_interpretations(
+        &self,
+    ) -> anyhow::Result<Vec<Interpretation>, NumberFormatterError> {
+        let mut vec = Vec::<Interpretation>::new();
+        let mut first_error = None;
+        let normalized_command_options = self.normalized_command_options()?;
+
+        for (number_type, note) in
+            detect::plausible_number_types(&normalized_command_options.number)
+        {
+            let command_options = CommandOptions {
+                number_type: Some(number_type),
+                ..normalized_command_options.clone()
+            };
+
+            match CommandContext::new(command_options).format_all_number_types() {
+                Ok(outputs) => vec.push(Interpretation {
+                    number_type,
+                    note,
+                    outputs,
+                }),
+                Err(e) => {
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+
+        match first_error {
+            Some(e) if vec.is_empty() => Err(e),
+            _ if vec.is_empty() => self.format_all_number_types().map(|_| vec),
+            _ => Ok(vec),
+        }
+    }
+
+    /// The command options with digits from other scripts in <NUMBER> replaced by ASCII digits.
+    /// A custom alphabet, a character or text may use such digits themselves, so their input is
+    /// left alone.
+    fn normalized_command_options(&self) -> anyhow::Result<CommandOptions, NumberFormatterError> {
+        if matches!(
+            self.command_options.input_type(),
+            NumberType::Alphabet | NumberType::Character | NumberType::Text
+        ) {
+            return Ok(self.command_options.clone());
+        }
+
+        Ok(CommandOptions {
+            number: digits::normalize_digits(&self.command_options.number)?,
+            ..self.command_options.clone()
+        })
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum NumberFormatterError {
+    #[error("Unknown error occurred")]
+    Unknown,
+    #[error("No value was entered")]
+    Empty,
+    #[error("Number too large")]
+    TooLargeError,
+    #[error("Number too small")]
+    TooSmallError,
+    #[error("Number contains an invalid digit")]
+    InvalidDigit,
+    #[error("Alphabet must contain at least two unique digits")]
+    InvalidAlphabet,
+    #[error("Number contains an invalid BCD nibble")]
+    InvalidBcd,
+    #[error("Bytes contain an incomplete byte")]
+    IncompleteByte,
+    #[error("UUID does not have 32 hex digits")]
+    IncompleteUuid,
+    #[error("Number is not a valid IP address")]
+    InvalidIpAddress,
+    #[error("Netmask bits are not contiguous")]
+    InvalidNetmask,
+    #[error("Number is not a valid MAC address")]
+    InvalidMacAddress,
+    #[error("Permissions are not written as rwxrwxrwx, optionally after a file type")]
+    InvalidPermissions,
+    #[error("Number or name is not a known signal or errno value")]
+    UnknownName,
+    #[error("Flags contain an unknown TCP flag")]
+    InvalidTcpFlag,
+    #[error("Color is not written as #rrggbb or r,g,b")]
+    InvalidColor,
+    #[error("Number is not a whole number")]
+    NotAnInteger,
+    #[error("Number has an unknown unit")]
+    UnknownUnit,
+    #[error("Number is not a valid floating-point number")]
+    InvalidFloat,
+    #[error("Hex float is not written as 0x1.8p3")]
+    InvalidHexFloat,
+    #[error("Fraction is not written as 3/7 or as a decimal such as 0.1[6]")]
+    InvalidFraction,
+    #[error("Number is divided by zero")]
+    DivisionByZero,
+    #[error("Number has no inverse modulo the modulus, as they share a factor")]
+    NoInverse,
+    #[error("Range is empty, as its end is below its start")]
+    EmptyRange,
+    #[error("Range is not written as START..END or START..=END")]
+    InvalidRange,
+    #[error("Expression is not made of numbers joined by operators and parentheses")]
+    InvalidExpression,
+    #[error("Date is not written as YYYY-MM-DD, optionally followed by THH:MM:SS and an offset")]
+    InvalidDateTime,
+    #[error("Varint is not terminated by its last byte")]
+    InvalidVarint,
+    #[error("Number is not a valid Zeckendorf representation")]
+    InvalidZeckendorf,
+    #[error("Number is not a valid Roman numeral")]
+    InvalidRoman,
+    #[error("Number is not spelled out in valid words")]
+    InvalidWords,
+    #[error("Number is not grouped according to the locale")]
+    InvalidGrouping,
+    #[error("Number mixes digits from different scripts")]
+    MixedDigitScripts,
+    #[error("Input is not a single character")]
+    InvalidCharacter,
+    #[error("Destination width is narrower than the source width")]
+    NarrowingExtension,
+    #[error("Invalid register definition, {0}")]
+    InvalidRegisterDefinition(String),
+    #[error("Could not read file: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+impl From<ParseIntError> for NumberFormatterError {
+    fn from(e: ParseIntError) -> Self {
+        match &e.kind() {
+            std::num::IntErrorKind::Empty => NumberFormatterError::Empty,
+            std::num::IntErrorKind::InvalidDigit => NumberFormatterError::InvalidDigit,
+            std::num::IntErrorKind::PosOverflow => NumberFormatterError::TooLargeError,
+            std::num::IntErrorKind::NegOverflow => NumberFormatterError::TooSmallError,
+            _ => NumberFormatterError::Unknown,
+        }
+    }
+}
+
+trait NumberFormatter {
+    fn read(&self, num: &str) -> anyhow::Result<i128, NumberFormatterError>;
+    fn format(&self, num: i128) -> anyhow::Result<String, NumberFormatterError>;
+
+    /// Reads a number too large for `read`, for the number types that can represent the full u128 range
+    fn read_unsigned(&self, _num: &str) -> anyhow::Result<u128, NumberFormatterError> {
+        Err(NumberFormatterError::TooLargeError)
+    }
+
+    /// Formats a number that may be too large for `format`
+    fn format_unsigned(&self, num: u128) -> anyhow::Result<String, NumberFormatterError> {
+        let num = i128::try_from(num).map_err(|_| NumberFormatterError::TooLargeError)?;
+        self.format(num)
+    }
+}
+
+struct IntegerNumberFormatter {
+    locale: Option<Locale>,
+    si: bool,
+}
+
+impl IntegerNumberFormatter {
+    /// Reads an integer in scientific notation such as `2.5e3`, or with a magnitude suffix such as
+    /// `4k` or `1.5Gi`, as its sign and magnitude, if it is written either way
+    fn read_expanded(
+        &self,
+        integer: &str,
+    ) -> Option<anyhow::Result<(bool, u128), NumberFormatterError>> {
+        type Expand<'a> = &'a dyn Fn(&str) -> anyhow::Result<(bool, u128), NumberFormatterError>;
+        let expand = |number: &str, expand: Expand| match &self.locale {
+            Some(locale) => locale.ungroup(number).and_then(|number| expand(&number)),
+            None => expand(number),
+        };
+
+        if let Some((mantissa, exponent)) = humanize::split_exponent(integer) {
+            return Some(expand(mantissa, &|mantissa| {
+                humanize::read_scientific(mantissa, exponent)
+            }));
+        }
+
+        let (number, multiplier) = humanize::split_suffix(integer, self.si)?;
+        Some(expand(number, &|number| {
+            humanize::read_scaled(number, multiplier)
+        }))
+    }
+}
+
+impl NumberFormatter for IntegerNumberFormatter {
+    fn read(&self, integer: &str) -> anyhow::Result<i128, NumberFormatterError> {
+        if let Some(expanded) = self.read_expanded(integer) {
+            let (negative, magnitude) = expanded?;
+            return match Value::from_sign_magnitude(negative, magnitude)? {
+                Value::Signed(num) => Ok(num),
+                Value::Unsigned(_) => Err(NumberFormatterError::TooLargeError),
+            };
+        }
+
+        match &self.locale {
+            Some(locale) => locale.ungroup(integer)?.parse::<i128>(),
+            None => integer.parse::<i128>(),
+        }
+        .map_err(|op| op.into())
+    }
+
+    fn format(&self, num: i128) -> anyhow::Result<String, NumberFormatterError> {
+        match &self.locale {
+            Some(locale) => Ok(locale.group(&num.to_string())),
+            None => Ok(num.to_string()),
+        }
+    }
+
+    fn read_unsigned(&self, integer: &str) -> anyhow::Result<u128, NumberFormatterError> {
+        if let Some(expanded) = self.read_expanded(integer) {
+            return match expanded? {
+                (true, _) => Err(NumberFormatterError::TooSmallError),
+                (false, magnitude) => Ok(magnitude),
+            };
+        }
+
+        match &self.locale {
+            Some(locale) => locale.ungroup(integer)?.parse::<u128>(),
+            None => integer.parse::<u128>(),
+        }
+        .map_err(|op| op.into())
+    }
+
+    fn format_unsigned(&self, num: u128) -> anyhow::Result<String, NumberFormatterError> {
+        match &self.locale {
+            Some(locale) => Ok(locale.group(&num.to_string())),
+            None => Ok(num.to_string()),
+        }
+    }
+}
+
+/// Removes a lowercase or uppercase radix prefix such as `0x`, keeping any sign written in front of
+/// it, so `-0x10` becomes `-10`
+fn without_prefix(num: &str, prefix: &str) -> String {
+    let (sign, digits) = match num.strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", num),
+    };
+
+    let digits = digits
+        .strip_prefix(prefix)
+        .or_else(|| digits.strip_prefix(&prefix.to_ascii_uppercase()))
+        .unwrap_or(digits);
+
+    if digits.starts_with(['-', '+']) {
+        return num.to_string();
+    }
+
+    format!("{}{}", sign, digits)
+}
+
+struct HexadecimalNumberFormatter {
+    uppercase: bool,
+    size: Option<IntegerSize>,
+}
+impl NumberFormatter for HexadecimalNumberFormatter {
+    fn read(&self, hexadecimal: &str) -> anyhow::Result<i128, NumberFormatterError> {
+        i128::from_str_radix(&without_prefix(hexadecimal, "0x"), 16).map_err(|op| op.into())
+    }
+
+    fn format(&self, num: i128) -> anyhow::Result<String, NumberFormatterError> {
+        match (self.size, self.uppercase) {
+            (Some(size), _) => self.format_unsigned(size.pattern(num)?),
+            (None, true) => Ok(format!("{:X}", &num)),
+            (None, false) => Ok(format!("{:x}", &num)),
+        }
+    }
+
+    fn read_unsigned(&self, hexadecimal: &str) -> anyhow::Result<u128, NumberFormatterError> {
+        u128::from_str_radix(&without_prefix(hexadecimal, "0x"), 16).map_err(|op| op.into())
+    }
+
+    fn format_unsigned(&self, num: u128) -> anyhow::Result<String, NumberFormatterError> {
+        let width = self.size.map_or(0, |size| size.digits(4));
+        if self.uppercase {
+            Ok(format!("{:01$X}", num, width))
+        } else {
+            Ok(format!("{:01$x}", num, width))
+        }
+    }
+}
+
+struct BinaryNumberFormatter {
+    size: Option<IntegerSize>,
+}
+impl NumberFormatter for BinaryNumberFormatter {
+    fn read(&self, binary_num: &str) -> anyhow::Result<i128, NumberFormatterError> {
+        i128::from_str_radix(&without_prefix(binary_num, "0b"), 2).map_err(|op| op.into())
+    }
+
+    fn format(&self, num: i128) -> anyhow::Result<String, NumberFormatterError> {
+        match self.size {
+            Some(size) => self.format_unsigned(size.pattern(num)?),
+            None => Ok(format!("{:b}", num)),
+        }
+    }
+
+    fn read_unsigned(&self, binary_num: &str) -> anyhow::Result<u128, NumberFormatterError> {
+        u128::from_str_radix(&without_prefix(binary_num, "0b"), 2).map_err(|op| op.into())
+    }
+
+    fn format_unsigned(&self, num: u128) -> anyhow::Result<String, NumberFormatterError> {
+        let width = self.size.map_or(0, |size| size.digits(1));
+        Ok(format!("{:01$b}", num, width))
+    }
+}
+
+struct OctalNumberFormatter {
+    size: Option<IntegerSize>,
+}
+impl NumberFormatter for OctalNumberFormatter {
+    fn read(&self, octal: &str) -> anyhow::Result<i128, NumberFormatterError> {
+        i128::from_str_radix(&without_prefix(octal, "0o"), 8).map_err(|op| op.into())
+    }
+
+    fn format(&self, num: i128) -> anyhow::Result<String, NumberFormatterError> {
+        match self.size {
+            Some(size) => self.format_unsigned(size.pattern(num)?),
+            None => Ok(format!("{:o}", num)),
+        }
+    }
+
+    fn read_unsigned(&self, octal: &str) -> anyhow::Result<u128, NumberFormatterError> {
+        u128::from_str_radix(&without_prefix(octal, "0o"), 8).map_err(|op| op.into())
+    }
+
+    fn format_unsigned(&self, num: u128) -> anyhow::Result<String, NumberFormatterError> {
+        let width = self.size.map_or(0, |size| size.digits(3));
+        Ok(format!("{:01$o}", num, width))
+    }
+}
+
+struct RadixNumberFormatter {
+    radix: u32,
+    uppercase: bool,
+}
+impl NumberFormatter for RadixNumberFormatter {
+    fn read(&self, num: &str) -> anyhow::Result<i128, NumberFormatterError> {
+        i128::from_str_radix(num, self.radix).map_err(|op| op.into())
+    }
+
+    fn format(&self, num: i128) -> anyhow::Result<String, NumberFormatterError> {
+        self.format_digits(num.unsigned_abs(), num < 0)
+    }
+
+    fn read_unsigned(&self, num: &str) -> anyhow::Result<u128, NumberFormatterError> {
+        u128::from_str_radix(num, self.radix).map_err(|op| op.into())
+    }
+
+    fn format_unsigned(&self, num: u128) -> anyhow::Result<String, NumberFormatterError> {
+        self.format_digits(num, false)
+    }
+}
+
+impl RadixNumberFormatter {
+    fn format_digits(
+        &self,
+        magnitude: u128,
+        negative: bool,
+    ) -> anyhow::Result<String, NumberFormatterError> {
+        let mut digits = Vec::<char>::new();
+        let mut remainder = magnitude;
+
+        loop {
+            let digit = (remainder % self.radix as u128) as u32;
+            let c = char::from_digit(digit, self.radix).ok_or(NumberFormatterError::Unknown)?;
+            digits.push(if self.uppercase {
+                c.to_ascii_uppercase()
+            } else {
+                c
+            });
+            remainder /= self.radix as u128;
+            if remainder == 0 {
+                break;
+            }
+        }
+
+        if negative {
+            digits.push('-');
+        }
+
+        Ok(digits.iter().rev().collect())
+    }
+}
+
+/// Reads a number written in any of the prefixed number types, e.g. `0x7f`, `0b101` or `127`
+pub(crate) fn read_prefixed_number(input: &str) -> anyhow::Result<i128, NumberFormatterError> {
+    NumberFormatterFactory::new_number_formatter(
+        &NumberType::detect(input),
+        &CommandOptions::default(),
+    )
+    .read(input)
+}
+
+struct NumberFormatterFactory;
+impl NumberFormatterFactory {
+    pub fn new_number_formatter(
+        number_type: &NumberType,
+        command_options: &CommandOptions,
+    ) -> Box<dyn NumberFormatter> {
+        match number_type {
+            NumberType::Integer => Box::new(IntegerNumberFormatter {
+                locale: command_options.locale,
+                si: command_options.si,
+            }),
+            NumberType::Hexadecimal => Box::new(HexadecimalNumberFormatter {
+                uppercase: command_options.uppercase,
+                size: command_options.pattern_size(),
+            }),
+            NumberType::Binary => Box::new(BinaryNumberFormatter {
+                size: command_options.pattern_size(),
+            }),
+            NumberType::Octal => Box::new(OctalNumberFormatter {
+                size: command_options.pattern_size(),
+            }),
+            NumberType::Radix => Box::new(RadixNumberFormatter {
+                radix: command_options.radix.unwrap_or(10),
+                uppercase: command_options.uppercase,
+            }),
+            NumberType::Alphabet => Box::new(AlphabetNumberFormatter::new(
+                command_options.alphabet.as_deref().unwrap_or_default(),
+            )),
+            NumberType::Base36 => Box::new(RadixNumberFormatter {
+                radix: 36,
+                uppercase: command_options.uppercase,
+            }),
+            NumberType::Column => Box::new(ColumnNumberFormatter {}),
+            NumberType::Sexagesimal => Box::new(SexagesimalNumberFormatter {}),
+            NumberType::Dozenal => {
+                Box::new(DozenalNumberFormatter::new(&command_options.dozenal_digits))
+            }
+            NumberType::Negabinary => Box::new(NegativeBaseNumberFormatter { radix: 2 }),
+            NumberType::Negadecimal => Box::new(NegativeBaseNumberFormatter { radix: 10 }),
+            NumberType::Gray => Box::new(GrayNumberFormatter {}),
+            NumberType::Bcd => Box::new(BcdNumberFormatter {}),
+            NumberType::OnesComplement => Box::new(OnesComplementNumberFormatter {
+                size: command_options.pattern_size(),
+            }),
+            NumberType::ZigZag => Box::new(ZigZagNumberFormatter {}),
+            NumberType::Uleb128 => Box::new(Leb128NumberFormatter {
+                signed: false,
+                endian: command_options.endian.unwrap_or(Endian::Little),
+            }),
+            NumberType::Sleb128 => Box::new(Leb128NumberFormatter {
+                signed: true,
+                endian: command_options.endian.unwrap_or(Endian::Little),
+            }),
+            NumberType::Zeckendorf => Box::new(ZeckendorfNumberFormatter {}),
+            NumberType::FibonacciCode => Box::new(FibonacciCodeNumberFormatter {}),
+            NumberType::Excess => Box::new(ExcessNumberFormatter {
+                bias: command_options.bias.unwrap_or(0),
+            }),
+            NumberType::Roman => Box::new(RomanNumberFormatter {
+                extended: command_options.roman_extended,
+            }),
+            NumberType::Words => Box::new(WordsNumberFormatter {
+                lang: command_options.lang,
+            }),
+            NumberType::Codepoint => Box::new(CodepointNumberFormatter {
+                #[cfg(feature = "unicode-info")]
+                unicode_info: command_options.unicode_info,
+            }),
+            NumberType::Character => Box::new(CharacterNumberFormatter {}),
+            NumberType::ByteArray => Box::new(ByteArrayNumberFormatter {
+                style: command_options.style.unwrap_or_default(),
+                endian: command_options.endian.unwrap_or(Endian::Big),
+                uppercase: command_options.uppercase,
+                size: command_options.pattern_size(),
+            }),
+            NumberType::Base64 => Box::new(EncodingNumberFormatter {
+                encoding: Encoding::Base64,
+                endian: command_options.endian.unwrap_or(Endian::Big),
+                size: command_options.pattern_size(),
+            }),
+            NumberType::Base64Url => Box::new(EncodingNumberFormatter {
+                encoding: Encoding::Base64Url,
+                endian: command_options.endian.unwrap_or(Endian::Big),
+                size: command_options.pattern_size(),
+            }),
+            NumberType::Base32 => Box::new(EncodingNumberFormatter {
+                encoding: Encoding::Base32,
+                endian: command_options.endian.unwrap_or(Endian::Big),
+                size: command_options.pattern_size(),
+            }),
+            NumberType::Base32Crockford => Box::new(EncodingNumberFormatter {
+                encoding: Encoding::Base32Crockford,
+                endian: command_options.endian.unwrap_or(Endian::Big),
+                size: command_options.pattern_size(),
+            }),
+            NumberType::Base58 => Box::new(AlphabetNumberFormatter::new("base58")),
+            NumberType::Uuid => Box::new(UuidNumberFormatter {
+                uppercase: command_options.uppercase,
+            }),
+            NumberType::Text => Box::new(TextNumberFormatter {
+                endian: command_options.endian.unwrap_or(Endian::Big),
+                size: command_options.pattern_size(),
+            }),
+            NumberType::Hexdump => Box::new(HexdumpNumberFormatter {
+                endian: command_options.endian.unwrap_or(Endian::Big),
+                size: command_options.pattern_size(),
+            }),
+            NumberType::Ipv4 => Box::new(Ipv4NumberFormatter),
+            NumberType::Ipv6 => Box::new(Ipv6NumberFormatter),
+            NumberType::Mac => Box::new(MacNumberFormatter {
+                uppercase: command_options.uppercase,
+            }),
+            NumberType::Permissions => Box::new(PermissionsNumberFormatter),
+            NumberType::Signal => Box::new(SignalNumberFormatter),
+            NumberType::Errno => Box::new(ErrnoNumberFormatter),
+            NumberType::TcpFlags => Box::new(TcpFlagsNumberFormatter),
+            NumberType::Color => Box::new(ColorNumberFormatter {
+                uppercase: command_options.uppercase,
+                size: command_options.integer_size(),
+            }),
+            NumberType::Rgb565 => Box::new(PackedPixelNumberFormatter {
+                format: PixelFormat::RGB565,
+                uppercase: command_options.uppercase,
+            }),
+            NumberType::Rgb555 => Box::new(PackedPixelNumberFormatter {
+                format: PixelFormat::RGB555,
+                uppercase: command_options.uppercase,
+            }),
+            NumberType::Time => Box::new(TimeNumberFormatter {
+                epoch: command_options.epoch.unwrap_or_default(),
+                unit: command_options.time_unit,
+                local: true,
+            }),
+            NumberType::Size => Box::new(SizeNumberFormatter {
+                units: command_options.units.unwrap_or_default(),
+                precision: command_options.precision(humanize::SIZE_DECIMALS),
+            }),
+            NumberType::Frequency => Box::new(FrequencyNumberFormatter {
+                precision: command_options.precision(humanize::FREQUENCY_DECIMALS),
+            }),
+            NumberType::Engineering => Box::new(EngineeringNumberFormatter {
+                notation: Notation::Engineering,
+                precision: command_options.precision(humanize::ENGINEERING_DECIMALS),
+            }),
+            NumberType::SiPrefix => Box::new(EngineeringNumberFormatter {
+                notation: Notation::SiPrefix,
+                precision: command_options.precision(humanize::ENGINEERING_DECIMALS),
+            }),
+            NumberType::Float => Box::new(FloatNumberFormatter {
+                format: command_options.float.unwrap_or_default(),
+                precision: command_options.float_precision(),
+            }),
+            NumberType::HexFloat => Box::new(HexFloatNumberFormatter {
+                format: command_options.float.unwrap_or_default(),
+            }),
+            NumberType::ExactFloat => Box::new(ExactFloatNumberFormatter {
+                format: command_options.float.unwrap_or_default(),
+            }),
+            NumberType::FixedPoint => {
+                let format = command_options.q_format.unwrap_or_default();
+                Box::new(FixedPointNumberFormatter {
+                    format,
+                    precision: command_options.precision(format.fraction_bits),
+                })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(NumberType::Integer, "Integer")]
+    #[test_case(NumberType::Binary, "Binary")]
+    #[test_case(NumberType::Hexadecimal, "Hexadecimal")]
+    #[test_case(NumberType::Octal, "Octal")]
+    #[test_case(NumberType::Radix, "Radix")]
+    #[test_case(NumberType::Alphabet, "Alphabet")]
+    #[test_case(NumberType::Base36, "Base36")]
+    #[test_case(NumberType::Column, "Column")]
+    #[test_case(NumberType::Sexagesimal, "Sexagesimal")]
+    #[test_case(NumberType::Dozenal, "Dozenal")]
+    #[test_case(NumberType::Negabinary, "Negabinary")]
+    #[test_case(NumberType::Negadecimal, "Negadecimal")]
+    #[test_case(NumberType::Gray, "Gray")]
+    #[test_case(NumberType::Bcd, "BCD")]
+    #[test_case(NumberType::OnesComplement, "One's complement")]
+    #[test_case(NumberType::ZigZag, "ZigZag")]
+    #[test_case(NumberType::Uleb128, "ULEB128")]
+    #[test_case(NumberType::Sleb128, "SLEB128")]
+    #[test_case(NumberType::Zeckendorf, "Zeckendorf")]
+    #[test_case(NumberType::FibonacciCode, "Fibonacci code")]
+    #[test_case(NumberType::Excess, "Excess")]
+    #[test_case(NumberType::Roman, "Roman")]
+    #[test_case(NumberType::Words, "Words")]
+    #[test_case(NumberType::Codepoint, "Codepoint")]
+    #[test_case(NumberType::Character, "Character")]
+    #[test_case(NumberType::ByteArray, "Byte array")]
+    #[test_case(NumberType::Base64, "Base64")]
+    #[test_case(NumberType::Base64Url, "Base64 URL")]
+    #[test_case(NumberType::Base32, "Base32")]
+    #[test_case(NumberType::Base32Crockford, "Crockford Base32")]
+    #[test_case(NumberType::Base58, "Base58")]
+    #[test_case(NumberType::Uuid, "UUID")]
+    #[test_case(NumberType::Text, "Text")]
+    #[test_case(NumberType::Hexdump, "Hexdump")]
+    #[test_case(NumberType::Ipv4, "IPv4")]
+    #[test_case(NumberType::Ipv6, "IPv6")]
+    #[test_case(NumberType::Mac, "MAC")]
+    #[test_case(NumberType::Permissions, "Permissions")]
+    #[test_case(NumberType::Signal, "Signal")]
+    #[test_case(NumberType::Errno, "Errno")]
+    #[test_case(NumberType::TcpFlags, "TCP flags")]
+    #[test_case(NumberType::Color, "Color")]
+    #[test_case(NumberType::Rgb565, "RGB565")]
+    #[test_case(NumberType::Rgb555, "RGB555")]
+    #[test_case(NumberType::Time, "Time")]
+    #[test_case(NumberType::Size, "Size")]
+    #[test_case(NumberType::Frequency, "Frequency")]
+    #[test_case(NumberType::Engineering, "Engineering")]
+    #[test_case(NumberType::SiPrefix, "SI prefix")]
+    #[test_case(NumberType::Float, "Float")]
+    #[test_case(NumberType::HexFloat, "Hex float")]
+    #[test_case(NumberType::ExactFloat, "Exact float")]
+    #[test_case(NumberType::FixedPoint, "Fixed point")]
+    fn number_type_fmt_should_be_expected_value(number_type: NumberType, expected_value: &str) {
+        // Act
+        let actual_value = format!("{}", number_type);
+
+        // Assert
+        assert_eq!(actual_value, expected_value);
+    }
+
+    #[test]
+    fn new_command_options_should_return_expected_value() {
+        // Arrange
+        let expected = CommandOptions {
+            number_type: Some(NumberType::Binary),
+            number: String::from("input"),
+            ..Default::default()
+        };
+
+        // Act
+        let actual = CommandOptions::new(NumberType::Binary, &expected.number);
+
+        // Assert
+        assert_eq!(actual.number_type, expected.number_type);
+        assert_eq!(actual.number, expected.number);
+    }
+
+    #[test_case(NumberType::Integer)]
+    #[test_case(NumberType::Hexadecimal)]
+    #[test_case(NumberType::Binary)]
+    #[test_case(NumberType::Octal)]
+    #[test_case(NumberType::Radix)]
+    #[test_case(NumberType::Alphabet)]
+    #[test_case(NumberType::Base36)]
+    #[test_case(NumberType::Column)]
+    #[test_case(NumberType::Sexagesimal)]
+    #[test_case(NumberType::Dozenal)]
+    #[test_case(NumberType::Negabinary)]
+    #[test_case(NumberType::Negadecimal)]
+    #[test_case(NumberType::Gray)]
+    #[test_case(NumberType::Bcd)]
+    #[test_case(NumberType::OnesComplement)]
+    #[test_case(NumberType::ZigZag)]
+    #[test_case(NumberType::Uleb128)]
+    #[test_case(NumberType::Sleb128)]
+    #[test_case(NumberType::Zeckendorf)]
+    #[test_case(NumberType::FibonacciCode)]
+    #[test_case(NumberType::Excess)]
+    #[test_case(NumberType::Roman)]
+    #[test_case(NumberType::Words)]
+    #[test_case(NumberType::Codepoint)]
+    #[test_case(NumberType::Character)]
+    #[test_case(NumberType::ByteArray)]
+    #[test_case(NumberType::Base64)]
+    #[test_case(NumberType::Base64Url)]
+    #[test_case(NumberType::Base32)]
+    #[test_case(NumberType::Base32Crockford)]
+    #[test_case(NumberType::Base58)]
+    #[test_case(NumberType::Uuid)]
+    #[test_case(NumberType::Text)]
+    #[test_case(NumberType::Hexdump)]
+    #[test_case(NumberType::Ipv4)]
+    #[test_case(NumberType::Ipv6)]
+    #[test_case(NumberType::Mac)]
+    #[test_case(NumberType::Permissions)]
+    #[test_case(NumberType::Signal)]
+    #[test_case(NumberType::Errno)]
+    #[test_case(NumberType::TcpFlags)]
+    #[test_case(NumberType::Color)]
+    #[test_case(NumberType::Rgb565)]
+    #[test_case(NumberType::Rgb555)]
+    #[test_case(NumberType::Time)]
+    #[test_case(NumberType::Size)]
+    #[test_case(NumberType::Frequency)]
+    #[test_case(NumberType::Engineering)]
+    #[test_case(NumberType::SiPrefix)]
+    #[test_case(NumberType::Float)]
+    #[test_case(NumberType::HexFloat)]
+    #[test_case(NumberType::ExactFloat)]
+    #[test_case(NumberType::FixedPoint)]
+    fn new_number_formatter_should_match_number_type(number_type: NumberType) {
+        let _ =
+            *NumberFormatterFactory::new_number_formatter(&number_type, &CommandOptions::default());
+    }
+
+    #[test_case(NumberType::Integer, "10", 10)]
+    #[test_case(NumberType::Hexadecimal, "FFFF", 65535)]
+    #[test_case(NumberType::Binary, "0000110", 6)]
+    #[test_case(NumberType::Octal, "0o755", 493)]
+    #[test_case(NumberType::Base36, "Zz", 1295)]
+    #[test_case(NumberType::Hexadecimal, "0XfF", 255)]
+    #[test_case(NumberType::Base64, "3q2+7w==", 0xdeadbeef)]
+    #[test_case(NumberType::Base32Crockford, "vtpvxvr", 0xdeadbeef)]
+    #[test_case(NumberType::Signal, "KILL", 9)]
+    #[test_case(NumberType::Color, "30,144,255", 0x1e90ff)]
+    #[test_case(NumberType::Rgb565, "#ff0000", 0xf800)]
+    #[test_case(NumberType::Time, "2023-11-14T22:13:20Z", 1700000000)]
+    #[test_case(NumberType::Integer, "4k", 4096; "binary suffix")]
+    #[test_case(NumberType::Integer, "1.5Gi", 1610612736; "fractional binary suffix")]
+    #[test_case(NumberType::Integer, "-16M", -16777216; "negative suffixed")]
+    #[test_case(NumberType::Integer, "1e6", 1000000; "scientific notation")]
+    #[test_case(NumberType::Float, "1.5", 0x3ff8000000000000; "float")]
+    #[test_case(NumberType::HexFloat, "0x1.8p3", 0x4028000000000000; "hex float")]
+    #[test_case(NumberType::FixedPoint, "0.5", 0x4000; "fixed point")]
+    #[test_case(NumberType::Integer, "2.5e3", 2500; "fractional scientific notation")]
+    fn new_number_formatter_should_read_number_type(
+        number_type: NumberType,
+        input_number: &str,
+        expected_number: i128,
+    ) {
+        // Arrange
+        let reader =
+            NumberFormatterFactory::new_number_formatter(&number_type, &CommandOptions::default());
+
+        // Act
+        let actual_number = reader.read(input_number);
+
+        // Assert
+        assert!(actual_number.is_ok());
+        assert_eq!(expected_number, actual_number.unwrap());
+    }
+
+    #[test_case(NumberType::Integer, 907823, "907823")]
+    #[test_case(NumberType::Hexadecimal, 65451, "ffab")]
+    #[test_case(NumberType::Binary, 9543, "10010101000111")]
+    #[test_case(NumberType::Octal, 493, "755")]
+    #[test_case(NumberType::Base36, 1295, "zz")]
+    #[test_case(NumberType::Base64Url, 0xfbff, "-_8")]
+    #[test_case(NumberType::Base32, 0xdeadbeef, "32W353Y=")]
+    #[test_case(NumberType::Base58, 0xdeadbeef, "6h8cQN")]
+    #[test_case(NumberType::Ipv4, 0x0a000001, "10.0.0.1")]
+    #[test_case(NumberType::Ipv6, 1, "::1")]
+    #[test_case(NumberType::Errno, 2, "ENOENT (No such file or directory)")]
+    #[test_case(NumberType::Size, 1536, "1.5 KiB (1.54 kB)")]
+    #[test_case(NumberType::Frequency, 480000000, "480 MHz")]
+    #[test_case(NumberType::Engineering, -1500, "-1.5e3")]
+    #[test_case(NumberType::SiPrefix, 4700, "4.7k")]
+    #[test_case(NumberType::Float, 0x3ff8000000000000, "1.5")]
+    #[test_case(NumberType::HexFloat, 0x3ff8000000000000, "0x1.8p+0")]
+    #[test_case(NumberType::FixedPoint, 0xc000, "-0.5")]
+    #[test_case(
+        NumberType::ExactFloat,
+        0x3fb999999999999a,
+        "0.1000000000000000055511151231257827021181583404541015625"
+    )]
+    fn new_number_formatter_should_format_i128(
+        number_type: NumberType,
+        input_number: i128,
+        expected_output: &str,
+    ) {
+        // Arrange
+        let reader =
+            NumberFormatterFactory::new_number_formatter(&number_type, &CommandOptions::default());
+
+        // Act
+        let actual_number = reader.format(input_number);
+
+        // Assert
+        assert!(actual_number.is_ok());
+        assert_eq!(expected_output, actual_number.unwrap());
+    }
+
+    #[test]
+    fn command_context_should_format_all_types_correctly() {
+        // Arrange
+        let command_options = CommandOptions {
+            number_type: Some(NumberType::Binary),
+            number: String::from("0b1101011"),
+            ..Default::default()
+        };
+
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let output = command_context.format_all_number_types();
+
+        // Assert
+        assert!(output.is_ok());
+        let vec = output.unwrap();
+
+        assert!(vec.contains(&(NumberType::Integer, String::from("107"))));
+        assert!(vec.contains(&(NumberType::Binary, String::from("1101011"))));
+        assert!(vec.contains(&(NumberType::Hexadecimal, String::from("6b"))));
+    }
+
+    #[test_case(CommandOptions { number_type: Some(NumberType::Integer), number: String::from("12"), ..Default::default() })]
+    #[test_case(CommandOptions { number_type: Some(NumberType::Binary), number: String::from("100001"), ..Default::default() })]
+    #[test_case(CommandOptions { number_type: Some(NumberType::Hexadecimal), number: String::from("0xAbC3f09"), ..Default::default() })]
+    fn command_context_should_format_all_types_in_expected_order(command_options: CommandOptions) {
+        // Arrange
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let output = command_context.format_all_number_types();
+
+        // Assert
+        assert!(output.is_ok());
+        let vec = output.unwrap();
+
+        assert_eq!(vec.len(), 3);
+        assert_eq!(vec[0].0, NumberType::Integer);
+        assert_eq!(vec[1].0, NumberType::Hexadecimal);
+        assert_eq!(vec[2].0, NumberType::Binary);
+    }
+
+    #[test_case(7, "-15", -12)]
+    #[test_case(12, "B3", 135)]
+    #[test_case(36, "zz", 1295)]
+    fn radix_number_formatter_should_read_number(radix: u32, input_number: &str, expected: i128) {
+        // Arrange
+        let formatter = RadixNumberFormatter {
+            radix,
+            uppercase: false,
+        };
+
+        // Act
+        let actual_number = formatter.read(input_number);
+
+        // Assert
+        assert!(actual_number.is_ok());
+        assert_eq!(expected, actual_number.unwrap());
+    }
+
+    #[test_case(7, -12, "-15")]
+    #[test_case(12, 135, "b3")]
+    #[test_case(36, 1295, "zz")]
+    #[test_case(2, 0, "0")]
+    fn radix_number_formatter_should_format_i128(radix: u32, input_number: i128, expected: &str) {
+        // Arrange
+        let formatter = RadixNumberFormatter {
+            radix,
+            uppercase: false,
+        };
+
+        // Act
+        let actual_output = formatter.format(input_number);
+
+        // Assert
+        assert!(actual_output.is_ok());
+        assert_eq!(expected, actual_output.unwrap());
+    }
+
+    #[test]
+    fn command_context_should_include_radix_when_requested() {
+        // Arrange
+        let command_options = CommandOptions {
+            number_type: Some(NumberType::Integer),
+            number: String::from("100"),
+            radix: Some(7),
+            ..Default::default()
+        };
+
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let output = command_context.format_all_number_types();
+
+        // Assert
+        assert!(output.is_ok());
+        let vec = output.unwrap();
+
+        assert_eq!(vec.len(), 4);
+        assert_eq!(vec[3], (NumberType::Radix, String::from("202")));
+    }
+
+    #[test_case("0xff", NumberType::Hexadecimal)]
+    #[test_case("0XAB", NumberType::Hexadecimal)]
+    #[test_case("0b101", NumberType::Binary)]
+    #[test_case("0o17", NumberType::Octal)]
+    #[test_case("-0x10", NumberType::Hexadecimal; "negative hexadecimal")]
+    #[test_case("-0b101", NumberType::Binary; "negative binary")]
+    #[test_case("U+1F600", NumberType::Codepoint)]
+    #[test_case("[0xde, 0xad]", NumberType::ByteArray)]
+    #[test_case("b\"\\xde\"", NumberType::ByteArray)]
+    #[test_case("'A'", NumberType::Character)]
+    #[test_case("\"RIFF\"", NumberType::Text)]
+    #[test_case("00000000: dead beef  ....", NumberType::Hexdump)]
+    #[test_case("0000000 4241 4443\n0000004", NumberType::Hexdump; "od")]
+    #[test_case("00000000  de ad be ef  |....|", NumberType::Hexdump; "hexdump canonical")]
+    #[test_case("192.168.0.1", NumberType::Ipv4)]
+    #[test_case("2001:db8::1", NumberType::Ipv6)]
+    #[test_case("00:1a:2b:3c:4d:5e", NumberType::Mac)]
+    #[test_case("001a.2b3c.4d5e", NumberType::Mac; "cisco mac")]
+    #[test_case("-rwxr-xr--", NumberType::Permissions)]
+    #[test_case("SIGKILL", NumberType::Signal)]
+    #[test_case("ENOENT", NumberType::Errno)]
+    #[test_case("SYN, ACK", NumberType::TcpFlags)]
+    #[test_case("#1e90ff", NumberType::Color)]
+    #[test_case("2023-11-14T22:13:20Z", NumberType::Time)]
+    #[test_case("25MHz", NumberType::Frequency)]
+    #[test_case("1.5", NumberType::Float)]
+    #[test_case("-2.5e-3", NumberType::Integer; "scientific notation")]
+    #[test_case("0x1.8p3", NumberType::HexFloat)]
+    #[test_case("123e4567-e89b-12d3-a456-426614174000", NumberType::Uuid)]
+    #[test_case("{123e4567-e89b-12d3-a456-426614174000}", NumberType::Uuid; "braced uuid")]
+    #[test_case("1234", NumberType::Integer)]
+    #[test_case("0", NumberType::Integer)]
+    fn number_type_detect_should_use_prefix(input: &str, expected: NumberType) {
+        // Act
+        let actual = NumberType::detect(input);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn command_options_input_type_should_prefer_given_number_type() {
+        // Arrange
+        let detected = CommandOptions {
+            number: String::from("0b11"),
+            ..Default::default()
+        };
+        let given = CommandOptions::new(NumberType::Hexadecimal, "0b11");
+
+        // Act & Assert
+        assert_eq!(detected.input_type(), NumberType::Binary);
+        assert_eq!(given.input_type(), NumberType::Hexadecimal);
+    }
+
+    #[test_case(None, "0754")]
+    #[test_case(Some(NumberType::Permissions), "0754"; "given type")]
+    #[test_case(Some(NumberType::Permissions), "754"; "without leading zero")]
+    fn command_context_should_read_octal_mode_for_permissions(
+        number_type: Option<NumberType>,
+        number: &str,
+    ) {
+        // Arrange
+        let command_options = CommandOptions {
+            number_type,
+            number: number.to_string(),
+            output: vec![NumberType::Permissions],
+            ..Default::default()
+        };
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let actual_output = command_context.format_all_number_types();
+
+        // Assert
+        assert_eq!(
+            actual_output.unwrap(),
+            vec![(NumberType::Permissions, String::from("rwxr-xr--"))]
+        );
+    }
+
+    #[test]
+    fn command_context_should_read_locale_grouped_integer_rather_than_float() {
+        // Arrange
+        let command_options = CommandOptions {
+            number: String::from("1.234"),
+            locale: Some(locale::parse_locale("de-DE").unwrap()),
+            output: vec![NumberType::Integer, NumberType::Hexadecimal],
+            ..Default::default()
+        };
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let actual_output = command_context.format_all_number_types();
+
+        // Assert
+        assert_eq!(
+            actual_output.unwrap(),
+            vec![
+                (NumberType::Integer, String::from("1.234")),
+                (NumberType::Hexadecimal, String::from("4d2")),
+            ]
+        );
+    }
+
+    #[test_case("2.5e0", NumberFormatterError::NotAnInteger)]
+    #[test_case("1e39", NumberFormatterError::TooLargeError; "too large")]
+    #[test_case("-2.5e-3", NumberFormatterError::NotAnInteger; "negative exponent")]
+    fn command_context_should_read_scientific_notation_as_integer(
+        number: &str,
+        expected: NumberFormatterError,
+    ) {
+        // Arrange
+        let command_options = CommandOptions {
+            number: number.to_string(),
+            ..Default::default()
+        };
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let actual_output = command_context.format_all_number_types();
+
+        // Assert
+        assert_eq!(
+            std::mem::discriminant(&actual_output.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+
+    #[test]
+    fn command_context_should_format_detected_octal_input() {
+        // Arrange
+        let command_options = CommandOptions {
+            number: String::from("0o17"),
+            ..Default::default()
+        };
+
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let output = command_context.format_all_number_types();
+
+        // Assert
+        assert!(output.is_ok());
+        let vec = output.unwrap();
+
+        assert_eq!(vec.len(), 4);
+        assert_eq!(vec[0], (NumberType::Integer, String::from("15")));
+        assert_eq!(vec[3], (NumberType::Octal, String::from("17")));
+    }
+
+    #[test]
+    fn command_context_should_format_all_interpretations_in_likelihood_order() {
+        // Arrange
+        let command_options = CommandOptions {
+            number: String::from("10"),
+            detect: true,
+            ..Default::default()
+        };
+
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let output = command_context.format_all_interpretations();
+
+        // Assert
+        assert!(output.is_ok());
+        let vec = output.unwrap();
+
+        assert_eq!(vec.len(), 4);
+        assert_eq!(vec[0].number_type, NumberType::Binary);
+        assert_eq!(vec[0].outputs[0], (NumberType::Integer, String::from("2")));
+        assert_eq!(vec[1].number_type, NumberType::Integer);
+        assert_eq!(vec[1].outputs[0], (NumberType::Integer, String::from("10")));
+        assert_eq!(vec[2].outputs[0], (NumberType::Integer, String::from("8")));
+        assert_eq!(vec[3].outputs[0], (NumberType::Integer, String::from("16")));
+    }
+
+    #[test]
+    fn command_context_should_fail_when_no_interpretation_is_plausible() {
+        // Arrange
+        let command_options = CommandOptions {
+            number: String::from("xyz"),
+            detect: true,
+            ..Default::default()
+        };
+
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let output = command_context.format_all_interpretations();
+
+        // Assert
+        assert!(matches!(output, Err(NumberFormatterError::InvalidDigit)));
+    }
+
+    #[test_case(NumberType::Hexadecimal, 65451, "FFAB")]
+    #[test_case(NumberType::Base36, 1295, "ZZ")]
+    #[test_case(NumberType::Integer, 1295, "1295")]
+    fn new_number_formatter_should_format_uppercase(
+        number_type: NumberType,
+        input_number: i128,
+        expected_output: &str,
+    ) {
+        // Arrange
+        let command_options = CommandOptions {
+            uppercase: true,
+            ..Default::default()
+        };
+        let formatter =
+            NumberFormatterFactory::new_number_formatter(&number_type, &command_options);
+
+        // Act
+        let actual_output = formatter.format(input_number);
+
+        // Assert
+        assert!(actual_output.is_ok());
+        assert_eq!(expected_output, actual_output.unwrap());
+    }
+
+    #[test]
+    fn command_context_should_add_binary_to_requested_output_types_when_visualizing() {
+        // Arrange
+        let command_options = CommandOptions {
+            number: String::from("5"),
+            output: vec![NumberType::Integer],
+            visualize: true,
+            ..Default::default()
+        };
+
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let output = command_context.format_all_number_types();
+
+        // Assert
+        assert_eq!(
+            output.unwrap(),
+            vec![
+                (NumberType::Integer, String::from("5")),
+                (NumberType::Binary, String::from("101")),
+            ]
+        );
+    }
+
+    #[test]
+    fn command_context_should_only_format_requested_output_types() {
+        // Arrange
+        let command_options = CommandOptions {
+            number: String::from("123456"),
+            output: vec![NumberType::Base36, NumberType::Integer],
+            ..Default::default()
+        };
+
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let output = command_context.format_all_number_types();
+
+        // Assert
         assert!(output.is_ok());
         let vec = output.unwrap();
 
-        assert_eq!(vec.len(), 3);
-        assert_eq!(vec[0].0, NumberType::Integer);
-        assert_eq!(vec[1].0, NumberType::Hexadecimal);
-        assert_eq!(vec[2].0, NumberType::Binary);
+        assert_eq!(
+            vec,
+            vec![
+                (NumberType::Base36, String::from("2n9c")),
+                (NumberType::Integer, String::from("123456")),
+            ]
+        );
+    }
+
+    #[test_case("en-US", "1,234,567")]
+    #[test_case("de-DE", "1.234.567")]
+    #[test_case("en-IN", "12,34,567")]
+    fn integer_number_formatter_should_group_digits_per_locale(tag: &str, expected: &str) {
+        // Arrange
+        let command_options = CommandOptions {
+            locale: Some(locale::parse_locale(tag).unwrap()),
+            ..Default::default()
+        };
+        let formatter =
+            NumberFormatterFactory::new_number_formatter(&NumberType::Integer, &command_options);
+
+        // Act
+        let actual_output = formatter.format(1234567);
+
+        // Assert
+        assert!(actual_output.is_ok());
+        assert_eq!(expected, actual_output.unwrap());
+    }
+
+    #[test_case("en-US", "1,234,567")]
+    #[test_case("de-DE", "1.234.567")]
+    #[test_case("fr-FR", "1 234 567")]
+    #[test_case("en-IN", "12,34,567")]
+    #[test_case("en-IN", "1234567"; "ungrouped")]
+    fn integer_number_formatter_should_read_digits_grouped_per_locale(tag: &str, input: &str) {
+        // Arrange
+        let command_options = CommandOptions {
+            locale: Some(locale::parse_locale(tag).unwrap()),
+            ..Default::default()
+        };
+        let formatter =
+            NumberFormatterFactory::new_number_formatter(&NumberType::Integer, &command_options);
+
+        // Act
+        let actual_output = formatter.read(input);
+
+        // Assert
+        assert!(actual_output.is_ok());
+        assert_eq!(1234567, actual_output.unwrap());
+    }
+
+    #[test_case("٤٢", NumberType::Integer, "42"; "arabic indic integer")]
+    #[test_case("１０", NumberType::Binary, "1010"; "full width integer to binary")]
+    #[test_case("０ｘ２Ａ", NumberType::Integer, "42"; "full width prefixed hexadecimal")]
+    fn format_all_number_types_should_accept_non_ascii_digits(
+        number: &str,
+        number_type: NumberType,
+        expected: &str,
+    ) {
+        // Arrange
+        let command_options = CommandOptions {
+            number: number.to_string(),
+            output: vec![number_type],
+            ..Default::default()
+        };
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let actual_output = command_context.format_all_number_types();
+
+        // Assert
+        assert!(actual_output.is_ok());
+        assert_eq!(
+            actual_output.unwrap(),
+            vec![(number_type, expected.to_string())]
+        );
+    }
+
+    #[test_case("'€'", false; "quoted")]
+    #[test_case("€", true; "char option")]
+    fn format_all_number_types_should_read_characters(number: &str, character: bool) {
+        // Arrange
+        let command_options = CommandOptions {
+            number: number.to_string(),
+            character,
+            ..Default::default()
+        };
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let actual_output = command_context.format_all_number_types();
+
+        // Assert
+        assert!(actual_output.is_ok());
+        assert_eq!(
+            actual_output.unwrap(),
+            vec![
+                (NumberType::Integer, String::from("8364")),
+                (NumberType::Hexadecimal, String::from("20ac")),
+                (NumberType::Binary, String::from("10000010101100")),
+                (NumberType::Codepoint, String::from("U+20AC €")),
+                (NumberType::Character, String::from("'€' (UTF-8 e2 82 ac)")),
+            ]
+        );
+    }
+
+    #[test_case("ff", "-1", "ff", "11111111")]
+    #[test_case("7f", "127", "7f", "01111111")]
+    #[test_case("80", "-128", "80", "10000000")]
+    fn format_all_number_types_should_interpret_width(
+        number: &str,
+        integer: &str,
+        hexadecimal: &str,
+        binary: &str,
+    ) {
+        // Arrange
+        let command_options = CommandOptions {
+            width: Some(8),
+            ..CommandOptions::new(NumberType::Hexadecimal, number)
+        };
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let actual_output = command_context.format_all_number_types();
+
+        // Assert
+        assert!(actual_output.is_ok());
+        assert_eq!(
+            actual_output.unwrap(),
+            vec![
+                (NumberType::Integer, integer.to_string()),
+                (NumberType::Hexadecimal, hexadecimal.to_string()),
+                (NumberType::Binary, binary.to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn format_all_number_types_should_reject_number_wider_than_width() {
+        // Arrange
+        let command_options = CommandOptions {
+            width: Some(8),
+            ..CommandOptions::new(NumberType::Hexadecimal, "100")
+        };
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let actual_output = command_context.format_all_number_types();
+
+        // Assert
+        assert!(actual_output.is_err());
+        assert_eq!(
+            std::mem::discriminant(&actual_output.unwrap_err()),
+            std::mem::discriminant(&NumberFormatterError::TooLargeError)
+        );
+    }
+
+    #[test_case("u8", "255", "ff", "11111111")]
+    #[test_case("i8", "-1", "ff", "11111111")]
+    #[test_case("u16", "255", "00ff", "0000000011111111")]
+    fn format_all_number_types_should_interpret_size(
+        size: &str,
+        integer: &str,
+        hexadecimal: &str,
+        binary: &str,
+    ) {
+        // Arrange
+        let command_options = CommandOptions {
+            size: Some(size::parse_size(size).unwrap()),
+            ..CommandOptions::new(NumberType::Hexadecimal, "ff")
+        };
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let actual_output = command_context.format_all_number_types();
+
+        // Assert
+        assert!(actual_output.is_ok());
+        assert_eq!(
+            actual_output.unwrap(),
+            vec![
+                (NumberType::Integer, integer.to_string()),
+                (NumberType::Hexadecimal, hexadecimal.to_string()),
+                (NumberType::Binary, binary.to_string()),
+            ]
+        );
+    }
+
+    #[test_case(
+        NumberType::Hexadecimal,
+        "0xffffffffffffffffffffffffffffffff",
+        NumberType::Integer,
+        "340282366920938463463374607431768211455"
+    )]
+    #[test_case(
+        NumberType::Integer,
+        "170141183460469231731687303715884105728",
+        NumberType::Hexadecimal,
+        "80000000000000000000000000000000"
+    )]
+    #[test_case(
+        NumberType::Integer,
+        "340282366920938463463374607431768211455",
+        NumberType::Base36,
+        "f5lxx1zz5pnorynqglhzmsp33"
+    )]
+    #[test_case(
+        NumberType::Integer,
+        "340282366920938463463374607431768211455",
+        NumberType::Gray,
+        "10000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"
+    )]
+    #[test_case(
+        NumberType::Hexadecimal,
+        "0xffffffffffffffffffffffffffffffff",
+        NumberType::Column,
+        "BCGDENLQRQWDSLRUGSNLBTMFIJAU"
+    )]
+    #[test_case(
+        NumberType::Hexadecimal,
+        "0xffffffffffffffffffffffffffffffff",
+        NumberType::Sexagesimal,
+        "15:30:42:36:26:26:06:27:10:42:35:01:04:29:55:43:39:02:36:32:04:15"
+    )]
+    #[test_case(
+        NumberType::Hexadecimal,
+        "0xffffffffffffffffffffffffffffffff",
+        NumberType::Bcd,
+        "0x340282366920938463463374607431768211455"
+    )]
+    #[test_case(
+        NumberType::Hexadecimal,
+        "0xffffffffffffffffffffffffffffffff",
+        NumberType::Uleb128,
+        "ff ff ff ff ff ff ff ff ff ff ff ff ff ff ff ff ff ff 03"
+    )]
+    fn format_all_number_types_should_support_full_u128_range(
+        input_type: NumberType,
+        number: &str,
+        output_type: NumberType,
+        expected: &str,
+    ) {
+        // Arrange
+        let command_options = CommandOptions {
+            output: vec![output_type],
+            ..CommandOptions::new(input_type, number)
+        };
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let actual_output = command_context.format_all_number_types();
+
+        // Assert
+        assert!(actual_output.is_ok());
+        assert_eq!(
+            actual_output.unwrap(),
+            vec![(output_type, expected.to_string())]
+        );
+    }
+
+    #[test_case(None, NumberType::Roman; "number type without u128 support")]
+    #[test_case(Some(IntegerSize { bits: 64, signed: false }), NumberType::Integer; "narrower size")]
+    fn format_all_number_types_should_reject_u128_values_that_do_not_fit(
+        size: Option<IntegerSize>,
+        output_type: NumberType,
+    ) {
+        // Arrange
+        let command_options = CommandOptions {
+            output: vec![output_type],
+            size,
+            ..CommandOptions::new(NumberType::Hexadecimal, "ffffffffffffffffffffffffffffffff")
+        };
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let actual_output = command_context.format_all_number_types();
+
+        // Assert
+        assert!(actual_output.is_err());
+        assert_eq!(
+            std::mem::discriminant(&actual_output.unwrap_err()),
+            std::mem::discriminant(&NumberFormatterError::TooLargeError)
+        );
+    }
+
+    #[test]
+    fn format_all_number_types_should_read_u128_bit_pattern_at_signed_128_bits() {
+        // Arrange
+        let command_options = CommandOptions {
+            output: vec![NumberType::Integer],
+            width: Some(128),
+            ..CommandOptions::new(NumberType::Hexadecimal, "ffffffffffffffffffffffffffffffff")
+        };
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let actual_output = command_context.format_all_number_types();
+
+        // Assert
+        assert!(actual_output.is_ok());
+        assert_eq!(
+            actual_output.unwrap(),
+            vec![(NumberType::Integer, String::from("-1"))]
+        );
+    }
+
+    #[test_case(8, 32, "ff", "-1", "ffffffff"; "negative byte to word")]
+    #[test_case(8, 32, "7f", "127", "0000007f"; "positive byte to word")]
+    #[test_case(16, 64, "8000", "-32768", "ffffffffffff8000"; "halfword to doubleword")]
+    #[test_case(8, 8, "80", "-128", "80"; "same width")]
+    fn sign_extend_should_extend_sign_bit(
+        from: u32,
+        to: u32,
+        number: &str,
+        integer: &str,
+        hexadecimal: &str,
+    ) {
+        // Arrange
+        let command_options = CommandOptions {
+            number_type: Some(NumberType::Hexadecimal),
+            output: vec![NumberType::Integer, NumberType::Hexadecimal],
+            ..Default::default()
+        };
+        let sext = SextOptions {
+            from,
+            to,
+            number: number.to_string(),
+        };
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let actual_output = command_context.sign_extend(&sext);
+
+        // Assert
+        assert!(actual_output.is_ok());
+        assert_eq!(
+            actual_output.unwrap(),
+            vec![
+                (NumberType::Integer, integer.to_string()),
+                (NumberType::Hexadecimal, hexadecimal.to_string()),
+            ]
+        );
+    }
+
+    #[test_case(32, 8, "ff", NumberFormatterError::NarrowingExtension; "narrowing")]
+    #[test_case(8, 32, "100", NumberFormatterError::TooLargeError; "wider than source")]
+    fn sign_extend_should_fail(from: u32, to: u32, number: &str, expected: NumberFormatterError) {
+        // Arrange
+        let command_options = CommandOptions {
+            number_type: Some(NumberType::Hexadecimal),
+            ..Default::default()
+        };
+        let sext = SextOptions {
+            from,
+            to,
+            number: number.to_string(),
+        };
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let actual_output = command_context.sign_extend(&sext);
+
+        // Assert
+        assert!(actual_output.is_err());
+        assert_eq!(
+            std::mem::discriminant(&actual_output.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+
+    #[test_case(None, "0xf0", 4, 120; "without size")]
+    #[test_case(Some(16), "0xf0", 4, 8; "with width")]
+    #[test_case(Some(8), "0x80", 1, 0; "negative with width")]
+    fn bit_info_should_count_bits_of_number(
+        width: Option<u32>,
+        number: &str,
+        popcount: u32,
+        leading_zeros: u32,
+    ) {
+        // Arrange
+        let command_options = CommandOptions {
+            number_type: Some(NumberType::Hexadecimal),
+            width,
+            ..Default::default()
+        };
+        let bits = BitsOptions {
+            number: number.to_string(),
+        };
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let actual = command_context.bit_info(&bits);
+
+        // Assert
+        assert!(actual.is_ok());
+        let actual = actual.unwrap();
+        assert_eq!(actual.popcount, popcount);
+        assert_eq!(actual.leading_zeros, leading_zeros);
+    }
+
+    #[test_case("0x90", None, Ok((12, None)))]
+    #[test_case("0b11110011", Some(5), Ok((15, Some((5, 3)))); "with degree")]
+    #[test_case("-4", None, Err(NumberFormatterError::TooSmallError); "negative")]
+    fn root_should_take_roots_of_number(
+        number: &str,
+        degree: Option<u32>,
+        expected: anyhow::Result<(u128, Option<(u32, u128)>), NumberFormatterError>,
+    ) {
+        // Arrange
+        let options = RootOptions {
+            number: number.to_string(),
+            degree,
+        };
+        let command_context = CommandContext::new(CommandOptions::default());
+
+        // Act
+        let actual = command_context
+            .root(&options)
+            .map(|info| (info.square_root, info.root));
+
+        // Assert
+        assert_eq!(format!("{:?}", actual), format!("{:?}", expected));
+    }
+
+    #[test_case("0x168", Ok(vec![(2, 3), (3, 2), (5, 1)]))]
+    #[test_case("0", Err(NumberFormatterError::TooSmallError); "zero")]
+    #[test_case("-6", Err(NumberFormatterError::TooSmallError); "negative")]
+    fn factor_should_factor_number(
+        number: &str,
+        expected: anyhow::Result<Vec<(u128, u32)>, NumberFormatterError>,
+    ) {
+        // Arrange
+        let options = FactorOptions {
+            number: number.to_string(),
+        };
+        let command_context = CommandContext::new(CommandOptions::default());
+
+        // Act
+        let actual = command_context.factor(&options).map(|info| info.factors);
+
+        // Assert
+        assert_eq!(format!("{:?}", actual), format!("{:?}", expected));
+    }
+
+    #[test]
+    fn info_should_describe_number() {
+        // Arrange
+        let options = InfoOptions {
+            number: String::from("0x61"),
+        };
+        let command_context = CommandContext::new(CommandOptions::default());
+
+        // Act
+        let actual = command_context.info(&options);
+
+        // Assert
+        let actual = actual.unwrap();
+        assert_eq!(actual.magnitude, 97);
+        assert!(actual.prime);
+        assert_eq!(actual.divisors, Some(2));
+    }
+
+    #[test_case("4242424242424242", true)]
+    #[test_case("4242424242424241", false)]
+    #[test_case("0x3b", true; "hexadecimal")]
+    #[test_case("4539 1488 0343 6467", true; "grouped with spaces")]
+    #[test_case("35-209900-176148-1", true; "grouped with hyphens")]
+    fn luhn_should_validate_check_digit(number: &str, valid: bool) {
+        // Arrange
+        let command_options = CommandOptions {
+            number_type: Some(NumberType::detect(number)),
+            ..Default::default()
+        };
+        let options = LuhnOptions {
+            number: number.to_string(),
+        };
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let actual = command_context.luhn(&options);
+
+        // Assert
+        assert_eq!(actual.unwrap().valid, valid);
+    }
+
+    #[test_case("45244.5", "45244.5", "2023-11-14T12:00:00")]
+    #[test_case("2023-11-14T12:00:00", "45244.5", "2023-11-14T12:00:00"; "from date")]
+    fn excel_should_convert_serial_and_date(value: &str, serial: &str, date: &str) {
+        // Arrange
+        let options = ExcelOptions {
+            value: value.to_string(),
+            date1904: false,
+        };
+        let command_context = CommandContext::new(CommandOptions::default());
+
+        // Act
+        let actual = command_context.excel(&options).unwrap();
+
+        // Assert
+        assert_eq!(actual.serial, serial);
+        assert_eq!(actual.date, date);
+    }
+
+    #[test_case(CalcOperator::Mul, "0x10", "3", None, Truncate::Error, Ok(("48", false)))]
+    #[test_case(CalcOperator::Add, "0x7f", "1", Some(8), Truncate::Error, Err(NumberFormatterError::TooLargeError); "overflows width")]
+    #[test_case(CalcOperator::Add, "0x7f", "1", Some(8), Truncate::Wrap, Ok(("-128", true)); "wraps")]
+    #[test_case(CalcOperator::Pow, "3", "5", Some(8), Truncate::Saturate, Ok(("127", true)); "saturates")]
+    fn calc_should_detect_overflow(
+        operator: CalcOperator,
+        first: &str,
+        second: &str,
+        width: Option<u32>,
+        truncate: Truncate,
+        expected: anyhow::Result<(&str, bool), NumberFormatterError>,
+    ) {
+        // Arrange
+        let options = CalcOptions {
+            operator,
+            first: first.to_string(),
+            second: second.to_string(),
+        };
+        let command_context = CommandContext::new(CommandOptions {
+            output: vec![NumberType::Integer],
+            width,
+            truncate,
+            ..Default::default()
+        });
+
+        // Act
+        let actual = command_context.calc(&options).map(|calculation| {
+            (
+                calculation.outputs[0].1.clone(),
+                calculation.warning.is_some(),
+            )
+        });
+
+        // Assert
+        assert_eq!(
+            format!("{:?}", actual),
+            format!(
+                "{:?}",
+                expected.map(|(output, warning)| (output.to_string(), warning))
+            )
+        );
+    }
+
+    #[test_case("3", "11", None, &[("a mod n", "3"), ("Inverse", "4")], None)]
+    #[test_case("-1", "0x10", Some("2"), &[("a mod n", "15"), ("Inverse", "15"), ("a^b mod n", "1")], None; "negative")]
+    #[test_case("4", "497", Some("13"), &[("a mod n", "4"), ("Inverse", "373"), ("a^b mod n", "445")], None; "power")]
+    #[test_case("3", "11", Some("-1"), &[("a mod n", "3"), ("Inverse", "4"), ("a^b mod n", "4")], None; "negative power")]
+    #[test_case("6", "9", Some("2"), &[("a mod n", "6"), ("a^b mod n", "0")], Some(3); "no inverse")]
+    fn modular_should_reduce_number(
+        number: &str,
+        modulus: &str,
+        power: Option<&str>,
+        expected: &[(&'static str, &str)],
+        common_factor: Option<u128>,
+    ) {
+        // Arrange
+        let command_options = CommandOptions {
+            output: vec![NumberType::Integer],
+            ..Default::default()
+        };
+        let options = ModOptions {
+            number: number.to_string(),
+            modulus: modulus.to_string(),
+            power: power.map(str::to_string),
+        };
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let actual = command_context.modular(&options);
+
+        // Assert
+        let outputs: LabelledOutputs = expected
+            .iter()
+            .map(|(label, output)| (*label, vec![(NumberType::Integer, output.to_string())]))
+            .collect();
+        assert_eq!(
+            actual.unwrap(),
+            ModularInfo {
+                outputs,
+                common_factor
+            }
+        );
+    }
+
+    #[test_case("6", "9", "-1", NumberFormatterError::NoInverse)]
+    #[test_case("6", "0", "1", NumberFormatterError::DivisionByZero; "zero modulus")]
+    #[test_case("6", "-9", "1", NumberFormatterError::TooSmallError; "negative modulus")]
+    fn modular_should_fail(
+        number: &str,
+        modulus: &str,
+        power: &str,
+        expected: NumberFormatterError,
+    ) {
+        // Arrange
+        let options = ModOptions {
+            number: number.to_string(),
+            modulus: modulus.to_string(),
+            power: Some(power.to_string()),
+        };
+        let command_context = CommandContext::new(CommandOptions::default());
+
+        // Act
+        let actual = command_context.modular(&options);
+
+        // Assert
+        assert_eq!(
+            std::mem::discriminant(&actual.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+
+    #[test_case(false, &["0x30", "0b100100", "-60"], "12", 8)]
+    #[test_case(true, &["4", "0x6", "10"], "60", 11; "least common multiple")]
+    #[test_case(false, &["0", "0"], "0", 2; "zeros")]
+    fn divisor_should_combine_numbers(
+        multiple: bool,
+        numbers: &[&str],
+        expected: &str,
+        steps: usize,
+    ) {
+        // Arrange
+        let command_options = CommandOptions {
+            output: vec![NumberType::Integer],
+            ..Default::default()
+        };
+        let options = DivisorOptions {
+            numbers: numbers.iter().map(|number| number.to_string()).collect(),
+            verbose: true,
+        };
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let actual = if multiple {
+            command_context.lcm(&options)
+        } else {
+            command_context.gcd(&options)
+        };
+
+        // Assert
+        let actual = actual.unwrap();
+        assert_eq!(
+            actual.outputs,
+            vec![(NumberType::Integer, expected.to_string())]
+        );
+        assert_eq!(actual.steps.len(), steps);
+    }
+
+    #[test_case("1.5", None, 0x3ff8000000000000)]
+    #[test_case("0x3fc00000", Some(FloatFormat::F32), 0x3fc00000; "bit pattern")]
+    #[test_case("-2.5", Some(FloatFormat::F32), 0xc0200000; "negative single precision")]
+    fn float_bits_should_read_float_or_bit_pattern(
+        number: &str,
+        float: Option<FloatFormat>,
+        pattern: u128,
+    ) {
+        // Arrange
+        let options = FloatBitsOptions {
+            number: number.to_string(),
+        };
+        let command_context = CommandContext::new(CommandOptions {
+            float,
+            ..Default::default()
+        });
+
+        // Act
+        let actual = command_context.float_bits(&options);
+
+        // Assert
+        assert_eq!(actual.unwrap().pattern, pattern);
+    }
+
+    #[test_case("0.3", "0.30000000000000004", None, 1)]
+    #[test_case("0x3f800000", "1.0000002", Some(FloatFormat::F32), 2; "bit pattern")]
+    fn ulp_should_count_floats_between(
+        first: &str,
+        second: &str,
+        float: Option<FloatFormat>,
+        expected: i128,
+    ) {
+        // Arrange
+        let options = UlpOptions {
+            first: first.to_string(),
+            second: second.to_string(),
+        };
+        let command_context = CommandContext::new(CommandOptions {
+            float,
+            ..Default::default()
+        });
+
+        // Act
+        let actual = command_context.ulp(&options);
+
+        // Assert
+        assert_eq!(actual.unwrap().distance, expected);
+    }
+
+    #[test_case("3.14159", false, (355, 113))]
+    #[test_case("0.1", true, (1, 10); "as float")]
+    fn ratio_should_find_closest_fraction(value: &str, as_float: bool, expected: (u128, u128)) {
+        // Arrange
+        let options = RatioOptions {
+            value: value.to_string(),
+            max_denominator: 1000,
+            as_float,
+        };
+        let command_context = CommandContext::new(CommandOptions::default());
+
+        // Act
+        let actual = command_context.ratio(&options).unwrap();
+
+        // Assert
+        let closest = actual.approximations.last().unwrap();
+        assert_eq!((closest.numerator, closest.denominator), expected);
+    }
+
+    #[test_case("0xc000", None, -0x4000, None)]
+    #[test_case("0.1", None, 0xccd, Some(Rounding::Rounded(3277.0 / 32768.0 - 0.1)); "real")]
+    #[test_case("300.0", Some("Q8.8"), 0x7fff, Some(Rounding::Saturated); "saturates")]
+    fn fixed_point_should_convert_raw_and_real(
+        value: &str,
+        q_format: Option<&str>,
+        raw: i128,
+        rounding: Option<Rounding>,
+    ) {
+        // Arrange
+        let options = FixedPointOptions {
+            value: value.to_string(),
+        };
+        let command_context = CommandContext::new(CommandOptions {
+            q_format: q_format.map(|format| fixed_point::parse_q_format(format).unwrap()),
+            ..Default::default()
+        });
+
+        // Act
+        let actual = command_context.fixed_point(&options).unwrap();
+
+        // Assert
+        assert_eq!(actual.raw, raw);
+        assert_eq!(
+            format!("{:.6?}", actual.rounding),
+            format!("{:.6?}", rounding)
+        );
+    }
+
+    #[test_case("6/14", "3/7", "0.[428571]")]
+    #[test_case("0.1[6]", "1/6", "0.1[6]"; "from repeating decimal")]
+    fn fraction_should_reduce_and_write_decimal(value: &str, fraction: &str, decimal: &str) {
+        // Arrange
+        let options = FractionOptions {
+            value: value.to_string(),
+        };
+        let command_context = CommandContext::new(CommandOptions::default());
+
+        // Act
+        let actual = command_context.fraction(&options).unwrap().lines();
+
+        // Assert
+        assert!(actual.contains(&("Fraction", fraction.to_string())));
+        assert!(actual.contains(&("Decimal", decimal.to_string())));
+    }
+
+    #[test_case("33", 33, [0, 135, 255], false)]
+    #[test_case("0xc4", 196, [255, 0, 0], false; "hexadecimal index")]
+    #[test_case("#1e90ff", 33, [0, 135, 255], true; "nearest to hex color")]
+    #[test_case("128,128,128", 244, [128, 128, 128], true; "nearest to components")]
+    fn xterm_should_convert_index_and_color(color: &str, index: u8, rgb: [u8; 3], nearest: bool) {
+        // Arrange
+        let command_options = CommandOptions {
+            number_type: Some(NumberType::detect(color)),
+            ..Default::default()
+        };
+        let options = XtermOptions {
+            color: color.to_string(),
+        };
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let actual = command_context.xterm(&options);
+
+        // Assert
+        assert_eq!(
+            actual.unwrap(),
+            XtermInfo {
+                index,
+                rgb,
+                nearest
+            }
+        );
+    }
+
+    #[test_case(None, "4..7,31", "800000f0", "7fffff0f")]
+    #[test_case(None, "0..3", "0f", "f0")]
+    #[test_case(Some(16), "15", "8000", "7fff"; "with width")]
+    fn mask_should_build_mask_and_inverse(
+        width: Option<u32>,
+        ranges: &str,
+        mask: &str,
+        inverse: &str,
+    ) {
+        // Arrange
+        let command_options = CommandOptions {
+            output: vec![NumberType::Hexadecimal],
+            width,
+            ..Default::default()
+        };
+        let options = MaskOptions {
+            ranges: ranges
+                .split(',')
+                .map(|range| bit_range::parse_bit_range(range).unwrap())
+                .collect(),
+        };
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let actual_output = command_context.mask(&options);
+
+        // Assert
+        assert!(actual_output.is_ok());
+        assert_eq!(
+            actual_output.unwrap(),
+            vec![
+                ("Mask", vec![(NumberType::Hexadecimal, mask.to_string())]),
+                (
+                    "Inverse",
+                    vec![(NumberType::Hexadecimal, inverse.to_string())]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn mask_should_reject_bits_outside_size() {
+        // Arrange
+        let command_options = CommandOptions {
+            width: Some(8),
+            ..Default::default()
+        };
+        let options = MaskOptions {
+            ranges: vec![BitRange { low: 8, high: 8 }],
+        };
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let actual_output = command_context.mask(&options);
+
+        // Assert
+        assert!(matches!(
+            actual_output,
+            Err(NumberFormatterError::TooLargeError)
+        ));
+    }
+
+    #[test_case("0x1234", Some(0x1000), None, false, "1000")]
+    #[test_case("0x1234", None, Some(0x1000), false, "2000")]
+    #[test_case("0x1234", None, None, true, "2000"; "next power of two")]
+    fn format_all_number_types_should_round_number(
+        number: &str,
+        align_down: Option<u128>,
+        align_up: Option<u128>,
+        next_power_of_two: bool,
+        hexadecimal: &str,
+    ) {
+        // Arrange
+        let command_options = CommandOptions {
+            output: vec![NumberType::Hexadecimal],
+            align_down,
+            align_up,
+            next_power_of_two,
+            ..CommandOptions::new(NumberType::Hexadecimal, number)
+        };
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let actual_output = command_context.format_all_number_types();
+
+        // Assert
+        assert!(actual_output.is_ok());
+        assert_eq!(
+            actual_output.unwrap(),
+            vec![(NumberType::Hexadecimal, hexadecimal.to_string())]
+        );
+    }
+
+    #[test_case("0x12345678", 4096, "74565", "12345", "1656", "678")]
+    #[test_case("0x200003", 2 << 20, "1", "1", "3", "3"; "huge page")]
+    fn page_should_split_address(
+        number: &str,
+        page_size: u128,
+        page_integer: &str,
+        page_hexadecimal: &str,
+        offset_integer: &str,
+        offset_hexadecimal: &str,
+    ) {
+        // Arrange
+        let command_options = CommandOptions {
+            number_type: Some(NumberType::Hexadecimal),
+            ..Default::default()
+        };
+        let options = PageOptions {
+            page_size,
+            number: number.to_string(),
+        };
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let actual_output = command_context.page(&options);
+
+        // Assert
+        assert!(actual_output.is_ok());
+        assert_eq!(
+            actual_output.unwrap(),
+            vec![
+                (
+                    "Page",
+                    vec![
+                        (NumberType::Integer, page_integer.to_string()),
+                        (NumberType::Hexadecimal, page_hexadecimal.to_string()),
+                    ]
+                ),
+                (
+                    "Offset",
+                    vec![
+                        (NumberType::Integer, offset_integer.to_string()),
+                        (NumberType::Hexadecimal, offset_hexadecimal.to_string()),
+                    ]
+                ),
+            ]
+        );
+    }
+
+    #[test_case("0x1000", "0x234", None, "4660", "-3532", None)]
+    #[test_case("0x1000", "0x1800", Some("0x1000"), "10240", "2048", Some(true))]
+    #[test_case("0x1000", "0x2000", Some("0x1000"), "12288", "4096", Some(false); "end is outside")]
+    #[test_case("0x1000", "0xfff", Some("0x1000"), "8191", "-1", Some(false); "below base")]
+    fn address_arithmetic_should_combine_addresses(
+        base: &str,
+        address: &str,
+        length: Option<&str>,
+        sum: &str,
+        difference: &str,
+        within: Option<bool>,
+    ) {
+        // Arrange
+        let command_options = CommandOptions {
+            number_type: Some(NumberType::Hexadecimal),
+            output: vec![NumberType::Integer],
+            ..Default::default()
+        };
+        let options = AddrOptions {
+            base: base.to_string(),
+            address: address.to_string(),
+            length: length.map(str::to_string),
+        };
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let actual = command_context.address_arithmetic(&options);
+
+        // Assert
+        assert!(actual.is_ok());
+        let actual = actual.unwrap();
+        assert_eq!(
+            actual.outputs[..2],
+            [
+                ("Sum", vec![(NumberType::Integer, sum.to_string())]),
+                (
+                    "Difference",
+                    vec![(NumberType::Integer, difference.to_string())]
+                ),
+            ]
+        );
+        assert_eq!(actual.within, within);
+    }
+
+    #[test_case(Value::Signed(5), Value::Signed(-7), Value::Signed(-2), Value::Signed(12))]
+    #[test_case(Value::Signed(i128::MAX), Value::Signed(1), Value::Unsigned(1 << 127), Value::Signed(i128::MAX - 1); "above i128 max")]
+    #[test_case(Value::Unsigned(u128::MAX - 1), Value::Signed(1), Value::Unsigned(u128::MAX), Value::Unsigned(u128::MAX - 2); "unsigned")]
+    #[test_case(Value::Signed(i128::MIN), Value::Signed(0), Value::Signed(i128::MIN), Value::Signed(i128::MIN); "i128 min")]
+    fn value_should_add_and_subtract(a: Value, b: Value, sum: Value, difference: Value) {
+        // Act
+        let actual_sum = a.checked_add(b);
+        let actual_difference = a.checked_sub(b);
+
+        // Assert
+        assert_eq!(actual_sum.unwrap(), sum);
+        assert_eq!(actual_difference.unwrap(), difference);
+    }
+
+    #[test_case(None, "0x12345678", "12345678", "78563412")]
+    #[test_case(None, "0x1234", "1234", "3412"; "narrowest width")]
+    #[test_case(Some(32), "0x1234", "00001234", "34120000"; "with width")]
+    #[test_case(None, "-2", "fe", "fe"; "negative")]
+    fn byte_swap_should_reverse_bytes(
+        width: Option<u32>,
+        number: &str,
+        original: &str,
+        swapped: &str,
+    ) {
+        // Arrange
+        let command_options = CommandOptions {
+            number_type: Some(NumberType::detect(number)),
+            output: vec![NumberType::Hexadecimal],
+            width,
+            ..Default::default()
+        };
+        let options = SwapOptions {
+            number: number.to_string(),
+        };
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let actual_output = command_context.byte_swap(&options);
+
+        // Assert
+        assert!(actual_output.is_ok());
+        assert_eq!(
+            actual_output.unwrap(),
+            vec![
+                (
+                    "Original",
+                    vec![(NumberType::Hexadecimal, original.to_string())]
+                ),
+                (
+                    "Swapped",
+                    vec![(NumberType::Hexadecimal, swapped.to_string())]
+                ),
+            ]
+        );
+    }
+
+    #[test_case(ShiftOperation::Shl, None, "0x81", 1, &[("Original", "81"), ("Shifted", "02"), ("Shifted out", "01")])]
+    #[test_case(ShiftOperation::Shr, None, "0x8f", 4, &[("Original", "8f"), ("Logical", "08"), ("Arithmetic", "f8"), ("Shifted out", "0f")]; "right")]
+    #[test_case(ShiftOperation::Sar, Some(16), "0x8f", 4, &[("Original", "008f"), ("Arithmetic", "0008"), ("Logical", "0008"), ("Shifted out", "0f")]; "arithmetic with width")]
+    #[test_case(ShiftOperation::Rol, Some(32), "0x12345678", 8, &[("Original", "12345678"), ("Rotated", "34567812")]; "rotate")]
+    #[test_case(ShiftOperation::Shl, None, "0xff", 12, &[("Original", "ff"), ("Shifted", "00"), ("Shifted out", "ff")]; "past width")]
+    fn shift_should_shift_within_width(
+        operation: ShiftOperation,
+        width: Option<u32>,
+        number: &str,
+        count: u32,
+        expected: &[(&'static str, &str)],
+    ) {
+        // Arrange
+        let command_options = CommandOptions {
+            number_type: Some(NumberType::detect(number)),
+            output: vec![NumberType::Hexadecimal],
+            width,
+            ..Default::default()
+        };
+        let options = ShiftOptions {
+            operation,
+            number: number.to_string(),
+            count,
+        };
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let actual_output = command_context.shift(&options);
+
+        // Assert
+        let expected: LabelledOutputs = expected
+            .iter()
+            .map(|(label, output)| (*label, vec![(NumberType::Hexadecimal, output.to_string())]))
+            .collect();
+        assert_eq!(actual_output.unwrap(), expected);
+    }
+
+    #[test_case(None, "0xc", "0x1234", &["000c", "1234", "0004", "123c", "1238", "fff3", "edcb", "0008"])]
+    #[test_case(Some(8), "0xc", "0xa", &["0c", "0a", "08", "0e", "06", "f3", "f5", "04"]; "with width")]
+    #[test_case(None, "-1", "0x5", &["ff", "05", "05", "ff", "fa", "00", "fa", "fa"]; "negative")]
+    fn bitwise_should_combine_numbers(
+        width: Option<u32>,
+        first: &str,
+        second: &str,
+        expected: &[&str],
+    ) {
+        // Arrange
+        let command_options = CommandOptions {
+            number_type: None,
+            output: vec![NumberType::Hexadecimal],
+            width,
+            ..Default::default()
+        };
+        let options = BitwiseOptions {
+            first: first.to_string(),
+            second: second.to_string(),
+        };
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let actual_output = command_context.bitwise(&options);
+
+        // Assert
+        let labels = [
+            "a",
+            "b",
+            "a AND b",
+            "a OR b",
+            "a XOR b",
+            "NOT a",
+            "NOT b",
+            "a AND NOT b",
+        ];
+        let expected: LabelledOutputs = labels
+            .iter()
+            .zip(expected)
+            .map(|(label, output)| (*label, vec![(NumberType::Hexadecimal, output.to_string())]))
+            .collect();
+        assert_eq!(actual_output.unwrap(), expected);
+    }
+
+    #[test_case(None, "0xf0", "0x10f", 16, &[0, 1, 2, 3, 4, 5, 6, 7, 8])]
+    #[test_case(Some(8), "-1", "0x7f", 8, &[7]; "negative with width")]
+    #[test_case(None, "-1", "1", 8, &[1, 2, 3, 4, 5, 6, 7]; "negative")]
+    fn hamming_should_compare_bits(
+        width: Option<u32>,
+        first: &str,
+        second: &str,
+        expected_width: u32,
+        positions: &[u32],
+    ) {
+        // Arrange
+        let command_options = CommandOptions {
+            width,
+            ..Default::default()
+        };
+        let options = HammingOptions {
+            first: first.to_string(),
+            second: second.to_string(),
+        };
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let actual = command_context.hamming(&options);
+
+        // Assert
+        assert_eq!(
+            actual.unwrap(),
+            HammingInfo {
+                width: expected_width,
+                positions: positions.to_vec(),
+            }
+        );
+    }
+
+    #[test]
+    fn clmul_should_multiply_polynomials() {
+        // Arrange
+        let command_options = CommandOptions {
+            output: vec![NumberType::Binary],
+            ..Default::default()
+        };
+        let options = ClmulOptions {
+            first: String::from("0b1011"),
+            second: String::from("0x6"),
+        };
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let actual = command_context.clmul(&options);
+
+        // Assert
+        assert_eq!(
+            actual.unwrap(),
+            ClmulInfo {
+                polynomials: vec![
+                    ("a", String::from("x^3 + x + 1")),
+                    ("b", String::from("x^2 + x")),
+                    ("a * b", String::from("x^5 + x^4 + x^3 + x")),
+                ],
+                outputs: vec![(
+                    "Product",
+                    vec![(NumberType::Binary, String::from("111010"))]
+                )],
+            }
+        );
+    }
+
+    #[test_case(None, None, None, 0, u64::MAX as i128)]
+    #[test_case(Some(8), None, None, -128, 127; "with width")]
+    #[test_case(None, Some("-3"), Some("0x3"), -3, 3; "range")]
+    #[test_case(None, Some("5"), Some("5"), 5, 5; "single number")]
+    fn random_should_stay_within_bounds(
+        width: Option<u32>,
+        min: Option<&str>,
+        max: Option<&str>,
+        low: i128,
+        high: i128,
+    ) {
+        // Arrange
+        let command_options = CommandOptions {
+            output: vec![NumberType::Integer],
+            width,
+            ..Default::default()
+        };
+        let options = RandomOptions {
+            count: 50,
+            min: min.map(str::to_string),
+            max: max.map(str::to_string),
+            seed: Some(1),
+        };
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let actual = command_context.random(&options);
+
+        // Assert
+        let actual = actual.unwrap();
+        assert_eq!(actual.len(), 50);
+        assert!(actual.iter().all(|outputs| {
+            let number = outputs[0].1.parse::<i128>().unwrap();
+            low <= number && number <= high
+        }));
+    }
+
+    #[test]
+    fn random_should_fail_for_empty_range() {
+        // Arrange
+        let options = RandomOptions {
+            count: 1,
+            min: Some(String::from("2")),
+            max: Some(String::from("1")),
+            seed: None,
+        };
+        let command_context = CommandContext::new(CommandOptions::default());
+
+        // Act
+        let actual = command_context.random(&options);
+
+        // Assert
+        assert_eq!(
+            std::mem::discriminant(&actual.unwrap_err()),
+            std::mem::discriminant(&NumberFormatterError::EmptyRange)
+        );
+    }
+
+    #[test_case("0x8..0xc", "1", &["8", "9", "a", "b"])]
+    #[test_case("0..=0x10", "0x8", &["0", "8", "10"]; "inclusive with step")]
+    #[test_case("-2..1", "1", &["fffffffffffffffffffffffffffffffe", "ffffffffffffffffffffffffffffffff", "0"]; "negative")]
+    #[test_case("3..3", "1", &[]; "empty")]
+    #[test_case("0xffffffffffffffffffffffffffffffff..=0xffffffffffffffffffffffffffffffff", "1", &["ffffffffffffffffffffffffffffffff"]; "largest")]
+    fn range_should_generate_numbers(range: &str, step: &str, expected: &[&str]) {
+        // Arrange
+        let command_options = CommandOptions {
+            output: vec![NumberType::Hexadecimal],
+            ..Default::default()
+        };
+        let options = RangeOptions {
+            range: range.to_string(),
+            step: step.to_string(),
+        };
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let actual = command_context
+            .range(&options)
+            .unwrap()
+            .collect::<anyhow::Result<Vec<_>, NumberFormatterError>>();
+
+        // Assert
+        let expected: Vec<Vec<(NumberType, String)>> = expected
+            .iter()
+            .map(|output| vec![(NumberType::Hexadecimal, output.to_string())])
+            .collect();
+        assert_eq!(actual.unwrap(), expected);
+    }
+
+    #[test_case("5..1", "1", NumberFormatterError::EmptyRange)]
+    #[test_case("1..5", "0", NumberFormatterError::TooSmallError; "zero step")]
+    #[test_case("1-5", "1", NumberFormatterError::InvalidRange; "not a range")]
+    fn range_should_fail(range: &str, step: &str, expected: NumberFormatterError) {
+        // Arrange
+        let options = RangeOptions {
+            range: range.to_string(),
+            step: step.to_string(),
+        };
+        let command_context = CommandContext::new(CommandOptions::default());
+
+        // Act
+        let actual = command_context.range(&options).map(|_| ());
+
+        // Assert
+        assert_eq!(
+            std::mem::discriminant(&actual.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+
+    #[test_case(None, None, "0xdeadbeef", &[("CRC-32", "7c9ca35a"), ("CRC-16/CCITT", "4097"), ("CRC-8", "ca")])]
+    #[test_case(None, None, "0x313233343536373839", &[("CRC-32", "cbf43926"), ("CRC-16/CCITT", "29b1"), ("CRC-8", "f4")]; "fewest bytes")]
+    #[test_case(Some(16), None, "0x1", &[("CRC-8", "07")]; "with width")]
+    #[test_case(None, Some(Endian::Little), "0x100", &[("CRC-8", "07")]; "little endian")]
+    fn crc_should_checksum_bytes(
+        width: Option<u32>,
+        endian: Option<Endian>,
+        number: &str,
+        expected: &[(&'static str, &str)],
+    ) {
+        // Arrange
+        let command_options = CommandOptions {
+            number_type: Some(NumberType::detect(number)),
+            output: vec![NumberType::Hexadecimal],
+            width,
+            endian,
+            ..Default::default()
+        };
+        let options = CrcOptions {
+            algorithm: match expected.len() {
+                1 => vec![CrcAlgorithm::Crc8],
+                _ => vec![],
+            },
+            number: number.to_string(),
+        };
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let actual_output = command_context.crc(&options);
+
+        // Assert
+        let expected: LabelledOutputs = expected
+            .iter()
+            .map(|(label, checksum)| {
+                (
+                    *label,
+                    vec![(NumberType::Hexadecimal, checksum.to_string())],
+                )
+            })
+            .collect();
+        assert_eq!(actual_output.unwrap(), expected);
+    }
+
+    #[test_case(Some(64), "0x0001f203f4f5f6f7", "ddf2", "220d")]
+    #[test_case(None, "0x010203", "0402", "fbfd"; "odd number of bytes")]
+    #[test_case(Some(32), "0x45", "0045", "ffba"; "with width")]
+    fn checksum_should_sum_words(width: Option<u32>, number: &str, sum: &str, checksum: &str) {
+        // Arrange
+        let command_options = CommandOptions {
+            number_type: Some(NumberType::detect(number)),
+            output: vec![NumberType::Hexadecimal],
+            width,
+            ..Default::default()
+        };
+        let options = ChecksumOptions {
+            number: number.to_string(),
+        };
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let actual_output = command_context.checksum(&options);
+
+        // Assert
+        assert_eq!(
+            actual_output.unwrap(),
+            vec![
+                ("Sum", vec![(NumberType::Hexadecimal, sum.to_string())]),
+                (
+                    "Checksum",
+                    vec![(NumberType::Hexadecimal, checksum.to_string())]
+                ),
+            ]
+        );
+    }
+
+    #[test_case(None, "/20", "255.255.240.0", 20, Some(4094))]
+    #[test_case(None, "255.255.255.0", "255.255.255.0", 24, Some(254); "netmask")]
+    #[test_case(Some(128), "/64", "ffff:ffff:ffff:ffff::", 64, Some(1 << 64); "ipv6")]
+    fn cidr_should_convert_prefix_length(
+        width: Option<u32>,
+        mask: &str,
+        netmask: &str,
+        prefix: u32,
+        usable_hosts: Option<u128>,
+    ) {
+        // Arrange
+        let output_type = match width {
+            Some(128) => NumberType::Ipv6,
+            _ => NumberType::Ipv4,
+        };
+        let command_options = CommandOptions {
+            number_type: Some(NumberType::detect(mask)),
+            output: vec![output_type],
+            width,
+            ..Default::default()
+        };
+        let options = CidrOptions {
+            mask: mask.to_string(),
+        };
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let actual = command_context.cidr(&options).unwrap();
+
+        // Assert
+        assert_eq!(
+            actual.outputs[0],
+            ("Netmask", vec![(output_type, netmask.to_string())])
+        );
+        assert_eq!(actual.prefix, prefix);
+        assert_eq!(actual.usable_hosts, usable_hosts);
+    }
+
+    #[test_case("/33", NumberFormatterError::TooLargeError; "prefix too long")]
+    #[test_case("/x", NumberFormatterError::InvalidDigit; "prefix not a number")]
+    #[test_case("255.0.255.0", NumberFormatterError::InvalidNetmask; "not contiguous")]
+    fn cidr_should_reject_invalid_mask(mask: &str, expected: NumberFormatterError) {
+        // Arrange
+        let command_options = CommandOptions {
+            number_type: Some(NumberType::detect(mask)),
+            ..Default::default()
+        };
+        let options = CidrOptions {
+            mask: mask.to_string(),
+        };
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let actual = command_context.cidr(&options);
+
+        // Assert
+        assert_eq!(
+            std::mem::discriminant(&actual.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+
+    #[test]
+    fn nibble_swap_should_reverse_nibbles() {
+        // Arrange
+        let command_options = CommandOptions {
+            number_type: Some(NumberType::Hexadecimal),
+            output: vec![NumberType::Integer, NumberType::Hexadecimal],
+            width: Some(16),
+            ..Default::default()
+        };
+        let options = SwapOptions {
+            number: String::from("0x00f1"),
+        };
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let actual_output = command_context.nibble_swap(&options);
+
+        // Assert
+        assert!(actual_output.is_ok());
+        assert_eq!(
+            actual_output.unwrap(),
+            vec![
+                (
+                    "Original",
+                    vec![
+                        (NumberType::Integer, String::from("241")),
+                        (NumberType::Hexadecimal, String::from("00f1")),
+                    ]
+                ),
+                (
+                    "Swapped",
+                    vec![
+                        (NumberType::Integer, String::from("7936")),
+                        (NumberType::Hexadecimal, String::from("1f00")),
+                    ]
+                ),
+            ]
+        );
+    }
+
+    #[test_case("0x123456789abcdef0", None, 16, None, &[(3, "1234"), (2, "5678"), (1, "9abc"), (0, "def0")])]
+    #[test_case("0x123456789abcdef0", None, 32, Some(Endian::Little), &[(0, "9abcdef0"), (1, "12345678")]; "low to high")]
+    #[test_case("0x1234", Some(64), 32, None, &[(1, "00000000"), (0, "00001234")]; "with width")]
+    #[test_case("-1", Some(32), 16, None, &[(1, "ffff"), (0, "ffff")]; "negative")]
+    #[test_case("0x12", None, 16, None, &[(0, "0012")]; "narrower than word")]
+    fn split_words_should_split_number(
+        number: &str,
+        width: Option<u32>,
+        split: u32,
+        endian: Option<Endian>,
+        expected: &[(u32, &str)],
+    ) {
+        // Arrange
+        let command_options = CommandOptions {
+            width,
+            split: Some(split),
+            endian,
+            ..CommandOptions::new(NumberType::detect(number), number)
+        };
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let actual = command_context.split_words();
+
+        // Assert
+        assert!(actual.is_ok());
+        assert_eq!(
+            actual.unwrap().unwrap(),
+            expected
+                .iter()
+                .map(|(index, word)| (*index, word.to_string()))
+                .collect::<Vec<(u32, String)>>()
+        );
+    }
+
+    #[test_case("0x4869", None, None, &[0x48, 0x69])]
+    #[test_case("0x4869", Some(32), None, &[0x00, 0x00, 0x48, 0x69]; "with width")]
+    #[test_case("0x4869", None, Some(Endian::Little), &[0x69, 0x48]; "little endian")]
+    fn hexdump_bytes_should_order_bytes(
+        number: &str,
+        width: Option<u32>,
+        endian: Option<Endian>,
+        expected: &[u8],
+    ) {
+        // Arrange
+        let command_options = CommandOptions {
+            hexdump: true,
+            width,
+            endian,
+            ..CommandOptions::new(NumberType::Hexadecimal, number)
+        };
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let actual = command_context.hexdump_bytes();
+
+        // Assert
+        assert_eq!(actual.unwrap().unwrap(), expected);
+    }
+
+    #[test_case(Some(3), None, "1.5 GiB (1.611 GB)")]
+    #[test_case(None, Some(2), "1.5 GiB (1.6 GB)"; "significant figures")]
+    #[test_case(None, None, "1.5 GiB (1.61 GB)"; "default")]
+    fn format_all_number_types_should_round_to_precision(
+        precision: Option<u32>,
+        sig_figs: Option<u32>,
+        expected: &str,
+    ) {
+        // Arrange
+        let command_options = CommandOptions {
+            output: vec![NumberType::Size],
+            precision,
+            sig_figs,
+            ..CommandOptions::new(NumberType::Integer, "1610612736")
+        };
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let actual = command_context.format_all_number_types();
+
+        // Assert
+        assert_eq!(
+            actual.unwrap(),
+            vec![(NumberType::Size, expected.to_string())]
+        );
+    }
+
+    #[test_case(false, "16777216")]
+    #[test_case(true, "16000000")]
+    fn format_all_number_types_should_expand_suffix(si: bool, expected: &str) {
+        // Arrange
+        let command_options = CommandOptions {
+            output: vec![NumberType::Integer],
+            si,
+            ..CommandOptions::new(NumberType::Integer, "16M")
+        };
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let actual = command_context.format_all_number_types();
+
+        // Assert
+        assert_eq!(
+            actual.unwrap(),
+            vec![(NumberType::Integer, expected.to_string())]
+        );
+    }
+
+    #[test_case(None, "0xFF + 0b1010 * 3", "285")]
+    #[test_case(None, "(0x10 - 'A') * 2", "-98"; "character literal")]
+    #[test_case(Some(NumberType::Hexadecimal), "ff + 10", "271"; "literals of chosen type")]
+    fn format_all_number_types_should_evaluate_expression(
+        number_type: Option<NumberType>,
+        expression: &str,
+        expected: &str,
+    ) {
+        // Arrange
+        let command_options = CommandOptions {
+            output: vec![NumberType::Integer],
+            number_type,
+            number: expression.to_string(),
+            ..Default::default()
+        };
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let actual = command_context.format_all_number_types();
+
+        // Assert
+        assert_eq!(
+            actual.unwrap(),
+            vec![(NumberType::Integer, expected.to_string())]
+        );
+    }
+
+    #[test_case(None, "1.5 + 1"; "float literal")]
+    #[test_case(None, "0x1.8p1 * 2"; "hex float literal")]
+    #[test_case(None, "2023-11-14T00:00:00 + 1"; "date literal")]
+    #[test_case(Some(NumberType::Float), "1.5 + 1"; "literals of chosen float type")]
+    fn format_all_number_types_should_reject_non_integer_literals_in_expression(
+        number_type: Option<NumberType>,
+        expression: &str,
+    ) {
+        // Arrange
+        let command_options = CommandOptions {
+            output: vec![NumberType::Integer],
+            number_type,
+            number: expression.to_string(),
+            ..Default::default()
+        };
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let actual = command_context.format_all_number_types();
+
+        // Assert
+        assert!(matches!(
+            actual,
+            Err(NumberFormatterError::InvalidExpression)
+        ));
+    }
+
+    #[test_case("89 + 8 + 3", "Ok([(Integer, \"100\")])")]
+    #[test_case("5 + 3", "Err(InvalidZeckendorf)"; "consecutive terms")]
+    fn format_all_number_types_should_read_zeckendorf_sum_rather_than_expression(
+        number: &str,
+        expected: &str,
+    ) {
+        // Arrange
+        let command_options = CommandOptions {
+            output: vec![NumberType::Integer],
+            ..CommandOptions::new(NumberType::Zeckendorf, number)
+        };
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let actual = command_context.format_all_number_types();
+
+        // Assert
+        assert_eq!(format!("{:?}", actual), expected);
+    }
+
+    #[test_case(Truncate::Wrap, "44")]
+    #[test_case(Truncate::Saturate, "127")]
+    fn format_all_number_types_should_truncate_to_size(truncate: Truncate, expected: &str) {
+        // Arrange
+        let command_options = CommandOptions {
+            output: vec![NumberType::Integer],
+            width: Some(8),
+            truncate,
+            ..CommandOptions::new(NumberType::Integer, "300")
+        };
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let actual_output = command_context.format_all_number_types();
+        let actual_warning = command_context.truncation_warning();
+
+        // Assert
+        assert!(actual_output.is_ok());
+        assert_eq!(
+            actual_output.unwrap(),
+            vec![(NumberType::Integer, expected.to_string())]
+        );
+        assert!(actual_warning
+            .unwrap()
+            .starts_with("300 does not fit in i8"));
+    }
+
+    #[test_case("100", Truncate::Wrap; "fits")]
+    #[test_case("300", Truncate::Error; "rejected")]
+    fn truncation_warning_should_be_none_without_truncation(number: &str, truncate: Truncate) {
+        // Arrange
+        let command_options = CommandOptions {
+            width: Some(8),
+            truncate,
+            ..CommandOptions::new(NumberType::Integer, number)
+        };
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let actual_warning = command_context.truncation_warning();
+
+        // Assert
+        assert_eq!(actual_warning, None);
+    }
+
+    #[test_case(NumberType::Hexadecimal, "-0x10", -16)]
+    #[test_case(NumberType::Hexadecimal, "-0XfF", -255; "uppercase prefix")]
+    #[test_case(NumberType::Hexadecimal, "-10", -16; "without prefix")]
+    #[test_case(NumberType::Binary, "-0b101", -5)]
+    #[test_case(NumberType::Octal, "-0o17", -15)]
+    #[test_case(NumberType::Hexadecimal, "-0x80000000000000000000000000000000", i128::MIN; "minimum")]
+    fn number_formatter_should_read_negative_prefixed_number(
+        number_type: NumberType,
+        input: &str,
+        expected: i128,
+    ) {
+        // Arrange
+        let formatter =
+            NumberFormatterFactory::new_number_formatter(&number_type, &CommandOptions::default());
+
+        // Act
+        let actual = formatter.read(input);
+
+        // Assert
+        assert!(actual.is_ok());
+        assert_eq!(actual.unwrap(), expected);
+    }
+
+    #[test_case("0x-10"; "sign after prefix")]
+    #[test_case("0x0x10"; "repeated prefix")]
+    fn hexadecimal_number_formatter_should_not_read_misplaced_prefix(input: &str) {
+        // Arrange
+        let formatter = NumberFormatterFactory::new_number_formatter(
+            &NumberType::Hexadecimal,
+            &CommandOptions::default(),
+        );
+
+        // Act
+        let actual = formatter.read(input);
+
+        // Assert
+        assert!(actual.is_err());
+    }
+
+    #[test_case(8, "-1", "ff", "11111111")]
+    #[test_case(16, "-2", "fffe", "1111111111111110")]
+    #[test_case(8, "255", "ff", "11111111"; "positive pattern is unchanged")]
+    fn format_all_number_types_should_show_twos_complement(
+        bits: u32,
+        number: &str,
+        hexadecimal: &str,
+        binary: &str,
+    ) {
+        // Arrange
+        let command_options = CommandOptions {
+            twos_complement: Some(bits),
+            ..CommandOptions::new(NumberType::Integer, number)
+        };
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let actual_output = command_context.format_all_number_types();
+
+        // Assert
+        assert!(actual_output.is_ok());
+        assert_eq!(
+            actual_output.unwrap(),
+            vec![
+                (NumberType::Integer, number.to_string()),
+                (NumberType::Hexadecimal, hexadecimal.to_string()),
+                (NumberType::Binary, binary.to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn format_all_number_types_should_reject_number_outside_twos_complement_width() {
+        // Arrange
+        let command_options = CommandOptions {
+            twos_complement: Some(8),
+            ..CommandOptions::new(NumberType::Integer, "-200")
+        };
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let actual_output = command_context.format_all_number_types();
+
+        // Assert
+        assert!(actual_output.is_err());
+        assert_eq!(
+            std::mem::discriminant(&actual_output.unwrap_err()),
+            std::mem::discriminant(&NumberFormatterError::TooSmallError)
+        );
+    }
+
+    #[test_case("0xa000", None, 12, 15, "10", "a")]
+    #[test_case("0x1234", None, 4, 7, "3", "3")]
+    #[test_case("-1", Some(16), 4, 11, "255", "00ff"; "negative with width")]
+    fn format_all_number_types_should_extract_bit_range(
+        number: &str,
+        width: Option<u32>,
+        low: u32,
+        high: u32,
+        integer: &str,
+        hexadecimal: &str,
+    ) {
+        // Arrange
+        let command_options = CommandOptions {
+            output: vec![NumberType::Integer, NumberType::Hexadecimal],
+            bits: Some(BitRange { low, high }),
+            width,
+            ..CommandOptions::new(NumberType::detect(number), number)
+        };
+        let command_context = CommandContext::new(command_options);
+
+        // Act
+        let actual_output = command_context.format_all_number_types();
+
+        // Assert
+        assert!(actual_output.is_ok());
+        assert_eq!(
+            actual_output.unwrap(),
+            vec![
+                (NumberType::Integer, integer.to_string()),
+                (NumberType::Hexadecimal, hexadecimal.to_string()),
+            ]
+        );
     }
 }
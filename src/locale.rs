@@ -0,0 +1,247 @@
+use crate::NumberFormatterError;
+
+/// How digits are grouped from the right
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Grouping {
+    /// Groups of three, e.g. 1,234,567
+    Thousands,
+    /// Three then groups of two, e.g. 12,34,567
+    Indian,
+}
+
+/// Number formatting conventions of a locale
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Locale {
+    pub tag: &'static str,
+    pub group_separator: &'static str,
+    pub decimal_separator: char,
+    pub grouping: Grouping,
+}
+
+const fn locale(
+    tag: &'static str,
+    group_separator: &'static str,
+    decimal_separator: char,
+    grouping: Grouping,
+) -> Locale {
+    Locale {
+        tag,
+        group_separator,
+        decimal_separator,
+        grouping,
+    }
+}
+
+static LOCALES: [Locale; 27] = [
+    locale("en-US", ",", '.', Grouping::Thousands),
+    locale("en-GB", ",", '.', Grouping::Thousands),
+    locale("en-AU", ",", '.', Grouping::Thousands),
+    locale("en-CA", ",", '.', Grouping::Thousands),
+    locale("en-IN", ",", '.', Grouping::Indian),
+    locale("hi-IN", ",", '.', Grouping::Indian),
+    locale("ja-JP", ",", '.', Grouping::Thousands),
+    locale("zh-CN", ",", '.', Grouping::Thousands),
+    locale("ko-KR", ",", '.', Grouping::Thousands),
+    locale("es-MX", ",", '.', Grouping::Thousands),
+    locale("de-DE", ".", ',', Grouping::Thousands),
+    locale("de-CH", "\u{2019}", '.', Grouping::Thousands),
+    locale("es-ES", ".", ',', Grouping::Thousands),
+    locale("it-IT", ".", ',', Grouping::Thousands),
+    locale("nl-NL", ".", ',', Grouping::Thousands),
+    locale("pt-BR", ".", ',', Grouping::Thousands),
+    locale("da-DK", ".", ',', Grouping::Thousands),
+    locale("tr-TR", ".", ',', Grouping::Thousands),
+    locale("id-ID", ".", ',', Grouping::Thousands),
+    locale("fr-FR", "\u{202F}", ',', Grouping::Thousands),
+    locale("fr-CA", "\u{A0}", ',', Grouping::Thousands),
+    locale("ru-RU", "\u{A0}", ',', Grouping::Thousands),
+    locale("pl-PL", "\u{A0}", ',', Grouping::Thousands),
+    locale("sv-SE", "\u{A0}", ',', Grouping::Thousands),
+    locale("nb-NO", "\u{A0}", ',', Grouping::Thousands),
+    locale("fi-FI", "\u{A0}", ',', Grouping::Thousands),
+    locale("cs-CZ", "\u{A0}", ',', Grouping::Thousands),
+];
+
+/// Clap value parser finding a locale by tag, such as `de-DE`, `de_DE` or just `de`
+pub(crate) fn parse_locale(tag: &str) -> Result<Locale, String> {
+    let tag = tag.replace('_', "-");
+
+    LOCALES
+        .iter()
+        .find(|locale| locale.tag.eq_ignore_ascii_case(&tag))
+        .or_else(|| {
+            LOCALES.iter().find(|locale| {
+                locale
+                    .tag
+                    .split('-')
+                    .next()
+                    .is_some_and(|language| language.eq_ignore_ascii_case(&tag))
+            })
+        })
+        .copied()
+        .ok_or_else(|| {
+            let tags: Vec<&str> = LOCALES.iter().map(|locale| locale.tag).collect();
+            format!("unknown locale, expected one of: {}", tags.join(", "))
+        })
+}
+
+impl Locale {
+    /// Inserts the group separator into a string of digits with an optional leading `-`
+    pub fn group(&self, digits: &str) -> String {
+        let (sign, digits) = match digits.strip_prefix('-') {
+            Some(digits) => ("-", digits),
+            None => ("", digits),
+        };
+
+        let mut groups = Vec::<&str>::new();
+        let mut end = digits.len();
+        let mut size = 3;
+
+        while end > size {
+            groups.push(&digits[end - size..end]);
+            end -= size;
+            if self.grouping == Grouping::Indian {
+                size = 2;
+            }
+        }
+        groups.push(&digits[..end]);
+
+        groups.reverse();
+        format!("{}{}", sign, groups.join(self.group_separator))
+    }
+
+    /// Removes the group separators from a number, checking that the groups are in the right places
+    pub fn ungroup(&self, number: &str) -> anyhow::Result<String, NumberFormatterError> {
+        let number = self.normalize_separators(number);
+        let (sign, digits) = match number.strip_prefix('-') {
+            Some(digits) => ("-", digits),
+            None => ("", number.as_str()),
+        };
+
+        let mut groups = digits.rsplit(self.group_separator);
+        let mut size = 3;
+        let mut ungrouped = Vec::new();
+
+        if let Some(last) = groups.next() {
+            ungrouped.push(last);
+        }
+        for group in groups {
+            if ungrouped.last().map(|last| last.chars().count()) != Some(size) {
+                return Err(NumberFormatterError::InvalidGrouping);
+            }
+            if self.grouping == Grouping::Indian {
+                size = 2;
+            }
+            if group.is_empty() || group.chars().count() > size {
+                return Err(NumberFormatterError::InvalidGrouping);
+            }
+            ungrouped.push(group);
+        }
+
+        ungrouped.reverse();
+        Ok(format!("{}{}", sign, ungrouped.concat()))
+    }
+
+    /// Maps separators that are easier to type, such as a plain space or apostrophe, to the locale's own
+    fn normalize_separators(&self, number: &str) -> String {
+        let aliases: &[char] = match self.group_separator {
+            "\u{A0}" | "\u{202F}" => &[' ', '\u{A0}', '\u{202F}'],
+            "\u{2019}" => &['\'', '\u{2019}'],
+            _ => &[],
+        };
+
+        number
+            .chars()
+            .map(|c| {
+                if aliases.contains(&c) {
+                    self.group_separator.to_string()
+                } else {
+                    c.to_string()
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("en-US", "1234567", "1,234,567")]
+    #[test_case("de-DE", "1234567", "1.234.567")]
+    #[test_case("en-IN", "1234567", "12,34,567")]
+    #[test_case("en-IN", "123456789", "12,34,56,789")]
+    #[test_case("fr-FR", "-1234567", "-1\u{202F}234\u{202F}567")]
+    #[test_case("de-CH", "1000", "1\u{2019}000")]
+    #[test_case("en-US", "999", "999")]
+    #[test_case("en-US", "-100000", "-100,000")]
+    fn locale_should_group_digits(tag: &str, digits: &str, expected: &str) {
+        // Arrange
+        let locale = parse_locale(tag).unwrap();
+
+        // Act
+        let actual = locale.group(digits);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test_case("en-US", "1,234,567", "1234567")]
+    #[test_case("de-DE", "-1.234.567", "-1234567")]
+    #[test_case("en-IN", "12,34,56,789", "123456789")]
+    #[test_case("fr-FR", "1 234\u{A0}567", "1234567")]
+    #[test_case("de-CH", "1'000", "1000")]
+    #[test_case("en-US", "1234567", "1234567"; "ungrouped")]
+    fn locale_should_ungroup_digits(tag: &str, number: &str, expected: &str) {
+        // Arrange
+        let locale = parse_locale(tag).unwrap();
+
+        // Act
+        let actual = locale.ungroup(number);
+
+        // Assert
+        assert_eq!(actual.unwrap(), expected);
+    }
+
+    #[test_case("en-US", "1,23,456")]
+    #[test_case("en-US", "1234,567")]
+    #[test_case("en-US", "1,234,56")]
+    #[test_case("en-US", ",234")]
+    #[test_case("en-IN", "1,234,567")]
+    #[test_case("de-DE", "1.234.567,"; "trailing decimal separator")]
+    fn locale_should_reject_misplaced_groups(tag: &str, number: &str) {
+        // Arrange
+        let locale = parse_locale(tag).unwrap();
+
+        // Act
+        let actual = locale.ungroup(number);
+
+        // Assert
+        assert!(actual.is_err());
+        assert_eq!(
+            std::mem::discriminant(&actual.unwrap_err()),
+            std::mem::discriminant(&NumberFormatterError::InvalidGrouping)
+        );
+    }
+
+    #[test_case("de_de", "de-DE")]
+    #[test_case("DE", "de-DE")]
+    #[test_case("hi", "hi-IN")]
+    fn parse_locale_should_accept_variations(tag: &str, expected: &str) {
+        // Act
+        let actual = parse_locale(tag);
+
+        // Assert
+        assert_eq!(actual.unwrap().tag, expected);
+    }
+
+    #[test]
+    fn parse_locale_should_reject_unknown_locale() {
+        // Act
+        let actual = parse_locale("xx-YY");
+
+        // Assert
+        assert!(actual.is_err());
+    }
+}
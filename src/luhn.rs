@@ -0,0 +1,186 @@
+use crate::{NumberFormatterError, Value};
+use clap::Args;
+
+/// Options of the luhn command
+#[derive(Args, Clone, Debug)]
+pub struct LuhnOptions {
+    /// The number to check, such as a card number or an IMEI, with its check digit last. Spaces
+    /// and hyphens grouping the digits are ignored
+    pub number: String,
+}
+
+/// Luhn check digits of a number's decimal digits, read both as a number ending in its check digit
+/// and as a payload that a check digit is yet to be appended to
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct LuhnInfo {
+    /// Whether the last digit is the check digit of the digits before it
+    pub valid: bool,
+    /// The last digit the number should have to be valid
+    pub expected_digit: u8,
+    /// The number with its last digit replaced by the expected one, none when that does not fit
+    /// in 128 bits
+    pub corrected: Option<u128>,
+    /// The check digit of the whole number, taking none of its digits as a check digit
+    pub appended_digit: u8,
+    /// The number followed by its own check digit, none when that does not fit in 128 bits
+    pub with_check_digit: Option<u128>,
+}
+
+/// Removes the spaces and hyphens card numbers and IMEIs are grouped with, such as in
+/// `4539 1488 0343 6467` or `35-209900-176148-1`, keeping a leading minus sign
+pub(crate) fn ungroup(number: &str) -> String {
+    let (sign, digits) = match number.trim().strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", number.trim()),
+    };
+
+    format!("{}{}", sign, digits.replace([' ', '-'], ""))
+}
+
+impl LuhnInfo {
+    pub(crate) fn new(value: Value) -> anyhow::Result<Self, NumberFormatterError> {
+        let num = match value {
+            Value::Signed(num) if num < 0 => return Err(NumberFormatterError::TooSmallError),
+            Value::Signed(num) => num as u128,
+            Value::Unsigned(num) => num,
+        };
+        let expected_digit = check_digit(num / 10);
+        let appended_digit = check_digit(num);
+
+        Ok(Self {
+            valid: (num % 10) as u8 == expected_digit,
+            expected_digit,
+            corrected: (num - num % 10).checked_add(expected_digit as u128),
+            appended_digit,
+            with_check_digit: num
+                .checked_mul(10)
+                .and_then(|shifted| shifted.checked_add(appended_digit as u128)),
+        })
+    }
+
+    /// Labelled lines describing the check digits, in the order they should be displayed
+    pub fn lines(&self) -> Vec<(&'static str, String)> {
+        let number =
+            |num: Option<u128>| num.map_or(String::from("too large"), |num| num.to_string());
+
+        vec![
+            ("Valid", String::from(if self.valid { "yes" } else { "no" })),
+            ("Expected check digit", self.expected_digit.to_string()),
+            ("Corrected", number(self.corrected)),
+            ("Check digit to append", self.appended_digit.to_string()),
+            ("With check digit appended", number(self.with_check_digit)),
+        ]
+    }
+}
+
+/// The digit to append to a payload so that it passes the Luhn check. Walking from the rightmost
+/// payload digit, every other digit is doubled, subtracting nine when that gives two digits.
+pub(crate) fn check_digit(payload: u128) -> u8 {
+    let mut remainder = payload;
+    let mut sum = 0;
+    let mut double = true;
+
+    while remainder > 0 {
+        let digit = (remainder % 10) as u8;
+        let digit = if !double {
+            digit
+        } else if digit > 4 {
+            digit * 2 - 9
+        } else {
+            digit * 2
+        };
+        sum += digit as u32;
+        double = !double;
+        remainder /= 10;
+    }
+
+    ((10 - sum % 10) % 10) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(7992739871, 3)]
+    #[test_case(0, 0)]
+    #[test_case(35145212345678, 0; "imei")]
+    #[test_case(424242424242424, 2; "card")]
+    fn check_digit_should_complete_payload(payload: u128, expected: u8) {
+        // Act
+        let actual = check_digit(payload);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test_case(Value::Signed(79927398713), true, 3)]
+    #[test_case(Value::Signed(79927398710), false, 3)]
+    #[test_case(Value::Signed(4242424242424242), true, 2; "card")]
+    fn luhn_info_should_validate_last_digit(value: Value, valid: bool, expected_digit: u8) {
+        // Act
+        let actual = LuhnInfo::new(value).unwrap();
+
+        // Assert
+        assert_eq!(actual.valid, valid);
+        assert_eq!(actual.expected_digit, expected_digit);
+    }
+
+    #[test]
+    fn luhn_info_should_append_check_digit() {
+        // Act
+        let actual = LuhnInfo::new(Value::Signed(7992739871)).unwrap();
+
+        // Assert
+        assert_eq!(actual.appended_digit, 3);
+        assert_eq!(actual.with_check_digit, Some(79927398713));
+    }
+
+    #[test_case(79927398710, 79927398713)]
+    #[test_case(79927398713, 79927398713; "already valid")]
+    fn luhn_info_should_correct_last_digit(num: i128, expected: u128) {
+        // Act
+        let actual = LuhnInfo::new(Value::Signed(num)).unwrap();
+
+        // Assert
+        assert_eq!(actual.corrected, Some(expected));
+    }
+
+    #[test_case("4539 1488 0343 6467", "4539148803436467")]
+    #[test_case("35-209900-176148-1", "352099001761481"; "hyphens")]
+    #[test_case("-12", "-12"; "negative")]
+    fn ungroup_should_remove_separators(number: &str, expected: &str) {
+        // Act
+        let actual = ungroup(number);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn luhn_info_should_label_lines() {
+        // Act
+        let actual = LuhnInfo::new(Value::Signed(79927398710)).unwrap().lines();
+
+        // Assert
+        assert_eq!(
+            actual,
+            vec![
+                ("Valid", String::from("no")),
+                ("Expected check digit", String::from("3")),
+                ("Corrected", String::from("79927398713")),
+                ("Check digit to append", String::from("4")),
+                ("With check digit appended", String::from("799273987104")),
+            ]
+        );
+    }
+
+    #[test]
+    fn luhn_info_should_reject_negative() {
+        // Act
+        let actual = LuhnInfo::new(Value::Signed(-1));
+
+        // Assert
+        assert!(matches!(actual, Err(NumberFormatterError::TooSmallError)));
+    }
+}
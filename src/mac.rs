@@ -0,0 +1,132 @@
+use crate::{NumberFormatter, NumberFormatterError};
+
+/// Largest 48-bit MAC address
+const MAX_MAC: i128 = 0xffff_ffff_ffff;
+
+/// Formatter writing 48-bit numbers as colon separated MAC addresses, followed by their
+/// organizationally unique identifier, such as `00:1a:2b:3c:4d:5e (OUI 00:1a:2b)`
+pub(crate) struct MacNumberFormatter {
+    pub uppercase: bool,
+}
+
+/// The hex digits of a MAC address written as `aa:bb:cc:dd:ee:ff`, `aa-bb-cc-dd-ee-ff` or Cisco's
+/// `aabb.ccdd.eeff`, if it is written in one of them
+fn mac_digits(address: &str) -> Option<String> {
+    let (separator, group_len) = match address.len() {
+        17 if address.contains(':') => (':', 2),
+        17 => ('-', 2),
+        14 => ('.', 4),
+        _ => return None,
+    };
+    let groups: Vec<&str> = address.split(separator).collect();
+
+    let valid = groups.len() == 12 / group_len
+        && groups
+            .iter()
+            .all(|group| group.len() == group_len && group.chars().all(|c| c.is_ascii_hexdigit()));
+
+    valid.then(|| groups.concat())
+}
+
+/// Whether the input is written as a MAC address
+pub(crate) fn is_mac(input: &str) -> bool {
+    mac_digits(input).is_some()
+}
+
+impl MacNumberFormatter {
+    fn join_octets(&self, octets: &[u8]) -> String {
+        octets
+            .iter()
+            .map(|octet| {
+                if self.uppercase {
+                    format!("{:02X}", octet)
+                } else {
+                    format!("{:02x}", octet)
+                }
+            })
+            .collect::<Vec<String>>()
+            .join(":")
+    }
+}
+
+impl NumberFormatter for MacNumberFormatter {
+    fn read(&self, number: &str) -> anyhow::Result<i128, NumberFormatterError> {
+        let address = number.split_whitespace().next().unwrap_or_default();
+
+        if address.is_empty() {
+            return Err(NumberFormatterError::Empty);
+        }
+
+        let digits = mac_digits(address).ok_or(NumberFormatterError::InvalidMacAddress)?;
+        Ok(i128::from_str_radix(&digits, 16)?)
+    }
+
+    fn format(&self, num: i128) -> anyhow::Result<String, NumberFormatterError> {
+        if num < 0 {
+            return Err(NumberFormatterError::TooSmallError);
+        }
+        if num > MAX_MAC {
+            return Err(NumberFormatterError::TooLargeError);
+        }
+
+        let octets = &(num as u64).to_be_bytes()[2..];
+        Ok(format!(
+            "{} (OUI {})",
+            self.join_octets(octets),
+            self.join_octets(&octets[..3])
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("00:1a:2b:3c:4d:5e", 0x001a2b3c4d5e)]
+    #[test_case("00-1A-2B-3C-4D-5E", 0x001a2b3c4d5e; "hyphens")]
+    #[test_case("001a.2b3c.4d5e", 0x001a2b3c4d5e; "cisco")]
+    #[test_case("00:1a:2b:3c:4d:5e (OUI 00:1a:2b)", 0x001a2b3c4d5e; "with oui")]
+    fn mac_number_formatter_should_read_address(input: &str, expected: i128) {
+        // Act
+        let actual = MacNumberFormatter { uppercase: false }.read(input);
+
+        // Assert
+        assert_eq!(actual.unwrap(), expected);
+    }
+
+    #[test_case("", NumberFormatterError::Empty; "empty")]
+    #[test_case("00:1a:2b:3c:4d", NumberFormatterError::InvalidMacAddress; "five octets")]
+    #[test_case("00:1a:2b-3c:4d:5e", NumberFormatterError::InvalidMacAddress; "mixed separators")]
+    #[test_case("00:1a:2b:3c:4d:5g", NumberFormatterError::InvalidMacAddress; "not hex")]
+    fn mac_number_formatter_should_fail_to_read(input: &str, expected: NumberFormatterError) {
+        // Act
+        let actual = MacNumberFormatter { uppercase: false }.read(input);
+
+        // Assert
+        assert_eq!(
+            std::mem::discriminant(&actual.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+
+    #[test_case(
+        0x001a2b3c4d5e,
+        false,
+        Ok(String::from("00:1a:2b:3c:4d:5e (OUI 00:1a:2b)"))
+    )]
+    #[test_case(0xffffffffffff, true, Ok(String::from("FF:FF:FF:FF:FF:FF (OUI FF:FF:FF)")); "broadcast")]
+    #[test_case(0x1000000000000, false, Err(NumberFormatterError::TooLargeError); "too large")]
+    #[test_case(-1, false, Err(NumberFormatterError::TooSmallError); "negative")]
+    fn mac_number_formatter_should_format_i128(
+        input: i128,
+        uppercase: bool,
+        expected: anyhow::Result<String, NumberFormatterError>,
+    ) {
+        // Act
+        let actual = MacNumberFormatter { uppercase }.format(input);
+
+        // Assert
+        assert_eq!(format!("{:?}", actual), format!("{:?}", expected));
+    }
+}
@@ -1,12 +1,277 @@
 use clap::StructOpt;
-use format_number::{CommandContext, CommandOptions, NumberFormatterError};
+use format_number::{
+    banner, bitfield_table, bitwise_table, hexdump, seven_segment, visualize, Command,
+    CommandContext, CommandOptions, LabelledOutputs, NumberFormatterError, NumberType,
+};
+use std::fmt::Display;
+use std::io::{IsTerminal, Write};
+
+fn print_output(
+    number_type: NumberType,
+    output: &str,
+    indent: &str,
+    command_options: &CommandOptions,
+) -> anyhow::Result<(), NumberFormatterError> {
+    let drawing = match command_options {
+        CommandOptions { banner: true, .. } => banner(output),
+        CommandOptions {
+            seven_segment: true,
+            ..
+        } => seven_segment(output)?,
+        CommandOptions {
+            visualize: true, ..
+        } if number_type == NumberType::Binary => visualize(output)?,
+        _ => {
+            println!("{}{}: {}", indent, number_type, output);
+            return Ok(());
+        }
+    };
+
+    println!("{}{}:", indent, number_type);
+    for line in drawing.lines() {
+        println!("{}{}", indent, line);
+    }
+    println!();
+
+    Ok(())
+}
+
+/// Prints the labelled lines describing a number, such as those of the bits or info command
+fn print_lines(lines: impl IntoIterator<Item = (impl Display, impl Display)>) {
+    for (label, output) in lines {
+        println!("{}: {}", label, output);
+    }
+}
+
+fn print_labelled_outputs(
+    labelled_outputs: LabelledOutputs,
+    command_options: &CommandOptions,
+) -> anyhow::Result<(), NumberFormatterError> {
+    for (label, outputs) in labelled_outputs {
+        println!("{}:", label);
+        for (number_type, output) in outputs {
+            print_output(number_type, &output, "    ", command_options)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Prints many numbers a line each as they are formatted, with their outputs separated by tabs
+/// under a header naming the output types when there are several, so that they can be piped into
+/// other tools. Stops quietly once the reader closes the pipe.
+fn print_rows(
+    rows: impl Iterator<Item = anyhow::Result<Vec<(NumberType, String)>, NumberFormatterError>>,
+) -> anyhow::Result<(), NumberFormatterError> {
+    let mut stdout = std::io::stdout().lock();
+    for (index, row) in rows.enumerate() {
+        let row = row?;
+        if index == 0 && row.len() > 1 {
+            let header: Vec<String> = row
+                .iter()
+                .map(|(number_type, _)| number_type.to_string())
+                .collect();
+            if writeln!(stdout, "{}", header.join("\t")).is_err() {
+                return Ok(());
+            }
+        }
+
+        let outputs: Vec<&str> = row.iter().map(|(_, output)| output.as_str()).collect();
+        if writeln!(stdout, "{}", outputs.join("\t")).is_err() {
+            return Ok(());
+        }
+    }
+
+    Ok(())
+}
 
 fn main() -> anyhow::Result<(), NumberFormatterError> {
-    let command_context = CommandContext::new(CommandOptions::parse());
+    let command_options = CommandOptions::parse();
+    let command_context = CommandContext::new(command_options.clone());
 
-    let result = command_context.format_all_number_types()?;
+    if command_options.detect && command_options.command.is_none() {
+        for interpretation in command_context.format_all_interpretations()? {
+            println!("{} ({}):", interpretation.number_type, interpretation.note);
+            for (number_type, output) in interpretation.outputs {
+                print_output(number_type, &output, "    ", &command_options)?;
+            }
+        }
+
+        return Ok(());
+    }
+
+    if let Some(warning) = command_context.truncation_warning() {
+        eprintln!("warning: {}", warning);
+    }
+
+    let result = match &command_options.command {
+        Some(Command::Sext(sext)) => command_context.sign_extend(sext)?,
+        Some(Command::Calc(calc)) => {
+            let calculation = command_context.calc(calc)?;
+            if let Some(warning) = calculation.warning {
+                eprintln!("warning: {}", warning);
+            }
+            calculation.outputs
+        }
+        Some(Command::Mask(mask)) => {
+            print_labelled_outputs(command_context.mask(mask)?, &command_options)?;
+            return Ok(());
+        }
+        Some(Command::Page(page)) => {
+            print_labelled_outputs(command_context.page(page)?, &command_options)?;
+            return Ok(());
+        }
+        Some(Command::Addr(addr)) => {
+            let arithmetic = command_context.address_arithmetic(addr)?;
+            print_labelled_outputs(arithmetic.outputs, &command_options)?;
+            if let Some(within) = arithmetic.within {
+                println!("Within range: {}", if within { "yes" } else { "no" });
+            }
+            return Ok(());
+        }
+        Some(Command::Cidr(cidr)) => {
+            let info = command_context.cidr(cidr)?;
+            print_labelled_outputs(info.outputs, &command_options)?;
+            println!("Prefix length: /{}", info.prefix);
+            match info.usable_hosts {
+                Some(hosts) => println!("Usable hosts: {}", hosts),
+                None => println!("Usable hosts: 2^128"),
+            }
+            return Ok(());
+        }
+        Some(Command::Bswap(swap)) => {
+            print_labelled_outputs(command_context.byte_swap(swap)?, &command_options)?;
+            return Ok(());
+        }
+        Some(Command::Nswap(swap)) => {
+            print_labelled_outputs(command_context.nibble_swap(swap)?, &command_options)?;
+            return Ok(());
+        }
+        Some(Command::Shift(shift)) => {
+            print_labelled_outputs(command_context.shift(shift)?, &command_options)?;
+            return Ok(());
+        }
+        Some(Command::Gcd(divisor)) | Some(Command::Lcm(divisor)) => {
+            let info = match &command_options.command {
+                Some(Command::Gcd(_)) => command_context.gcd(divisor)?,
+                _ => command_context.lcm(divisor)?,
+            };
+            if divisor.verbose {
+                for step in info.steps {
+                    println!("{}", step);
+                }
+            }
+            info.outputs
+        }
+        Some(Command::Mod(modular)) => {
+            let info = command_context.modular(modular)?;
+            print_labelled_outputs(info.outputs, &command_options)?;
+            if let Some(factor) = info.common_factor {
+                println!("Inverse: none, a and n share the factor {}", factor);
+            }
+            return Ok(());
+        }
+        Some(Command::Bitwise(bitwise)) => {
+            println!("{}", bitwise_table(&command_context.bitwise(bitwise)?));
+            return Ok(());
+        }
+        Some(Command::Crc(crc)) => {
+            print_labelled_outputs(command_context.crc(crc)?, &command_options)?;
+            return Ok(());
+        }
+        Some(Command::Bits(bits)) => {
+            print_lines(command_context.bit_info(bits)?.lines());
+            return Ok(());
+        }
+        Some(Command::Checksum(checksum)) => {
+            print_labelled_outputs(command_context.checksum(checksum)?, &command_options)?;
+            return Ok(());
+        }
+        Some(Command::Random(random)) => {
+            return print_rows(command_context.random(random)?.into_iter().map(Ok));
+        }
+        Some(Command::Range(range)) => {
+            return print_rows(command_context.range(range)?);
+        }
+        Some(Command::Clmul(clmul)) => {
+            let info = command_context.clmul(clmul)?;
+            print_lines(info.polynomials);
+            print_labelled_outputs(info.outputs, &command_options)?;
+            return Ok(());
+        }
+        Some(Command::Hamming(hamming)) => {
+            print_lines(command_context.hamming(hamming)?.lines());
+            return Ok(());
+        }
+        Some(Command::Info(info)) => {
+            print_lines(command_context.info(info)?.lines());
+            return Ok(());
+        }
+        Some(Command::Factor(factor)) => {
+            print_lines(command_context.factor(factor)?.lines());
+            return Ok(());
+        }
+        Some(Command::Root(root)) => {
+            print_lines(command_context.root(root)?.lines());
+            return Ok(());
+        }
+        Some(Command::Luhn(luhn)) => {
+            print_lines(command_context.luhn(luhn)?.lines());
+            return Ok(());
+        }
+        Some(Command::Xterm(xterm)) => {
+            let info = command_context.xterm(xterm)?;
+            print_lines(info.lines());
+            if std::io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none() {
+                println!("Preview: {}", info.preview());
+            }
+            return Ok(());
+        }
+        Some(Command::Excel(excel)) => {
+            print_lines(command_context.excel(excel)?.lines());
+            return Ok(());
+        }
+        Some(Command::FloatBits(float_bits)) => {
+            print_lines(command_context.float_bits(float_bits)?.lines());
+            return Ok(());
+        }
+        Some(Command::Ulp(ulp)) => {
+            print_lines(command_context.ulp(ulp)?.lines());
+            return Ok(());
+        }
+        Some(Command::Ratio(ratio)) => {
+            print_lines(command_context.ratio(ratio)?.lines());
+            return Ok(());
+        }
+        Some(Command::FixedPoint(fixed_point)) => {
+            print_lines(command_context.fixed_point(fixed_point)?.lines());
+            return Ok(());
+        }
+        Some(Command::Fraction(fraction)) => {
+            print_lines(command_context.fraction(fraction)?.lines());
+            return Ok(());
+        }
+        None => command_context.format_all_number_types()?,
+    };
     for (number_type, output) in result {
-        println!("{}: {}", number_type, output);
+        print_output(number_type, &output, "", &command_options)?;
+    }
+
+    if let Some(words) = command_context.split_words()? {
+        println!();
+        for (index, word) in words {
+            println!("Word {}: {}", index, word);
+        }
+    }
+
+    if let Some(bytes) = command_context.hexdump_bytes()? {
+        println!();
+        println!("{}", hexdump(&bytes));
+    }
+
+    if let Some(fields) = command_context.decode_bitfields()? {
+        println!();
+        println!("{}", bitfield_table(&fields));
     }
 
     Ok(())
@@ -0,0 +1,36 @@
+use crate::bit_range::{self, BitRange};
+use clap::Args;
+
+/// Options of the mask command
+#[derive(Args, Clone, Debug)]
+pub struct MaskOptions {
+    /// Comma separated bits and inclusive bit ranges to set, e.g. 4..7,31
+    #[clap(
+        required = true,
+        use_value_delimiter = true,
+        value_parser = bit_range::parse_bit_range
+    )]
+    pub ranges: Vec<BitRange>,
+}
+
+/// Sets the bits of every range
+pub(crate) fn build_mask(ranges: &[BitRange]) -> u128 {
+    ranges.iter().fold(0, |mask, range| mask | range.mask())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(&[BitRange { low: 4, high: 7 }, BitRange { low: 31, high: 31 }], 0x800000f0)]
+    #[test_case(&[BitRange { low: 0, high: 3 }, BitRange { low: 2, high: 5 }], 0x3f; "overlapping")]
+    #[test_case(&[BitRange { low: 0, high: 127 }], u128::MAX; "every bit")]
+    fn build_mask_should_set_bits_of_ranges(ranges: &[BitRange], expected: u128) {
+        // Act
+        let actual = build_mask(ranges);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+}
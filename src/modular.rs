@@ -0,0 +1,175 @@
+use crate::rational;
+use crate::{LabelledOutputs, NumberFormatterError, Value};
+use clap::Args;
+
+/// Options of the mod command
+#[derive(Args, Clone, Debug)]
+pub struct ModOptions {
+    /// The number to reduce, a
+    pub number: String,
+    /// The modulus, n
+    pub modulus: String,
+    /// An exponent b, to also raise a to the power of b modulo n. Negative exponents raise the
+    /// inverse of a.
+    #[clap(long, allow_hyphen_values = true)]
+    pub power: Option<String>,
+}
+
+/// A number reduced modulo a modulus, with its inverse and power when asked for
+#[derive(Debug, PartialEq, Eq)]
+pub struct ModularInfo {
+    pub outputs: LabelledOutputs,
+    /// The factor the number shares with the modulus, when it has no inverse
+    pub common_factor: Option<u128>,
+}
+
+/// The modulus as a positive number
+pub(crate) fn modulus(value: Value) -> anyhow::Result<u128, NumberFormatterError> {
+    match value.sign_magnitude() {
+        (_, 0) => Err(NumberFormatterError::DivisionByZero),
+        (true, _) => Err(NumberFormatterError::TooSmallError),
+        (false, modulus) => Ok(modulus),
+    }
+}
+
+/// The least non-negative residue of a value modulo `modulus`, so -1 is `modulus - 1`
+pub(crate) fn residue(value: Value, modulus: u128) -> u128 {
+    match value.sign_magnitude() {
+        (true, magnitude) if magnitude % modulus != 0 => modulus - magnitude % modulus,
+        (_, magnitude) => magnitude % modulus,
+    }
+}
+
+/// The sum of two residues modulo `modulus`, without overflowing
+pub(crate) fn add(a: u128, b: u128, modulus: u128) -> u128 {
+    if a >= modulus - b {
+        a - (modulus - b)
+    } else {
+        a + b
+    }
+}
+
+/// The difference of two residues modulo `modulus`
+fn subtract(a: u128, b: u128, modulus: u128) -> u128 {
+    if a >= b {
+        a - b
+    } else {
+        modulus - (b - a)
+    }
+}
+
+/// The product of two residues modulo `modulus`, by doubling and adding when multiplying them
+/// outright would overflow
+pub(crate) fn multiply(a: u128, mut b: u128, modulus: u128) -> u128 {
+    if let Some(product) = a.checked_mul(b) {
+        return product % modulus;
+    }
+
+    let mut product = 0;
+    let mut doubled = a;
+    while b > 0 {
+        if b & 1 == 1 {
+            product = add(product, doubled, modulus);
+        }
+        doubled = add(doubled, doubled, modulus);
+        b >>= 1;
+    }
+
+    product
+}
+
+/// A residue raised to a power modulo `modulus`, by repeated squaring
+pub(crate) fn power(base: u128, mut exponent: u128, modulus: u128) -> u128 {
+    let mut result = 1 % modulus;
+    let mut squared = base;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = multiply(result, squared, modulus);
+        }
+        squared = multiply(squared, squared, modulus);
+        exponent >>= 1;
+    }
+
+    result
+}
+
+/// The residue that multiplies with `a` to 1 modulo `modulus`, by the extended Euclidean algorithm,
+/// or else the factor they share
+pub(crate) fn inverse(a: u128, modulus: u128) -> Result<u128, u128> {
+    let divisor = rational::gcd(a, modulus);
+    if divisor != 1 {
+        return Err(divisor);
+    }
+
+    // Only the coefficients of a are kept, reduced modulo the modulus so they never go negative
+    let (mut remainder, mut next_remainder) = (modulus, a);
+    let (mut coefficient, mut next_coefficient) = (0, 1 % modulus);
+    while next_remainder != 0 {
+        let quotient = remainder / next_remainder;
+        (remainder, next_remainder) = (next_remainder, remainder % next_remainder);
+        (coefficient, next_coefficient) = (
+            next_coefficient,
+            subtract(
+                coefficient,
+                multiply(quotient % modulus, next_coefficient, modulus),
+                modulus,
+            ),
+        );
+    }
+
+    Ok(coefficient)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(Value::Signed(17), 5, 2)]
+    #[test_case(Value::Signed(-1), 5, 4; "negative")]
+    #[test_case(Value::Signed(-10), 5, 0; "negative multiple")]
+    #[test_case(Value::Unsigned(u128::MAX), 10, 5; "unsigned")]
+    fn residue_should_be_non_negative(value: Value, modulus: u128, expected: u128) {
+        // Act
+        let actual = residue(value, modulus);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test_case(u128::MAX - 1, u128::MAX - 1, u128::MAX, 1)]
+    #[test_case(6, 7, 10, 2; "small")]
+    #[test_case(5, 0, 7, 0; "zero")]
+    fn multiply_should_not_overflow(a: u128, b: u128, modulus: u128, expected: u128) {
+        // Act
+        let actual = multiply(a, b, modulus);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test_case(4, 13, 497, 445)]
+    #[test_case(2, 0, 7, 1; "zero exponent")]
+    #[test_case(2, 0, 1, 0; "modulo one")]
+    #[test_case(3, u128::MAX - 1, u128::MAX, 216_434_416_826_713_267_302_305_937_111_878_741_529; "large")]
+    fn power_should_raise_modulo(base: u128, exponent: u128, modulus: u128, expected: u128) {
+        // Act
+        let actual = power(base, exponent, modulus);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test_case(3, 11, Ok(4))]
+    #[test_case(10, 17, Ok(12); "prime modulus")]
+    #[test_case(6, 9, Err(3); "shared factor")]
+    #[test_case(0, 1, Ok(0); "modulo one")]
+    #[test_case(2, u128::MAX, Ok(1 << 127); "largest modulus")]
+    fn inverse_should_multiply_to_one(a: u128, modulus: u128, expected: Result<u128, u128>) {
+        // Act
+        let actual = inverse(a, modulus);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+}
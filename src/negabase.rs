@@ -0,0 +1,136 @@
+use crate::{NumberFormatter, NumberFormatterError};
+
+/// Positional formatter for a negative base (−radix), which represents negative
+/// numbers without a sign, e.g. −3 in negabinary is `1101`
+pub(crate) struct NegativeBaseNumberFormatter {
+    pub radix: u32,
+}
+
+impl NegativeBaseNumberFormatter {
+    fn base(&self) -> i128 {
+        -(self.radix as i128)
+    }
+}
+
+impl NumberFormatter for NegativeBaseNumberFormatter {
+    fn read(&self, num: &str) -> anyhow::Result<i128, NumberFormatterError> {
+        if num.is_empty() {
+            return Err(NumberFormatterError::Empty);
+        }
+
+        let mut value: i128 = 0;
+
+        for c in num.chars() {
+            let digit = c
+                .to_digit(self.radix)
+                .ok_or(NumberFormatterError::InvalidDigit)? as i128;
+
+            value = value
+                .checked_mul(self.base())
+                .and_then(|v| v.checked_add(digit))
+                .ok_or(NumberFormatterError::TooLargeError)?;
+        }
+
+        Ok(value)
+    }
+
+    fn format(&self, num: i128) -> anyhow::Result<String, NumberFormatterError> {
+        let mut digits = Vec::<char>::new();
+        let mut remainder = num;
+
+        loop {
+            let mut digit = remainder % self.base();
+            remainder /= self.base();
+
+            if digit < 0 {
+                digit += self.radix as i128;
+                remainder += 1;
+            }
+
+            digits.push(
+                char::from_digit(digit as u32, self.radix).ok_or(NumberFormatterError::Unknown)?,
+            );
+
+            if remainder == 0 {
+                break;
+            }
+        }
+
+        Ok(digits.iter().rev().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(2, "1101", -3)]
+    #[test_case(2, "110", 2)]
+    #[test_case(2, "0", 0)]
+    #[test_case(10, "19", -1)]
+    #[test_case(10, "192", 12)]
+    fn negative_base_number_formatter_should_read_number(radix: u32, input: &str, expected: i128) {
+        // Arrange
+        let formatter = NegativeBaseNumberFormatter { radix };
+
+        // Act
+        let actual_number = formatter.read(input);
+
+        // Assert
+        assert!(actual_number.is_ok());
+        assert_eq!(expected, actual_number.unwrap());
+    }
+
+    #[test_case(2, -3, "1101")]
+    #[test_case(2, 2, "110")]
+    #[test_case(2, 0, "0")]
+    #[test_case(10, -1, "19")]
+    #[test_case(10, 12, "192")]
+    #[test_case(10, -10, "10")]
+    fn negative_base_number_formatter_should_format_i128(radix: u32, input: i128, expected: &str) {
+        // Arrange
+        let formatter = NegativeBaseNumberFormatter { radix };
+
+        // Act
+        let actual_output = formatter.format(input);
+
+        // Assert
+        assert!(actual_output.is_ok());
+        assert_eq!(expected, actual_output.unwrap());
+    }
+
+    #[test_case(i128::MIN)]
+    #[test_case(i128::MAX)]
+    #[test_case(-123456789)]
+    fn negative_base_number_formatter_should_round_trip(input: i128) {
+        // Arrange
+        let formatter = NegativeBaseNumberFormatter { radix: 2 };
+
+        // Act
+        let actual_number = formatter.read(&formatter.format(input).unwrap());
+
+        // Assert
+        assert_eq!(input, actual_number.unwrap());
+    }
+
+    #[test_case("-101", NumberFormatterError::InvalidDigit ; "sign")]
+    #[test_case("102", NumberFormatterError::InvalidDigit ; "digit")]
+    #[test_case("", NumberFormatterError::Empty ; "empty")]
+    fn negative_base_number_formatter_should_fail_to_read(
+        input: &str,
+        expected: NumberFormatterError,
+    ) {
+        // Arrange
+        let formatter = NegativeBaseNumberFormatter { radix: 2 };
+
+        // Act
+        let actual_number = formatter.read(input);
+
+        // Assert
+        assert_eq!(
+            std::mem::discriminant(&actual_number.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+}
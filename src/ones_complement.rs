@@ -0,0 +1,156 @@
+use crate::{IntegerSize, NumberFormatter, NumberFormatterError};
+
+/// One's complement formatter reading and writing binary bit patterns. Input is
+/// interpreted at the width of the digits given, so `11111010` is −5 while `1010`
+/// is also −5 at four bits. Output is padded with the sign bit to the integer size,
+/// or else uses the smallest width with a sign bit
+pub(crate) struct OnesComplementNumberFormatter {
+    pub size: Option<IntegerSize>,
+}
+
+impl NumberFormatter for OnesComplementNumberFormatter {
+    fn read(&self, bits: &str) -> anyhow::Result<i128, NumberFormatterError> {
+        let without_prefix = bits.trim_start_matches("0b").trim_start_matches("0B");
+
+        if without_prefix.is_empty() {
+            return Err(NumberFormatterError::Empty);
+        }
+
+        if !without_prefix.chars().all(|c| c == '0' || c == '1') {
+            return Err(NumberFormatterError::InvalidDigit);
+        }
+
+        if !without_prefix.starts_with('1') {
+            return i128::from_str_radix(without_prefix, 2).map_err(|op| op.into());
+        }
+
+        let inverted: String = without_prefix
+            .chars()
+            .map(|c| if c == '1' { '0' } else { '1' })
+            .collect();
+
+        i128::from_str_radix(&inverted, 2)
+            .map(|magnitude| -magnitude)
+            .map_err(|_| NumberFormatterError::TooSmallError)
+    }
+
+    fn format(&self, num: i128) -> anyhow::Result<String, NumberFormatterError> {
+        let magnitude = format!("0{:b}", num.unsigned_abs());
+        let bits: String = if num == 0 {
+            String::from("0")
+        } else if num > 0 {
+            magnitude
+        } else {
+            magnitude
+                .chars()
+                .map(|c| if c == '1' { '0' } else { '1' })
+                .collect()
+        };
+
+        let Some(size) = self.size else {
+            return Ok(bits);
+        };
+
+        let width = size.bits as usize;
+        if bits.len() > width {
+            return Err(if num < 0 {
+                NumberFormatterError::TooSmallError
+            } else {
+                NumberFormatterError::TooLargeError
+            });
+        }
+
+        let sign = if num < 0 { "1" } else { "0" };
+        Ok(sign.repeat(width - bits.len()) + &bits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("11111010", -5)]
+    #[test_case("1010", -5)]
+    #[test_case("0b0101", 5)]
+    #[test_case("1111", 0 ; "negative zero")]
+    #[test_case("0", 0)]
+    fn ones_complement_number_formatter_should_read_number(input: &str, expected: i128) {
+        // Act
+        let actual_number = OnesComplementNumberFormatter { size: None }.read(input);
+
+        // Assert
+        assert!(actual_number.is_ok());
+        assert_eq!(expected, actual_number.unwrap());
+    }
+
+    #[test_case(-5, "1010")]
+    #[test_case(5, "0101")]
+    #[test_case(-1, "10")]
+    #[test_case(0, "0")]
+    fn ones_complement_number_formatter_should_format_i128(input: i128, expected: &str) {
+        // Act
+        let actual_output = OnesComplementNumberFormatter { size: None }.format(input);
+
+        // Assert
+        assert!(actual_output.is_ok());
+        assert_eq!(expected, actual_output.unwrap());
+    }
+
+    #[test_case(-5, 8, "11111010")]
+    #[test_case(5, 8, "00000101"; "positive")]
+    #[test_case(0, 4, "0000"; "zero")]
+    #[test_case(-127, 8, "10000000"; "smallest")]
+    fn ones_complement_number_formatter_should_pad_to_size(input: i128, bits: u32, expected: &str) {
+        // Arrange
+        let formatter = OnesComplementNumberFormatter {
+            size: Some(IntegerSize { bits, signed: true }),
+        };
+
+        // Act
+        let actual_output = formatter.format(input);
+
+        // Assert
+        assert_eq!(actual_output.unwrap(), expected);
+    }
+
+    #[test_case(128, NumberFormatterError::TooLargeError; "too large")]
+    #[test_case(-128, NumberFormatterError::TooSmallError; "too small")]
+    fn ones_complement_number_formatter_should_fail_to_format(
+        input: i128,
+        expected: NumberFormatterError,
+    ) {
+        // Arrange
+        let formatter = OnesComplementNumberFormatter {
+            size: Some(IntegerSize {
+                bits: 8,
+                signed: true,
+            }),
+        };
+
+        // Act
+        let actual_output = formatter.format(input);
+
+        // Assert
+        assert_eq!(
+            std::mem::discriminant(&actual_output.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+
+    #[test_case("1012", NumberFormatterError::InvalidDigit ; "invalid digit")]
+    #[test_case("", NumberFormatterError::Empty ; "empty")]
+    fn ones_complement_number_formatter_should_fail_to_read(
+        input: &str,
+        expected: NumberFormatterError,
+    ) {
+        // Act
+        let actual_number = OnesComplementNumberFormatter { size: None }.read(input);
+
+        // Assert
+        assert_eq!(
+            std::mem::discriminant(&actual_number.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+}
@@ -0,0 +1,48 @@
+use crate::align;
+use clap::Args;
+
+/// Options of the page command
+#[derive(Args, Clone, Debug)]
+pub struct PageOptions {
+    /// Size of a page, in bytes or with a K, M or G suffix for binary multiples
+    #[clap(long, default_value = "4K", value_parser = parse_page_size)]
+    pub page_size: u128,
+    /// The address to split into a page number and offset
+    pub number: String,
+}
+
+/// Clap value parser accepting a page size such as `4096`, `0x1000` or `4K`
+pub(crate) fn parse_page_size(size: &str) -> Result<u128, String> {
+    let (digits, multiplier) = match size.char_indices().last() {
+        Some((index, 'k' | 'K')) => (&size[..index], 1 << 10),
+        Some((index, 'm' | 'M')) => (&size[..index], 1 << 20),
+        Some((index, 'g' | 'G')) => (&size[..index], 1 << 30),
+        _ => (size, 1),
+    };
+
+    align::parse_alignment(digits)
+        .ok()
+        .and_then(|size| size.checked_mul(multiplier))
+        .ok_or_else(|| String::from("page size must be a positive size, e.g. 4096, 0x1000 or 4K"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("4096", Ok(4096))]
+    #[test_case("0x1000", Ok(4096))]
+    #[test_case("4K", Ok(4096))]
+    #[test_case("2m", Ok(2 << 20))]
+    #[test_case("1G", Ok(1 << 30))]
+    #[test_case("K", Err(()))]
+    #[test_case("0K", Err(()); "zero")]
+    fn parse_page_size_should_read_size(size: &str, expected: Result<u128, ()>) {
+        // Act
+        let actual = parse_page_size(size);
+
+        // Assert
+        assert_eq!(actual.map_err(|_| ()), expected);
+    }
+}
@@ -0,0 +1,211 @@
+use crate::{NumberFormatter, NumberFormatterError};
+
+/// File type bits of a mode, as in `S_IFMT`
+const FILE_TYPE_MASK: i128 = 0o170000;
+
+/// Largest mode, with every permission, special and file type bit set
+const MAX_MODE: i128 = 0o177777;
+
+/// File types by their bits and the character `ls -l` shows them with
+static FILE_TYPES: [(i128, char); 7] = [
+    (0o140000, 's'),
+    (0o120000, 'l'),
+    (0o100000, '-'),
+    (0o060000, 'b'),
+    (0o040000, 'd'),
+    (0o020000, 'c'),
+    (0o010000, 'p'),
+];
+
+/// Permission classes from user to other, with the special bit each one's execute position shows
+/// and the characters for it with and without execute permission
+static CLASSES: [(u32, i128, char, char); 3] = [
+    (6, 0o4000, 's', 'S'),
+    (3, 0o2000, 's', 'S'),
+    (0, 0o1000, 't', 'T'),
+];
+
+/// Formatter writing file modes symbolically as `ls -l` does, such as `rwxr-xr--` for 0754 or
+/// `drwxrwxrwt` for 041777. Reads modes either symbolically or in octal as chmod takes them, with
+/// or without a leading 0.
+pub(crate) struct PermissionsNumberFormatter;
+
+/// Whether the input is written as symbolic permissions
+pub(crate) fn is_permissions(input: &str) -> bool {
+    read_symbolic(input).is_ok()
+}
+
+/// Whether the input is an octal mode with a leading 0 as chmod takes it, such as `0754`
+pub(crate) fn is_octal_mode(input: &str) -> bool {
+    input.len() > 1 && input.starts_with('0') && input.chars().all(|c| c.is_digit(8))
+}
+
+/// Reads an octal mode such as `754`, `0754` or `0o754`
+fn read_octal(octal: &str) -> Option<anyhow::Result<i128, NumberFormatterError>> {
+    let digits = octal.strip_prefix("0o").unwrap_or(octal);
+    if digits.is_empty() || !digits.chars().all(|c| c.is_digit(8)) {
+        return None;
+    }
+
+    Some(
+        i128::from_str_radix(digits, 8)
+            .ok()
+            .filter(|mode| *mode <= MAX_MODE)
+            .ok_or(NumberFormatterError::TooLargeError),
+    )
+}
+
+/// Reads a mode written as `ls -l` shows it, such as `rwxr-xr--` or `drwxrwxrwt`
+fn read_symbolic(symbolic: &str) -> anyhow::Result<i128, NumberFormatterError> {
+    let chars: Vec<char> = symbolic.chars().collect();
+    let (file_type, permissions) = match chars.len() {
+        0 => return Err(NumberFormatterError::Empty),
+        9 => (0, &chars[..]),
+        10 => {
+            let bits = FILE_TYPES
+                .iter()
+                .find(|(_, c)| *c == chars[0])
+                .map(|(bits, _)| *bits)
+                .ok_or(NumberFormatterError::InvalidPermissions)?;
+            (bits, &chars[1..])
+        }
+        _ => return Err(NumberFormatterError::InvalidPermissions),
+    };
+
+    let mut mode = file_type;
+    for (class, (shift, special, executable, special_only)) in permissions.chunks(3).zip(CLASSES) {
+        let read = match class[0] {
+            'r' => 0o4,
+            '-' => 0,
+            _ => return Err(NumberFormatterError::InvalidPermissions),
+        };
+        let write = match class[1] {
+            'w' => 0o2,
+            '-' => 0,
+            _ => return Err(NumberFormatterError::InvalidPermissions),
+        };
+        let (execute, special) = match class[2] {
+            'x' => (0o1, 0),
+            '-' => (0, 0),
+            c if c == executable => (0o1, special),
+            c if c == special_only => (0, special),
+            _ => return Err(NumberFormatterError::InvalidPermissions),
+        };
+
+        mode |= (read | write | execute) << shift | special;
+    }
+
+    Ok(mode)
+}
+
+impl NumberFormatter for PermissionsNumberFormatter {
+    fn read(&self, mode: &str) -> anyhow::Result<i128, NumberFormatterError> {
+        read_octal(mode).unwrap_or_else(|| read_symbolic(mode))
+    }
+
+    fn format(&self, num: i128) -> anyhow::Result<String, NumberFormatterError> {
+        if num < 0 {
+            return Err(NumberFormatterError::TooSmallError);
+        }
+        if num > MAX_MODE {
+            return Err(NumberFormatterError::TooLargeError);
+        }
+
+        let mut symbolic = String::new();
+
+        if num & FILE_TYPE_MASK != 0 {
+            let (_, c) = FILE_TYPES
+                .iter()
+                .find(|(bits, _)| *bits == num & FILE_TYPE_MASK)
+                .ok_or(NumberFormatterError::InvalidPermissions)?;
+            symbolic.push(*c);
+        }
+
+        for (shift, special, executable, special_only) in CLASSES {
+            let bits = num >> shift;
+            symbolic.push(if bits & 0o4 != 0 { 'r' } else { '-' });
+            symbolic.push(if bits & 0o2 != 0 { 'w' } else { '-' });
+            symbolic.push(match (bits & 0o1 != 0, num & special != 0) {
+                (true, true) => executable,
+                (false, true) => special_only,
+                (true, false) => 'x',
+                (false, false) => '-',
+            });
+        }
+
+        Ok(symbolic)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(0o754, "rwxr-xr--")]
+    #[test_case(0o644, "rw-r--r--")]
+    #[test_case(0o4755, "rwsr-xr-x"; "setuid")]
+    #[test_case(0o2745, "rwxr-Sr-x"; "setgid without execute")]
+    #[test_case(0o1777, "rwxrwxrwt"; "sticky")]
+    #[test_case(0o041777, "drwxrwxrwt"; "directory")]
+    #[test_case(0o100644, "-rw-r--r--"; "regular file")]
+    #[test_case(0o120777, "lrwxrwxrwx"; "symbolic link")]
+    fn permissions_number_formatter_should_format_and_read(mode: i128, symbolic: &str) {
+        // Act
+        let actual_symbolic = PermissionsNumberFormatter.format(mode);
+        let actual_mode = PermissionsNumberFormatter.read(symbolic);
+
+        // Assert
+        assert_eq!(actual_symbolic.unwrap(), symbolic);
+        assert_eq!(actual_mode.unwrap(), mode);
+    }
+
+    #[test_case("0754", 0o754)]
+    #[test_case("754", 0o754; "without leading zero")]
+    #[test_case("0o4755", 0o4755; "prefixed")]
+    #[test_case("041777", 0o041777; "directory")]
+    fn permissions_number_formatter_should_read_octal_mode(octal: &str, expected: i128) {
+        // Act
+        let actual = PermissionsNumberFormatter.read(octal);
+
+        // Assert
+        assert_eq!(actual.unwrap(), expected);
+    }
+
+    #[test_case("", NumberFormatterError::Empty; "empty")]
+    #[test_case("0200000", NumberFormatterError::TooLargeError; "octal too large")]
+    #[test_case("rwxr-x", NumberFormatterError::InvalidPermissions; "too short")]
+    #[test_case("rwtr-xr--", NumberFormatterError::InvalidPermissions; "sticky bit out of place")]
+    #[test_case("xrwr-xr--", NumberFormatterError::InvalidPermissions; "out of order")]
+    #[test_case("zrwxr-xr--", NumberFormatterError::InvalidPermissions; "unknown file type")]
+    fn permissions_number_formatter_should_fail_to_read(
+        input: &str,
+        expected: NumberFormatterError,
+    ) {
+        // Act
+        let actual = PermissionsNumberFormatter.read(input);
+
+        // Assert
+        assert_eq!(
+            std::mem::discriminant(&actual.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+
+    #[test_case(0o200000, NumberFormatterError::TooLargeError; "too large")]
+    #[test_case(0o030644, NumberFormatterError::InvalidPermissions; "unknown file type")]
+    #[test_case(-1, NumberFormatterError::TooSmallError; "negative")]
+    fn permissions_number_formatter_should_fail_to_format(
+        input: i128,
+        expected: NumberFormatterError,
+    ) {
+        // Act
+        let actual = PermissionsNumberFormatter.format(input);
+
+        // Assert
+        assert_eq!(
+            std::mem::discriminant(&actual.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+}
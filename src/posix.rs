@@ -0,0 +1,302 @@
+use crate::{NumberFormatter, NumberFormatterError};
+
+/// Linux signal numbers, names and the descriptions `strsignal` gives them
+static SIGNALS: [(i128, &str, &str); 31] = [
+    (1, "SIGHUP", "Hangup"),
+    (2, "SIGINT", "Interrupt"),
+    (3, "SIGQUIT", "Quit"),
+    (4, "SIGILL", "Illegal instruction"),
+    (5, "SIGTRAP", "Trace/breakpoint trap"),
+    (6, "SIGABRT", "Aborted"),
+    (7, "SIGBUS", "Bus error"),
+    (8, "SIGFPE", "Floating point exception"),
+    (9, "SIGKILL", "Killed"),
+    (10, "SIGUSR1", "User defined signal 1"),
+    (11, "SIGSEGV", "Segmentation fault"),
+    (12, "SIGUSR2", "User defined signal 2"),
+    (13, "SIGPIPE", "Broken pipe"),
+    (14, "SIGALRM", "Alarm clock"),
+    (15, "SIGTERM", "Terminated"),
+    (16, "SIGSTKFLT", "Stack fault"),
+    (17, "SIGCHLD", "Child exited"),
+    (18, "SIGCONT", "Continued"),
+    (19, "SIGSTOP", "Stopped (signal)"),
+    (20, "SIGTSTP", "Stopped"),
+    (21, "SIGTTIN", "Stopped (tty input)"),
+    (22, "SIGTTOU", "Stopped (tty output)"),
+    (23, "SIGURG", "Urgent I/O condition"),
+    (24, "SIGXCPU", "CPU time limit exceeded"),
+    (25, "SIGXFSZ", "File size limit exceeded"),
+    (26, "SIGVTALRM", "Virtual timer expired"),
+    (27, "SIGPROF", "Profiling timer expired"),
+    (28, "SIGWINCH", "Window changed"),
+    (29, "SIGIO", "I/O possible"),
+    (30, "SIGPWR", "Power failure"),
+    (31, "SIGSYS", "Bad system call"),
+];
+
+/// Linux errno values, names and the descriptions `strerror` gives them
+static ERRNOS: [(i128, &str, &str); 74] = [
+    (1, "EPERM", "Operation not permitted"),
+    (2, "ENOENT", "No such file or directory"),
+    (3, "ESRCH", "No such process"),
+    (4, "EINTR", "Interrupted system call"),
+    (5, "EIO", "Input/output error"),
+    (6, "ENXIO", "No such device or address"),
+    (7, "E2BIG", "Argument list too long"),
+    (8, "ENOEXEC", "Exec format error"),
+    (9, "EBADF", "Bad file descriptor"),
+    (10, "ECHILD", "No child processes"),
+    (11, "EAGAIN", "Resource temporarily unavailable"),
+    (12, "ENOMEM", "Cannot allocate memory"),
+    (13, "EACCES", "Permission denied"),
+    (14, "EFAULT", "Bad address"),
+    (15, "ENOTBLK", "Block device required"),
+    (16, "EBUSY", "Device or resource busy"),
+    (17, "EEXIST", "File exists"),
+    (18, "EXDEV", "Invalid cross-device link"),
+    (19, "ENODEV", "No such device"),
+    (20, "ENOTDIR", "Not a directory"),
+    (21, "EISDIR", "Is a directory"),
+    (22, "EINVAL", "Invalid argument"),
+    (23, "ENFILE", "Too many open files in system"),
+    (24, "EMFILE", "Too many open files"),
+    (25, "ENOTTY", "Inappropriate ioctl for device"),
+    (26, "ETXTBSY", "Text file busy"),
+    (27, "EFBIG", "File too large"),
+    (28, "ENOSPC", "No space left on device"),
+    (29, "ESPIPE", "Illegal seek"),
+    (30, "EROFS", "Read-only file system"),
+    (31, "EMLINK", "Too many links"),
+    (32, "EPIPE", "Broken pipe"),
+    (33, "EDOM", "Numerical argument out of domain"),
+    (34, "ERANGE", "Numerical result out of range"),
+    (35, "EDEADLK", "Resource deadlock avoided"),
+    (36, "ENAMETOOLONG", "File name too long"),
+    (37, "ENOLCK", "No locks available"),
+    (38, "ENOSYS", "Function not implemented"),
+    (39, "ENOTEMPTY", "Directory not empty"),
+    (40, "ELOOP", "Too many levels of symbolic links"),
+    (42, "ENOMSG", "No message of desired type"),
+    (43, "EIDRM", "Identifier removed"),
+    (61, "ENODATA", "No data available"),
+    (62, "ETIME", "Timer expired"),
+    (67, "ENOLINK", "Link has been severed"),
+    (71, "EPROTO", "Protocol error"),
+    (74, "EBADMSG", "Bad message"),
+    (75, "EOVERFLOW", "Value too large for defined data type"),
+    (
+        84,
+        "EILSEQ",
+        "Invalid or incomplete multibyte or wide character",
+    ),
+    (88, "ENOTSOCK", "Socket operation on non-socket"),
+    (89, "EDESTADDRREQ", "Destination address required"),
+    (90, "EMSGSIZE", "Message too long"),
+    (91, "EPROTOTYPE", "Protocol wrong type for socket"),
+    (92, "ENOPROTOOPT", "Protocol not available"),
+    (93, "EPROTONOSUPPORT", "Protocol not supported"),
+    (95, "EOPNOTSUPP", "Operation not supported"),
+    (
+        97,
+        "EAFNOSUPPORT",
+        "Address family not supported by protocol",
+    ),
+    (98, "EADDRINUSE", "Address already in use"),
+    (99, "EADDRNOTAVAIL", "Cannot assign requested address"),
+    (100, "ENETDOWN", "Network is down"),
+    (101, "ENETUNREACH", "Network is unreachable"),
+    (102, "ENETRESET", "Network dropped connection on reset"),
+    (103, "ECONNABORTED", "Software caused connection abort"),
+    (104, "ECONNRESET", "Connection reset by peer"),
+    (105, "ENOBUFS", "No buffer space available"),
+    (106, "EISCONN", "Transport endpoint is already connected"),
+    (107, "ENOTCONN", "Transport endpoint is not connected"),
+    (110, "ETIMEDOUT", "Connection timed out"),
+    (111, "ECONNREFUSED", "Connection refused"),
+    (112, "EHOSTDOWN", "Host is down"),
+    (113, "EHOSTUNREACH", "No route to host"),
+    (114, "EALREADY", "Operation already in progress"),
+    (115, "EINPROGRESS", "Operation now in progress"),
+    (125, "ECANCELED", "Operation canceled"),
+];
+
+/// Exit statuses shells give processes killed by a signal are this plus the signal number
+const SIGNAL_EXIT_STATUS: i128 = 128;
+
+/// The number of a name in a table, ignoring case
+fn number_of(table: &[(i128, &str, &str)], name: &str) -> Option<i128> {
+    table
+        .iter()
+        .find(|(_, known, _)| known.eq_ignore_ascii_case(name))
+        .map(|(num, _, _)| *num)
+}
+
+/// The name and description of a number in a table
+fn describe(table: &[(i128, &str, &str)], num: i128) -> Option<String> {
+    table
+        .iter()
+        .find(|(known, _, _)| *known == num)
+        .map(|(_, name, description)| format!("{} ({})", name, description))
+}
+
+/// Whether the input is the name of a signal
+pub(crate) fn is_signal(input: &str) -> bool {
+    number_of(&SIGNALS, input).is_some()
+}
+
+/// Whether the input is the name of an errno value
+pub(crate) fn is_errno(input: &str) -> bool {
+    number_of(&ERRNOS, input.strip_prefix('-').unwrap_or(input)).is_some()
+}
+
+/// Formatter writing numbers as Linux signal names, such as `SIGKILL (Killed)` for 9. Exit statuses
+/// of processes killed by a signal, such as 137, are written as the signal plus 128.
+pub(crate) struct SignalNumberFormatter;
+
+impl NumberFormatter for SignalNumberFormatter {
+    fn read(&self, name: &str) -> anyhow::Result<i128, NumberFormatterError> {
+        let name = name.split_whitespace().next().unwrap_or_default();
+
+        if name.is_empty() {
+            return Err(NumberFormatterError::Empty);
+        }
+
+        number_of(&SIGNALS, name)
+            .or_else(|| number_of(&SIGNALS, &format!("SIG{}", name)))
+            .ok_or(NumberFormatterError::UnknownName)
+    }
+
+    fn format(&self, num: i128) -> anyhow::Result<String, NumberFormatterError> {
+        describe(&SIGNALS, num)
+            .or_else(|| {
+                describe(&SIGNALS, num.checked_sub(SIGNAL_EXIT_STATUS)?)
+                    .map(|signal| format!("{} + {}", SIGNAL_EXIT_STATUS, signal))
+            })
+            .ok_or(NumberFormatterError::UnknownName)
+    }
+}
+
+/// Formatter writing numbers as Linux errno names, such as `ENOENT (No such file or directory)`
+/// for 2. Negative numbers, as system calls return errors, keep their sign.
+pub(crate) struct ErrnoNumberFormatter;
+
+impl NumberFormatter for ErrnoNumberFormatter {
+    fn read(&self, name: &str) -> anyhow::Result<i128, NumberFormatterError> {
+        let name = name.split_whitespace().next().unwrap_or_default();
+        let (negative, name) = match name.strip_prefix('-') {
+            Some(name) => (true, name),
+            None => (false, name),
+        };
+
+        if name.is_empty() {
+            return Err(NumberFormatterError::Empty);
+        }
+
+        let num = number_of(&ERRNOS, name).ok_or(NumberFormatterError::UnknownName)?;
+        Ok(if negative { -num } else { num })
+    }
+
+    fn format(&self, num: i128) -> anyhow::Result<String, NumberFormatterError> {
+        let errno = i128::try_from(num.unsigned_abs())
+            .ok()
+            .and_then(|magnitude| describe(&ERRNOS, magnitude))
+            .ok_or(NumberFormatterError::UnknownName)?;
+        Ok(if num < 0 {
+            format!("-{}", errno)
+        } else {
+            errno
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(9, "SIGKILL (Killed)")]
+    #[test_case(11, "SIGSEGV (Segmentation fault)")]
+    #[test_case(137, "128 + SIGKILL (Killed)"; "exit status")]
+    fn signal_number_formatter_should_format_i128(input: i128, expected: &str) {
+        // Act
+        let actual = SignalNumberFormatter.format(input);
+
+        // Assert
+        assert_eq!(actual.unwrap(), expected);
+    }
+
+    #[test_case("SIGTERM", 15)]
+    #[test_case("term", 15; "without prefix")]
+    #[test_case("SIGKILL (Killed)", 9; "with description")]
+    fn signal_number_formatter_should_read_name(input: &str, expected: i128) {
+        // Act
+        let actual = SignalNumberFormatter.read(input);
+
+        // Assert
+        assert_eq!(actual.unwrap(), expected);
+    }
+
+    #[test_case(2, "ENOENT (No such file or directory)")]
+    #[test_case(-13, "-EACCES (Permission denied)"; "negative")]
+    fn errno_number_formatter_should_format_i128(input: i128, expected: &str) {
+        // Act
+        let actual = ErrnoNumberFormatter.format(input);
+
+        // Assert
+        assert_eq!(actual.unwrap(), expected);
+    }
+
+    #[test_case("ENOENT", 2)]
+    #[test_case("econnrefused", 111; "lowercase")]
+    #[test_case("-EAGAIN", -11; "negative")]
+    fn errno_number_formatter_should_read_name(input: &str, expected: i128) {
+        // Act
+        let actual = ErrnoNumberFormatter.read(input);
+
+        // Assert
+        assert_eq!(actual.unwrap(), expected);
+    }
+
+    #[test]
+    fn number_formatters_should_reject_unknown_numbers_and_names() {
+        // Act
+        let signal_number = SignalNumberFormatter.format(0);
+        let signal_name = SignalNumberFormatter.read("SIGFOO");
+        let errno_number = ErrnoNumberFormatter.format(41);
+        let errno_name = ErrnoNumberFormatter.read("EFOO");
+
+        // Assert
+        assert!(matches!(
+            signal_number,
+            Err(NumberFormatterError::UnknownName)
+        ));
+        assert!(matches!(
+            signal_name,
+            Err(NumberFormatterError::UnknownName)
+        ));
+        assert!(matches!(
+            errno_number,
+            Err(NumberFormatterError::UnknownName)
+        ));
+        assert!(matches!(errno_name, Err(NumberFormatterError::UnknownName)));
+    }
+
+    #[test]
+    fn number_formatters_should_reject_smallest_number() {
+        // Act
+        let signal = SignalNumberFormatter.format(i128::MIN);
+        let errno = ErrnoNumberFormatter.format(i128::MIN);
+
+        // Assert
+        assert!(matches!(signal, Err(NumberFormatterError::UnknownName)));
+        assert!(matches!(errno, Err(NumberFormatterError::UnknownName)));
+    }
+
+    #[test]
+    fn tables_should_be_sorted_by_number() {
+        // Assert
+        assert!(SIGNALS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        assert!(ERRNOS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+}
@@ -0,0 +1,251 @@
+use clap::ArgEnum;
+
+/// How far fractional output is rounded
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Precision {
+    /// A number of decimal places
+    Decimals(u32),
+    /// A number of significant figures
+    SignificantFigures(u32),
+}
+
+/// Decimal representations floats can be written in without rounding them
+#[derive(ArgEnum, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FloatDigits {
+    /// The fewest digits that are guaranteed to read back as the same float
+    Shortest,
+    /// Every digit of the value the float holds
+    Exact,
+}
+
+/// How floats are written, either unrounded or rounded like other fractional output
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) enum FloatPrecision {
+    /// The fewest digits that read back as the same float, without noting it
+    #[default]
+    Shortest,
+    /// Digits chosen by --float-digits, noting which were chosen
+    Digits(FloatDigits),
+    /// The exact value rounded by --precision or --sig-figs
+    Rounded(Precision),
+}
+
+/// The next decimal digit of a fraction `remainder / den`, and the remainder left after it. Ten
+/// times the remainder is added up modulo den, as it can overflow for large dens.
+pub(crate) fn next_digit(remainder: u128, den: u128) -> (u8, u128) {
+    let mut digit = 0;
+    let mut tens = 0;
+
+    for _ in 0..10 {
+        if tens >= den - remainder {
+            tens -= den - remainder;
+            digit += 1;
+        } else {
+            tens += remainder
+        }
+    }
+
+    (digit, tens)
+}
+
+/// Zeros after the decimal point of a nonzero fraction `remainder / den` before its first nonzero
+/// digit
+fn leading_zeros(mut remainder: u128, den: u128) -> u32 {
+    let mut zeros = 0;
+
+    loop {
+        let (digit, next) = next_digit(remainder, den);
+        if digit != 0 {
+            return zeros;
+        }
+        remainder = next;
+        zeros += 1;
+    }
+}
+
+/// Divides two numbers, rounding half up to some precision, and writes the quotient without
+/// trailing zeros after the decimal point. The digits are found one at a time by long division so
+/// that nothing overflows.
+pub(crate) fn divide(num: u128, den: u128, precision: Precision) -> String {
+    let mut quotient = num / den;
+    let mut remainder = num % den;
+
+    let decimals = match precision {
+        Precision::Decimals(decimals) => decimals,
+        Precision::SignificantFigures(figures) => {
+            let whole_digits = quotient.checked_ilog10().map_or(0, |log| log + 1);
+            match (quotient, remainder) {
+                (0, 0) => 0,
+                (0, _) => leading_zeros(remainder, den) + figures,
+                _ if figures < whole_digits => {
+                    return round_whole(quotient, whole_digits - figures)
+                }
+                _ => figures - whole_digits,
+            }
+        }
+    };
+
+    let mut digits = Vec::new();
+    for _ in 0..decimals {
+        let (digit, next) = next_digit(remainder, den);
+        digits.push(digit);
+        remainder = next;
+    }
+
+    // Round half up, carrying through the digits into the integer part
+    if remainder >= den - remainder {
+        let mut carry = true;
+        for digit in digits.iter_mut().rev() {
+            if !carry {
+                break;
+            }
+            *digit = (*digit + 1) % 10;
+            carry = *digit == 0;
+        }
+        if carry {
+            quotient += 1;
+        }
+    }
+
+    while digits.last() == Some(&0) {
+        digits.pop();
+    }
+
+    if digits.is_empty() {
+        quotient.to_string()
+    } else {
+        format!(
+            "{}.{}",
+            quotient,
+            digits
+                .iter()
+                .map(|digit| char::from(b'0' + digit))
+                .collect::<String>()
+        )
+    }
+}
+
+/// Rounds a decimal number such as `-0.1000000000000000055` half up to some precision, and writes
+/// it without trailing zeros after the decimal point
+pub(crate) fn round_decimal(decimal: &str, precision: Precision) -> String {
+    let (sign, unsigned) = match decimal.strip_prefix('-') {
+        Some(unsigned) => ("-", unsigned),
+        None => ("", decimal),
+    };
+    let (whole, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    let mut digits: Vec<u8> = whole
+        .bytes()
+        .chain(fraction.bytes())
+        .map(|b| b - b'0')
+        .collect();
+    let mut point = whole.len();
+
+    let keep = match precision {
+        Precision::Decimals(decimals) => point + decimals as usize,
+        Precision::SignificantFigures(figures) => match digits.iter().position(|d| *d != 0) {
+            Some(first) => first + figures as usize,
+            None => digits.len(),
+        },
+    };
+
+    if keep < digits.len() {
+        let round_up = digits[keep] >= 5;
+        digits.truncate(keep);
+
+        // Digits dropped from the whole part become zeros
+        if keep < point {
+            digits.resize(point, 0);
+        }
+
+        if round_up {
+            let mut carry = true;
+            for digit in digits[..keep].iter_mut().rev() {
+                *digit = (*digit + 1) % 10;
+                carry = *digit == 0;
+                if !carry {
+                    break;
+                }
+            }
+            if carry {
+                digits.insert(0, 1);
+                point += 1;
+            }
+        }
+    }
+
+    let to_text = |digits: &[u8]| {
+        digits
+            .iter()
+            .map(|d| char::from(b'0' + d))
+            .collect::<String>()
+    };
+    let whole = to_text(&digits[..point]);
+    let whole = match whole.trim_start_matches('0') {
+        "" => "0",
+        trimmed => trimmed,
+    };
+    let fraction = to_text(&digits[point.min(digits.len())..]);
+
+    match fraction.trim_end_matches('0') {
+        "" => format!("{}{}", sign, whole),
+        fraction => format!("{}{}.{}", sign, whole, fraction),
+    }
+}
+
+/// Rounds a whole number half up to a multiple of 10 to the power of `places`, ignoring any fraction
+/// as that cannot reach the half way point. The digits are written rather than multiplied back as
+/// rounding up can leave more than a u128 holds.
+fn round_whole(quotient: u128, places: u32) -> String {
+    let place = 10u128.pow(places);
+    let rounded = if quotient % place >= place / 2 {
+        quotient / place + 1
+    } else {
+        quotient / place
+    };
+
+    format!("{}{}", rounded, "0".repeat(places as usize))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(3, 2, Precision::Decimals(2), "1.5")]
+    #[test_case(2, 3, Precision::Decimals(2), "0.67"; "rounds up")]
+    #[test_case(1, 3, Precision::Decimals(2), "0.33"; "rounds down")]
+    #[test_case(1999, 1000, Precision::Decimals(2), "2"; "carries")]
+    #[test_case(10, 5, Precision::Decimals(2), "2"; "whole")]
+    #[test_case(u128::MAX, u128::MAX / 2, Precision::Decimals(3), "2"; "large")]
+    #[test_case(2, 3, Precision::SignificantFigures(3), "0.667"; "significant figures")]
+    #[test_case(1, 700, Precision::SignificantFigures(2), "0.0014"; "significant figures after zeros")]
+    #[test_case(12345, 10, Precision::SignificantFigures(3), "1230"; "significant figures of whole part")]
+    #[test_case(12355, 10, Precision::SignificantFigures(3), "1240"; "significant figures rounding up")]
+    #[test_case(15, 10, Precision::SignificantFigures(2), "1.5"; "significant figures with fraction")]
+    #[test_case(0, 10, Precision::SignificantFigures(2), "0"; "significant figures of zero")]
+    #[test_case(u128::MAX, 1, Precision::SignificantFigures(9), "340282367000000000000000000000000000000"; "significant figures past u128")]
+    fn divide_should_round_half_up(num: u128, den: u128, precision: Precision, expected: &str) {
+        // Act
+        let actual = divide(num, den, precision);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test_case("0.1000000000000000055", Precision::Decimals(3), "0.1")]
+    #[test_case("0.125", Precision::Decimals(2), "0.13"; "rounds half up")]
+    #[test_case("-9.96", Precision::Decimals(1), "-10"; "carries")]
+    #[test_case("1234.5", Precision::Decimals(0), "1235"; "whole")]
+    #[test_case("0.000123", Precision::SignificantFigures(2), "0.00012"; "significant figures after zeros")]
+    #[test_case("98765", Precision::SignificantFigures(2), "99000"; "significant figures of whole part")]
+    #[test_case("99.5", Precision::SignificantFigures(2), "100"; "significant figures carrying")]
+    #[test_case("1.5", Precision::Decimals(5), "1.5"; "fewer digits than precision")]
+    #[test_case("0", Precision::SignificantFigures(3), "0"; "zero")]
+    fn round_decimal_should_round_half_up(decimal: &str, precision: Precision, expected: &str) {
+        // Act
+        let actual = round_decimal(decimal, precision);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,185 @@
+use clap::Args;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::io::Read;
+
+/// Options of the random command
+#[derive(Args, Clone, Debug)]
+pub struct RandomOptions {
+    /// How many random numbers to generate
+    #[clap(long, default_value_t = 1)]
+    pub count: usize,
+    /// Smallest number to generate, instead of the smallest of the integer size
+    #[clap(long, requires = "max", allow_hyphen_values = true)]
+    pub min: Option<String>,
+    /// Largest number to generate, instead of the largest of the integer size
+    #[clap(long, requires = "min", allow_hyphen_values = true)]
+    pub max: Option<String>,
+    /// Seed to generate the same numbers from each time, such as for reproducible test vectors.
+    /// Without one the generator is seeded from /dev/urandom. Either way the numbers are
+    /// predictable from earlier output, so are not fit for keys or tokens
+    #[clap(long)]
+    pub seed: Option<u64>,
+}
+
+/// A xoshiro256** pseudorandom number generator. Fast and well distributed, but predictable from
+/// its output, so not fit for keys.
+pub(crate) struct Rng {
+    state: [u64; 4],
+}
+
+/// The next output of a splitmix64 generator, used to spread a seed over the xoshiro state
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e3779b97f4a7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
+}
+
+impl Rng {
+    pub(crate) fn from_seed(mut seed: u64) -> Self {
+        Self {
+            state: std::array::from_fn(|_| splitmix64(&mut seed)),
+        }
+    }
+
+    /// A generator seeded from the operating system through /dev/urandom, so that each run
+    /// generates different numbers. Where that cannot be read, falls back to hashing the clock
+    /// with the randomly keyed hasher of the standard library
+    pub(crate) fn from_entropy() -> Self {
+        let mut bytes = [0u8; 32];
+        let read = std::fs::File::open("/dev/urandom")
+            .and_then(|mut urandom| urandom.read_exact(&mut bytes));
+
+        if read.is_ok() && bytes.iter().any(|byte| *byte != 0) {
+            return Self {
+                state: std::array::from_fn(|index| {
+                    u64::from_le_bytes(bytes[index * 8..][..8].try_into().unwrap())
+                }),
+            };
+        }
+
+        let mut hasher = RandomState::new().build_hasher();
+        hasher.write_u128(
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map_or(0, |duration| duration.as_nanos()),
+        );
+        Self::from_seed(hasher.finish())
+    }
+
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        let result = self.state[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = self.state[1] << 17;
+
+        self.state[2] ^= self.state[0];
+        self.state[3] ^= self.state[1];
+        self.state[1] ^= self.state[2];
+        self.state[0] ^= self.state[3];
+        self.state[2] ^= t;
+        self.state[3] = self.state[3].rotate_left(45);
+
+        result
+    }
+
+    pub(crate) fn next_u128(&mut self) -> u128 {
+        u128::from(self.next_u64()) << 64 | u128::from(self.next_u64())
+    }
+
+    /// A number from zero to `max` inclusive, each equally likely, by rejecting the outputs above
+    /// the largest multiple of the span that fits
+    pub(crate) fn up_to(&mut self, max: u128) -> u128 {
+        let Some(span) = max.checked_add(1) else {
+            return self.next_u128();
+        };
+        // 2^128 modulo the span, the count of outputs that would favour the lowest numbers
+        let excess = (u128::MAX % span + 1) % span;
+
+        loop {
+            let output = self.next_u128();
+            if output <= u128::MAX - excess {
+                return output % span;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test]
+    fn rng_should_match_reference_output() {
+        // Arrange
+        let mut rng = Rng {
+            state: [1, 2, 3, 4],
+        };
+
+        // Act
+        let actual: Vec<u64> = (0..3).map(|_| rng.next_u64()).collect();
+
+        // Assert
+        assert_eq!(actual, vec![11520, 0, 1509978240]);
+    }
+
+    #[test]
+    fn rng_should_repeat_from_seed() {
+        // Arrange
+        let mut first = Rng::from_seed(42);
+        let mut second = Rng::from_seed(42);
+
+        // Act
+        let actual: Vec<u128> = (0..4).map(|_| first.next_u128()).collect();
+
+        // Assert
+        assert_eq!(
+            actual,
+            (0..4).map(|_| second.next_u128()).collect::<Vec<u128>>()
+        );
+    }
+
+    #[test]
+    fn rng_should_differ_from_entropy() {
+        // Arrange
+        let mut first = Rng::from_entropy();
+        let mut second = Rng::from_entropy();
+
+        // Act
+        let actual = first.next_u128();
+
+        // Assert
+        assert_ne!(actual, second.next_u128());
+    }
+
+    #[test_case(0)]
+    #[test_case(6; "die")]
+    #[test_case(u128::MAX / 3 * 2; "large span")]
+    #[test_case(u128::MAX; "full range")]
+    fn rng_should_stay_up_to_max(max: u128) {
+        // Arrange
+        let mut rng = Rng::from_seed(7);
+
+        // Act
+        let actual: Vec<u128> = (0..100).map(|_| rng.up_to(max)).collect();
+
+        // Assert
+        assert!(actual.iter().all(|output| *output <= max));
+    }
+
+    #[test]
+    fn rng_should_reach_every_number_up_to_max() {
+        // Arrange
+        let mut rng = Rng::from_seed(7);
+
+        // Act
+        let mut seen = [false; 6];
+        for _ in 0..200 {
+            seen[rng.up_to(5) as usize] = true;
+        }
+
+        // Assert
+        assert!(seen.iter().all(|seen| *seen));
+    }
+}
@@ -0,0 +1,61 @@
+use crate::NumberFormatterError;
+use clap::Args;
+
+/// Options of the range command
+#[derive(Args, Clone, Debug)]
+pub struct RangeOptions {
+    /// The numbers to generate, from START up to but not including END as START..END, or up to and
+    /// including END as START..=END
+    #[clap(allow_hyphen_values = true)]
+    pub range: String,
+    /// Difference between consecutive numbers
+    #[clap(long, default_value = "1")]
+    pub step: String,
+}
+
+/// Splits a range written as `START..END` or `START..=END` into its start, its end and whether the
+/// end is included
+pub(crate) fn parse_range(range: &str) -> anyhow::Result<(&str, &str, bool), NumberFormatterError> {
+    let (start, end) = range
+        .split_once("..")
+        .filter(|(start, end)| !start.is_empty() && !end.is_empty())
+        .ok_or(NumberFormatterError::InvalidRange)?;
+
+    match end.strip_prefix('=') {
+        Some("") => Err(NumberFormatterError::InvalidRange),
+        Some(end) => Ok((start, end, true)),
+        None => Ok((start, end, false)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("0..10", "0", "10", false)]
+    #[test_case("0x10..=0x1f", "0x10", "0x1f", true; "inclusive")]
+    #[test_case("-5..-1", "-5", "-1", false; "negative")]
+    fn parse_range_should_split_range(range: &str, start: &str, end: &str, inclusive: bool) {
+        // Act
+        let actual = parse_range(range);
+
+        // Assert
+        assert_eq!(actual.unwrap(), (start, end, inclusive));
+    }
+
+    #[test_case("10")]
+    #[test_case("..10"; "no start")]
+    #[test_case("0.."; "no end")]
+    #[test_case("0..="; "no inclusive end")]
+    fn parse_range_should_fail(range: &str) {
+        // Act
+        let actual = parse_range(range);
+
+        // Assert
+        assert_eq!(
+            std::mem::discriminant(&actual.unwrap_err()),
+            std::mem::discriminant(&NumberFormatterError::InvalidRange)
+        );
+    }
+}
@@ -0,0 +1,384 @@
+use crate::float::{FloatBits, FloatFormat};
+use crate::humanize;
+use crate::precision::{self, Precision};
+use crate::rational::gcd;
+use crate::NumberFormatterError;
+use clap::Args;
+
+/// Options of the ratio command
+#[derive(Args, Clone, Debug)]
+pub struct RatioOptions {
+    /// The value to approximate, as a decimal such as 3.14159 or 1.5e-3
+    pub value: String,
+    /// The largest denominator an approximation may have
+    #[clap(long, default_value = "1000", value_parser = clap::value_parser!(u64).range(1..))]
+    pub max_denominator: u64,
+    /// Approximate the nearest float of the --float format to the value rather than the value as
+    /// written, e.g. 3602879701896397/36028797018963968 rather than 1/10 for 0.1
+    #[clap(long)]
+    pub as_float: bool,
+}
+
+/// How a best rational approximation was found from the continued fraction of a value
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ApproximationKind {
+    /// A continued fraction cut off after one of its terms
+    Convergent,
+    /// A convergent with its last term lowered to keep within the largest denominator
+    Semiconvergent,
+}
+
+/// A fraction closer to a value than any other fraction with a smaller denominator
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Approximation {
+    pub kind: ApproximationKind,
+    pub negative: bool,
+    pub numerator: u128,
+    pub denominator: u128,
+    /// How far the approximation is from the value times both of their denominators, which keeps
+    /// it whole
+    pub error: u128,
+    /// Whether the approximation is further from zero than the value
+    pub above: bool,
+}
+
+/// A value as a fraction in lowest terms, and its best rational approximations up to the largest
+/// denominator, from furthest to closest
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RatioInfo {
+    pub negative: bool,
+    pub numerator: u128,
+    pub denominator: u128,
+    pub approximations: Vec<Approximation>,
+    /// How the decimal values of the approximations are rounded
+    pub precision: Precision,
+}
+
+/// Decimal places the approximations are written with when no precision is chosen
+pub(crate) const RATIO_DECIMALS: u32 = 10;
+
+/// Reads a decimal with an optional exponent of ten as its sign, numerator and denominator
+pub(crate) fn read_decimal(
+    decimal: &str,
+) -> anyhow::Result<(bool, u128, u128), NumberFormatterError> {
+    let (negative, unsigned) = match decimal.strip_prefix('-') {
+        Some(unsigned) => (true, unsigned),
+        None => (false, decimal),
+    };
+    let (mantissa, exponent) = humanize::split_exponent(unsigned).unwrap_or((unsigned, 0));
+    let (digits, scale) = humanize::parse_decimal(mantissa)?;
+
+    let power = |places: i64| {
+        u32::try_from(places)
+            .ok()
+            .and_then(|places| 10u128.checked_pow(places))
+            .ok_or(NumberFormatterError::TooLargeError)
+    };
+    let places = exponent as i64 - scale as i64;
+    if places >= 0 {
+        Ok((
+            negative,
+            digits
+                .checked_mul(power(places)?)
+                .ok_or(NumberFormatterError::TooLargeError)?,
+            1,
+        ))
+    } else {
+        Ok((negative, digits, power(-places)?))
+    }
+}
+
+/// Reads a decimal as its nearest float of a format, with the float's exact value as its sign,
+/// numerator and denominator
+fn read_float(
+    float: &str,
+    format: FloatFormat,
+) -> anyhow::Result<(bool, u128, u128), NumberFormatterError> {
+    let bits = FloatBits::from_pattern(format.parse(float)?, format);
+    let (mantissa, exponent) = bits
+        .exact_value()
+        .ok_or(NumberFormatterError::InvalidFloat)?;
+
+    let power = |exponent: i64| {
+        if exponent < 128 {
+            Ok(1u128 << exponent)
+        } else {
+            Err(NumberFormatterError::TooLargeError)
+        }
+    };
+    if exponent >= 0 {
+        Ok((
+            bits.negative,
+            mantissa
+                .checked_mul(power(exponent)?)
+                .ok_or(NumberFormatterError::TooLargeError)?,
+            1,
+        ))
+    } else {
+        Ok((bits.negative, mantissa, power(-exponent)?))
+    }
+}
+
+/// The convergents of the continued fraction of `numerator / denominator` up to the largest
+/// denominator, followed by the semiconvergent nearest the largest denominator if it is closer
+/// than the last of them. Every other semiconvergent lies between two convergents and so is left
+/// out, as there can be thousands of them.
+///
+/// The Euclidean algorithm gives both the terms and the errors: the nth convergent is the nth
+/// remainder over both denominators from the value, on alternating sides of it.
+fn approximations(
+    negative: bool,
+    numerator: u128,
+    denominator: u128,
+    max_denominator: u128,
+) -> Vec<Approximation> {
+    let mut approximations = Vec::new();
+    let approximation = |kind, numerator, denominator, error, above: bool| Approximation {
+        kind,
+        negative: negative && numerator != 0,
+        numerator,
+        denominator,
+        error,
+        above: above && error != 0,
+    };
+
+    // The two previous convergents and remainders, starting from the ones before the first
+    let (mut h0, mut h1) = (0u128, 1u128);
+    let (mut k0, mut k1) = (1u128, 0u128);
+    let (mut r0, mut r1) = (numerator, denominator);
+    let mut above = false;
+
+    loop {
+        let term = r0 / r1;
+        let remainder = r0 % r1;
+        let next = term
+            .checked_mul(h1)
+            .and_then(|h| h.checked_add(h0))
+            .zip(term.checked_mul(k1).and_then(|k| k.checked_add(k0)));
+
+        let Some((h, k)) = next.filter(|(_, k)| *k <= max_denominator) else {
+            // Lowering the term to keep within the largest denominator leaves the semiconvergent
+            // on the side the convergent would have been, the side of the one before last
+            let lowered = (max_denominator - k0) / k1;
+            let h = lowered * h1 + h0;
+            let k = lowered * k1 + k0;
+            let error = r0 - lowered * r1;
+            let closer = match (lowered * 2).cmp(&term) {
+                std::cmp::Ordering::Greater => true,
+                std::cmp::Ordering::Equal => matches!(
+                    (error.checked_mul(k1), r1.checked_mul(k)),
+                    (Some(semiconvergent), Some(convergent)) if semiconvergent < convergent
+                ),
+                std::cmp::Ordering::Less => false,
+            };
+            if lowered > 0 && closer {
+                approximations.push(approximation(
+                    ApproximationKind::Semiconvergent,
+                    h,
+                    k,
+                    error,
+                    above,
+                ));
+            }
+            break;
+        };
+
+        approximations.push(approximation(
+            ApproximationKind::Convergent,
+            h,
+            k,
+            remainder,
+            above,
+        ));
+        if remainder == 0 {
+            break;
+        }
+
+        (h0, h1) = (h1, h);
+        (k0, k1) = (k1, k);
+        (r0, r1) = (r1, remainder);
+        above = !above;
+    }
+
+    approximations
+}
+
+impl RatioInfo {
+    /// Reads the value given to the ratio command, as written or as a float of a format, and finds
+    /// its best rational approximations
+    pub(crate) fn new(
+        options: &RatioOptions,
+        float: Option<FloatFormat>,
+        precision: Precision,
+    ) -> anyhow::Result<Self, NumberFormatterError> {
+        let (negative, numerator, denominator) = match float {
+            Some(format) => read_float(&options.value, format)?,
+            None => read_decimal(&options.value)?,
+        };
+        let divisor = gcd(numerator, denominator);
+        let (numerator, denominator) = (numerator / divisor, denominator / divisor);
+
+        Ok(Self {
+            negative: negative && numerator != 0,
+            numerator,
+            denominator,
+            approximations: approximations(
+                negative,
+                numerator,
+                denominator,
+                options.max_denominator as u128,
+            ),
+            precision,
+        })
+    }
+
+    /// Labelled lines describing the value and its approximations, in the order they should be
+    /// displayed
+    pub fn lines(&self) -> Vec<(&'static str, String)> {
+        let sign = |negative| if negative { "-" } else { "" };
+        let mut lines = vec![(
+            "Value",
+            format!(
+                "{}{}/{}",
+                sign(self.negative),
+                self.numerator,
+                self.denominator
+            ),
+        )];
+
+        for approximation in &self.approximations {
+            let label = match approximation.kind {
+                ApproximationKind::Convergent => "Convergent",
+                ApproximationKind::Semiconvergent => "Semiconvergent",
+            };
+            let error = match approximation.error {
+                0 => String::from("exact"),
+                error => {
+                    // Only a few figures are shown, which f64 keeps however small the error is
+                    let error =
+                        error as f64 / (approximation.denominator as f64 * self.denominator as f64);
+                    let error = if approximation.above == self.negative {
+                        -error
+                    } else {
+                        error
+                    };
+                    format!("error {:+.3e}", error)
+                }
+            };
+
+            lines.push((
+                label,
+                format!(
+                    "{}{}/{} = {}{} ({})",
+                    sign(approximation.negative),
+                    approximation.numerator,
+                    approximation.denominator,
+                    sign(approximation.negative),
+                    precision::divide(
+                        approximation.numerator,
+                        approximation.denominator,
+                        self.precision
+                    ),
+                    error
+                ),
+            ));
+        }
+
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("3.14159", Ok((false, 314159, 100000)))]
+    #[test_case("-0.25", Ok((true, 25, 100)); "negative")]
+    #[test_case("1.5e-3", Ok((false, 15, 10000)); "negative exponent")]
+    #[test_case("2.5e3", Ok((false, 2500, 1)); "positive exponent")]
+    #[test_case("1e39", Err(NumberFormatterError::TooLargeError); "too large")]
+    #[test_case("pi", Err(NumberFormatterError::InvalidDigit); "invalid")]
+    fn read_decimal_should_read_fraction(
+        decimal: &str,
+        expected: anyhow::Result<(bool, u128, u128), NumberFormatterError>,
+    ) {
+        // Act
+        let actual = read_decimal(decimal);
+
+        // Assert
+        assert_eq!(format!("{:?}", actual), format!("{:?}", expected));
+    }
+
+    #[test_case("0.1", FloatFormat::F64, Ok((false, 3602879701896397, 36028797018963968)))]
+    #[test_case("-0.1", FloatFormat::F32, Ok((true, 13421773, 134217728)); "single precision")]
+    #[test_case("3", FloatFormat::F64, Ok((false, 3, 1)); "whole")]
+    #[test_case("inf", FloatFormat::F64, Err(NumberFormatterError::InvalidFloat); "infinity")]
+    #[test_case("1e-300", FloatFormat::F64, Err(NumberFormatterError::TooLargeError); "denominator too large")]
+    fn read_float_should_read_exact_value(
+        float: &str,
+        format: FloatFormat,
+        expected: anyhow::Result<(bool, u128, u128), NumberFormatterError>,
+    ) {
+        // Act
+        let actual = read_float(float, format);
+
+        // Assert
+        assert_eq!(format!("{:?}", actual), format!("{:?}", expected));
+    }
+
+    #[test_case(314159, 100000, 1000, &[(3, 1), (22, 7), (333, 106), (355, 113)]; "pi")]
+    #[test_case(314159, 100000, 100, &[(3, 1), (22, 7), (311, 99)]; "bounded convergents")]
+    #[test_case(314159, 100000, 52, &[(3, 1), (22, 7)]; "semiconvergent too far")]
+    #[test_case(3, 8, 1000, &[(0, 1), (1, 2), (1, 3), (3, 8)]; "exact")]
+    #[test_case(1, 3, 2, &[(0, 1), (1, 2)]; "semiconvergent at bound")]
+    #[test_case(0, 1, 10, &[(0, 1)]; "zero")]
+    fn approximations_should_find_best_fractions(
+        numerator: u128,
+        denominator: u128,
+        max_denominator: u128,
+        expected: &[(u128, u128)],
+    ) {
+        // Act
+        let actual = approximations(false, numerator, denominator, max_denominator);
+
+        // Assert
+        assert_eq!(
+            actual
+                .iter()
+                .map(|approximation| (approximation.numerator, approximation.denominator))
+                .collect::<Vec<_>>(),
+            expected
+        );
+    }
+
+    #[test]
+    fn ratio_info_should_describe_approximations() {
+        // Arrange
+        let options = RatioOptions {
+            value: String::from("-0.3"),
+            max_denominator: 7,
+            as_float: false,
+        };
+
+        // Act
+        let actual = RatioInfo::new(&options, None, Precision::Decimals(3));
+
+        // Assert
+        assert_eq!(
+            actual.unwrap().lines(),
+            vec![
+                ("Value", String::from("-3/10")),
+                ("Convergent", String::from("0/1 = 0 (error +3.000e-1)")),
+                (
+                    "Convergent",
+                    String::from("-1/3 = -0.333 (error -3.333e-2)")
+                ),
+                (
+                    "Semiconvergent",
+                    String::from("-2/7 = -0.286 (error +1.429e-2)")
+                ),
+            ]
+        );
+    }
+}
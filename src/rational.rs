@@ -0,0 +1,324 @@
+use crate::humanize;
+use crate::precision;
+use crate::NumberFormatterError;
+use clap::Args;
+
+/// Options of the fraction command
+#[derive(Args, Clone, Debug)]
+pub struct FractionOptions {
+    /// The fraction, such as 6/14, or a decimal with its repetend in brackets such as 0.1[6]
+    pub value: String,
+}
+
+/// Most repetend digits written before the decimal is cut off, as a repetend can be almost as
+/// long as the denominator is large
+pub(crate) const REPETEND_LIMIT: usize = 1000;
+
+/// An exact fraction, kept in lowest terms
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Rational {
+    pub negative: bool,
+    pub numerator: u128,
+    pub denominator: u128,
+}
+
+/// The decimal digits of a fraction, which either end or repeat forever
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Decimal {
+    /// Digits before the decimal point
+    pub whole: u128,
+    /// Digits after the decimal point that are not repeated
+    pub fraction: String,
+    /// Digits repeated forever after the fraction, which are empty for decimals that end
+    pub repetend: String,
+    /// Whether the repetend is longer than the repetend limit and so was cut off
+    pub cut_off: bool,
+}
+
+/// The greatest common divisor of two numbers, by the Euclidean algorithm
+pub(crate) fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+impl Rational {
+    /// A fraction reduced to lowest terms, with zero never negative
+    pub(crate) fn new(
+        negative: bool,
+        numerator: u128,
+        denominator: u128,
+    ) -> anyhow::Result<Self, NumberFormatterError> {
+        if denominator == 0 {
+            return Err(NumberFormatterError::DivisionByZero);
+        }
+
+        let divisor = gcd(numerator, denominator);
+        Ok(Self {
+            negative: negative && numerator != 0,
+            numerator: numerator / divisor,
+            denominator: denominator / divisor,
+        })
+    }
+
+    /// Reads a fraction such as `-6/14`, a whole number, or a decimal such as `2.5` that may have
+    /// its repetend in brackets such as `0.1[6]`
+    pub(crate) fn parse(fraction: &str) -> anyhow::Result<Self, NumberFormatterError> {
+        let (negative, unsigned) = match fraction.strip_prefix('-') {
+            Some(unsigned) => (true, unsigned),
+            None => (false, fraction),
+        };
+        let whole = |digits: &str| {
+            if digits.chars().all(|c| c.is_ascii_digit()) {
+                Ok(digits.parse::<u128>()?)
+            } else {
+                Err(NumberFormatterError::InvalidFraction)
+            }
+        };
+
+        if let Some((numerator, denominator)) = unsigned.split_once('/') {
+            return Rational::new(negative, whole(numerator)?, whole(denominator)?);
+        }
+
+        let Some((decimal, repetend)) = unsigned.split_once('[') else {
+            let (digits, scale) = humanize::parse_decimal(unsigned)?;
+            return Rational::new(negative, digits, ten_to(scale)?);
+        };
+        let repetend = repetend
+            .strip_suffix(']')
+            .filter(|repetend| !repetend.is_empty() && decimal.contains('.'))
+            .ok_or(NumberFormatterError::InvalidFraction)?;
+
+        // x = d / 10^f + r / ((10^n - 1) * 10^f) for a repetend r of n digits
+        let decimal = if decimal.starts_with('.') {
+            format!("0{}", decimal)
+        } else {
+            decimal.to_string()
+        };
+        let (digits, scale) = humanize::parse_decimal(&decimal)?;
+        let nines = ten_to(repetend.len() as u32)? - 1;
+        let numerator = digits
+            .checked_mul(nines)
+            .and_then(|numerator| numerator.checked_add(whole(repetend).ok()?))
+            .ok_or(NumberFormatterError::TooLargeError)?;
+        let denominator = nines
+            .checked_mul(ten_to(scale)?)
+            .ok_or(NumberFormatterError::TooLargeError)?;
+
+        Rational::new(negative, numerator, denominator)
+    }
+
+    /// The decimal digits of the fraction, found by long division. The digits before the repetend
+    /// number as many as the powers of two or five in the denominator, whichever is more, after
+    /// which the remainders repeat from the first one.
+    pub fn decimal(&self) -> Decimal {
+        let whole = self.numerator / self.denominator;
+        let mut remainder = self.numerator % self.denominator;
+
+        let power_of = |factor: u128| {
+            let mut denominator = self.denominator;
+            let mut power = 0;
+            while denominator.is_multiple_of(factor) {
+                denominator /= factor;
+                power += 1;
+            }
+            power
+        };
+        let digit = |remainder: &mut u128| {
+            let (digit, next) = precision::next_digit(*remainder, self.denominator);
+            *remainder = next;
+            char::from(b'0' + digit)
+        };
+
+        let mut fraction = String::new();
+        for _ in 0..power_of(2).max(power_of(5)) {
+            if remainder == 0 {
+                break;
+            }
+            fraction.push(digit(&mut remainder));
+        }
+
+        let mut repetend = String::new();
+        let first = remainder;
+        while remainder != 0 && repetend.len() < REPETEND_LIMIT {
+            repetend.push(digit(&mut remainder));
+            if remainder == first {
+                break;
+            }
+        }
+
+        Decimal {
+            whole,
+            fraction,
+            cut_off: remainder != first,
+            repetend,
+        }
+    }
+
+    /// Labelled lines describing the fraction, in the order they should be displayed
+    pub fn lines(&self) -> Vec<(&'static str, String)> {
+        let sign = if self.negative { "-" } else { "" };
+        let decimal = self.decimal();
+        let mut lines = vec![(
+            "Fraction",
+            format!("{}{}/{}", sign, self.numerator, self.denominator),
+        )];
+
+        let remainder = self.numerator % self.denominator;
+        if decimal.whole != 0 && remainder != 0 {
+            lines.push((
+                "Mixed number",
+                format!(
+                    "{}{} {}/{}",
+                    sign, decimal.whole, remainder, self.denominator
+                ),
+            ));
+        }
+
+        let point = if decimal.fraction.is_empty() && decimal.repetend.is_empty() {
+            ""
+        } else {
+            "."
+        };
+        let (repetend, length) = match (decimal.repetend.len(), decimal.cut_off) {
+            (0, _) => (String::new(), String::from("none, the decimal ends")),
+            (1, false) => (format!("[{}]", decimal.repetend), String::from("1 digit")),
+            (length, false) => (
+                format!("[{}]", decimal.repetend),
+                format!("{} digits", length),
+            ),
+            (length, true) => (
+                format!("[{}...", decimal.repetend),
+                format!("more than {} digits", length),
+            ),
+        };
+        lines.push((
+            "Decimal",
+            format!(
+                "{}{}{}{}{}",
+                sign, decimal.whole, point, decimal.fraction, repetend
+            ),
+        ));
+        lines.push(("Repetend", length));
+
+        lines
+    }
+}
+
+fn ten_to(power: u32) -> anyhow::Result<u128, NumberFormatterError> {
+    10u128
+        .checked_pow(power)
+        .ok_or(NumberFormatterError::TooLargeError)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("6/14", false, 3, 7)]
+    #[test_case("-10/4", true, 5, 2; "negative")]
+    #[test_case("-0/5", false, 0, 1; "negative zero")]
+    #[test_case("2.5", false, 5, 2; "decimal")]
+    #[test_case("7", false, 7, 1; "whole")]
+    #[test_case("0.[3]", false, 1, 3; "repetend")]
+    #[test_case("0.1[6]", false, 1, 6; "repetend after fraction")]
+    #[test_case("-1.[142857]", true, 8, 7; "negative repetend")]
+    #[test_case(".[9]", false, 1, 1; "repeating nines")]
+    fn rational_should_parse_fraction(
+        fraction: &str,
+        negative: bool,
+        numerator: u128,
+        denominator: u128,
+    ) {
+        // Act
+        let actual = Rational::parse(fraction);
+
+        // Assert
+        assert_eq!(
+            actual.unwrap(),
+            Rational {
+                negative,
+                numerator,
+                denominator
+            }
+        );
+    }
+
+    #[test_case("1/0", NumberFormatterError::DivisionByZero; "division by zero")]
+    #[test_case("1/x", NumberFormatterError::InvalidFraction; "invalid denominator")]
+    #[test_case("0.[]", NumberFormatterError::InvalidFraction; "empty repetend")]
+    #[test_case("1[3]", NumberFormatterError::InvalidFraction; "repetend without point")]
+    #[test_case("0.[3", NumberFormatterError::InvalidFraction; "unclosed repetend")]
+    fn rational_should_fail_to_parse(fraction: &str, expected: NumberFormatterError) {
+        // Act
+        let actual = Rational::parse(fraction);
+
+        // Assert
+        assert_eq!(
+            std::mem::discriminant(&actual.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+
+    #[test_case(3, 7, "0", "", "428571")]
+    #[test_case(1, 6, "0", "1", "6"; "fraction before repetend")]
+    #[test_case(7, 4, "1", "75", ""; "ends")]
+    #[test_case(5, 1, "5", "", ""; "whole")]
+    #[test_case(1, 12, "0", "08", "3"; "zero before repetend")]
+    #[test_case(1, 81, "0", "", "012345679"; "repetend with zero")]
+    fn rational_should_find_repeating_decimal(
+        numerator: u128,
+        denominator: u128,
+        whole: &str,
+        fraction: &str,
+        repetend: &str,
+    ) {
+        // Arrange
+        let rational = Rational::new(false, numerator, denominator).unwrap();
+
+        // Act
+        let actual = rational.decimal();
+
+        // Assert
+        assert_eq!(actual.whole.to_string(), whole);
+        assert_eq!(actual.fraction, fraction);
+        assert_eq!(actual.repetend, repetend);
+        assert!(!actual.cut_off);
+    }
+
+    #[test]
+    fn rational_should_cut_off_long_repetend() {
+        // Arrange
+        let rational = Rational::new(false, 1, 1_000_000_007).unwrap();
+
+        // Act
+        let actual = rational.decimal();
+
+        // Assert
+        assert_eq!(actual.repetend.len(), REPETEND_LIMIT);
+        assert!(actual.cut_off);
+    }
+
+    #[test]
+    fn rational_should_describe_fraction() {
+        // Arrange
+        let rational = Rational::parse("-14/6").unwrap();
+
+        // Act
+        let actual = rational.lines();
+
+        // Assert
+        assert_eq!(
+            actual,
+            vec![
+                ("Fraction", String::from("-7/3")),
+                ("Mixed number", String::from("-2 1/3")),
+                ("Decimal", String::from("-2.[3]")),
+                ("Repetend", String::from("1 digit")),
+            ]
+        );
+    }
+}
@@ -0,0 +1,180 @@
+mod svd;
+mod toml;
+
+use crate::{BitRange, NumberFormatterError, Value};
+use std::path::Path;
+
+/// A named field of a register, holding the bits of its range
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Field {
+    pub name: String,
+    pub range: Option<BitRange>,
+    /// Names of particular field values, such as 2 meaning "fast"
+    pub values: Vec<(u128, String)>,
+}
+
+/// A register made up of named bitfields
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Register {
+    pub name: Option<String>,
+    pub fields: Vec<Field>,
+}
+
+/// The value one field of a register holds
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecodedField {
+    pub name: String,
+    pub range: BitRange,
+    pub value: u128,
+    /// The name of the value, if the register definition gives it one
+    pub meaning: Option<String>,
+}
+
+impl Register {
+    /// Reads a register definition from a TOML file
+    pub fn from_file(path: &Path) -> anyhow::Result<Register, NumberFormatterError> {
+        toml::parse(&std::fs::read_to_string(path)?)
+    }
+
+    /// Reads a register named `PERIPHERAL.REGISTER` from a CMSIS-SVD file
+    pub fn from_svd_file(
+        path: &Path,
+        name: &str,
+    ) -> anyhow::Result<Register, NumberFormatterError> {
+        svd::parse(&std::fs::read_to_string(path)?, name)
+    }
+
+    /// Splits a number into the values of the register's fields
+    pub(crate) fn decode(&self, value: Value) -> Vec<DecodedField> {
+        self.fields
+            .iter()
+            .filter_map(|field| {
+                let range = field.range?;
+                let value = match range.extract(value) {
+                    Value::Signed(num) => num as u128,
+                    Value::Unsigned(num) => num,
+                };
+                let meaning = field
+                    .values
+                    .iter()
+                    .find(|(v, _)| *v == value)
+                    .map(|(_, meaning)| meaning.clone());
+
+                Some(DecodedField {
+                    name: field.name.clone(),
+                    range,
+                    value,
+                    meaning,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Lays out decoded fields as a table with a column each for the name, bits, value and meaning
+pub fn bitfield_table(fields: &[DecodedField]) -> String {
+    let rows: Vec<[String; 5]> = std::iter::once([
+        String::from("Field"),
+        String::from("Bits"),
+        String::from("Hexadecimal"),
+        String::from("Integer"),
+        String::from("Meaning"),
+    ])
+    .chain(fields.iter().map(|field| {
+        [
+            field.name.clone(),
+            field.range.to_string(),
+            format!("{:#x}", field.value),
+            field.value.to_string(),
+            field.meaning.clone().unwrap_or_default(),
+        ]
+    }))
+    .collect();
+
+    let widths: Vec<usize> = (0..5)
+        .map(|column| {
+            rows.iter()
+                .map(|row| row[column].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    rows.iter()
+        .map(|row| {
+            row.iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+                .collect::<Vec<String>>()
+                .join("  ")
+                .trim_end()
+                .to_string()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn register() -> Register {
+        Register {
+            name: Some(String::from("CTRL")),
+            fields: vec![
+                Field {
+                    name: String::from("EN"),
+                    range: Some(BitRange { low: 0, high: 0 }),
+                    values: vec![],
+                },
+                Field {
+                    name: String::from("MODE"),
+                    range: Some(BitRange { low: 4, high: 5 }),
+                    values: vec![(2, String::from("fast"))],
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn register_should_decode_fields() {
+        // Act
+        let actual = register().decode(Value::Signed(0x21));
+
+        // Assert
+        assert_eq!(
+            actual,
+            vec![
+                DecodedField {
+                    name: String::from("EN"),
+                    range: BitRange { low: 0, high: 0 },
+                    value: 1,
+                    meaning: None,
+                },
+                DecodedField {
+                    name: String::from("MODE"),
+                    range: BitRange { low: 4, high: 5 },
+                    value: 2,
+                    meaning: Some(String::from("fast")),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn bitfield_table_should_align_columns() {
+        // Arrange
+        let fields = register().decode(Value::Signed(0x21));
+
+        // Act
+        let actual = bitfield_table(&fields);
+
+        // Assert
+        assert_eq!(
+            actual,
+            "Field  Bits  Hexadecimal  Integer  Meaning\n\
+             EN     0     0x1          1\n\
+             MODE   4..5  0x2          2        fast"
+        );
+    }
+}
@@ -0,0 +1,430 @@
+use crate::register::{Field, Register};
+use crate::{BitRange, NumberFormatterError};
+
+/// An XML element with its attributes, child elements and text
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Element {
+    name: String,
+    attributes: Vec<(String, String)>,
+    children: Vec<Element>,
+    text: String,
+}
+
+fn invalid(message: &str) -> NumberFormatterError {
+    NumberFormatterError::InvalidRegisterDefinition(message.to_string())
+}
+
+impl Element {
+    fn child(&self, name: &str) -> Option<&Element> {
+        self.children.iter().find(|child| child.name == name)
+    }
+
+    fn children<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Element> {
+        self.children.iter().filter(move |child| child.name == name)
+    }
+
+    fn child_text(&self, name: &str) -> Option<&str> {
+        self.child(name).map(|child| child.text.trim())
+    }
+
+    fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Finds a register named `PERIPHERAL.REGISTER` in a CMSIS-SVD device description and reads its fields
+pub(crate) fn parse(text: &str, name: &str) -> anyhow::Result<Register, NumberFormatterError> {
+    let device = parse_xml(text)?;
+    let (peripheral_name, register_name) = name
+        .split_once('.')
+        .ok_or_else(|| invalid("register must be named PERIPHERAL.REGISTER"))?;
+
+    let peripherals: Vec<&Element> = device
+        .child("peripherals")
+        .map(|peripherals| peripherals.children("peripheral").collect())
+        .unwrap_or_default();
+    let find_peripheral = |name: &str| {
+        peripherals
+            .iter()
+            .copied()
+            .find(|peripheral| {
+                peripheral
+                    .child_text("name")
+                    .is_some_and(|n| n.eq_ignore_ascii_case(name))
+            })
+            .ok_or_else(|| invalid(&format!("no peripheral named {}", name)))
+    };
+
+    let mut peripheral = find_peripheral(peripheral_name)?;
+    if peripheral.child("registers").is_none() {
+        if let Some(base) = peripheral.attribute("derivedFrom") {
+            peripheral = find_peripheral(base)?;
+        }
+    }
+
+    let register = peripheral
+        .child("registers")
+        .and_then(|registers| find_register(registers, register_name))
+        .ok_or_else(|| invalid(&format!("no register named {}", name)))?;
+
+    let fields = register
+        .child("fields")
+        .map(|fields| fields.children("field").map(read_field).collect())
+        .unwrap_or_else(|| Ok(vec![]))?;
+
+    Ok(Register {
+        name: Some(name.to_uppercase()),
+        fields,
+    })
+}
+
+/// Searches registers, including those grouped in clusters, for one with the given name
+fn find_register<'a>(registers: &'a Element, name: &str) -> Option<&'a Element> {
+    registers
+        .children
+        .iter()
+        .find_map(|child| match child.name.as_str() {
+            "register" if child.child_text("name")?.eq_ignore_ascii_case(name) => Some(child),
+            "cluster" => find_register(child, name),
+            _ => None,
+        })
+}
+
+fn read_field(field: &Element) -> anyhow::Result<Field, NumberFormatterError> {
+    let name = field
+        .child_text("name")
+        .ok_or_else(|| invalid("field has no name"))?
+        .to_string();
+    let number = |tag: &str| field.child_text(tag).map(parse_number).transpose();
+
+    let range = match (
+        number("bitOffset")?,
+        number("bitWidth")?,
+        number("lsb")?,
+        number("msb")?,
+        field.child_text("bitRange"),
+    ) {
+        (Some(offset), Some(width), _, _, _) if width > 0 => (offset, offset + width - 1),
+        (_, _, Some(lsb), Some(msb), _) => (lsb, msb),
+        (_, _, _, _, Some(range)) => {
+            let (msb, lsb) = range
+                .trim_start_matches('[')
+                .trim_end_matches(']')
+                .split_once(':')
+                .ok_or_else(|| invalid(&format!("field {} has an invalid bitRange", name)))?;
+            (parse_number(lsb)?, parse_number(msb)?)
+        }
+        _ => return Err(invalid(&format!("field {} has no bit range", name))),
+    };
+    if range.1 > 127 || range.0 > range.1 {
+        return Err(invalid(&format!("field {} has an invalid bit range", name)));
+    }
+
+    let mut values = Vec::new();
+    for enumerated_values in field.children("enumeratedValues") {
+        for enumerated_value in enumerated_values.children("enumeratedValue") {
+            let value = match enumerated_value.child_text("value") {
+                Some(value) if !is_dont_care(value) => parse_number(value)?,
+                _ => continue,
+            };
+            if let Some(meaning) = enumerated_value.child_text("name") {
+                values.push((value, meaning.to_string()));
+            }
+        }
+    }
+
+    Ok(Field {
+        name,
+        range: Some(BitRange {
+            low: range.0 as u32,
+            high: range.1 as u32,
+        }),
+        values,
+    })
+}
+
+/// Whether an enumerated value is a binary pattern with don't care bits, such as `#1x`, which
+/// matches several field values rather than naming one
+fn is_dont_care(text: &str) -> bool {
+    let text = text.trim();
+    let binary = match (text.get(..2), text.strip_prefix('#')) {
+        (Some("0b" | "0B"), _) => &text[2..],
+        (_, Some(binary)) => binary,
+        _ => return false,
+    };
+
+    binary.contains(['x', 'X'])
+}
+
+/// Parses an SVD scaled non-negative integer, written in decimal, 0x hexadecimal or # or 0b binary
+fn parse_number(text: &str) -> anyhow::Result<u128, NumberFormatterError> {
+    let text = text.trim();
+    let parsed = match (text.get(..2), text.strip_prefix('#')) {
+        (Some("0x" | "0X"), _) => u128::from_str_radix(&text[2..], 16),
+        (Some("0b" | "0B"), _) => u128::from_str_radix(&text[2..], 2),
+        (_, Some(binary)) => u128::from_str_radix(binary, 2),
+        _ => text.parse::<u128>(),
+    };
+
+    parsed.map_err(|_| invalid(&format!("'{}' is not a number", text)))
+}
+
+/// Reads the root element of an XML document, skipping the declaration, comments and doctype
+fn parse_xml(text: &str) -> anyhow::Result<Element, NumberFormatterError> {
+    let mut stack = vec![Element::default()];
+    let mut rest = text;
+
+    while let Some(start) = rest.find('<') {
+        let text = decode_entities(&rest[..start]);
+        if let Some(element) = stack.last_mut() {
+            element.text.push_str(&text);
+        }
+        rest = &rest[start..];
+
+        let skip = [("<?", "?>"), ("<!--", "-->"), ("<!", ">")]
+            .iter()
+            .find(|(open, _)| rest.starts_with(open));
+        if let Some((_, close)) = skip {
+            let end = rest
+                .find(close)
+                .ok_or_else(|| invalid("unterminated XML"))?;
+            rest = &rest[end + close.len()..];
+            continue;
+        }
+
+        let end = rest
+            .find('>')
+            .ok_or_else(|| invalid("unterminated XML tag"))?;
+        let tag = &rest[1..end];
+        rest = &rest[end + 1..];
+
+        if let Some(name) = tag.strip_prefix('/') {
+            let element = stack.pop().filter(|element| element.name == name.trim());
+            match (element, stack.last_mut()) {
+                (Some(element), Some(parent)) => parent.children.push(element),
+                _ => return Err(invalid(&format!("unexpected closing tag </{}>", name))),
+            }
+            continue;
+        }
+
+        let (tag, self_closing) = match tag.strip_suffix('/') {
+            Some(tag) => (tag, true),
+            None => (tag, false),
+        };
+        let element = parse_tag(tag)?;
+
+        match (self_closing, stack.last_mut()) {
+            (true, Some(parent)) => parent.children.push(element),
+            _ => stack.push(element),
+        }
+    }
+
+    match stack.pop() {
+        Some(mut document) if stack.is_empty() && document.children.len() == 1 => {
+            Ok(document.children.remove(0))
+        }
+        _ => Err(invalid("XML is not a single element")),
+    }
+}
+
+/// Reads the name and attributes of an opening tag, without its angle brackets
+fn parse_tag(tag: &str) -> anyhow::Result<Element, NumberFormatterError> {
+    let tag = tag.trim();
+    let (name, mut rest) = tag.split_at(tag.find(char::is_whitespace).unwrap_or(tag.len()));
+    let mut attributes = Vec::new();
+
+    loop {
+        rest = rest.trim_start();
+        if rest.is_empty() {
+            break;
+        }
+
+        let (key, value) = rest
+            .split_once('=')
+            .ok_or_else(|| invalid(&format!("invalid attribute in <{}>", name)))?;
+        let value = value.trim_start();
+        let quote = value
+            .chars()
+            .next()
+            .filter(|quote| *quote == '"' || *quote == '\'')
+            .ok_or_else(|| invalid(&format!("unquoted attribute in <{}>", name)))?;
+        let end = value[1..]
+            .find(quote)
+            .ok_or_else(|| invalid(&format!("unterminated attribute in <{}>", name)))?;
+
+        attributes.push((key.trim().to_string(), decode_entities(&value[1..end + 1])));
+        rest = &value[end + 2..];
+    }
+
+    Ok(Element {
+        name: name.to_string(),
+        attributes,
+        ..Default::default()
+    })
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    const SVD: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<!-- A cut down device description -->
+<device schemaVersion="1.1" xmlns:xs="http://www.w3.org/2001/XMLSchema-instance">
+  <name>STM32F4</name>
+  <peripherals>
+    <peripheral>
+      <name>GPIOA</name>
+      <registers>
+        <register>
+          <name>MODER</name>
+          <description>GPIO port mode register</description>
+          <fields>
+            <field>
+              <name>MODER15</name>
+              <bitOffset>30</bitOffset>
+              <bitWidth>2</bitWidth>
+              <enumeratedValues>
+                <enumeratedValue><name>Input</name><value>0</value></enumeratedValue>
+                <enumeratedValue><name>Output</name><value>0X1</value></enumeratedValue>
+                <enumeratedValue><name>Alternate</name><value>#10</value></enumeratedValue>
+                <enumeratedValue><name>Analog</name><value>#1x</value></enumeratedValue>
+              </enumeratedValues>
+            </field>
+            <field>
+              <name>MODER14</name>
+              <lsb>28</lsb>
+              <msb>29</msb>
+              <enumeratedValues>
+                <enumeratedValue><name>Analog</name><value>0b11</value></enumeratedValue>
+                <enumeratedValue><name>Either</name><value>0b0X</value></enumeratedValue>
+              </enumeratedValues>
+            </field>
+            <field>
+              <name>MODER13</name>
+              <bitRange>[27:26]</bitRange>
+            </field>
+          </fields>
+        </register>
+        <cluster>
+          <name>AFR</name>
+          <register>
+            <name>AFRL</name>
+            <fields/>
+          </register>
+        </cluster>
+      </registers>
+    </peripheral>
+    <peripheral derivedFrom="GPIOA">
+      <name>GPIOB</name>
+    </peripheral>
+  </peripherals>
+</device>
+"#;
+
+    #[test_case("GPIOA.MODER")]
+    #[test_case("gpiob.moder"; "derived peripheral")]
+    fn parse_should_read_register_fields(name: &str) {
+        // Act
+        let actual = parse(SVD, name);
+
+        // Assert
+        let actual = actual.unwrap();
+        assert_eq!(actual.name, Some(name.to_uppercase()));
+        assert_eq!(
+            actual.fields,
+            vec![
+                Field {
+                    name: String::from("MODER15"),
+                    range: Some(BitRange { low: 30, high: 31 }),
+                    values: vec![
+                        (0, String::from("Input")),
+                        (1, String::from("Output")),
+                        (2, String::from("Alternate")),
+                    ],
+                },
+                Field {
+                    name: String::from("MODER14"),
+                    range: Some(BitRange { low: 28, high: 29 }),
+                    values: vec![(3, String::from("Analog"))],
+                },
+                Field {
+                    name: String::from("MODER13"),
+                    range: Some(BitRange { low: 26, high: 27 }),
+                    values: vec![],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_should_find_register_in_cluster() {
+        // Act
+        let actual = parse(SVD, "GPIOA.AFRL");
+
+        // Assert
+        assert_eq!(actual.unwrap().fields, vec![]);
+    }
+
+    #[test_case(SVD, "GPIOA"; "unqualified name")]
+    #[test_case(SVD, "GPIOC.MODER"; "unknown peripheral")]
+    #[test_case(SVD, "GPIOA.ODR"; "unknown register")]
+    #[test_case("<device><peripherals></device>", "GPIOA.MODER"; "mismatched tags")]
+    #[test_case("<device>", "GPIOA.MODER"; "unclosed element")]
+    fn parse_should_fail(text: &str, name: &str) {
+        // Act
+        let actual = parse(text, name);
+
+        // Assert
+        assert!(matches!(
+            actual,
+            Err(NumberFormatterError::InvalidRegisterDefinition(_))
+        ));
+    }
+
+    #[test_case("42", 42)]
+    #[test_case("0x1F", 31; "hexadecimal")]
+    #[test_case("0X1F", 31; "uppercase hexadecimal")]
+    #[test_case("#101", 5; "binary")]
+    #[test_case("0b101", 5; "prefixed binary")]
+    #[test_case("0B101", 5; "uppercase prefixed binary")]
+    fn parse_number_should_read_prefixes(text: &str, expected: u128) {
+        // Act
+        let actual = parse_number(text);
+
+        // Assert
+        assert_eq!(actual.unwrap(), expected);
+    }
+
+    #[test_case("#1x", true)]
+    #[test_case("0b1X", true; "prefixed")]
+    #[test_case("#10", false; "binary")]
+    #[test_case("0x1F", false; "hexadecimal")]
+    fn is_dont_care_should_find_dont_care_bits(text: &str, expected: bool) {
+        // Act
+        let actual = is_dont_care(text);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test_case("1 &lt; 2 &amp;&amp; &quot;x&quot;", "1 < 2 && \"x\"")]
+    fn decode_entities_should_replace_entities(text: &str, expected: &str) {
+        // Act
+        let actual = decode_entities(text);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+}
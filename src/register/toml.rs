@@ -0,0 +1,297 @@
+use crate::bit_range::parse_bit_range;
+use crate::register::{Field, Register};
+use crate::NumberFormatterError;
+use std::iter::Peekable;
+use std::str::Chars;
+
+/// A value on the right hand side of `key = value`
+#[derive(Debug, PartialEq, Eq)]
+enum TomlValue {
+    String(String),
+    Integer(u128),
+    Table(Vec<(String, TomlValue)>),
+}
+
+/// The table the following keys belong to
+enum Section {
+    Register,
+    Field(Field),
+}
+
+fn invalid(line: usize, message: &str) -> NumberFormatterError {
+    NumberFormatterError::InvalidRegisterDefinition(format!("line {}: {}", line, message))
+}
+
+/// Parses a register definition written in a subset of TOML, with a `[[field]]` table per field:
+///
+/// ```toml
+/// name = "CTRL"
+///
+/// [[field]]
+/// name = "MODE"
+/// bits = "4..5"
+/// values = { 0 = "off", 1 = "slow", 2 = "fast" }
+/// ```
+pub(crate) fn parse(text: &str) -> anyhow::Result<Register, NumberFormatterError> {
+    let mut register = Register::default();
+    let mut section = Section::Register;
+
+    for (index, line) in text.lines().enumerate() {
+        let number = index + 1;
+        let line = strip_comment(line).trim();
+
+        if line.is_empty() {
+            continue;
+        }
+
+        if line == "[[field]]" {
+            if let Section::Field(field) = section {
+                register.fields.push(field.validate(number)?);
+            }
+            section = Section::Field(Field::default());
+            continue;
+        }
+
+        if line.starts_with('[') {
+            return Err(invalid(number, "only [[field]] tables are supported"));
+        }
+
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| invalid(number, "expected key = value"))?;
+        let key = key.trim();
+        let value = parse_value(value.trim()).map_err(|message| invalid(number, &message))?;
+
+        match (&mut section, key, value) {
+            (Section::Register, "name", TomlValue::String(name)) => register.name = Some(name),
+            (Section::Field(field), "name", TomlValue::String(name)) => field.name = name,
+            (Section::Field(field), "bits", TomlValue::String(bits)) => {
+                field.range = Some(parse_bit_range(&bits).map_err(|e| invalid(number, &e))?);
+            }
+            (Section::Field(field), "bits", TomlValue::Integer(bit)) => {
+                field.range =
+                    Some(parse_bit_range(&bit.to_string()).map_err(|e| invalid(number, &e))?);
+            }
+            (Section::Field(field), "values", TomlValue::Table(values)) => {
+                for (value, meaning) in values {
+                    let value = parse_integer(&value).map_err(|e| invalid(number, &e))?;
+                    match meaning {
+                        TomlValue::String(meaning) => field.values.push((value, meaning)),
+                        _ => return Err(invalid(number, "value names must be strings")),
+                    }
+                }
+            }
+            (_, "name" | "bits" | "values", _) => {
+                return Err(invalid(number, &format!("'{}' has the wrong type", key)))
+            }
+            _ => return Err(invalid(number, &format!("unknown key '{}'", key))),
+        }
+    }
+
+    if let Section::Field(field) = section {
+        register.fields.push(field.validate(text.lines().count())?);
+    }
+
+    Ok(register)
+}
+
+impl Field {
+    fn validate(self, line: usize) -> anyhow::Result<Field, NumberFormatterError> {
+        if self.name.is_empty() {
+            return Err(invalid(line, "field has no name"));
+        }
+        if self.range.is_none() {
+            return Err(invalid(line, &format!("field '{}' has no bits", self.name)));
+        }
+        Ok(self)
+    }
+}
+
+/// Removes a `#` comment which is not inside a string
+fn strip_comment(line: &str) -> &str {
+    let mut quoted = false;
+    let mut escaped = false;
+
+    for (index, c) in line.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if quoted => escaped = true,
+            '"' => quoted = !quoted,
+            '#' if !quoted => return &line[..index],
+            _ => {}
+        }
+    }
+
+    line
+}
+
+fn parse_value(text: &str) -> Result<TomlValue, String> {
+    let mut chars = text.chars().peekable();
+    let value = read_value(&mut chars)?;
+
+    skip_whitespace(&mut chars);
+    match chars.next() {
+        None => Ok(value),
+        Some(c) => Err(format!("unexpected '{}' after value", c)),
+    }
+}
+
+fn skip_whitespace(chars: &mut Peekable<Chars>) {
+    while chars.next_if(|c| c.is_whitespace()).is_some() {}
+}
+
+fn read_value(chars: &mut Peekable<Chars>) -> Result<TomlValue, String> {
+    skip_whitespace(chars);
+    match chars.peek() {
+        Some('"') => read_string(chars).map(TomlValue::String),
+        Some('{') => read_table(chars).map(TomlValue::Table),
+        Some(_) => parse_integer(&read_bare(chars)).map(TomlValue::Integer),
+        None => Err(String::from("missing value")),
+    }
+}
+
+fn read_string(chars: &mut Peekable<Chars>) -> Result<String, String> {
+    chars.next();
+    let mut string = String::new();
+
+    loop {
+        match chars.next() {
+            Some('"') => return Ok(string),
+            Some('\\') => match chars.next() {
+                Some('n') => string.push('\n'),
+                Some('t') => string.push('\t'),
+                Some(c @ ('"' | '\\')) => string.push(c),
+                _ => return Err(String::from("unsupported escape in string")),
+            },
+            Some(c) => string.push(c),
+            None => return Err(String::from("unterminated string")),
+        }
+    }
+}
+
+/// Reads a bare key or integer, up to whitespace or punctuation
+fn read_bare(chars: &mut Peekable<Chars>) -> String {
+    let mut bare = String::new();
+    while let Some(c) = chars.next_if(|c| c.is_alphanumeric() || *c == '_' || *c == '-') {
+        bare.push(c);
+    }
+    bare
+}
+
+fn read_table(chars: &mut Peekable<Chars>) -> Result<Vec<(String, TomlValue)>, String> {
+    chars.next();
+    let mut table = Vec::new();
+
+    loop {
+        skip_whitespace(chars);
+        if chars.next_if_eq(&'}').is_some() {
+            return Ok(table);
+        }
+
+        let key = match chars.peek() {
+            Some('"') => read_string(chars)?,
+            _ => read_bare(chars),
+        };
+        if key.is_empty() {
+            return Err(String::from("expected a key in inline table"));
+        }
+
+        skip_whitespace(chars);
+        if chars.next_if_eq(&'=').is_none() {
+            return Err(String::from("expected = in inline table"));
+        }
+        table.push((key, read_value(chars)?));
+
+        skip_whitespace(chars);
+        match chars.next() {
+            Some(',') => {}
+            Some('}') => return Ok(table),
+            _ => return Err(String::from("expected , or } in inline table")),
+        }
+    }
+}
+
+/// Parses a TOML integer, which may have a 0x, 0o or 0b prefix and underscores between digits
+fn parse_integer(text: &str) -> Result<u128, String> {
+    let digits = text.replace('_', "");
+    let (radix, digits) = match digits.get(..2) {
+        Some("0x") => (16, &digits[2..]),
+        Some("0o") => (8, &digits[2..]),
+        Some("0b") => (2, &digits[2..]),
+        _ => (10, digits.as_str()),
+    };
+
+    u128::from_str_radix(digits, radix).map_err(|_| format!("'{}' is not an integer", text))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BitRange;
+    use test_case::test_case;
+
+    #[test]
+    fn parse_should_read_register_definition() {
+        // Arrange
+        let text = r#"
+            # Control register
+            name = "CTRL"
+
+            [[field]]
+            name = "EN"
+            bits = 0
+
+            [[field]]
+            name = "MODE" # two bits
+            bits = "4..5"
+            values = { 0 = "off", 1 = "slow", 0x2 = "fast", "3" = "turbo #1" }
+        "#;
+
+        // Act
+        let actual = parse(text);
+
+        // Assert
+        let actual = actual.unwrap();
+        assert_eq!(actual.name.as_deref(), Some("CTRL"));
+        assert_eq!(
+            actual.fields,
+            vec![
+                Field {
+                    name: String::from("EN"),
+                    range: Some(BitRange { low: 0, high: 0 }),
+                    values: vec![],
+                },
+                Field {
+                    name: String::from("MODE"),
+                    range: Some(BitRange { low: 4, high: 5 }),
+                    values: vec![
+                        (0, String::from("off")),
+                        (1, String::from("slow")),
+                        (2, String::from("fast")),
+                        (3, String::from("turbo #1")),
+                    ],
+                },
+            ]
+        );
+    }
+
+    #[test_case("[register]"; "unsupported table")]
+    #[test_case("width = 32"; "unknown key")]
+    #[test_case("name = 3"; "wrong type")]
+    #[test_case("name"; "missing equals")]
+    #[test_case("[[field]]\nbits = 1"; "field without name")]
+    #[test_case("[[field]]\nname = \"EN\""; "field without bits")]
+    #[test_case("[[field]]\nname = \"EN\"\nbits = \"1..200\""; "invalid bits")]
+    #[test_case("[[field]]\nname = \"EN\"\nbits = 1\nvalues = { 0 = \"off\""; "unterminated table")]
+    #[test_case("[[field]]\nname = \"EN\"\nbits = 1\nvalues = { x = \"off\" }"; "non-integer value")]
+    fn parse_should_reject_invalid_definition(text: &str) {
+        // Act
+        let actual = parse(text);
+
+        // Assert
+        assert!(matches!(
+            actual,
+            Err(NumberFormatterError::InvalidRegisterDefinition(_))
+        ));
+    }
+}
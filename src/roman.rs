@@ -0,0 +1,210 @@
+use crate::{NumberFormatter, NumberFormatterError};
+
+/// Combining overline, the vinculum multiplying the numeral it is placed over by 1000
+const VINCULUM: char = '\u{0305}';
+
+static NUMERALS: [(i128, &str); 13] = [
+    (1000, "M"),
+    (900, "CM"),
+    (500, "D"),
+    (400, "CD"),
+    (100, "C"),
+    (90, "XC"),
+    (50, "L"),
+    (40, "XL"),
+    (10, "X"),
+    (9, "IX"),
+    (5, "V"),
+    (4, "IV"),
+    (1, "I"),
+];
+
+fn to_roman(num: i128) -> String {
+    let mut roman = String::new();
+    let mut remainder = num;
+
+    for (value, numeral) in NUMERALS.iter() {
+        while remainder >= *value {
+            roman.push_str(numeral);
+            remainder -= value;
+        }
+    }
+
+    roman
+}
+
+fn from_roman(roman: &str) -> anyhow::Result<i128, NumberFormatterError> {
+    let mut value: i128 = 0;
+    let mut rest = roman;
+
+    for (numeral_value, numeral) in NUMERALS.iter() {
+        while let Some(remaining) = rest.strip_prefix(numeral) {
+            value += numeral_value;
+            rest = remaining;
+        }
+    }
+
+    if !rest.is_empty() {
+        return Err(NumberFormatterError::InvalidRoman);
+    }
+
+    Ok(value)
+}
+
+/// Roman numeral formatter for 1 to 3999. When extended, larger values up to 3999999
+/// place a vinculum (combining overline) over the thousands, e.g. 4000 = `I̅V̅`
+pub(crate) struct RomanNumberFormatter {
+    pub extended: bool,
+}
+
+impl NumberFormatter for RomanNumberFormatter {
+    fn read(&self, roman: &str) -> anyhow::Result<i128, NumberFormatterError> {
+        if roman.is_empty() {
+            return Err(NumberFormatterError::Empty);
+        }
+
+        let roman = roman.to_uppercase();
+        let mut thousands = String::new();
+        let mut units = String::new();
+        let mut chars = roman.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            if c == VINCULUM {
+                return Err(NumberFormatterError::InvalidRoman);
+            }
+
+            if chars.next_if_eq(&VINCULUM).is_some() {
+                thousands.push(c);
+            } else {
+                units.push(c);
+            }
+        }
+
+        let value = from_roman(&thousands)? * 1000 + from_roman(&units)?;
+
+        if self.format(value)? != roman {
+            return Err(NumberFormatterError::InvalidRoman);
+        }
+
+        Ok(value)
+    }
+
+    fn format(&self, num: i128) -> anyhow::Result<String, NumberFormatterError> {
+        if num < 1 {
+            return Err(NumberFormatterError::TooSmallError);
+        }
+
+        if num < 4000 {
+            return Ok(to_roman(num));
+        }
+
+        if !self.extended || num >= 4_000_000 {
+            return Err(NumberFormatterError::TooLargeError);
+        }
+
+        let thousands: String = to_roman(num / 1000)
+            .chars()
+            .flat_map(|c| [c, VINCULUM])
+            .collect();
+
+        Ok(thousands + &to_roman(num % 1000))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("XIV", 14)]
+    #[test_case("mcmxcix", 1999)]
+    #[test_case("MMMCMXCIX", 3999)]
+    #[test_case("I", 1)]
+    fn roman_number_formatter_should_read_number(input: &str, expected: i128) {
+        // Arrange
+        let formatter = RomanNumberFormatter { extended: false };
+
+        // Act
+        let actual_number = formatter.read(input);
+
+        // Assert
+        assert!(actual_number.is_ok());
+        assert_eq!(expected, actual_number.unwrap());
+    }
+
+    #[test_case(14, "XIV")]
+    #[test_case(1999, "MCMXCIX")]
+    #[test_case(3999, "MMMCMXCIX")]
+    #[test_case(444, "CDXLIV")]
+    fn roman_number_formatter_should_format_i128(input: i128, expected: &str) {
+        // Arrange
+        let formatter = RomanNumberFormatter { extended: false };
+
+        // Act
+        let actual_output = formatter.format(input);
+
+        // Assert
+        assert!(actual_output.is_ok());
+        assert_eq!(expected, actual_output.unwrap());
+    }
+
+    #[test_case(4000, "I\u{305}V\u{305}")]
+    #[test_case(12345, "X\u{305}I\u{305}I\u{305}CCCXLV")]
+    #[test_case(
+        3999999,
+        "M\u{305}M\u{305}M\u{305}C\u{305}M\u{305}X\u{305}C\u{305}I\u{305}X\u{305}CMXCIX"
+    )]
+    fn extended_roman_number_formatter_should_round_trip(input: i128, expected: &str) {
+        // Arrange
+        let formatter = RomanNumberFormatter { extended: true };
+
+        // Act
+        let actual_output = formatter.format(input);
+        let actual_number = formatter.read(expected);
+
+        // Assert
+        assert_eq!(expected, actual_output.unwrap());
+        assert_eq!(input, actual_number.unwrap());
+    }
+
+    #[test_case("IIII", NumberFormatterError::InvalidRoman ; "repeated too often")]
+    #[test_case("IC", NumberFormatterError::InvalidRoman ; "invalid subtraction")]
+    #[test_case("VX", NumberFormatterError::InvalidRoman ; "descending order")]
+    #[test_case("XIVA", NumberFormatterError::InvalidRoman ; "invalid numeral")]
+    #[test_case("I\u{305}V\u{305}", NumberFormatterError::TooLargeError ; "vinculum without extended")]
+    #[test_case("", NumberFormatterError::Empty ; "empty")]
+    fn roman_number_formatter_should_fail_to_read(input: &str, expected: NumberFormatterError) {
+        // Arrange
+        let formatter = RomanNumberFormatter { extended: false };
+
+        // Act
+        let actual_number = formatter.read(input);
+
+        // Assert
+        assert_eq!(
+            std::mem::discriminant(&actual_number.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+
+    #[test_case(false, 0, NumberFormatterError::TooSmallError ; "zero")]
+    #[test_case(false, 4000, NumberFormatterError::TooLargeError ; "above 3999")]
+    #[test_case(true, 4000000, NumberFormatterError::TooLargeError ; "extended above 3999999")]
+    fn roman_number_formatter_should_fail_to_format(
+        extended: bool,
+        input: i128,
+        expected: NumberFormatterError,
+    ) {
+        // Arrange
+        let formatter = RomanNumberFormatter { extended };
+
+        // Act
+        let actual_output = formatter.format(input);
+
+        // Assert
+        assert_eq!(
+            std::mem::discriminant(&actual_output.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+}
@@ -0,0 +1,144 @@
+use clap::Args;
+
+/// Options of the root command
+#[derive(Args, Clone, Debug)]
+pub struct RootOptions {
+    /// The number to take the roots and logarithms of
+    pub number: String,
+    /// Also take the root of this degree, such as 3 for the cube root
+    #[clap(long, value_parser = clap::value_parser!(u32).range(2..))]
+    pub degree: Option<u32>,
+}
+
+/// Integer roots and base 2 logarithms of a number, rounded down unless noted
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RootInfo {
+    pub number: u128,
+    pub square_root: u128,
+    /// The degree and the root of that degree, when asked for
+    pub root: Option<(u32, u128)>,
+    /// The base 2 logarithm rounded down, none for zero
+    pub floor_log2: Option<u32>,
+    /// The base 2 logarithm rounded up, none for zero
+    pub ceil_log2: Option<u32>,
+}
+
+/// The largest number whose `degree`th power is at most `number`, found a bit at a time from the
+/// highest bit the root can have
+pub(crate) fn nth_root(number: u128, degree: u32) -> u128 {
+    (0..=127 / degree).rev().fold(0, |root, bit| {
+        let candidate = root | 1 << bit;
+        match candidate.checked_pow(degree) {
+            Some(power) if power <= number => candidate,
+            _ => root,
+        }
+    })
+}
+
+impl RootInfo {
+    pub(crate) fn new(number: u128, degree: Option<u32>) -> Self {
+        let floor_log2 = number.checked_ilog2();
+
+        Self {
+            number,
+            square_root: number.isqrt(),
+            root: degree.map(|degree| (degree, nth_root(number, degree))),
+            floor_log2,
+            ceil_log2: floor_log2.map(|log| log + u32::from(!number.is_power_of_two())),
+        }
+    }
+
+    /// Labelled lines describing the roots and logarithms, in the order they should be displayed
+    pub fn lines(&self) -> Vec<(&'static str, String)> {
+        let exactness = |root: u128, degree: u32| match root.pow(degree) {
+            power if power == self.number => String::from("exact"),
+            power => format!("inexact, {}^{} = {}", root, degree, power),
+        };
+        let log = |log: Option<u32>| match log {
+            Some(log) if self.number.is_power_of_two() => format!("{} (exact)", log),
+            Some(log) => format!("{} (inexact)", log),
+            None => String::from("undefined"),
+        };
+
+        let mut lines = vec![(
+            "Square root",
+            format!("{} ({})", self.square_root, exactness(self.square_root, 2)),
+        )];
+        if let Some((degree, root)) = self.root {
+            lines.push((
+                "Root",
+                format!("{} (degree {}, {})", root, degree, exactness(root, degree)),
+            ));
+        }
+        lines.push(("Floor log2", log(self.floor_log2)));
+        lines.push(("Ceil log2", log(self.ceil_log2)));
+
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(243, 5, 3)]
+    #[test_case(242, 5, 2; "below power")]
+    #[test_case(u128::MAX, 2, u64::MAX as u128; "square root of largest")]
+    #[test_case(u128::MAX, 3, 6_981_463_658_331; "cube root of largest")]
+    #[test_case(1, 200, 1; "high degree")]
+    #[test_case(0, 3, 0; "zero")]
+    fn nth_root_should_round_down(number: u128, degree: u32, expected: u128) {
+        // Act
+        let actual = nth_root(number, degree);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn root_info_should_describe_inexact_roots() {
+        // Arrange
+        let info = RootInfo::new(123, Some(3));
+
+        // Act
+        let actual = info.lines();
+
+        // Assert
+        assert_eq!(
+            actual,
+            vec![
+                ("Square root", String::from("11 (inexact, 11^2 = 121)")),
+                ("Root", String::from("4 (degree 3, inexact, 4^3 = 64)")),
+                ("Floor log2", String::from("6 (inexact)")),
+                ("Ceil log2", String::from("7 (inexact)")),
+            ]
+        );
+    }
+
+    #[test_case(64, "8 (exact)", "6 (exact)", "6 (exact)")]
+    #[test_case(0, "0 (exact)", "undefined", "undefined"; "zero")]
+    #[test_case(1, "1 (exact)", "0 (exact)", "0 (exact)"; "one")]
+    fn root_info_should_describe_exact_roots(
+        number: u128,
+        square_root: &str,
+        floor_log2: &str,
+        ceil_log2: &str,
+    ) {
+        // Arrange
+        let info = RootInfo::new(number, None);
+
+        // Act
+        let actual = info.lines();
+
+        // Assert
+        assert_eq!(
+            actual,
+            vec![
+                ("Square root", square_root.to_string()),
+                ("Floor log2", floor_log2.to_string()),
+                ("Ceil log2", ceil_log2.to_string()),
+            ]
+        );
+    }
+}
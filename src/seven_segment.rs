@@ -0,0 +1,120 @@
+use crate::NumberFormatterError;
+
+/// Segments lit for each character, as bits `gfedcba` in the usual display driver order
+static SEGMENTS: [(char, u8); 18] = [
+    ('0', 0x3F),
+    ('1', 0x06),
+    ('2', 0x5B),
+    ('3', 0x4F),
+    ('4', 0x66),
+    ('5', 0x6D),
+    ('6', 0x7D),
+    ('7', 0x07),
+    ('8', 0x7F),
+    ('9', 0x6F),
+    ('A', 0x77),
+    ('B', 0x7C),
+    ('C', 0x39),
+    ('D', 0x5E),
+    ('E', 0x79),
+    ('F', 0x71),
+    ('-', 0x40),
+    (' ', 0x00),
+];
+
+const A: u8 = 1;
+const B: u8 = 1 << 1;
+const C: u8 = 1 << 2;
+const D: u8 = 1 << 3;
+const E: u8 = 1 << 4;
+const F: u8 = 1 << 5;
+const G: u8 = 1 << 6;
+
+fn horizontal(segments: u8, segment: u8) -> &'static str {
+    match segments & segment {
+        0 => "    ",
+        _ => " ━━ ",
+    }
+}
+
+fn vertical(segments: u8, left: u8, right: u8) -> String {
+    let lit = |segment| match segments & segment {
+        0 => ' ',
+        _ => '┃',
+    };
+    format!("{}  {}", lit(left), lit(right))
+}
+
+/// Draws hexadecimal digits as they would appear on a seven-segment display, five rows high
+pub fn seven_segment(text: &str) -> anyhow::Result<String, NumberFormatterError> {
+    let digits = text
+        .chars()
+        .map(|c| {
+            SEGMENTS
+                .iter()
+                .find(|(digit, _)| *digit == c.to_ascii_uppercase())
+                .map(|(_, segments)| *segments)
+                .ok_or(NumberFormatterError::InvalidDigit)
+        })
+        .collect::<anyhow::Result<Vec<u8>, NumberFormatterError>>()?;
+
+    let rows: [Box<dyn Fn(u8) -> String>; 5] = [
+        Box::new(|segments| horizontal(segments, A).to_string()),
+        Box::new(|segments| vertical(segments, F, B)),
+        Box::new(|segments| horizontal(segments, G).to_string()),
+        Box::new(|segments| vertical(segments, E, C)),
+        Box::new(|segments| horizontal(segments, D).to_string()),
+    ];
+
+    Ok(rows
+        .iter()
+        .map(|row| {
+            let line: Vec<String> = digits.iter().map(|segments| row(*segments)).collect();
+            line.join(" ").trim_end().to_string()
+        })
+        .collect::<Vec<String>>()
+        .join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test]
+    fn seven_segment_should_draw_digits_side_by_side() {
+        // Act
+        let actual = seven_segment("8b");
+
+        // Assert
+        assert_eq!(
+            actual.unwrap(),
+            [" ━━", "┃  ┃ ┃", " ━━   ━━", "┃  ┃ ┃  ┃", " ━━   ━━",].join("\n")
+        );
+    }
+
+    #[test_case("0123456789"; "decimal digits")]
+    #[test_case("abcdef"; "hexadecimal digits")]
+    #[test_case("-1f"; "negative")]
+    fn seven_segment_should_draw_hexadecimal(text: &str) {
+        // Act
+        let actual = seven_segment(text);
+
+        // Assert
+        assert!(actual.is_ok());
+    }
+
+    #[test_case("1:02"; "colon")]
+    #[test_case("zz"; "letters")]
+    fn seven_segment_should_reject_characters_without_segments(text: &str) {
+        // Act
+        let actual = seven_segment(text);
+
+        // Assert
+        assert!(actual.is_err());
+        assert_eq!(
+            std::mem::discriminant(&actual.unwrap_err()),
+            std::mem::discriminant(&NumberFormatterError::InvalidDigit)
+        );
+    }
+}
@@ -0,0 +1,152 @@
+use crate::{NumberFormatter, NumberFormatterError};
+
+/// Base 60 formatter writing each digit in decimal separated by colons, e.g. 3725 = `1:02:05`.
+/// The leading group may exceed 59 on input so `90:00` reads as 5400
+pub(crate) struct SexagesimalNumberFormatter;
+
+/// Reads the sign and magnitude of a sexagesimal number
+fn read_magnitude(sexagesimal: &str) -> anyhow::Result<(bool, u128), NumberFormatterError> {
+    let (negative, groups) = match sexagesimal.strip_prefix('-') {
+        Some(groups) => (true, groups),
+        None => (false, sexagesimal),
+    };
+
+    if groups.is_empty() {
+        return Err(NumberFormatterError::Empty);
+    }
+
+    let mut value: u128 = 0;
+
+    for (index, group) in groups.split(':').enumerate() {
+        if group.is_empty() || !group.chars().all(|c| c.is_ascii_digit()) {
+            return Err(NumberFormatterError::InvalidDigit);
+        }
+
+        let digit = group.parse::<u128>()?;
+        if index > 0 && digit >= 60 {
+            return Err(NumberFormatterError::InvalidDigit);
+        }
+
+        value = value
+            .checked_mul(60)
+            .and_then(|v| v.checked_add(digit))
+            .ok_or(NumberFormatterError::TooLargeError)?;
+    }
+
+    Ok((negative, value))
+}
+
+/// Writes a magnitude in sexagesimal, with a leading minus sign when negative
+fn format_magnitude(negative: bool, magnitude: u128) -> String {
+    let mut groups = Vec::<String>::new();
+    let mut remainder = magnitude;
+
+    loop {
+        let digit = remainder % 60;
+        remainder /= 60;
+        if remainder == 0 {
+            groups.push(digit.to_string());
+            break;
+        }
+        groups.push(format!("{:02}", digit));
+    }
+
+    if negative {
+        groups.last_mut().unwrap().insert(0, '-');
+    }
+
+    groups.reverse();
+    groups.join(":")
+}
+
+impl NumberFormatter for SexagesimalNumberFormatter {
+    fn read(&self, sexagesimal: &str) -> anyhow::Result<i128, NumberFormatterError> {
+        let (negative, magnitude) = read_magnitude(sexagesimal)?;
+
+        if negative {
+            0i128
+                .checked_sub_unsigned(magnitude)
+                .ok_or(NumberFormatterError::TooSmallError)
+        } else {
+            i128::try_from(magnitude).map_err(|_| NumberFormatterError::TooLargeError)
+        }
+    }
+
+    fn format(&self, num: i128) -> anyhow::Result<String, NumberFormatterError> {
+        Ok(format_magnitude(num < 0, num.unsigned_abs()))
+    }
+
+    fn read_unsigned(&self, sexagesimal: &str) -> anyhow::Result<u128, NumberFormatterError> {
+        match read_magnitude(sexagesimal)? {
+            (true, magnitude) if magnitude > 0 => Err(NumberFormatterError::TooSmallError),
+            (_, magnitude) => Ok(magnitude),
+        }
+    }
+
+    fn format_unsigned(&self, num: u128) -> anyhow::Result<String, NumberFormatterError> {
+        Ok(format_magnitude(false, num))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("1:02:05", 3725)]
+    #[test_case("-1:00", -60)]
+    #[test_case("59", 59)]
+    #[test_case("90:00", 5400)]
+    #[test_case("0:00:01", 1)]
+    fn sexagesimal_number_formatter_should_read_number(input: &str, expected: i128) {
+        // Act
+        let actual_number = SexagesimalNumberFormatter.read(input);
+
+        // Assert
+        assert!(actual_number.is_ok());
+        assert_eq!(expected, actual_number.unwrap());
+    }
+
+    #[test_case(3725, "1:02:05")]
+    #[test_case(-60, "-1:00")]
+    #[test_case(0, "0")]
+    #[test_case(86399, "23:59:59")]
+    fn sexagesimal_number_formatter_should_format_i128(input: i128, expected: &str) {
+        // Act
+        let actual_output = SexagesimalNumberFormatter.format(input);
+
+        // Assert
+        assert!(actual_output.is_ok());
+        assert_eq!(expected, actual_output.unwrap());
+    }
+
+    #[test]
+    fn sexagesimal_number_formatter_should_round_trip_unsigned() {
+        // Act
+        let sexagesimal = SexagesimalNumberFormatter
+            .format_unsigned(u128::MAX)
+            .unwrap();
+        let actual = SexagesimalNumberFormatter.read_unsigned(&sexagesimal);
+
+        // Assert
+        assert_eq!(actual.unwrap(), u128::MAX);
+    }
+
+    #[test_case("1:60", NumberFormatterError::InvalidDigit ; "digit above 59")]
+    #[test_case("1::05", NumberFormatterError::InvalidDigit ; "empty group")]
+    #[test_case("1:+5", NumberFormatterError::InvalidDigit ; "signed group")]
+    #[test_case("", NumberFormatterError::Empty ; "empty")]
+    fn sexagesimal_number_formatter_should_fail_to_read(
+        input: &str,
+        expected: NumberFormatterError,
+    ) {
+        // Act
+        let actual_number = SexagesimalNumberFormatter.read(input);
+
+        // Assert
+        assert_eq!(
+            std::mem::discriminant(&actual_number.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+}
@@ -0,0 +1,15 @@
+use crate::size;
+use clap::Args;
+
+/// Options of the sext command
+#[derive(Args, Clone, Debug)]
+pub struct SextOptions {
+    /// Width in bits the number is read at
+    #[clap(long, value_parser = size::parse_width)]
+    pub from: u32,
+    /// Width in bits the number is extended to
+    #[clap(long, value_parser = size::parse_width)]
+    pub to: u32,
+    /// The number to sign-extend
+    pub number: String,
+}
@@ -0,0 +1,151 @@
+use clap::{ArgEnum, Args};
+
+/// Options of the shift command
+#[derive(Args, Clone, Debug)]
+pub struct ShiftOptions {
+    /// The shift or rotation to apply
+    #[clap(arg_enum)]
+    pub operation: ShiftOperation,
+    /// The number to shift
+    pub number: String,
+    /// How many bits to shift or rotate by
+    pub count: u32,
+}
+
+/// Shifts and rotations of the shift command
+#[derive(ArgEnum, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ShiftOperation {
+    /// Shift left, filling with zeros
+    Shl,
+    /// Logical shift right, filling with zeros
+    Shr,
+    /// Arithmetic shift right, filling with copies of the sign bit
+    Sar,
+    /// Rotate left, bringing the bits shifted out back in on the right
+    Rol,
+    /// Rotate right, bringing the bits shifted out back in on the left
+    Ror,
+}
+
+/// Shifts the low `bits` bits of a bit pattern left, dropping the bits shifted past the top
+pub(crate) fn shift_left(pattern: u128, count: u32, bits: u32) -> u128 {
+    pattern.checked_shl(count).unwrap_or(0) & mask(bits)
+}
+
+/// Shifts a bit pattern right, filling with zeros
+pub(crate) fn shift_right(pattern: u128, count: u32) -> u128 {
+    pattern.checked_shr(count).unwrap_or(0)
+}
+
+/// Shifts the low `bits` bits of a bit pattern right, filling with copies of its top bit
+pub(crate) fn shift_right_arithmetic(pattern: u128, count: u32, bits: u32) -> u128 {
+    let shifted = shift_right(pattern, count);
+    match (pattern >> (bits - 1)) & 1 {
+        1 => shifted | (mask(bits) & !shift_right(mask(bits), count)),
+        _ => shifted,
+    }
+}
+
+/// Rotates the low `bits` bits of a bit pattern left
+pub(crate) fn rotate_left(pattern: u128, count: u32, bits: u32) -> u128 {
+    match count % bits {
+        0 => pattern,
+        count => (pattern << count | pattern >> (bits - count)) & mask(bits),
+    }
+}
+
+/// Rotates the low `bits` bits of a bit pattern right
+pub(crate) fn rotate_right(pattern: u128, count: u32, bits: u32) -> u128 {
+    rotate_left(pattern, bits - count % bits, bits)
+}
+
+/// The bits a shift drops off the end of the low `bits` bits of a bit pattern, as a number of
+/// `count` bits
+pub(crate) fn shifted_out(operation: ShiftOperation, pattern: u128, count: u32, bits: u32) -> u128 {
+    let count = count.min(bits);
+    match operation {
+        ShiftOperation::Shl => shift_right(pattern, bits - count),
+        ShiftOperation::Shr | ShiftOperation::Sar => pattern & mask(count),
+        ShiftOperation::Rol | ShiftOperation::Ror => 0,
+    }
+}
+
+fn mask(bits: u32) -> u128 {
+    shift_right(u128::MAX, 128 - bits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(0x81, 1, 8, 0x02)]
+    #[test_case(0xff, 8, 8, 0; "shifted out entirely")]
+    #[test_case(1, 127, 128, 1 << 127; "widest")]
+    fn shift_left_should_drop_top_bits(pattern: u128, count: u32, bits: u32, expected: u128) {
+        // Act
+        let actual = shift_left(pattern, count, bits);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test_case(0x80, 1, 8, 0xc0)]
+    #[test_case(0x40, 1, 8, 0x20; "positive")]
+    #[test_case(0x80, 8, 8, 0xff; "shifted out entirely")]
+    #[test_case(0x8000, 4, 16, 0xf800; "wider")]
+    fn shift_right_arithmetic_should_copy_sign_bit(
+        pattern: u128,
+        count: u32,
+        bits: u32,
+        expected: u128,
+    ) {
+        // Act
+        let actual = shift_right_arithmetic(pattern, count, bits);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test_case(0x12345678, 8, 32, 0x34567812)]
+    #[test_case(0x81, 1, 8, 0x03; "wraps around")]
+    #[test_case(0x81, 9, 8, 0x03; "more than width")]
+    #[test_case(0x81, 8, 8, 0x81; "whole width")]
+    fn rotate_left_should_bring_bits_around(pattern: u128, count: u32, bits: u32, expected: u128) {
+        // Act
+        let actual = rotate_left(pattern, count, bits);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test_case(0x12345678, 8, 32, 0x78123456)]
+    #[test_case(0x81, 1, 8, 0xc0; "wraps around")]
+    #[test_case(0x81, 0, 8, 0x81; "not rotated")]
+    fn rotate_right_should_bring_bits_around(pattern: u128, count: u32, bits: u32, expected: u128) {
+        // Act
+        let actual = rotate_right(pattern, count, bits);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test_case(ShiftOperation::Shl, 0xabcd, 4, 16, 0xa)]
+    #[test_case(ShiftOperation::Shr, 0xabcd, 4, 16, 0xd; "right")]
+    #[test_case(ShiftOperation::Sar, 0xabcd, 20, 16, 0xabcd; "past width")]
+    #[test_case(ShiftOperation::Shl, 0xabcd, 0, 16, 0; "not shifted")]
+    #[test_case(ShiftOperation::Rol, 0xabcd, 4, 16, 0; "rotated")]
+    fn shifted_out_should_find_dropped_bits(
+        operation: ShiftOperation,
+        pattern: u128,
+        count: u32,
+        bits: u32,
+        expected: u128,
+    ) {
+        // Act
+        let actual = shifted_out(operation, pattern, count, bits);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,533 @@
+use crate::{NumberFormatterError, Value};
+use clap::ArgEnum;
+use std::fmt;
+
+/// The bit widths numbers can be interpreted at
+static WIDTHS: [u32; 5] = [8, 16, 32, 64, 128];
+
+/// Clap value parser accepting one of the supported bit widths
+pub(crate) fn parse_width(width: &str) -> Result<u32, String> {
+    width
+        .parse::<u32>()
+        .ok()
+        .filter(|bits| WIDTHS.contains(bits))
+        .ok_or_else(|| String::from("width must be 8, 16, 32, 64 or 128"))
+}
+
+/// The narrowest supported width holding the given number of bits
+pub(crate) fn width_holding(bits: u32) -> u32 {
+    WIDTHS
+        .iter()
+        .copied()
+        .find(|width| *width >= bits)
+        .unwrap_or(128)
+}
+
+/// The bits needed to hold a value, including a sign bit if it is negative
+fn significant_bits(value: Value) -> (u32, bool) {
+    match value {
+        Value::Signed(num) if num < 0 => (129 - (!num).leading_zeros(), true),
+        Value::Signed(num) => (128 - num.leading_zeros(), false),
+        Value::Unsigned(_) => (128, false),
+    }
+}
+
+/// Clap value parser accepting Rust integer type names such as `u8` or `i64`
+pub(crate) fn parse_size(size: &str) -> Result<IntegerSize, String> {
+    let signed = match size.get(..1) {
+        Some("i") => true,
+        Some("u") => false,
+        _ => return Err(String::from("size must be a type such as u8, i16 or u128")),
+    };
+
+    let bits = parse_width(&size[1..])
+        .map_err(|_| String::from("size must be a type such as u8, i16 or u128"))?;
+
+    Ok(IntegerSize { bits, signed })
+}
+
+/// What to do with numbers that do not fit in the chosen integer size
+#[derive(ArgEnum, Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Truncate {
+    /// Reject the number
+    #[default]
+    Error,
+    /// Keep only the bits that fit, as a narrowing cast would
+    Wrap,
+    /// Clamp the number to the smallest or largest value that fits
+    Saturate,
+}
+
+/// The bit width and signedness numbers are interpreted and formatted at
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct IntegerSize {
+    pub bits: u32,
+    pub signed: bool,
+}
+
+impl IntegerSize {
+    pub(crate) fn mask(&self) -> u128 {
+        match self.bits {
+            128 => u128::MAX,
+            bits => (1 << bits) - 1,
+        }
+    }
+
+    /// Interprets a number read from the input as a value of this size. Signed sizes accept both the
+    /// value itself and its two's complement bit pattern, so `0xff` is -1 at 8 bits.
+    pub fn interpret(&self, num: i128) -> anyhow::Result<i128, NumberFormatterError> {
+        if !self.signed {
+            return match num {
+                num if num < 0 => Err(NumberFormatterError::TooSmallError),
+                num if self.bits < 128 && num > self.mask() as i128 => {
+                    Err(NumberFormatterError::TooLargeError)
+                }
+                num => Ok(num),
+            };
+        }
+
+        if self.bits == 128 {
+            return Ok(num);
+        }
+
+        let min = -(1i128 << (self.bits - 1));
+        let max = (1i128 << (self.bits - 1)) - 1;
+
+        if num < min {
+            return Err(NumberFormatterError::TooSmallError);
+        }
+        if num > self.mask() as i128 {
+            return Err(NumberFormatterError::TooLargeError);
+        }
+
+        if num > max {
+            Ok(num - (1i128 << self.bits))
+        } else {
+            Ok(num)
+        }
+    }
+
+    fn exact(&self, value: Value) -> anyhow::Result<Value, NumberFormatterError> {
+        match value {
+            Value::Signed(num) => self.interpret(num).map(Value::Signed),
+            Value::Unsigned(num) if self.bits == 128 && self.signed => {
+                Ok(Value::Signed(num as i128))
+            }
+            Value::Unsigned(num) if self.bits == 128 => Ok(Value::Unsigned(num)),
+            Value::Unsigned(_) => Err(NumberFormatterError::TooLargeError),
+        }
+    }
+
+    fn min(&self) -> i128 {
+        match (self.signed, self.bits) {
+            (false, _) => 0,
+            (true, 128) => i128::MIN,
+            (true, bits) => -(1i128 << (bits - 1)),
+        }
+    }
+
+    fn max(&self) -> Value {
+        match (self.signed, self.bits) {
+            (false, 128) => Value::Unsigned(u128::MAX),
+            (false, _) => Value::Signed(self.mask() as i128),
+            (true, 128) => Value::Signed(i128::MAX),
+            (true, bits) => Value::Signed((1i128 << (bits - 1)) - 1),
+        }
+    }
+
+    fn wrap(&self, value: Value) -> Value {
+        let bits = match value {
+            Value::Signed(num) => num as u128,
+            Value::Unsigned(num) => num,
+        } & self.mask();
+
+        if !self.signed {
+            i128::try_from(bits).map_or(Value::Unsigned(bits), Value::Signed)
+        } else if self.bits < 128 && bits >> (self.bits - 1) == 1 {
+            Value::Signed(bits as i128 - (1i128 << self.bits))
+        } else {
+            Value::Signed(bits as i128)
+        }
+    }
+
+    /// Fits a number read from the input into this size, truncating it as chosen if it does not fit
+    pub(crate) fn fit(
+        &self,
+        value: Value,
+        truncate: Truncate,
+    ) -> anyhow::Result<Value, NumberFormatterError> {
+        match (self.exact(value), truncate) {
+            (Ok(value), _) => Ok(value),
+            (Err(e), Truncate::Error) => Err(e),
+            (Err(_), Truncate::Wrap) => Ok(self.wrap(value)),
+            (Err(NumberFormatterError::TooSmallError), Truncate::Saturate) => {
+                Ok(Value::Signed(self.min()))
+            }
+            (Err(_), Truncate::Saturate) => Ok(self.max()),
+        }
+    }
+
+    /// Fits the result of arithmetic into this size, truncating it as chosen if it does not fit.
+    /// Unlike numbers read from the input, results of signed sizes are not taken as bit patterns,
+    /// so 127 + 1 overflows at 8 bits rather than being -128.
+    pub(crate) fn fit_result(
+        &self,
+        value: Value,
+        truncate: Truncate,
+    ) -> anyhow::Result<Value, NumberFormatterError> {
+        let overflow = match value {
+            value if value < Value::Signed(self.min()) => NumberFormatterError::TooSmallError,
+            value if value > self.max() => NumberFormatterError::TooLargeError,
+            value => return Ok(value),
+        };
+
+        match (overflow, truncate) {
+            (overflow, Truncate::Error) => Err(overflow),
+            (_, Truncate::Wrap) => Ok(self.wrap(value)),
+            (NumberFormatterError::TooSmallError, Truncate::Saturate) => {
+                Ok(Value::Signed(self.min()))
+            }
+            (_, Truncate::Saturate) => Ok(self.max()),
+        }
+    }
+
+    /// The two's complement bit pattern of a number at this width
+    pub fn bits_of(&self, num: i128) -> u128 {
+        num as u128 & self.mask()
+    }
+
+    /// The narrowest size holding a value, signed if the value is negative
+    pub(crate) fn holding(value: Value) -> IntegerSize {
+        let (bits, signed) = significant_bits(value);
+
+        IntegerSize {
+            bits: width_holding(bits),
+            signed,
+        }
+    }
+
+    /// The fewest whole bytes holding a value, signed if the value is negative, so 0x313233 takes
+    /// three bytes rather than a 32-bit width
+    pub(crate) fn bytes_holding(value: Value) -> IntegerSize {
+        let (bits, signed) = significant_bits(value);
+
+        IntegerSize {
+            bits: bits.div_ceil(8).max(1) * 8,
+            signed,
+        }
+    }
+
+    /// The two's complement bit pattern of a value at this width
+    pub(crate) fn pattern_of(&self, value: Value) -> u128 {
+        match value {
+            Value::Signed(num) => self.bits_of(num),
+            Value::Unsigned(num) => num & self.mask(),
+        }
+    }
+
+    /// The value a bit pattern of this size holds, sign-extending it if the size is signed
+    pub(crate) fn value_of(&self, bits: u128) -> Value {
+        let shift = 128 - self.bits;
+        if self.signed {
+            Value::Signed(((bits << shift) as i128) >> shift)
+        } else {
+            Value::from_unsigned(bits & self.mask())
+        }
+    }
+
+    /// The two's complement bit pattern of a number, checking that it fits in this width
+    pub fn pattern(&self, num: i128) -> anyhow::Result<u128, NumberFormatterError> {
+        self.interpret(num)?;
+        Ok(self.bits_of(num))
+    }
+
+    /// The number of digits needed to show every bit, for digits holding `digit_bits` bits each
+    pub fn digits(&self, digit_bits: u32) -> usize {
+        self.bits.div_ceil(digit_bits) as usize
+    }
+}
+
+impl fmt::Display for IntegerSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.signed {
+            write!(f, "i{}", self.bits)
+        } else {
+            write!(f, "u{}", self.bits)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(8, 0xff, -1)]
+    #[test_case(8, 0x7f, 127)]
+    #[test_case(8, 0x80, -128)]
+    #[test_case(8, -128, -128; "minimum")]
+    #[test_case(16, 0xfffe, -2)]
+    #[test_case(32, 0xffffffff, -1)]
+    #[test_case(64, 0x8000000000000000, i64::MIN as i128)]
+    #[test_case(128, -1, -1; "full width")]
+    fn integer_size_should_interpret_bit_patterns(bits: u32, num: i128, expected: i128) {
+        // Arrange
+        let size = IntegerSize { bits, signed: true };
+
+        // Act
+        let actual = size.interpret(num);
+
+        // Assert
+        assert_eq!(actual.unwrap(), expected);
+    }
+
+    #[test_case(8, 256, NumberFormatterError::TooLargeError)]
+    #[test_case(8, -129, NumberFormatterError::TooSmallError)]
+    #[test_case(16, 0x10000, NumberFormatterError::TooLargeError)]
+    fn integer_size_should_reject_numbers_that_do_not_fit(
+        bits: u32,
+        num: i128,
+        expected: NumberFormatterError,
+    ) {
+        // Arrange
+        let size = IntegerSize { bits, signed: true };
+
+        // Act
+        let actual = size.interpret(num);
+
+        // Assert
+        assert!(actual.is_err());
+        assert_eq!(
+            std::mem::discriminant(&actual.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+
+    #[test_case(8, -1, 0xff)]
+    #[test_case(16, -2, 0xfffe)]
+    #[test_case(8, 5, 5)]
+    #[test_case(128, -1, u128::MAX)]
+    fn integer_size_bits_of_should_be_twos_complement(bits: u32, num: i128, expected: u128) {
+        // Arrange
+        let size = IntegerSize { bits, signed: true };
+
+        // Act
+        let actual = size.bits_of(num);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test_case(8, 0xff, 255)]
+    #[test_case(16, 0, 0)]
+    #[test_case(128, i128::MAX, i128::MAX)]
+    fn integer_size_should_keep_unsigned_values(bits: u32, num: i128, expected: i128) {
+        // Arrange
+        let size = IntegerSize {
+            bits,
+            signed: false,
+        };
+
+        // Act
+        let actual = size.interpret(num);
+
+        // Assert
+        assert_eq!(actual.unwrap(), expected);
+    }
+
+    #[test_case(8, 256, NumberFormatterError::TooLargeError)]
+    #[test_case(8, -1, NumberFormatterError::TooSmallError)]
+    #[test_case(128, -1, NumberFormatterError::TooSmallError; "negative at 128 bits")]
+    fn integer_size_should_reject_values_outside_unsigned_range(
+        bits: u32,
+        num: i128,
+        expected: NumberFormatterError,
+    ) {
+        // Arrange
+        let size = IntegerSize {
+            bits,
+            signed: false,
+        };
+
+        // Act
+        let actual = size.interpret(num);
+
+        // Assert
+        assert!(actual.is_err());
+        assert_eq!(
+            std::mem::discriminant(&actual.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+
+    #[test_case(8, 4, 2)]
+    #[test_case(8, 3, 3)]
+    #[test_case(128, 1, 128)]
+    fn integer_size_digits_should_cover_every_bit(bits: u32, digit_bits: u32, expected: usize) {
+        // Arrange
+        let size = IntegerSize { bits, signed: true };
+
+        // Act
+        let actual = size.digits(digit_bits);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test_case("u8", 8, false)]
+    #[test_case("i16", 16, true)]
+    #[test_case("u128", 128, false)]
+    fn parse_size_should_read_type_names(size: &str, bits: u32, signed: bool) {
+        // Act
+        let actual = parse_size(size);
+
+        // Assert
+        assert_eq!(actual, Ok(IntegerSize { bits, signed }));
+    }
+
+    #[test_case("u12")]
+    #[test_case("f32")]
+    #[test_case(""; "empty")]
+    fn parse_size_should_reject_unknown_types(size: &str) {
+        // Act
+        let actual = parse_size(size);
+
+        // Assert
+        assert!(actual.is_err());
+    }
+
+    #[test_case("i8", Value::Signed(300), Truncate::Wrap, Value::Signed(44))]
+    #[test_case("i8", Value::Signed(200), Truncate::Wrap, Value::Signed(-56); "wrap into sign bit")]
+    #[test_case("u8", Value::Signed(-1), Truncate::Wrap, Value::Signed(255))]
+    #[test_case("u128", Value::Signed(-1), Truncate::Wrap, Value::Unsigned(u128::MAX))]
+    #[test_case("u64", Value::Unsigned(u128::MAX), Truncate::Wrap, Value::Signed(u64::MAX as i128))]
+    #[test_case("i8", Value::Signed(300), Truncate::Saturate, Value::Signed(127))]
+    #[test_case("i8", Value::Signed(-300), Truncate::Saturate, Value::Signed(-128))]
+    #[test_case("u8", Value::Signed(-5), Truncate::Saturate, Value::Signed(0))]
+    #[test_case("u128", Value::Signed(-5), Truncate::Saturate, Value::Signed(0); "saturate u128")]
+    #[test_case("i64", Value::Unsigned(u128::MAX), Truncate::Saturate, Value::Signed(i64::MAX as i128))]
+    #[test_case("i8", Value::Signed(0xff), Truncate::Error, Value::Signed(-1); "bit pattern fits")]
+    fn integer_size_fit_should_truncate(
+        size: &str,
+        value: Value,
+        truncate: Truncate,
+        expected: Value,
+    ) {
+        // Arrange
+        let size = parse_size(size).unwrap();
+
+        // Act
+        let actual = size.fit(value, truncate);
+
+        // Assert
+        assert_eq!(actual.unwrap(), expected);
+    }
+
+    #[test_case("i8", Value::Signed(300), NumberFormatterError::TooLargeError)]
+    #[test_case("u8", Value::Signed(-1), NumberFormatterError::TooSmallError)]
+    #[test_case("u64", Value::Unsigned(u128::MAX), NumberFormatterError::TooLargeError)]
+    fn integer_size_fit_should_reject_without_truncation(
+        size: &str,
+        value: Value,
+        expected: NumberFormatterError,
+    ) {
+        // Arrange
+        let size = parse_size(size).unwrap();
+
+        // Act
+        let actual = size.fit(value, Truncate::Error);
+
+        // Assert
+        assert!(actual.is_err());
+        assert_eq!(
+            std::mem::discriminant(&actual.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+
+    #[test_case(8, -1, Ok(0xff))]
+    #[test_case(8, 200, Ok(200); "unsigned pattern")]
+    #[test_case(8, -200, Err(()); "too small")]
+    #[test_case(8, 256, Err(()); "too large")]
+    fn integer_size_pattern_should_check_fit(bits: u32, num: i128, expected: Result<u128, ()>) {
+        // Arrange
+        let size = IntegerSize { bits, signed: true };
+
+        // Act
+        let actual = size.pattern(num);
+
+        // Assert
+        assert_eq!(actual.map_err(|_| ()), expected);
+    }
+
+    #[test_case(8, true, 0xff, Value::Signed(-1))]
+    #[test_case(8, false, 0xff, Value::Signed(255))]
+    #[test_case(16, true, 0x7fff, Value::Signed(32767))]
+    #[test_case(128, true, u128::MAX, Value::Signed(-1); "i128")]
+    #[test_case(128, false, u128::MAX, Value::Unsigned(u128::MAX); "u128")]
+    fn integer_size_value_of_should_read_pattern(
+        bits: u32,
+        signed: bool,
+        pattern: u128,
+        expected: Value,
+    ) {
+        // Arrange
+        let size = IntegerSize { bits, signed };
+
+        // Act
+        let actual = size.value_of(pattern);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test_case(Value::Signed(0x1234), 16, false)]
+    #[test_case(Value::Signed(-129), 16, true)]
+    #[test_case(Value::Signed(-128), 8, true)]
+    #[test_case(Value::Signed(0), 8, false)]
+    #[test_case(Value::Unsigned(u128::MAX), 128, false)]
+    fn integer_size_holding_should_find_narrowest_size(value: Value, bits: u32, signed: bool) {
+        // Act
+        let actual = IntegerSize::holding(value);
+
+        // Assert
+        assert_eq!(actual, IntegerSize { bits, signed });
+    }
+
+    #[test_case(Value::Signed(0x313233), 24, false)]
+    #[test_case(Value::Signed(-129), 16, true; "negative")]
+    #[test_case(Value::Signed(0), 8, false; "zero")]
+    #[test_case(Value::Signed(0x313233343536373839), 72, false; "wider than a width")]
+    fn integer_size_bytes_holding_should_find_fewest_bytes(value: Value, bits: u32, signed: bool) {
+        // Act
+        let actual = IntegerSize::bytes_holding(value);
+
+        // Assert
+        assert_eq!(actual, IntegerSize { bits, signed });
+    }
+
+    #[test_case(1, 8)]
+    #[test_case(8, 8)]
+    #[test_case(9, 16)]
+    #[test_case(32, 32)]
+    #[test_case(100, 128)]
+    fn width_holding_should_round_up_to_width(bits: u32, expected: u32) {
+        // Act
+        let actual = width_holding(bits);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test_case("8", Ok(8))]
+    #[test_case("128", Ok(128))]
+    #[test_case("12", Err(()))]
+    #[test_case("x", Err(()))]
+    fn parse_width_should_only_accept_supported_widths(width: &str, expected: Result<u32, ()>) {
+        // Act
+        let actual = parse_width(width);
+
+        // Assert
+        assert_eq!(actual.map_err(|_| ()), expected);
+    }
+}
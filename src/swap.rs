@@ -0,0 +1,50 @@
+use clap::Args;
+
+/// Options of the bswap and nswap commands
+#[derive(Args, Clone, Debug)]
+pub struct SwapOptions {
+    /// The number to swap
+    pub number: String,
+}
+
+/// Reverses the order of the bytes in the low `bits` bits of a bit pattern
+pub(crate) fn swap_bytes(pattern: u128, bits: u32) -> u128 {
+    pattern.swap_bytes() >> (128 - bits)
+}
+
+/// Reverses the order of the nibbles in the low `bits` bits of a bit pattern
+pub(crate) fn swap_nibbles(pattern: u128, bits: u32) -> u128 {
+    (0..bits / 4).fold(0, |swapped, index| {
+        let nibble = (pattern >> (index * 4)) & 0xf;
+        swapped | nibble << (bits - 4 - index * 4)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(0x12345678, 32, 0x78563412)]
+    #[test_case(0x1234, 16, 0x3412)]
+    #[test_case(0xab, 8, 0xab)]
+    #[test_case(0x0102030405060708, 64, 0x0807060504030201)]
+    fn swap_bytes_should_reverse_bytes(pattern: u128, bits: u32, expected: u128) {
+        // Act
+        let actual = swap_bytes(pattern, bits);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test_case(0x12345678, 32, 0x87654321)]
+    #[test_case(0xab, 8, 0xba)]
+    #[test_case(0x1, 16, 0x1000)]
+    fn swap_nibbles_should_reverse_nibbles(pattern: u128, bits: u32, expected: u128) {
+        // Act
+        let actual = swap_nibbles(pattern, bits);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+}
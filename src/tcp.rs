@@ -0,0 +1,128 @@
+use crate::{NumberFormatter, NumberFormatterError};
+
+/// TCP header flags from the least significant bit of the flags byte up
+static FLAGS: [(i128, &str); 8] = [
+    (0x01, "FIN"),
+    (0x02, "SYN"),
+    (0x04, "RST"),
+    (0x08, "PSH"),
+    (0x10, "ACK"),
+    (0x20, "URG"),
+    (0x40, "ECE"),
+    (0x80, "CWR"),
+];
+
+/// What a flags byte with no flags set is written as
+const NO_FLAGS: &str = "none";
+
+/// Whether the input is written as a list of TCP flags
+pub(crate) fn is_tcp_flags(input: &str) -> bool {
+    !input.eq_ignore_ascii_case(NO_FLAGS) && TcpFlagsNumberFormatter.read(input).is_ok()
+}
+
+/// Formatter writing bytes as the TCP flags that are set in them, such as `SYN, ACK` for 0x12
+pub(crate) struct TcpFlagsNumberFormatter;
+
+impl NumberFormatter for TcpFlagsNumberFormatter {
+    fn read(&self, flags: &str) -> anyhow::Result<i128, NumberFormatterError> {
+        let names: Vec<&str> = flags
+            .split(|c: char| c == ',' || c == '|' || c.is_whitespace())
+            .filter(|name| !name.is_empty())
+            .collect();
+
+        if names.is_empty() {
+            return Err(NumberFormatterError::Empty);
+        }
+        if names.len() == 1 && names[0].eq_ignore_ascii_case(NO_FLAGS) {
+            return Ok(0);
+        }
+
+        names.iter().try_fold(0, |byte, name| {
+            FLAGS
+                .iter()
+                .find(|(_, flag)| flag.eq_ignore_ascii_case(name))
+                .map(|(bit, _)| byte | bit)
+                .ok_or(NumberFormatterError::InvalidTcpFlag)
+        })
+    }
+
+    fn format(&self, num: i128) -> anyhow::Result<String, NumberFormatterError> {
+        if num < 0 {
+            return Err(NumberFormatterError::TooSmallError);
+        }
+        if num > u8::MAX as i128 {
+            return Err(NumberFormatterError::TooLargeError);
+        }
+        if num == 0 {
+            return Ok(String::from(NO_FLAGS));
+        }
+
+        Ok(FLAGS
+            .iter()
+            .filter(|(bit, _)| num & bit != 0)
+            .map(|(_, flag)| *flag)
+            .collect::<Vec<&str>>()
+            .join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(0x02, "SYN")]
+    #[test_case(0x12, "SYN, ACK")]
+    #[test_case(0x18, "PSH, ACK")]
+    #[test_case(0xc2, "SYN, ECE, CWR")]
+    #[test_case(0, "none")]
+    fn tcp_flags_number_formatter_should_format_and_read(flags: i128, names: &str) {
+        // Act
+        let actual_names = TcpFlagsNumberFormatter.format(flags);
+        let actual_flags = TcpFlagsNumberFormatter.read(names);
+
+        // Assert
+        assert_eq!(actual_names.unwrap(), names);
+        assert_eq!(actual_flags.unwrap(), flags);
+    }
+
+    #[test_case("syn|ack", 0x12; "pipes")]
+    #[test_case("FIN ACK", 0x11; "spaces")]
+    #[test_case("RST,RST", 0x04; "repeated")]
+    fn tcp_flags_number_formatter_should_read_separators(input: &str, expected: i128) {
+        // Act
+        let actual = TcpFlagsNumberFormatter.read(input);
+
+        // Assert
+        assert_eq!(actual.unwrap(), expected);
+    }
+
+    #[test_case("", NumberFormatterError::Empty; "empty")]
+    #[test_case("SYN, NS", NumberFormatterError::InvalidTcpFlag; "unknown flag")]
+    fn tcp_flags_number_formatter_should_fail_to_read(input: &str, expected: NumberFormatterError) {
+        // Act
+        let actual = TcpFlagsNumberFormatter.read(input);
+
+        // Assert
+        assert_eq!(
+            std::mem::discriminant(&actual.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+
+    #[test_case(0x100, NumberFormatterError::TooLargeError; "too large")]
+    #[test_case(-1, NumberFormatterError::TooSmallError; "negative")]
+    fn tcp_flags_number_formatter_should_fail_to_format(
+        input: i128,
+        expected: NumberFormatterError,
+    ) {
+        // Act
+        let actual = TcpFlagsNumberFormatter.format(input);
+
+        // Assert
+        assert_eq!(
+            std::mem::discriminant(&actual.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+}
@@ -0,0 +1,165 @@
+use crate::bytes::{pattern_of_bytes, to_endian, value_bytes, Endian};
+use crate::{IntegerSize, NumberFormatter, NumberFormatterError, Value};
+
+/// Formatter decoding the bytes of a number's bit pattern as UTF-8 text, such as the FourCC
+/// `"RIFF"`, at the integer size or else in as few bytes as hold the number. Control characters
+/// and bytes which are not UTF-8 are escaped.
+pub(crate) struct TextNumberFormatter {
+    pub endian: Endian,
+    pub size: Option<IntegerSize>,
+}
+
+impl TextNumberFormatter {
+    fn format_value(&self, value: Value) -> String {
+        let size = self.size.unwrap_or_else(|| IntegerSize::holding(value));
+        let mut bytes = value_bytes(value, size, Endian::Little);
+        if self.size.is_none() {
+            while bytes.len() > 1 && bytes.last() == Some(&0) {
+                bytes.pop();
+            }
+        }
+        let bytes = to_endian(bytes, self.endian);
+        let mut text = String::from('"');
+
+        for chunk in bytes.utf8_chunks() {
+            for c in chunk.valid().chars() {
+                match c {
+                    '"' => text.push_str("\\\""),
+                    '\\' => text.push_str("\\\\"),
+                    '\n' => text.push_str("\\n"),
+                    '\r' => text.push_str("\\r"),
+                    '\t' => text.push_str("\\t"),
+                    '\0' => text.push_str("\\0"),
+                    c if c.is_ascii_control() => text.push_str(&format!("\\x{:02x}", c as u8)),
+                    c if c.is_control() => text.push_str(&format!("\\u{{{:x}}}", c as u32)),
+                    c => text.push(c),
+                }
+            }
+            for byte in chunk.invalid() {
+                text.push_str(&format!("\\x{:02x}", byte));
+            }
+        }
+
+        text.push('"');
+        text
+    }
+}
+
+/// Reads the UTF-8 bytes of a string, optionally double quoted, with `\xNN`, `\u{N}` and
+/// the usual single character escapes
+fn parse_text(text: &str) -> anyhow::Result<Vec<u8>, NumberFormatterError> {
+    let text = if text.len() >= 2 && text.starts_with('"') && text.ends_with('"') {
+        &text[1..text.len() - 1]
+    } else {
+        text
+    };
+
+    let mut bytes = Vec::new();
+    let mut chars = text.chars();
+
+    while let Some(c) = chars.next() {
+        let c = match c {
+            '\\' => match chars.next() {
+                Some('x') => {
+                    let digits: String = chars.by_ref().take(2).collect();
+                    bytes.push(
+                        u8::from_str_radix(&digits, 16)
+                            .map_err(|_| NumberFormatterError::InvalidDigit)?,
+                    );
+                    continue;
+                }
+                Some('u') => {
+                    let digits: String = chars.by_ref().take_while(|c| *c != '}').collect();
+                    digits
+                        .strip_prefix('{')
+                        .and_then(|digits| u32::from_str_radix(digits, 16).ok())
+                        .and_then(char::from_u32)
+                        .ok_or(NumberFormatterError::InvalidCharacter)?
+                }
+                Some('n') => '\n',
+                Some('r') => '\r',
+                Some('t') => '\t',
+                Some('0') => '\0',
+                Some(c @ ('\\' | '"' | '\'')) => c,
+                _ => return Err(NumberFormatterError::InvalidCharacter),
+            },
+            c => c,
+        };
+
+        let mut utf8 = [0; 4];
+        bytes.extend_from_slice(c.encode_utf8(&mut utf8).as_bytes());
+    }
+
+    Ok(bytes)
+}
+
+impl NumberFormatter for TextNumberFormatter {
+    fn read(&self, text: &str) -> anyhow::Result<i128, NumberFormatterError> {
+        i128::try_from(self.read_unsigned(text)?).map_err(|_| NumberFormatterError::TooLargeError)
+    }
+
+    fn format(&self, num: i128) -> anyhow::Result<String, NumberFormatterError> {
+        Ok(self.format_value(Value::Signed(num)))
+    }
+
+    fn read_unsigned(&self, text: &str) -> anyhow::Result<u128, NumberFormatterError> {
+        pattern_of_bytes(parse_text(text)?, self.endian)
+    }
+
+    fn format_unsigned(&self, num: u128) -> anyhow::Result<String, NumberFormatterError> {
+        Ok(self.format_value(Value::Unsigned(num)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    fn formatter(endian: Endian) -> TextNumberFormatter {
+        TextNumberFormatter { endian, size: None }
+    }
+
+    #[test_case(Endian::Big, 0x52494646, "\"RIFF\"")]
+    #[test_case(Endian::Little, 0x46464952, "\"RIFF\""; "little endian")]
+    #[test_case(Endian::Big, 0x7f454c46, "\"\\x7fELF\""; "control character")]
+    #[test_case(Endian::Big, 0x0a22, "\"\\n\\\"\""; "escapes")]
+    #[test_case(Endian::Big, 0xe282ac, "\"€\""; "utf8")]
+    #[test_case(Endian::Big, 0xff41, "\"\\xffA\""; "invalid utf8")]
+    fn text_number_formatter_should_format_i128(endian: Endian, input: i128, expected: &str) {
+        // Act
+        let actual_output = formatter(endian).format(input);
+
+        // Assert
+        assert_eq!(actual_output.unwrap(), expected);
+    }
+
+    #[test_case(Endian::Big, "\"RIFF\"", 0x52494646)]
+    #[test_case(Endian::Big, "RIFF", 0x52494646; "unquoted")]
+    #[test_case(Endian::Little, "RIFF", 0x46464952; "little endian")]
+    #[test_case(Endian::Big, "\\x7fELF", 0x7f454c46; "hex escape")]
+    #[test_case(Endian::Big, "\\u{20ac}", 0xe282ac; "unicode escape")]
+    #[test_case(Endian::Big, "€", 0xe282ac; "utf8")]
+    fn text_number_formatter_should_read_number(endian: Endian, input: &str, expected: i128) {
+        // Act
+        let actual_number = formatter(endian).read(input);
+
+        // Assert
+        assert_eq!(actual_number.unwrap(), expected);
+    }
+
+    #[test_case("\"\"", NumberFormatterError::Empty; "empty")]
+    #[test_case("\\q", NumberFormatterError::InvalidCharacter; "unknown escape")]
+    #[test_case("\\xg0", NumberFormatterError::InvalidDigit; "invalid hex escape")]
+    #[test_case("seventeen bytes!!", NumberFormatterError::TooLargeError; "too long")]
+    fn text_number_formatter_should_fail_to_read(input: &str, expected: NumberFormatterError) {
+        // Act
+        let actual_number = formatter(Endian::Big).read(input);
+
+        // Assert
+        assert_eq!(
+            std::mem::discriminant(&actual_number.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+}
@@ -0,0 +1,465 @@
+use crate::{NumberFormatter, NumberFormatterError};
+use clap::ArgEnum;
+
+/// Unit a timestamp counts the time since its epoch in
+#[derive(ArgEnum, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TimeUnit {
+    /// Seconds
+    S,
+    /// Milliseconds
+    Ms,
+    /// Microseconds
+    Us,
+    /// Nanoseconds
+    Ns,
+}
+
+/// Start of the time a timestamp counts, and the resolution it counts it in
+#[derive(ArgEnum, Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Epoch {
+    /// Unix time since 1970-01-01, in the --time-unit
+    #[default]
+    Unix,
+    /// Windows FILETIME and NTFS timestamps, 100 ns intervals since 1601-01-01
+    Filetime,
+    /// Classic Mac OS and HFS+ timestamps, seconds since 1904-01-01
+    Mac,
+}
+
+impl Epoch {
+    /// Seconds from 1970-01-01 to the epoch
+    fn unix_offset(&self) -> i128 {
+        match self {
+            Epoch::Unix => 0,
+            Epoch::Filetime => -11_644_473_600,
+            Epoch::Mac => -2_082_844_800,
+        }
+    }
+
+    /// Digits of the fraction of a second timestamps from the epoch count, the unit's for Unix time
+    fn fraction_digits(&self, unit: TimeUnit) -> u32 {
+        match self {
+            Epoch::Unix => unit.fraction_digits(),
+            Epoch::Filetime => 7,
+            Epoch::Mac => 0,
+        }
+    }
+}
+
+pub(crate) const SECONDS_PER_DAY: i128 = 86_400;
+
+/// Days from 0000-03-01 to 1970-01-01, the offset of the proleptic Gregorian day count
+const UNIX_EPOCH_DAYS: i128 = 719_468;
+
+/// Days in a 400 year cycle of the Gregorian calendar
+const DAYS_PER_ERA: i128 = 146_097;
+
+impl TimeUnit {
+    /// Digits of the fraction of a second the unit counts
+    fn fraction_digits(&self) -> u32 {
+        match self {
+            TimeUnit::S => 0,
+            TimeUnit::Ms => 3,
+            TimeUnit::Us => 6,
+            TimeUnit::Ns => 9,
+        }
+    }
+
+    /// The unit counting fractions of a second with this many digits
+    fn with_fraction_digits(digits: usize) -> Option<TimeUnit> {
+        match digits {
+            0 => Some(TimeUnit::S),
+            3 => Some(TimeUnit::Ms),
+            6 => Some(TimeUnit::Us),
+            9 => Some(TimeUnit::Ns),
+            _ => None,
+        }
+    }
+
+    /// Guesses the unit of a Unix timestamp from its magnitude, reading it in the largest unit
+    /// that puts it before the year 5138
+    fn detect(timestamp: i128) -> TimeUnit {
+        match timestamp.unsigned_abs() {
+            0..=99_999_999_999 => TimeUnit::S,
+            100_000_000_000..=99_999_999_999_999 => TimeUnit::Ms,
+            100_000_000_000_000..=99_999_999_999_999_999 => TimeUnit::Us,
+            _ => TimeUnit::Ns,
+        }
+    }
+}
+
+/// Days since 1970-01-01 of a date in the proleptic Gregorian calendar
+pub(crate) fn days_from_civil(year: i128, month: u32, day: u32) -> i128 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month_from_march = (month as i128 + 9) % 12;
+    let day_of_year = (153 * month_from_march + 2) / 5 + day as i128 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * DAYS_PER_ERA + day_of_era - UNIX_EPOCH_DAYS
+}
+
+/// The date in the proleptic Gregorian calendar that is some days since 1970-01-01
+pub(crate) fn civil_from_days(days: i128) -> (i128, u32, u32) {
+    let days = days + UNIX_EPOCH_DAYS;
+    let era = days.div_euclid(DAYS_PER_ERA);
+    let day_of_era = days.rem_euclid(DAYS_PER_ERA);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_from_march = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_from_march + 2) / 5 + 1) as u32;
+    let month = ((month_from_march + 2) % 12 + 1) as u32;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    (year, month, day)
+}
+
+/// Writes a time in seconds since 1970-01-01 as an ISO 8601 date and time at an offset from UTC in
+/// seconds, with the fraction of a second written with the number of digits it counts
+pub(crate) fn format_datetime(
+    seconds: i128,
+    fraction: i128,
+    fraction_digits: u32,
+    offset: i128,
+) -> anyhow::Result<String, NumberFormatterError> {
+    let local = seconds + offset;
+    let (year, month, day) = civil_from_days(local.div_euclid(SECONDS_PER_DAY));
+
+    // ISO 8601 writes years with four digits
+    if year < 0 {
+        return Err(NumberFormatterError::TooSmallError);
+    }
+    if year > 9999 {
+        return Err(NumberFormatterError::TooLargeError);
+    }
+
+    let time_of_day = local.rem_euclid(SECONDS_PER_DAY);
+    let mut datetime = format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+        year,
+        month,
+        day,
+        time_of_day / 3600,
+        time_of_day / 60 % 60,
+        time_of_day % 60
+    );
+
+    if fraction_digits > 0 {
+        datetime.push_str(&format!(
+            ".{:0digits$}",
+            fraction,
+            digits = fraction_digits as usize
+        ));
+    }
+
+    match offset {
+        0 => datetime.push('Z'),
+        offset => datetime.push_str(&format!(
+            "{}{:02}:{:02}",
+            if offset < 0 { '-' } else { '+' },
+            offset.abs() / 3600,
+            offset.abs() / 60 % 60
+        )),
+    }
+
+    Ok(datetime)
+}
+
+/// Reads an ISO 8601 date with an optional time, fraction of a second and offset from UTC, such as
+/// `2023-11-14T22:13:20.5+01:00`, as seconds since 1970-01-01 and the fraction of a second's digits
+pub(crate) fn parse_datetime(
+    datetime: &str,
+) -> anyhow::Result<(i128, String), NumberFormatterError> {
+    let invalid = || NumberFormatterError::InvalidDateTime;
+    let number = |digits: &str, len: usize| -> anyhow::Result<u32, NumberFormatterError> {
+        if digits.len() == len && digits.chars().all(|c| c.is_ascii_digit()) {
+            Ok(digits.parse()?)
+        } else {
+            Err(NumberFormatterError::InvalidDateTime)
+        }
+    };
+
+    let (date, rest) = match (datetime.get(..10), datetime.get(10..)) {
+        (Some(date), Some(rest)) => (date, rest),
+        _ => return Err(invalid()),
+    };
+    let mut fields = date.split('-');
+    let year = number(fields.next().ok_or_else(invalid)?, 4)?;
+    let month = number(fields.next().ok_or_else(invalid)?, 2)?;
+    let day = number(fields.next().ok_or_else(invalid)?, 2)?;
+
+    let days_in_month = match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        1..=12 => 31,
+        _ => return Err(invalid()),
+    };
+    if day == 0 || day > days_in_month {
+        return Err(invalid());
+    }
+
+    let mut seconds = days_from_civil(year as i128, month, day) * SECONDS_PER_DAY;
+    let mut fraction = String::new();
+
+    let rest = match rest.strip_prefix(['T', 't', ' ']) {
+        Some(time) => {
+            let zone_start = time.find(['Z', 'z', '+', '-']).unwrap_or(time.len());
+            let (time, zone) = time.split_at(zone_start);
+            let (time, digits) = time.split_once('.').unwrap_or((time, ""));
+
+            let mut fields = time.split(':');
+            let hour = number(fields.next().ok_or_else(invalid)?, 2)?;
+            let minute = number(fields.next().ok_or_else(invalid)?, 2)?;
+            let second = fields.next().map_or(Ok(0), |second| number(second, 2))?;
+            if fields.next().is_some() || hour > 23 || minute > 59 || second > 60 {
+                return Err(invalid());
+            }
+            if !digits.chars().all(|c| c.is_ascii_digit()) {
+                return Err(invalid());
+            }
+
+            seconds += (hour * 3600 + minute * 60 + second) as i128;
+            fraction.push_str(digits);
+            zone
+        }
+        None => rest,
+    };
+
+    match rest {
+        "" | "Z" | "z" => {}
+        zone => {
+            let sign = match zone.chars().next() {
+                Some('+') => -1,
+                Some('-') => 1,
+                _ => return Err(invalid()),
+            };
+            let zone = zone[1..].replace(':', "");
+            let hours = number(zone.get(..2).ok_or_else(invalid)?, 2)?;
+            let minutes = number(zone.get(2..).ok_or_else(invalid)?, 2)?;
+            seconds += sign * (hours * 3600 + minutes * 60) as i128;
+        }
+    }
+
+    Ok((seconds, fraction))
+}
+
+/// Whether the input starts with an ISO 8601 date
+pub(crate) fn is_datetime(input: &str) -> bool {
+    parse_datetime(input).is_ok()
+}
+
+/// Offset from UTC in seconds of the local time zone at a time in seconds since 1970-01-01
+#[cfg(unix)]
+fn local_offset(seconds: i128) -> Option<i128> {
+    let time = libc::time_t::try_from(seconds).ok()?;
+    let mut tm = std::mem::MaybeUninit::<libc::tm>::uninit();
+
+    // SAFETY: localtime_r only writes to the tm it is given, which is only read once it succeeded
+    let tm = unsafe {
+        if libc::localtime_r(&time, tm.as_mut_ptr()).is_null() {
+            return None;
+        }
+        tm.assume_init()
+    };
+
+    Some(tm.tm_gmtoff as i128)
+}
+
+#[cfg(not(unix))]
+fn local_offset(_seconds: i128) -> Option<i128> {
+    None
+}
+
+/// Formatter writing timestamps as ISO 8601 dates and times in UTC and in the local time zone, such
+/// as `2023-11-14T22:13:20Z (local 2023-11-14T23:13:20+01:00)`. Unix timestamps are counted in the
+/// given unit, or else one guessed from their magnitude.
+pub(crate) struct TimeNumberFormatter {
+    pub epoch: Epoch,
+    pub unit: Option<TimeUnit>,
+    pub local: bool,
+}
+
+impl NumberFormatter for TimeNumberFormatter {
+    fn read(&self, datetime: &str) -> anyhow::Result<i128, NumberFormatterError> {
+        if datetime.is_empty() {
+            return Err(NumberFormatterError::Empty);
+        }
+
+        // Only the UTC part of the formatted output is read back
+        let datetime = datetime.split(" (").next().unwrap_or_default();
+        let (seconds, mut fraction) = parse_datetime(datetime)?;
+
+        let unit = match self.unit {
+            Some(unit) => unit,
+            None => TimeUnit::with_fraction_digits(fraction.len()).unwrap_or(TimeUnit::Ns),
+        };
+        let digits = self.epoch.fraction_digits(unit) as usize;
+        fraction.truncate(digits);
+        let fraction = if fraction.is_empty() {
+            0
+        } else {
+            format!("{:0<digits$}", fraction).parse::<i128>()?
+        };
+
+        (seconds - self.epoch.unix_offset())
+            .checked_mul(10i128.pow(digits as u32))
+            .and_then(|timestamp| timestamp.checked_add(fraction))
+            .ok_or(NumberFormatterError::TooLargeError)
+    }
+
+    fn format(&self, num: i128) -> anyhow::Result<String, NumberFormatterError> {
+        let unit = self.unit.unwrap_or_else(|| TimeUnit::detect(num));
+        let digits = self.epoch.fraction_digits(unit);
+        let per_second = 10i128.pow(digits);
+        let seconds = num.div_euclid(per_second) + self.epoch.unix_offset();
+        let fraction = num.rem_euclid(per_second);
+
+        let utc = format_datetime(seconds, fraction, digits, 0)?;
+
+        match self.local.then(|| local_offset(seconds)).flatten() {
+            Some(offset) => Ok(format!(
+                "{} (local {})",
+                utc,
+                format_datetime(seconds, fraction, digits, offset)?
+            )),
+            None => Ok(utc),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(1970, 1, 1, 0)]
+    #[test_case(2000, 3, 1, 11017; "after leap day")]
+    #[test_case(1969, 12, 31, -1; "before epoch")]
+    #[test_case(1601, 1, 1, -134774; "filetime epoch")]
+    fn days_from_civil_should_count_days_and_invert(year: i128, month: u32, day: u32, days: i128) {
+        // Act
+        let actual_days = days_from_civil(year, month, day);
+        let actual_date = civil_from_days(days);
+
+        // Assert
+        assert_eq!(actual_days, days);
+        assert_eq!(actual_date, (year, month, day));
+    }
+
+    #[test_case(1700000000, None, "2023-11-14T22:13:20Z")]
+    #[test_case(1700000000123, None, "2023-11-14T22:13:20.123Z"; "millis")]
+    #[test_case(1700000000123456, None, "2023-11-14T22:13:20.123456Z"; "micros")]
+    #[test_case(1700000000123456789, None, "2023-11-14T22:13:20.123456789Z"; "nanos")]
+    #[test_case(1700000000, Some(TimeUnit::Ms), "1970-01-20T16:13:20.000Z"; "forced unit")]
+    #[test_case(-1, None, "1969-12-31T23:59:59Z"; "before epoch")]
+    #[test_case(951782400, None, "2000-02-29T00:00:00Z"; "leap day")]
+    fn time_number_formatter_should_format_i128(
+        input: i128,
+        unit: Option<TimeUnit>,
+        expected: &str,
+    ) {
+        // Act
+        let actual = TimeNumberFormatter {
+            epoch: Epoch::Unix,
+            unit,
+            local: false,
+        }
+        .format(input);
+
+        // Assert
+        assert_eq!(actual.unwrap(), expected);
+    }
+
+    #[test_case("2023-11-14T22:13:20Z", None, 1700000000)]
+    #[test_case("2023-11-14 22:13:20", None, 1700000000; "space separated")]
+    #[test_case("2023-11-14T23:13:20+01:00", None, 1700000000; "offset")]
+    #[test_case("2023-11-14T22:13:20.123Z", None, 1700000000123; "millis")]
+    #[test_case("2023-11-14T22:13:20.5Z", None, 1700000000500000000; "short fraction")]
+    #[test_case("2023-11-14T22:13:20.123Z", Some(TimeUnit::S), 1700000000; "forced unit")]
+    #[test_case("2023-11-14", None, 1699920000; "date only")]
+    #[test_case("2023-11-14T22:13:20Z (local 2023-11-14T23:13:20+01:00)", None, 1700000000; "with local")]
+    fn time_number_formatter_should_read_datetime(
+        input: &str,
+        unit: Option<TimeUnit>,
+        expected: i128,
+    ) {
+        // Act
+        let actual = TimeNumberFormatter {
+            epoch: Epoch::Unix,
+            unit,
+            local: false,
+        }
+        .read(input);
+
+        // Assert
+        assert_eq!(actual.unwrap(), expected);
+    }
+
+    #[test_case("", NumberFormatterError::Empty; "empty")]
+    #[test_case("2023-02-29", NumberFormatterError::InvalidDateTime; "not a leap year")]
+    #[test_case("2023-13-01", NumberFormatterError::InvalidDateTime; "month too large")]
+    #[test_case("2023-11-14T24:00", NumberFormatterError::InvalidDateTime; "hour too large")]
+    #[test_case("2023-11-14T22:13:20+1", NumberFormatterError::InvalidDateTime; "short offset")]
+    #[test_case("1700000000", NumberFormatterError::InvalidDateTime; "timestamp")]
+    fn time_number_formatter_should_fail_to_read(input: &str, expected: NumberFormatterError) {
+        // Act
+        let actual = TimeNumberFormatter {
+            epoch: Epoch::Unix,
+            unit: None,
+            local: false,
+        }
+        .read(input);
+
+        // Assert
+        assert_eq!(
+            std::mem::discriminant(&actual.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+
+    #[test_case(253402300800, NumberFormatterError::TooLargeError; "year 10000")]
+    #[test_case(-62167219201, NumberFormatterError::TooSmallError; "before year 0")]
+    fn time_number_formatter_should_fail_to_format(input: i128, expected: NumberFormatterError) {
+        // Act
+        let actual = TimeNumberFormatter {
+            epoch: Epoch::Unix,
+            unit: Some(TimeUnit::S),
+            local: false,
+        }
+        .format(input);
+
+        // Assert
+        assert_eq!(
+            std::mem::discriminant(&actual.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+
+    #[test_case(Epoch::Filetime, 133444736001234567, "2023-11-14T22:13:20.1234567Z")]
+    #[test_case(Epoch::Filetime, 0, "1601-01-01T00:00:00.0000000Z"; "filetime epoch")]
+    #[test_case(Epoch::Mac, 3782844800, "2023-11-14T22:13:20Z"; "mac")]
+    #[test_case(Epoch::Mac, 0, "1904-01-01T00:00:00Z"; "mac epoch")]
+    fn time_number_formatter_should_format_and_read_epoch(
+        epoch: Epoch,
+        timestamp: i128,
+        datetime: &str,
+    ) {
+        // Arrange
+        let formatter = TimeNumberFormatter {
+            epoch,
+            unit: None,
+            local: false,
+        };
+
+        // Act
+        let actual_datetime = formatter.format(timestamp);
+        let actual_timestamp = formatter.read(datetime);
+
+        // Assert
+        assert_eq!(actual_datetime.unwrap(), datetime);
+        assert_eq!(actual_timestamp.unwrap(), timestamp);
+    }
+}
@@ -0,0 +1,253 @@
+mod tables;
+
+use tables::{CATEGORIES, NAMES};
+
+/// Ranges of CJK unified ideographs, whose names are their codepoint
+static CJK_UNIFIED_IDEOGRAPHS: [(u32, u32); 8] = [
+    (0x3400, 0x4DBF),
+    (0x4E00, 0x9FFF),
+    (0x20000, 0x2A6DF),
+    (0x2A700, 0x2B738),
+    (0x2B740, 0x2B81D),
+    (0x2B820, 0x2CEA1),
+    (0x2CEB0, 0x2EBE0),
+    (0x30000, 0x3134A),
+];
+
+const HANGUL_SYLLABLES: (u32, u32) = (0xAC00, 0xD7A3);
+
+/// Short names of the leading consonants, vowels and trailing consonants making up a Hangul syllable
+static HANGUL_LEADS: [&str; 19] = [
+    "G", "GG", "N", "D", "DD", "R", "M", "B", "BB", "S", "SS", "", "J", "JJ", "C", "K", "T", "P",
+    "H",
+];
+static HANGUL_VOWELS: [&str; 21] = [
+    "A", "AE", "YA", "YAE", "EO", "E", "YEO", "YE", "O", "WA", "WAE", "OE", "YO", "U", "WEO", "WE",
+    "WI", "YU", "EU", "YI", "I",
+];
+static HANGUL_TAILS: [&str; 28] = [
+    "", "G", "GG", "GS", "N", "NJ", "NH", "D", "L", "LG", "LM", "LB", "LS", "LT", "LP", "LH", "M",
+    "B", "BS", "S", "SS", "NG", "J", "C", "K", "T", "P", "H",
+];
+
+static BLOCKS: [(u32, u32, &str); 118] = [
+    (0x0000, 0x007F, "Basic Latin"),
+    (0x0080, 0x00FF, "Latin-1 Supplement"),
+    (0x0100, 0x017F, "Latin Extended-A"),
+    (0x0180, 0x024F, "Latin Extended-B"),
+    (0x0250, 0x02AF, "IPA Extensions"),
+    (0x02B0, 0x02FF, "Spacing Modifier Letters"),
+    (0x0300, 0x036F, "Combining Diacritical Marks"),
+    (0x0370, 0x03FF, "Greek and Coptic"),
+    (0x0400, 0x04FF, "Cyrillic"),
+    (0x0500, 0x052F, "Cyrillic Supplement"),
+    (0x0530, 0x058F, "Armenian"),
+    (0x0590, 0x05FF, "Hebrew"),
+    (0x0600, 0x06FF, "Arabic"),
+    (0x0700, 0x074F, "Syriac"),
+    (0x0750, 0x077F, "Arabic Supplement"),
+    (0x0780, 0x07BF, "Thaana"),
+    (0x07C0, 0x07FF, "NKo"),
+    (0x0800, 0x083F, "Samaritan"),
+    (0x0840, 0x085F, "Mandaic"),
+    (0x0900, 0x097F, "Devanagari"),
+    (0x0980, 0x09FF, "Bengali"),
+    (0x0A00, 0x0A7F, "Gurmukhi"),
+    (0x0A80, 0x0AFF, "Gujarati"),
+    (0x0B00, 0x0B7F, "Oriya"),
+    (0x0B80, 0x0BFF, "Tamil"),
+    (0x0C00, 0x0C7F, "Telugu"),
+    (0x0C80, 0x0CFF, "Kannada"),
+    (0x0D00, 0x0D7F, "Malayalam"),
+    (0x0D80, 0x0DFF, "Sinhala"),
+    (0x0E00, 0x0E7F, "Thai"),
+    (0x0E80, 0x0EFF, "Lao"),
+    (0x0F00, 0x0FFF, "Tibetan"),
+    (0x1000, 0x109F, "Myanmar"),
+    (0x10A0, 0x10FF, "Georgian"),
+    (0x1100, 0x11FF, "Hangul Jamo"),
+    (0x1200, 0x137F, "Ethiopic"),
+    (0x13A0, 0x13FF, "Cherokee"),
+    (0x1400, 0x167F, "Unified Canadian Aboriginal Syllabics"),
+    (0x1680, 0x169F, "Ogham"),
+    (0x16A0, 0x16FF, "Runic"),
+    (0x1780, 0x17FF, "Khmer"),
+    (0x1800, 0x18AF, "Mongolian"),
+    (0x1AB0, 0x1AFF, "Combining Diacritical Marks Extended"),
+    (0x1D00, 0x1D7F, "Phonetic Extensions"),
+    (0x1D80, 0x1DBF, "Phonetic Extensions Supplement"),
+    (0x1DC0, 0x1DFF, "Combining Diacritical Marks Supplement"),
+    (0x1E00, 0x1EFF, "Latin Extended Additional"),
+    (0x1F00, 0x1FFF, "Greek Extended"),
+    (0x2000, 0x206F, "General Punctuation"),
+    (0x2070, 0x209F, "Superscripts and Subscripts"),
+    (0x20A0, 0x20CF, "Currency Symbols"),
+    (0x20D0, 0x20FF, "Combining Diacritical Marks for Symbols"),
+    (0x2100, 0x214F, "Letterlike Symbols"),
+    (0x2150, 0x218F, "Number Forms"),
+    (0x2190, 0x21FF, "Arrows"),
+    (0x2200, 0x22FF, "Mathematical Operators"),
+    (0x2300, 0x23FF, "Miscellaneous Technical"),
+    (0x2400, 0x243F, "Control Pictures"),
+    (0x2440, 0x245F, "Optical Character Recognition"),
+    (0x2460, 0x24FF, "Enclosed Alphanumerics"),
+    (0x2500, 0x257F, "Box Drawing"),
+    (0x2580, 0x259F, "Block Elements"),
+    (0x25A0, 0x25FF, "Geometric Shapes"),
+    (0x2600, 0x26FF, "Miscellaneous Symbols"),
+    (0x2700, 0x27BF, "Dingbats"),
+    (0x27C0, 0x27EF, "Miscellaneous Mathematical Symbols-A"),
+    (0x27F0, 0x27FF, "Supplemental Arrows-A"),
+    (0x2800, 0x28FF, "Braille Patterns"),
+    (0x2900, 0x297F, "Supplemental Arrows-B"),
+    (0x2980, 0x29FF, "Miscellaneous Mathematical Symbols-B"),
+    (0x2A00, 0x2AFF, "Supplemental Mathematical Operators"),
+    (0x2B00, 0x2BFF, "Miscellaneous Symbols and Arrows"),
+    (0x2C00, 0x2C5F, "Glagolitic"),
+    (0x2C60, 0x2C7F, "Latin Extended-C"),
+    (0x2E80, 0x2EFF, "CJK Radicals Supplement"),
+    (0x3000, 0x303F, "CJK Symbols and Punctuation"),
+    (0x3040, 0x309F, "Hiragana"),
+    (0x30A0, 0x30FF, "Katakana"),
+    (0x3400, 0x4DBF, "CJK Unified Ideographs Extension A"),
+    (0x4DC0, 0x4DFF, "Yijing Hexagram Symbols"),
+    (0x4E00, 0x9FFF, "CJK Unified Ideographs"),
+    (0xA000, 0xA48F, "Yi Syllables"),
+    (0xAC00, 0xD7AF, "Hangul Syllables"),
+    (0xD800, 0xDB7F, "High Surrogates"),
+    (0xDB80, 0xDBFF, "High Private Use Surrogates"),
+    (0xDC00, 0xDFFF, "Low Surrogates"),
+    (0xE000, 0xF8FF, "Private Use Area"),
+    (0xF900, 0xFAFF, "CJK Compatibility Ideographs"),
+    (0xFB00, 0xFB4F, "Alphabetic Presentation Forms"),
+    (0xFB50, 0xFDFF, "Arabic Presentation Forms-A"),
+    (0xFE00, 0xFE0F, "Variation Selectors"),
+    (0xFE20, 0xFE2F, "Combining Half Marks"),
+    (0xFE30, 0xFE4F, "CJK Compatibility Forms"),
+    (0xFE70, 0xFEFF, "Arabic Presentation Forms-B"),
+    (0xFF00, 0xFFEF, "Halfwidth and Fullwidth Forms"),
+    (0xFFF0, 0xFFFF, "Specials"),
+    (0x10000, 0x1007F, "Linear B Syllabary"),
+    (0x10300, 0x1032F, "Old Italic"),
+    (0x10330, 0x1034F, "Gothic"),
+    (0x13000, 0x1342F, "Egyptian Hieroglyphs"),
+    (0x1D100, 0x1D1FF, "Musical Symbols"),
+    (0x1D400, 0x1D7FF, "Mathematical Alphanumeric Symbols"),
+    (0x1F000, 0x1F02F, "Mahjong Tiles"),
+    (0x1F0A0, 0x1F0FF, "Playing Cards"),
+    (0x1F100, 0x1F1FF, "Enclosed Alphanumeric Supplement"),
+    (0x1F300, 0x1F5FF, "Miscellaneous Symbols and Pictographs"),
+    (0x1F600, 0x1F64F, "Emoticons"),
+    (0x1F680, 0x1F6FF, "Transport and Map Symbols"),
+    (0x1F900, 0x1F9FF, "Supplemental Symbols and Pictographs"),
+    (0x1FA70, 0x1FAFF, "Symbols and Pictographs Extended-A"),
+    (0x20000, 0x2A6DF, "CJK Unified Ideographs Extension B"),
+    (0x2A700, 0x2B73F, "CJK Unified Ideographs Extension C"),
+    (0x2B740, 0x2B81F, "CJK Unified Ideographs Extension D"),
+    (0x2B820, 0x2CEAF, "CJK Unified Ideographs Extension E"),
+    (0x30000, 0x3134F, "CJK Unified Ideographs Extension G"),
+    (0xE0000, 0xE007F, "Tags"),
+    (0xF0000, 0xFFFFF, "Supplementary Private Use Area-A"),
+    (0x100000, 0x10FFFF, "Supplementary Private Use Area-B"),
+];
+
+/// The name of a codepoint, if it is in a range whose names are known
+pub(crate) fn name(codepoint: u32) -> Option<String> {
+    if CJK_UNIFIED_IDEOGRAPHS
+        .iter()
+        .any(|(first, last)| (*first..=*last).contains(&codepoint))
+    {
+        return Some(format!("CJK UNIFIED IDEOGRAPH-{:04X}", codepoint));
+    }
+
+    if (HANGUL_SYLLABLES.0..=HANGUL_SYLLABLES.1).contains(&codepoint) {
+        let syllable = (codepoint - HANGUL_SYLLABLES.0) as usize;
+        let (lead, rest) = (syllable / 588, syllable % 588);
+        return Some(format!(
+            "HANGUL SYLLABLE {}{}{}",
+            HANGUL_LEADS[lead],
+            HANGUL_VOWELS[rest / 28],
+            HANGUL_TAILS[rest % 28]
+        ));
+    }
+
+    NAMES
+        .binary_search_by_key(&codepoint, |(c, _)| *c)
+        .ok()
+        .map(|i| NAMES[i].1.to_string())
+}
+
+/// The two letter general category of a codepoint, e.g. `Lu` for an uppercase letter
+pub(crate) fn category(codepoint: u32) -> &'static str {
+    let i = CATEGORIES.partition_point(|(first, _)| *first <= codepoint);
+    CATEGORIES[i - 1].1
+}
+
+/// The block a codepoint belongs to, for the more common blocks
+pub(crate) fn block(codepoint: u32) -> Option<&'static str> {
+    BLOCKS
+        .iter()
+        .find(|(first, last, _)| (*first..=*last).contains(&codepoint))
+        .map(|(_, _, block)| *block)
+}
+
+/// Describes a codepoint by its name, general category and block, e.g. `EURO SIGN (Sc, Currency Symbols)`
+pub(crate) fn describe(codepoint: u32) -> String {
+    let mut details = vec![category(codepoint)];
+    details.extend(block(codepoint));
+
+    match name(codepoint) {
+        Some(name) => format!("{} ({})", name, details.join(", ")),
+        None => format!("({})", details.join(", ")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(0x41, Some("LATIN CAPITAL LETTER A"))]
+    #[test_case(0x20AC, Some("EURO SIGN"))]
+    #[test_case(0x1F600, Some("GRINNING FACE"))]
+    #[test_case(0x4E00, Some("CJK UNIFIED IDEOGRAPH-4E00"))]
+    #[test_case(0xD4DB, Some("HANGUL SYLLABLE PWILH"))]
+    #[test_case(0xAC00, Some("HANGUL SYLLABLE GA"))]
+    #[test_case(0x0E01, None; "outside the name table")]
+    fn name_should_be_expected_value(codepoint: u32, expected: Option<&str>) {
+        // Act
+        let actual = name(codepoint);
+
+        // Assert
+        assert_eq!(actual.as_deref(), expected);
+    }
+
+    #[test_case(0x41, "Lu")]
+    #[test_case(0x61, "Ll")]
+    #[test_case(0x30, "Nd")]
+    #[test_case(0x0A, "Cc")]
+    #[test_case(0x20AC, "Sc")]
+    #[test_case(0x0301, "Mn")]
+    #[test_case(0xD800, "Cs")]
+    #[test_case(0x0378, "Cn"; "unassigned")]
+    #[test_case(0x10FFFF, "Cn"; "last codepoint")]
+    fn category_should_be_expected_value(codepoint: u32, expected: &str) {
+        // Act
+        let actual = category(codepoint);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test_case(0x41, "LATIN CAPITAL LETTER A (Lu, Basic Latin)")]
+    #[test_case(0x20AC, "EURO SIGN (Sc, Currency Symbols)")]
+    #[test_case(0x0E01, "(Lo, Thai)")]
+    #[test_case(0xE0100, "(Mn)"; "outside the name and block tables")]
+    fn describe_should_be_expected_value(codepoint: u32, expected: &str) {
+        // Act
+        let actual = describe(codepoint);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,154 @@
+use crate::{NumberFormatter, NumberFormatterError};
+
+/// Hex digits in each hyphen separated group of a canonical UUID
+const GROUPS: [usize; 5] = [8, 4, 4, 4, 12];
+
+/// Formatter writing the 128-bit pattern of a number as a hyphenated UUID, such as
+/// `123e4567-e89b-12d3-a456-426614174000`
+pub(crate) struct UuidNumberFormatter {
+    pub uppercase: bool,
+}
+
+/// Removes the braces or `urn:uuid:` prefix a UUID is sometimes written with
+fn strip_decoration(input: &str) -> &str {
+    let input = input.trim();
+    input
+        .strip_prefix('{')
+        .and_then(|input| input.strip_suffix('}'))
+        .or_else(|| input.strip_prefix("urn:uuid:"))
+        .unwrap_or(input)
+}
+
+/// Whether the input is written as a hyphenated UUID
+pub(crate) fn is_uuid(input: &str) -> bool {
+    let groups: Vec<&str> = strip_decoration(input).split('-').collect();
+
+    groups.len() == GROUPS.len()
+        && groups.iter().zip(GROUPS).all(|(group, digits)| {
+            group.len() == digits && group.chars().all(|c| c.is_ascii_hexdigit())
+        })
+}
+
+impl NumberFormatter for UuidNumberFormatter {
+    fn read(&self, uuid: &str) -> anyhow::Result<i128, NumberFormatterError> {
+        i128::try_from(self.read_unsigned(uuid)?).map_err(|_| NumberFormatterError::TooLargeError)
+    }
+
+    fn format(&self, num: i128) -> anyhow::Result<String, NumberFormatterError> {
+        self.format_unsigned(num as u128)
+    }
+
+    fn read_unsigned(&self, uuid: &str) -> anyhow::Result<u128, NumberFormatterError> {
+        let uuid = strip_decoration(uuid);
+        let digits = uuid.replace('-', "");
+
+        if digits.is_empty() {
+            return Err(NumberFormatterError::Empty);
+        }
+        if !digits.chars().all(|c| c.is_ascii_hexdigit()) || (uuid.contains('-') && !is_uuid(uuid))
+        {
+            return Err(NumberFormatterError::InvalidDigit);
+        }
+        if digits.len() != 32 {
+            return Err(NumberFormatterError::IncompleteUuid);
+        }
+
+        Ok(u128::from_str_radix(&digits, 16)?)
+    }
+
+    fn format_unsigned(&self, num: u128) -> anyhow::Result<String, NumberFormatterError> {
+        let digits = if self.uppercase {
+            format!("{:032X}", num)
+        } else {
+            format!("{:032x}", num)
+        };
+
+        let mut groups = Vec::new();
+        let mut start = 0;
+        for length in GROUPS {
+            groups.push(&digits[start..start + length]);
+            start += length;
+        }
+
+        Ok(groups.join("-"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("123e4567-e89b-12d3-a456-426614174000", true)]
+    #[test_case("{123e4567-e89b-12d3-a456-426614174000}", true; "braced")]
+    #[test_case("urn:uuid:123e4567-e89b-12d3-a456-426614174000", true; "urn")]
+    #[test_case("123e4567e89b12d3a456426614174000", false; "unhyphenated")]
+    #[test_case("123e4567-e89b-12d3-a456-42661417400", false; "short group")]
+    #[test_case("123e4567-e89b-12d3-a456-42661417400g", false; "not hex")]
+    fn is_uuid_should_check_groups(input: &str, expected: bool) {
+        // Act
+        let actual = is_uuid(input);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test_case(
+        0x123e4567_e89b_12d3_a456_426614174000,
+        false,
+        "123e4567-e89b-12d3-a456-426614174000"
+    )]
+    #[test_case(0xff, true, "00000000-0000-0000-0000-0000000000FF"; "padded uppercase")]
+    #[test_case(u128::MAX, false, "ffffffff-ffff-ffff-ffff-ffffffffffff"; "max")]
+    fn uuid_number_formatter_should_format_u128(input: u128, uppercase: bool, expected: &str) {
+        // Arrange
+        let formatter = UuidNumberFormatter { uppercase };
+
+        // Act
+        let actual_output = formatter.format_unsigned(input);
+
+        // Assert
+        assert_eq!(actual_output.unwrap(), expected);
+    }
+
+    #[test]
+    fn uuid_number_formatter_should_format_negative_as_twos_complement() {
+        // Act
+        let actual_output = UuidNumberFormatter { uppercase: false }.format(-1);
+
+        // Assert
+        assert_eq!(
+            actual_output.unwrap(),
+            "ffffffff-ffff-ffff-ffff-ffffffffffff"
+        );
+    }
+
+    #[test_case(
+        "123e4567-e89b-12d3-a456-426614174000",
+        0x123e4567_e89b_12d3_a456_426614174000
+    )]
+    #[test_case("{00000000-0000-0000-0000-0000000000FF}", 0xff; "braced")]
+    #[test_case("ffffffffffffffffffffffffffffffff", u128::MAX; "unhyphenated")]
+    fn uuid_number_formatter_should_read_u128(input: &str, expected: u128) {
+        // Act
+        let actual_number = UuidNumberFormatter { uppercase: false }.read_unsigned(input);
+
+        // Assert
+        assert_eq!(actual_number.unwrap(), expected);
+    }
+
+    #[test_case("", NumberFormatterError::Empty; "empty")]
+    #[test_case("123e4567-e89b-12d3-a456", NumberFormatterError::InvalidDigit; "missing groups")]
+    #[test_case("123e4567e89b", NumberFormatterError::IncompleteUuid; "too few digits")]
+    #[test_case("zz3e4567-e89b-12d3-a456-426614174000", NumberFormatterError::InvalidDigit; "not hex")]
+    fn uuid_number_formatter_should_fail_to_read(input: &str, expected: NumberFormatterError) {
+        // Act
+        let actual_number = UuidNumberFormatter { uppercase: false }.read_unsigned(input);
+
+        // Assert
+        assert_eq!(
+            std::mem::discriminant(&actual_number.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+}
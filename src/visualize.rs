@@ -0,0 +1,57 @@
+use crate::NumberFormatterError;
+
+/// Lays out binary digits a byte at a time under a ruler of bit indices, padding to whole bytes
+pub fn visualize(bits: &str) -> anyhow::Result<String, NumberFormatterError> {
+    if bits.is_empty() {
+        return Err(NumberFormatterError::Empty);
+    }
+    if !bits.chars().all(|c| c == '0' || c == '1') {
+        return Err(NumberFormatterError::InvalidDigit);
+    }
+
+    let width = bits.len().div_ceil(8) * 8;
+    let bits = format!("{:0>width$}", bits, width = width);
+
+    let mut ruler = Vec::<String>::new();
+    let mut bytes = Vec::<&str>::new();
+    for (index, byte) in bits.as_bytes().chunks(8).enumerate() {
+        let high = width - 1 - index * 8;
+        ruler.push(format!("{:<4}{:>4}", high, high - 7));
+        bytes.push(std::str::from_utf8(byte).unwrap_or_default());
+    }
+
+    Ok(format!("{}\n{}", ruler.join("   "), bytes.join(" | ")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("101010", "7      0\n00101010")]
+    #[test_case("1000000001", "15     8   7      0\n00000010 | 00000001")]
+    #[test_case(
+        "11111111000000001111111100000000",
+        "31    24   23    16   15     8   7      0\n11111111 | 00000000 | 11111111 | 00000000"
+    )]
+    fn visualize_should_lay_out_bytes(bits: &str, expected: &str) {
+        // Act
+        let actual = visualize(bits);
+
+        // Assert
+        assert_eq!(actual.unwrap(), expected);
+    }
+
+    #[test_case("", NumberFormatterError::Empty; "empty")]
+    #[test_case("102", NumberFormatterError::InvalidDigit)]
+    fn visualize_should_reject_invalid_bits(bits: &str, expected: NumberFormatterError) {
+        // Act
+        let actual = visualize(bits);
+
+        // Assert
+        assert_eq!(
+            std::mem::discriminant(&actual.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+}
@@ -0,0 +1,173 @@
+use super::{Language, Word};
+
+static ONES: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+static TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+/// Short scale names for each power of a thousand, enough for the i128 range
+static SCALES: [&str; 13] = [
+    "",
+    "thousand",
+    "million",
+    "billion",
+    "trillion",
+    "quadrillion",
+    "quintillion",
+    "sextillion",
+    "septillion",
+    "octillion",
+    "nonillion",
+    "decillion",
+    "undecillion",
+];
+
+static WORDS: &[(&str, Word)] = &[
+    ("zero", Word::Value(0)),
+    ("one", Word::Value(1)),
+    ("two", Word::Value(2)),
+    ("three", Word::Value(3)),
+    ("four", Word::Value(4)),
+    ("five", Word::Value(5)),
+    ("six", Word::Value(6)),
+    ("seven", Word::Value(7)),
+    ("eight", Word::Value(8)),
+    ("nine", Word::Value(9)),
+    ("ten", Word::Value(10)),
+    ("eleven", Word::Value(11)),
+    ("twelve", Word::Value(12)),
+    ("thirteen", Word::Value(13)),
+    ("fourteen", Word::Value(14)),
+    ("fifteen", Word::Value(15)),
+    ("sixteen", Word::Value(16)),
+    ("seventeen", Word::Value(17)),
+    ("eighteen", Word::Value(18)),
+    ("nineteen", Word::Value(19)),
+    ("twenty", Word::Value(20)),
+    ("thirty", Word::Value(30)),
+    ("forty", Word::Value(40)),
+    ("fifty", Word::Value(50)),
+    ("sixty", Word::Value(60)),
+    ("seventy", Word::Value(70)),
+    ("eighty", Word::Value(80)),
+    ("ninety", Word::Value(90)),
+    ("hundred", Word::Hundred),
+    ("thousand", Word::Thousand),
+    ("million", Word::Scale(6)),
+    ("billion", Word::Scale(9)),
+    ("trillion", Word::Scale(12)),
+    ("quadrillion", Word::Scale(15)),
+    ("quintillion", Word::Scale(18)),
+    ("sextillion", Word::Scale(21)),
+    ("septillion", Word::Scale(24)),
+    ("octillion", Word::Scale(27)),
+    ("nonillion", Word::Scale(30)),
+    ("decillion", Word::Scale(33)),
+    ("undecillion", Word::Scale(36)),
+    ("and", Word::Ignored),
+    ("minus", Word::Minus),
+    ("negative", Word::Minus),
+];
+
+fn group_to_words(group: u128) -> String {
+    let mut words = Vec::<String>::new();
+    let hundreds = group / 100;
+    let rest = (group % 100) as usize;
+
+    if hundreds > 0 {
+        words.push(format!("{} hundred", ONES[hundreds as usize]));
+    }
+
+    if rest >= 20 {
+        match rest % 10 {
+            0 => words.push(TENS[rest / 10].to_string()),
+            units => words.push(format!("{}-{}", TENS[rest / 10], ONES[units])),
+        }
+    } else if rest > 0 {
+        words.push(ONES[rest].to_string());
+    }
+
+    words.join(" ")
+}
+
+/// English without "and", e.g. 1024 = `one thousand twenty-four`
+pub(crate) struct English;
+
+impl Language for English {
+    fn spell(&self, num: u128) -> String {
+        if num == 0 {
+            return ONES[0].to_string();
+        }
+
+        let mut groups = Vec::<String>::new();
+        let mut remainder = num;
+        let mut scale = 0;
+
+        while remainder > 0 {
+            let group = remainder % 1000;
+            if group > 0 {
+                let words = group_to_words(group);
+                groups.push(match SCALES[scale] {
+                    "" => words,
+                    name => format!("{} {}", words, name),
+                });
+            }
+            remainder /= 1000;
+            scale += 1;
+        }
+
+        groups.reverse();
+        groups.join(" ")
+    }
+
+    fn minus(&self) -> &'static str {
+        "minus"
+    }
+
+    fn words(&self) -> &'static [(&'static str, Word)] {
+        WORDS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(0, "zero")]
+    #[test_case(7, "seven")]
+    #[test_case(40, "forty")]
+    #[test_case(1024, "one thousand twenty-four")]
+    #[test_case(115, "one hundred fifteen")]
+    #[test_case(1_000_001, "one million one")]
+    #[test_case(999_999, "nine hundred ninety-nine thousand nine hundred ninety-nine")]
+    fn english_should_spell_number(input: u128, expected: &str) {
+        // Act
+        let actual = English.spell(input);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+}
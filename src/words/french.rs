@@ -0,0 +1,202 @@
+use super::{Language, Word};
+
+static BELOW_SEVENTEEN: [&str; 17] = [
+    "zéro", "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf", "dix", "onze",
+    "douze", "treize", "quatorze", "quinze", "seize",
+];
+
+static TENS: [&str; 7] = [
+    "",
+    "",
+    "vingt",
+    "trente",
+    "quarante",
+    "cinquante",
+    "soixante",
+];
+
+/// Long scale names for each power of a thousand from a million up
+static SCALES: [&str; 13] = [
+    "",
+    "",
+    "million",
+    "milliard",
+    "billion",
+    "billiard",
+    "trillion",
+    "trilliard",
+    "quadrillion",
+    "quadrilliard",
+    "quintillion",
+    "quintilliard",
+    "sextillion",
+];
+
+static WORDS: &[(&str, Word)] = &[
+    ("zéro", Word::Value(0)),
+    ("zero", Word::Value(0)),
+    ("un", Word::Value(1)),
+    ("une", Word::Value(1)),
+    ("deux", Word::Value(2)),
+    ("trois", Word::Value(3)),
+    ("quatre", Word::Value(4)),
+    ("cinq", Word::Value(5)),
+    ("six", Word::Value(6)),
+    ("sept", Word::Value(7)),
+    ("huit", Word::Value(8)),
+    ("neuf", Word::Value(9)),
+    ("dix", Word::Value(10)),
+    ("onze", Word::Value(11)),
+    ("douze", Word::Value(12)),
+    ("treize", Word::Value(13)),
+    ("quatorze", Word::Value(14)),
+    ("quinze", Word::Value(15)),
+    ("seize", Word::Value(16)),
+    ("vingt", Word::Value(20)),
+    ("trente", Word::Value(30)),
+    ("quarante", Word::Value(40)),
+    ("cinquante", Word::Value(50)),
+    ("soixante", Word::Value(60)),
+    ("quatre-vingt", Word::Value(80)),
+    ("quatre-vingts", Word::Value(80)),
+    ("cent", Word::Hundred),
+    ("cents", Word::Hundred),
+    ("mille", Word::Thousand),
+    ("million", Word::Scale(6)),
+    ("millions", Word::Scale(6)),
+    ("milliard", Word::Scale(9)),
+    ("milliards", Word::Scale(9)),
+    ("billion", Word::Scale(12)),
+    ("billions", Word::Scale(12)),
+    ("billiard", Word::Scale(15)),
+    ("billiards", Word::Scale(15)),
+    ("trillion", Word::Scale(18)),
+    ("trillions", Word::Scale(18)),
+    ("trilliard", Word::Scale(21)),
+    ("trilliards", Word::Scale(21)),
+    ("quadrillion", Word::Scale(24)),
+    ("quadrillions", Word::Scale(24)),
+    ("quadrilliard", Word::Scale(27)),
+    ("quadrilliards", Word::Scale(27)),
+    ("quintillion", Word::Scale(30)),
+    ("quintillions", Word::Scale(30)),
+    ("quintilliard", Word::Scale(33)),
+    ("quintilliards", Word::Scale(33)),
+    ("sextillion", Word::Scale(36)),
+    ("sextillions", Word::Scale(36)),
+    ("et", Word::Ignored),
+    ("moins", Word::Minus),
+];
+
+/// Spells out 1 to 99. `plural` keeps the `s` of a final `quatre-vingts`, which is
+/// dropped before `mille`
+fn below_hundred(num: u128, plural: bool) -> String {
+    let num = num as usize;
+
+    match num {
+        0..=16 => BELOW_SEVENTEEN[num].to_string(),
+        17..=19 => format!("dix-{}", BELOW_SEVENTEEN[num - 10]),
+        20..=69 => match num % 10 {
+            0 => TENS[num / 10].to_string(),
+            1 => format!("{} et un", TENS[num / 10]),
+            units => format!("{}-{}", TENS[num / 10], BELOW_SEVENTEEN[units]),
+        },
+        71 => String::from("soixante et onze"),
+        70..=79 => format!("soixante-{}", below_hundred(num as u128 - 60, plural)),
+        80 if plural => String::from("quatre-vingts"),
+        80 => String::from("quatre-vingt"),
+        _ => format!("quatre-vingt-{}", below_hundred(num as u128 - 80, plural)),
+    }
+}
+
+fn below_thousand(num: u128, plural: bool) -> String {
+    let hundreds = (num / 100) as usize;
+    let rest = num % 100;
+
+    let cent = match (hundreds, rest) {
+        (0, _) => return below_hundred(rest, plural),
+        (1, _) => String::from("cent"),
+        (_, 0) if plural => format!("{} cents", BELOW_SEVENTEEN[hundreds]),
+        _ => format!("{} cent", BELOW_SEVENTEEN[hundreds]),
+    };
+
+    match rest {
+        0 => cent,
+        _ => format!("{} {}", cent, below_hundred(rest, plural)),
+    }
+}
+
+/// French with traditional spelling, e.g. 1024 = `mille vingt-quatre`
+pub(crate) struct French;
+
+impl Language for French {
+    fn spell(&self, num: u128) -> String {
+        if num == 0 {
+            return BELOW_SEVENTEEN[0].to_string();
+        }
+
+        let mut groups = Vec::<String>::new();
+        let mut remainder = num;
+        let mut scale = 0;
+
+        while remainder > 0 {
+            let group = remainder % 1000;
+
+            match (scale, group) {
+                (_, 0) => {}
+                (0, _) => groups.push(below_thousand(group, true)),
+                (1, 1) => groups.push(String::from("mille")),
+                (1, _) => groups.push(format!("{} mille", below_thousand(group, false))),
+                (_, 1) => groups.push(format!("un {}", SCALES[scale])),
+                _ => groups.push(format!(
+                    "{} {}s",
+                    below_thousand(group, true),
+                    SCALES[scale]
+                )),
+            }
+
+            remainder /= 1000;
+            scale += 1;
+        }
+
+        groups.reverse();
+        groups.join(" ")
+    }
+
+    fn minus(&self) -> &'static str {
+        "moins"
+    }
+
+    fn words(&self) -> &'static [(&'static str, Word)] {
+        WORDS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(0, "zéro")]
+    #[test_case(17, "dix-sept")]
+    #[test_case(21, "vingt et un")]
+    #[test_case(71, "soixante et onze")]
+    #[test_case(77, "soixante-dix-sept")]
+    #[test_case(80, "quatre-vingts")]
+    #[test_case(81, "quatre-vingt-un")]
+    #[test_case(99, "quatre-vingt-dix-neuf")]
+    #[test_case(200, "deux cents")]
+    #[test_case(201, "deux cent un")]
+    #[test_case(1024, "mille vingt-quatre")]
+    #[test_case(80_000, "quatre-vingt mille")]
+    #[test_case(200_000, "deux cent mille")]
+    #[test_case(2_000_000, "deux millions")]
+    #[test_case(1_000_000_000, "un milliard")]
+    fn french_should_spell_number(input: u128, expected: &str) {
+        // Act
+        let actual = French.spell(input);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,212 @@
+use super::{Language, Word};
+
+static BELOW_TWENTY: [&str; 20] = [
+    "null",
+    "eins",
+    "zwei",
+    "drei",
+    "vier",
+    "fünf",
+    "sechs",
+    "sieben",
+    "acht",
+    "neun",
+    "zehn",
+    "elf",
+    "zwölf",
+    "dreizehn",
+    "vierzehn",
+    "fünfzehn",
+    "sechzehn",
+    "siebzehn",
+    "achtzehn",
+    "neunzehn",
+];
+
+static TENS: [&str; 10] = [
+    "", "", "zwanzig", "dreißig", "vierzig", "fünfzig", "sechzig", "siebzig", "achtzig", "neunzig",
+];
+
+/// Long scale names for each power of a thousand from a million up, singular and plural
+static SCALES: [(&str, &str); 13] = [
+    ("", ""),
+    ("", ""),
+    ("Million", "Millionen"),
+    ("Milliarde", "Milliarden"),
+    ("Billion", "Billionen"),
+    ("Billiarde", "Billiarden"),
+    ("Trillion", "Trillionen"),
+    ("Trilliarde", "Trilliarden"),
+    ("Quadrillion", "Quadrillionen"),
+    ("Quadrilliarde", "Quadrilliarden"),
+    ("Quintillion", "Quintillionen"),
+    ("Quintilliarde", "Quintilliarden"),
+    ("Sextillion", "Sextillionen"),
+];
+
+static WORDS: &[(&str, Word)] = &[
+    ("null", Word::Value(0)),
+    ("eins", Word::Value(1)),
+    ("ein", Word::Value(1)),
+    ("eine", Word::Value(1)),
+    ("zwei", Word::Value(2)),
+    ("drei", Word::Value(3)),
+    ("vier", Word::Value(4)),
+    ("fünf", Word::Value(5)),
+    ("sechs", Word::Value(6)),
+    ("sieben", Word::Value(7)),
+    ("acht", Word::Value(8)),
+    ("neun", Word::Value(9)),
+    ("zehn", Word::Value(10)),
+    ("elf", Word::Value(11)),
+    ("zwölf", Word::Value(12)),
+    ("dreizehn", Word::Value(13)),
+    ("vierzehn", Word::Value(14)),
+    ("fünfzehn", Word::Value(15)),
+    ("sechzehn", Word::Value(16)),
+    ("siebzehn", Word::Value(17)),
+    ("achtzehn", Word::Value(18)),
+    ("neunzehn", Word::Value(19)),
+    ("zwanzig", Word::Value(20)),
+    ("dreißig", Word::Value(30)),
+    ("vierzig", Word::Value(40)),
+    ("fünfzig", Word::Value(50)),
+    ("sechzig", Word::Value(60)),
+    ("siebzig", Word::Value(70)),
+    ("achtzig", Word::Value(80)),
+    ("neunzig", Word::Value(90)),
+    ("hundert", Word::Hundred),
+    ("tausend", Word::Thousand),
+    ("million", Word::Scale(6)),
+    ("millionen", Word::Scale(6)),
+    ("milliarde", Word::Scale(9)),
+    ("milliarden", Word::Scale(9)),
+    ("billion", Word::Scale(12)),
+    ("billionen", Word::Scale(12)),
+    ("billiarde", Word::Scale(15)),
+    ("billiarden", Word::Scale(15)),
+    ("trillion", Word::Scale(18)),
+    ("trillionen", Word::Scale(18)),
+    ("trilliarde", Word::Scale(21)),
+    ("trilliarden", Word::Scale(21)),
+    ("quadrillion", Word::Scale(24)),
+    ("quadrillionen", Word::Scale(24)),
+    ("quadrilliarde", Word::Scale(27)),
+    ("quadrilliarden", Word::Scale(27)),
+    ("quintillion", Word::Scale(30)),
+    ("quintillionen", Word::Scale(30)),
+    ("quintilliarde", Word::Scale(33)),
+    ("quintilliarden", Word::Scale(33)),
+    ("sextillion", Word::Scale(36)),
+    ("sextillionen", Word::Scale(36)),
+    ("und", Word::Ignored),
+    ("minus", Word::Minus),
+];
+
+/// Spells out 1 to 99 written together, with `one` used for a final 1 since German
+/// writes `eins` alone, `ein` before `hundert` or `tausend` and `eine` before `Million`
+fn below_hundred(num: u128, one: &str) -> String {
+    let num = num as usize;
+
+    match num {
+        1 => one.to_string(),
+        0..=19 => BELOW_TWENTY[num].to_string(),
+        _ => match num % 10 {
+            0 => TENS[num / 10].to_string(),
+            1 => format!("einund{}", TENS[num / 10]),
+            units => format!("{}und{}", BELOW_TWENTY[units], TENS[num / 10]),
+        },
+    }
+}
+
+fn below_thousand(num: u128, one: &str) -> String {
+    let hundreds = num / 100;
+    let rest = num % 100;
+    let mut words = String::new();
+
+    if hundreds > 0 {
+        words.push_str(&below_hundred(hundreds, "ein"));
+        words.push_str("hundert");
+    }
+
+    if rest > 0 {
+        words.push_str(&below_hundred(rest, one));
+    }
+
+    words
+}
+
+/// German writing numbers below a million as one word, e.g. 1024 = `eintausendvierundzwanzig`
+pub(crate) struct German;
+
+impl Language for German {
+    fn spell(&self, num: u128) -> String {
+        if num == 0 {
+            return BELOW_TWENTY[0].to_string();
+        }
+
+        let mut groups = Vec::<String>::new();
+
+        let below_million = num % 1_000_000;
+        if below_million > 0 {
+            let mut words = String::new();
+            if below_million >= 1000 {
+                words.push_str(&below_thousand(below_million / 1000, "ein"));
+                words.push_str("tausend");
+            }
+            words.push_str(&below_thousand(below_million % 1000, "eins"));
+            groups.push(words);
+        }
+
+        let mut remainder = num / 1_000_000;
+        let mut scale = 2;
+
+        while remainder > 0 {
+            let group = remainder % 1000;
+            let (singular, plural) = SCALES[scale];
+
+            match group {
+                0 => {}
+                1 => groups.push(format!("eine {}", singular)),
+                _ => groups.push(format!("{} {}", below_thousand(group, "eine"), plural)),
+            }
+
+            remainder /= 1000;
+            scale += 1;
+        }
+
+        groups.reverse();
+        groups.join(" ")
+    }
+
+    fn minus(&self) -> &'static str {
+        "minus"
+    }
+
+    fn words(&self) -> &'static [(&'static str, Word)] {
+        WORDS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(0, "null")]
+    #[test_case(1, "eins")]
+    #[test_case(21, "einundzwanzig")]
+    #[test_case(101, "einhunderteins")]
+    #[test_case(1024, "eintausendvierundzwanzig")]
+    #[test_case(101_000, "einhunderteintausend")]
+    #[test_case(1_000_000, "eine Million")]
+    #[test_case(2_500_000, "zwei Millionen fünfhunderttausend")]
+    #[test_case(101_000_000, "einhunderteine Millionen")]
+    fn german_should_spell_number(input: u128, expected: &str) {
+        // Act
+        let actual = German.spell(input);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,326 @@
+use crate::{NumberFormatter, NumberFormatterError};
+use clap::ArgEnum;
+
+mod english;
+mod french;
+mod german;
+mod spanish;
+
+use english::English;
+use french::French;
+use german::German;
+use spanish::Spanish;
+
+/// Languages numbers can be spelled out in
+#[derive(ArgEnum, Copy, Clone, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Lang {
+    #[default]
+    En,
+    Es,
+    Fr,
+    De,
+}
+
+/// The meaning of a word when reading a number spelled out in words
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Word {
+    /// Added to the hundreds group, e.g. `twenty` or `doscientos`
+    Value(u128),
+    /// Multiplies the hundreds group by 100, e.g. `hundred` or `cents`
+    Hundred,
+    /// Multiplies the hundreds group by 1000, e.g. `thousand` or `mil`
+    Thousand,
+    /// Multiplies everything since the previous scale by 10 to the given power, e.g. `million`
+    Scale(u32),
+    /// Joins other words without a value, e.g. `and` or `und`
+    Ignored,
+    /// Makes the number negative when it is the first word
+    Minus,
+}
+
+/// The grammar and vocabulary used to spell numbers out in a language
+pub(crate) trait Language {
+    /// Spells out the magnitude of a number
+    fn spell(&self, num: u128) -> String;
+    /// The word placed before a negative number
+    fn minus(&self) -> &'static str;
+    /// Every word used by `spell`, and accepted alternatives, with their meaning
+    fn words(&self) -> &'static [(&'static str, Word)];
+}
+
+impl Lang {
+    fn language(&self) -> &'static dyn Language {
+        match self {
+            Lang::En => &English,
+            Lang::Es => &Spanish,
+            Lang::Fr => &French,
+            Lang::De => &German,
+        }
+    }
+}
+
+/// Splits words into the longest entries of the language's word table, so words written
+/// together such as `vierundzwanzig` are read as `vier`, `und` and `zwanzig`
+fn tokenize(
+    language: &dyn Language,
+    words: &str,
+) -> anyhow::Result<Vec<Word>, NumberFormatterError> {
+    let lowercase = words.to_lowercase();
+    let mut rest = lowercase.as_str();
+    let mut tokens = Vec::<Word>::new();
+
+    loop {
+        rest = rest.trim_start_matches(|c: char| c.is_whitespace() || c == '-');
+        if rest.is_empty() {
+            return Ok(tokens);
+        }
+
+        let (text, word) = language
+            .words()
+            .iter()
+            .filter(|(text, _)| rest.starts_with(text))
+            .max_by_key(|(text, _)| text.len())
+            .ok_or(NumberFormatterError::InvalidWords)?;
+
+        tokens.push(*word);
+        rest = &rest[text.len()..];
+    }
+}
+
+/// Reads the sign and magnitude of a number spelled out in a language, ignoring case and
+/// hyphens
+fn read_magnitude(
+    language: &dyn Language,
+    words: &str,
+) -> anyhow::Result<(bool, u128), NumberFormatterError> {
+    let mut tokens = tokenize(language, words)?.into_iter().peekable();
+
+    if tokens.peek().is_none() {
+        return Err(NumberFormatterError::Empty);
+    }
+
+    let negative = tokens.next_if_eq(&Word::Minus).is_some();
+    let tokens: Vec<Word> = tokens.filter(|word| *word != Word::Ignored).collect();
+
+    if tokens.is_empty() || (tokens.len() > 1 && tokens.contains(&Word::Value(0))) {
+        return Err(NumberFormatterError::InvalidWords);
+    }
+
+    let mut total: u128 = 0;
+    let mut thousands: u128 = 0;
+    let mut hundreds: u128 = 0;
+    let mut last_scale = u32::MAX;
+
+    for token in tokens {
+        match token {
+            Word::Value(value) => {
+                hundreds += value;
+                if hundreds >= 1000 {
+                    return Err(NumberFormatterError::InvalidWords);
+                }
+            }
+            Word::Hundred => {
+                if hundreds >= 10 {
+                    return Err(NumberFormatterError::InvalidWords);
+                }
+                hundreds = hundreds.max(1) * 100;
+            }
+            Word::Thousand => {
+                if thousands > 0 {
+                    return Err(NumberFormatterError::InvalidWords);
+                }
+                thousands = hundreds.max(1) * 1000;
+                hundreds = 0;
+            }
+            Word::Scale(power) => {
+                let group = thousands + hundreds;
+                if group == 0 || power >= last_scale {
+                    return Err(NumberFormatterError::InvalidWords);
+                }
+                let value = 10u128
+                    .checked_pow(power)
+                    .and_then(|scale| group.checked_mul(scale))
+                    .ok_or(NumberFormatterError::TooLargeError)?;
+                total = total
+                    .checked_add(value)
+                    .ok_or(NumberFormatterError::TooLargeError)?;
+                thousands = 0;
+                hundreds = 0;
+                last_scale = power;
+            }
+            Word::Ignored | Word::Minus => return Err(NumberFormatterError::InvalidWords),
+        }
+    }
+
+    let magnitude = total
+        .checked_add(thousands + hundreds)
+        .ok_or(NumberFormatterError::TooLargeError)?;
+
+    Ok((negative, magnitude))
+}
+
+/// Reads a number spelled out in a language, ignoring case and hyphens
+fn from_words(language: &dyn Language, words: &str) -> anyhow::Result<i128, NumberFormatterError> {
+    let (negative, magnitude) = read_magnitude(language, words)?;
+
+    if negative {
+        0i128
+            .checked_sub_unsigned(magnitude)
+            .ok_or(NumberFormatterError::TooSmallError)
+    } else {
+        i128::try_from(magnitude).map_err(|_| NumberFormatterError::TooLargeError)
+    }
+}
+
+/// Spells out a number in a language, including its sign
+fn to_words(language: &dyn Language, num: i128) -> String {
+    let words = language.spell(num.unsigned_abs());
+
+    if num < 0 {
+        format!("{} {}", language.minus(), words)
+    } else {
+        words
+    }
+}
+
+/// Formatter spelling numbers out in words
+pub(crate) struct WordsNumberFormatter {
+    pub lang: Lang,
+}
+
+impl NumberFormatter for WordsNumberFormatter {
+    fn read(&self, words: &str) -> anyhow::Result<i128, NumberFormatterError> {
+        from_words(self.lang.language(), words)
+    }
+
+    fn format(&self, num: i128) -> anyhow::Result<String, NumberFormatterError> {
+        Ok(to_words(self.lang.language(), num))
+    }
+
+    fn read_unsigned(&self, words: &str) -> anyhow::Result<u128, NumberFormatterError> {
+        match read_magnitude(self.lang.language(), words)? {
+            (true, magnitude) if magnitude > 0 => Err(NumberFormatterError::TooSmallError),
+            (_, magnitude) => Ok(magnitude),
+        }
+    }
+
+    fn format_unsigned(&self, num: u128) -> anyhow::Result<String, NumberFormatterError> {
+        Ok(self.lang.language().spell(num))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(Lang::En, "one thousand twenty-four", 1024)]
+    #[test_case(Lang::En, "One Hundred and Five", 105)]
+    #[test_case(Lang::En, "negative twelve", -12)]
+    #[test_case(Lang::En, "zero", 0)]
+    #[test_case(Lang::En, "two million three thousand", 2_003_000)]
+    #[test_case(Lang::Es, "mil veinticuatro", 1024)]
+    #[test_case(Lang::Es, "tres mil cuatro millones", 3_004_000_000)]
+    #[test_case(Lang::Es, "menos ciento un", -101)]
+    #[test_case(Lang::Fr, "quatre-vingt-dix-sept", 97)]
+    #[test_case(Lang::Fr, "soixante et onze mille", 71_000)]
+    #[test_case(Lang::Fr, "deux cents millions", 200_000_000)]
+    #[test_case(Lang::De, "eintausendvierundzwanzig", 1024)]
+    #[test_case(Lang::De, "zwei Milliarden dreihundert Millionen", 2_300_000_000)]
+    #[test_case(Lang::De, "minus siebzehn", -17)]
+    fn from_words_should_read_number(lang: Lang, input: &str, expected: i128) {
+        // Act
+        let actual = from_words(lang.language(), input);
+
+        // Assert
+        assert!(actual.is_ok());
+        assert_eq!(actual.unwrap(), expected);
+    }
+
+    #[test_case(Lang::En)]
+    #[test_case(Lang::Es)]
+    #[test_case(Lang::Fr)]
+    #[test_case(Lang::De)]
+    fn from_words_should_round_trip(lang: Lang) {
+        for input in [
+            i128::MIN,
+            i128::MAX,
+            0,
+            1,
+            21,
+            71,
+            80,
+            81,
+            99,
+            100,
+            101,
+            200,
+            1001,
+            21_000,
+            80_000,
+            101_101,
+            1_000_000,
+            2_000_001,
+            1_000_000_000,
+            987_654_321_012,
+        ] {
+            // Act
+            let words = to_words(lang.language(), input);
+            let actual = from_words(lang.language(), &words);
+
+            // Assert
+            assert_eq!(actual.unwrap(), input, "{}", words);
+        }
+    }
+
+    #[test_case("one thousand one thousand", NumberFormatterError::InvalidWords ; "repeated thousand")]
+    #[test_case("one million two million", NumberFormatterError::InvalidWords ; "repeated scale")]
+    #[test_case("twelve hundred", NumberFormatterError::InvalidWords ; "hundred after teen")]
+    #[test_case("one zero", NumberFormatterError::InvalidWords ; "zero after number")]
+    #[test_case("one fish", NumberFormatterError::InvalidWords ; "unknown word")]
+    #[test_case("minus", NumberFormatterError::InvalidWords ; "only sign")]
+    #[test_case("million", NumberFormatterError::InvalidWords ; "scale without number")]
+    #[test_case("", NumberFormatterError::Empty ; "empty")]
+    fn from_words_should_fail(input: &str, expected: NumberFormatterError) {
+        // Act
+        let actual = from_words(Lang::En.language(), input);
+
+        // Assert
+        assert_eq!(
+            std::mem::discriminant(&actual.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+
+    #[test_case(Lang::En)]
+    #[test_case(Lang::Es)]
+    #[test_case(Lang::Fr)]
+    #[test_case(Lang::De)]
+    fn words_number_formatter_should_round_trip_unsigned(lang: Lang) {
+        // Arrange
+        let formatter = WordsNumberFormatter { lang };
+
+        // Act
+        let words = formatter.format_unsigned(u128::MAX).unwrap();
+        let actual = formatter.read_unsigned(&words);
+
+        // Assert
+        assert_eq!(actual.unwrap(), u128::MAX, "{}", words);
+    }
+
+    #[test_case(Lang::En, -1024, "minus one thousand twenty-four")]
+    #[test_case(Lang::Es, -1024, "menos mil veinticuatro")]
+    #[test_case(Lang::Fr, -1024, "moins mille vingt-quatre")]
+    #[test_case(Lang::De, -1024, "minus eintausendvierundzwanzig")]
+    fn words_number_formatter_should_format_negative(lang: Lang, input: i128, expected: &str) {
+        // Arrange
+        let formatter = WordsNumberFormatter { lang };
+
+        // Act
+        let actual_output = formatter.format(input);
+
+        // Assert
+        assert!(actual_output.is_ok());
+        assert_eq!(expected, actual_output.unwrap());
+    }
+}
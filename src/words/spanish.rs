@@ -0,0 +1,264 @@
+use super::{Language, Word};
+
+static BELOW_THIRTY: [&str; 30] = [
+    "cero",
+    "uno",
+    "dos",
+    "tres",
+    "cuatro",
+    "cinco",
+    "seis",
+    "siete",
+    "ocho",
+    "nueve",
+    "diez",
+    "once",
+    "doce",
+    "trece",
+    "catorce",
+    "quince",
+    "dieciséis",
+    "diecisiete",
+    "dieciocho",
+    "diecinueve",
+    "veinte",
+    "veintiuno",
+    "veintidós",
+    "veintitrés",
+    "veinticuatro",
+    "veinticinco",
+    "veintiséis",
+    "veintisiete",
+    "veintiocho",
+    "veintinueve",
+];
+
+static TENS: [&str; 10] = [
+    "",
+    "",
+    "",
+    "treinta",
+    "cuarenta",
+    "cincuenta",
+    "sesenta",
+    "setenta",
+    "ochenta",
+    "noventa",
+];
+
+static HUNDREDS: [&str; 10] = [
+    "",
+    "ciento",
+    "doscientos",
+    "trescientos",
+    "cuatrocientos",
+    "quinientos",
+    "seiscientos",
+    "setecientos",
+    "ochocientos",
+    "novecientos",
+];
+
+/// Long scale names for each power of a million, singular and plural
+static SCALES: [(&str, &str); 7] = [
+    ("", ""),
+    ("millón", "millones"),
+    ("billón", "billones"),
+    ("trillón", "trillones"),
+    ("cuatrillón", "cuatrillones"),
+    ("quintillón", "quintillones"),
+    ("sextillón", "sextillones"),
+];
+
+static WORDS: &[(&str, Word)] = &[
+    ("cero", Word::Value(0)),
+    ("uno", Word::Value(1)),
+    ("un", Word::Value(1)),
+    ("una", Word::Value(1)),
+    ("dos", Word::Value(2)),
+    ("tres", Word::Value(3)),
+    ("cuatro", Word::Value(4)),
+    ("cinco", Word::Value(5)),
+    ("seis", Word::Value(6)),
+    ("siete", Word::Value(7)),
+    ("ocho", Word::Value(8)),
+    ("nueve", Word::Value(9)),
+    ("diez", Word::Value(10)),
+    ("once", Word::Value(11)),
+    ("doce", Word::Value(12)),
+    ("trece", Word::Value(13)),
+    ("catorce", Word::Value(14)),
+    ("quince", Word::Value(15)),
+    ("dieciséis", Word::Value(16)),
+    ("dieciseis", Word::Value(16)),
+    ("diecisiete", Word::Value(17)),
+    ("dieciocho", Word::Value(18)),
+    ("diecinueve", Word::Value(19)),
+    ("veinte", Word::Value(20)),
+    ("veintiuno", Word::Value(21)),
+    ("veintiún", Word::Value(21)),
+    ("veintiun", Word::Value(21)),
+    ("veintidós", Word::Value(22)),
+    ("veintidos", Word::Value(22)),
+    ("veintitrés", Word::Value(23)),
+    ("veintitres", Word::Value(23)),
+    ("veinticuatro", Word::Value(24)),
+    ("veinticinco", Word::Value(25)),
+    ("veintiséis", Word::Value(26)),
+    ("veintiseis", Word::Value(26)),
+    ("veintisiete", Word::Value(27)),
+    ("veintiocho", Word::Value(28)),
+    ("veintinueve", Word::Value(29)),
+    ("treinta", Word::Value(30)),
+    ("cuarenta", Word::Value(40)),
+    ("cincuenta", Word::Value(50)),
+    ("sesenta", Word::Value(60)),
+    ("setenta", Word::Value(70)),
+    ("ochenta", Word::Value(80)),
+    ("noventa", Word::Value(90)),
+    ("cien", Word::Value(100)),
+    ("ciento", Word::Value(100)),
+    ("doscientos", Word::Value(200)),
+    ("trescientos", Word::Value(300)),
+    ("cuatrocientos", Word::Value(400)),
+    ("quinientos", Word::Value(500)),
+    ("seiscientos", Word::Value(600)),
+    ("setecientos", Word::Value(700)),
+    ("ochocientos", Word::Value(800)),
+    ("novecientos", Word::Value(900)),
+    ("mil", Word::Thousand),
+    ("millón", Word::Scale(6)),
+    ("millon", Word::Scale(6)),
+    ("millones", Word::Scale(6)),
+    ("billón", Word::Scale(12)),
+    ("billon", Word::Scale(12)),
+    ("billones", Word::Scale(12)),
+    ("trillón", Word::Scale(18)),
+    ("trillon", Word::Scale(18)),
+    ("trillones", Word::Scale(18)),
+    ("cuatrillón", Word::Scale(24)),
+    ("cuatrillon", Word::Scale(24)),
+    ("cuatrillones", Word::Scale(24)),
+    ("quintillón", Word::Scale(30)),
+    ("quintillon", Word::Scale(30)),
+    ("quintillones", Word::Scale(30)),
+    ("sextillón", Word::Scale(36)),
+    ("sextillon", Word::Scale(36)),
+    ("sextillones", Word::Scale(36)),
+    ("y", Word::Ignored),
+    ("menos", Word::Minus),
+];
+
+/// Spells out 1 to 99, shortening a final `uno` to `un` before a noun such as `mil`
+fn below_hundred(num: u128, apocope: bool) -> String {
+    let num = num as usize;
+
+    match num {
+        1 if apocope => String::from("un"),
+        21 if apocope => String::from("veintiún"),
+        0..=29 => BELOW_THIRTY[num].to_string(),
+        _ => match num % 10 {
+            0 => TENS[num / 10].to_string(),
+            units => format!(
+                "{} y {}",
+                TENS[num / 10],
+                below_hundred(units as u128, apocope)
+            ),
+        },
+    }
+}
+
+fn below_thousand(num: u128, apocope: bool) -> String {
+    let hundreds = (num / 100) as usize;
+    let rest = num % 100;
+
+    match (hundreds, rest) {
+        (0, _) => below_hundred(rest, apocope),
+        (1, 0) => String::from("cien"),
+        (_, 0) => HUNDREDS[hundreds].to_string(),
+        _ => format!("{} {}", HUNDREDS[hundreds], below_hundred(rest, apocope)),
+    }
+}
+
+fn below_million(num: u128, apocope: bool) -> String {
+    let thousands = num / 1000;
+    let rest = num % 1000;
+    let mut words = Vec::<String>::new();
+
+    match thousands {
+        0 => {}
+        1 => words.push(String::from("mil")),
+        _ => words.push(format!("{} mil", below_thousand(thousands, true))),
+    }
+
+    if rest > 0 {
+        words.push(below_thousand(rest, apocope));
+    }
+
+    words.join(" ")
+}
+
+/// Spanish using the long scale, e.g. 1024 = `mil veinticuatro`
+pub(crate) struct Spanish;
+
+impl Language for Spanish {
+    fn spell(&self, num: u128) -> String {
+        if num == 0 {
+            return BELOW_THIRTY[0].to_string();
+        }
+
+        let mut groups = Vec::<String>::new();
+        let mut remainder = num;
+        let mut scale = 0;
+
+        while remainder > 0 {
+            let group = remainder % 1_000_000;
+            let (singular, plural) = SCALES[scale];
+
+            match (scale, group) {
+                (_, 0) => {}
+                (0, _) => groups.push(below_million(group, false)),
+                (_, 1) => groups.push(format!("un {}", singular)),
+                _ => groups.push(format!("{} {}", below_million(group, true), plural)),
+            }
+
+            remainder /= 1_000_000;
+            scale += 1;
+        }
+
+        groups.reverse();
+        groups.join(" ")
+    }
+
+    fn minus(&self) -> &'static str {
+        "menos"
+    }
+
+    fn words(&self) -> &'static [(&'static str, Word)] {
+        WORDS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(0, "cero")]
+    #[test_case(16, "dieciséis")]
+    #[test_case(31, "treinta y uno")]
+    #[test_case(100, "cien")]
+    #[test_case(101, "ciento uno")]
+    #[test_case(1024, "mil veinticuatro")]
+    #[test_case(21_000, "veintiún mil")]
+    #[test_case(1_000_000, "un millón")]
+    #[test_case(3_004_000_000, "tres mil cuatro millones")]
+    #[test_case(2_000_000_000_000, "dos billones")]
+    fn spanish_should_spell_number(input: u128, expected: &str) {
+        // Act
+        let actual = Spanish.spell(input);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,196 @@
+use crate::{color, NumberFormatterError, Value};
+use clap::Args;
+
+/// Options of the xterm command
+#[derive(Args, Clone, Debug)]
+pub struct XtermOptions {
+    /// A palette index such as 33, or a color such as #1e90ff or 30,144,255 to find the nearest
+    /// index of
+    pub color: String,
+}
+
+/// Standard colors 0 to 15 as xterm shows them, which terminal themes often redefine
+static SYSTEM_COLORS: [[u8; 3]; 16] = [
+    [0x00, 0x00, 0x00],
+    [0x80, 0x00, 0x00],
+    [0x00, 0x80, 0x00],
+    [0x80, 0x80, 0x00],
+    [0x00, 0x00, 0x80],
+    [0x80, 0x00, 0x80],
+    [0x00, 0x80, 0x80],
+    [0xc0, 0xc0, 0xc0],
+    [0x80, 0x80, 0x80],
+    [0xff, 0x00, 0x00],
+    [0x00, 0xff, 0x00],
+    [0xff, 0xff, 0x00],
+    [0x00, 0x00, 0xff],
+    [0xff, 0x00, 0xff],
+    [0x00, 0xff, 0xff],
+    [0xff, 0xff, 0xff],
+];
+
+/// Channel levels of the 6x6x6 color cube at indices 16 to 231
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// First index of the color cube, which nearest index lookups start from as the standard colors
+/// before it vary between themes
+const CUBE_START: u8 = 16;
+
+/// First index of the grayscale ramp, which runs from 8 to 238 in steps of 10
+const GRAYSCALE_START: u8 = 232;
+
+/// A palette index and the color it shows
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct XtermInfo {
+    pub index: u8,
+    pub rgb: [u8; 3],
+    /// Whether the index was found as the nearest to a color rather than given
+    pub nearest: bool,
+}
+
+impl XtermInfo {
+    pub(crate) fn from_index(value: Value) -> anyhow::Result<Self, NumberFormatterError> {
+        let index = match value {
+            Value::Signed(num) if num < 0 => return Err(NumberFormatterError::TooSmallError),
+            Value::Signed(num) => {
+                u8::try_from(num).map_err(|_| NumberFormatterError::TooLargeError)?
+            }
+            Value::Unsigned(_) => return Err(NumberFormatterError::TooLargeError),
+        };
+
+        Ok(Self {
+            index,
+            rgb: rgb_of(index),
+            nearest: false,
+        })
+    }
+
+    pub(crate) fn nearest_to(rgb: [u8; 3]) -> Self {
+        let index = nearest_index(rgb);
+
+        Self {
+            index,
+            rgb: rgb_of(index),
+            nearest: true,
+        }
+    }
+
+    /// Labelled lines describing the color, in the order they should be displayed
+    pub fn lines(&self) -> Vec<(&'static str, String)> {
+        let [red, green, blue] = self.rgb;
+
+        vec![
+            (
+                if self.nearest {
+                    "Nearest index"
+                } else {
+                    "Index"
+                },
+                self.index.to_string(),
+            ),
+            ("RGB", format!("{}, {}, {}", red, green, blue)),
+            ("Hex", format!("#{:02x}{:02x}{:02x}", red, green, blue)),
+        ]
+    }
+
+    /// A swatch of the color drawn with its 256-color background escape sequence
+    pub fn preview(&self) -> String {
+        format!("\x1b[48;5;{}m        \x1b[0m", self.index)
+    }
+}
+
+/// The color a palette index shows
+pub(crate) fn rgb_of(index: u8) -> [u8; 3] {
+    match index {
+        0..=15 => SYSTEM_COLORS[index as usize],
+        CUBE_START..=231 => {
+            let cube = index - CUBE_START;
+            [
+                CUBE_LEVELS[(cube / 36) as usize],
+                CUBE_LEVELS[(cube / 6 % 6) as usize],
+                CUBE_LEVELS[(cube % 6) as usize],
+            ]
+        }
+        _ => {
+            let level = 8 + (index - GRAYSCALE_START) * 10;
+            [level, level, level]
+        }
+    }
+}
+
+/// The cube or grayscale index whose color is closest to a color, the lowest one on ties
+pub(crate) fn nearest_index(rgb: [u8; 3]) -> u8 {
+    (CUBE_START..=u8::MAX)
+        .min_by_key(|index| {
+            rgb_of(*index)
+                .iter()
+                .zip(rgb)
+                .map(|(a, b)| (*a as i32 - b as i32).pow(2))
+                .sum::<i32>()
+        })
+        .unwrap_or(CUBE_START)
+}
+
+/// Reads a color written as `#rrggbb` or as decimal `r,g,b` components, if it is written as one
+pub(crate) fn parse_rgb(color: &str) -> Option<anyhow::Result<[u8; 3], NumberFormatterError>> {
+    color::parse_color(color).map(|channels| {
+        <[u8; 3]>::try_from(channels?).map_err(|_| NumberFormatterError::InvalidColor)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(1, [0x80, 0x00, 0x00]; "system red")]
+    #[test_case(16, [0, 0, 0]; "cube start")]
+    #[test_case(33, [0, 135, 255])]
+    #[test_case(196, [255, 0, 0]; "cube red")]
+    #[test_case(231, [255, 255, 255]; "cube end")]
+    #[test_case(232, [8, 8, 8]; "grayscale start")]
+    #[test_case(255, [238, 238, 238]; "grayscale end")]
+    fn rgb_of_should_follow_palette(index: u8, expected: [u8; 3]) {
+        // Act
+        let actual = rgb_of(index);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test_case([30, 144, 255], 33; "dodger blue")]
+    #[test_case([255, 0, 0], 196; "exact red")]
+    #[test_case([128, 128, 128], 244; "gray")]
+    #[test_case([0, 0, 0], 16; "black")]
+    fn nearest_index_should_find_closest_color(rgb: [u8; 3], expected: u8) {
+        // Act
+        let actual = nearest_index(rgb);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test_case("#1e90ff", Some(Ok([30, 144, 255])))]
+    #[test_case("30, 144, 255", Some(Ok([30, 144, 255])); "components")]
+    #[test_case("#1e90f", Some(Err(())); "short hex")]
+    #[test_case("30,144", Some(Err(())); "two components")]
+    #[test_case("#1e90ff80", Some(Err(())); "with alpha")]
+    #[test_case("30,144,256", Some(Err(())); "component too large")]
+    #[test_case("33", None; "index")]
+    fn parse_rgb_should_read_color(input: &str, expected: Option<Result<[u8; 3], ()>>) {
+        // Act
+        let actual = parse_rgb(input);
+
+        // Assert
+        assert_eq!(actual.map(|rgb| rgb.map_err(|_| ())), expected);
+    }
+
+    #[test]
+    fn xterm_info_should_reject_index_above_palette() {
+        // Act
+        let actual = XtermInfo::from_index(Value::Signed(256));
+
+        // Assert
+        assert!(matches!(actual, Err(NumberFormatterError::TooLargeError)));
+    }
+}
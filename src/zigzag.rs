@@ -0,0 +1,66 @@
+use crate::{NumberFormatter, NumberFormatterError};
+
+/// Protobuf style ZigZag formatter, mapping signed numbers onto unsigned ones so small
+/// magnitudes stay small (0 = 0, −1 = 1, 1 = 2, −2 = 3). The encoded value is in decimal
+pub(crate) struct ZigZagNumberFormatter;
+
+impl NumberFormatter for ZigZagNumberFormatter {
+    fn read(&self, zigzag: &str) -> anyhow::Result<i128, NumberFormatterError> {
+        let encoded = zigzag.parse::<u128>()?;
+        Ok(((encoded >> 1) as i128) ^ -((encoded & 1) as i128))
+    }
+
+    fn format(&self, num: i128) -> anyhow::Result<String, NumberFormatterError> {
+        let encoded = ((num << 1) ^ (num >> 127)) as u128;
+        Ok(encoded.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("0", 0)]
+    #[test_case("1", -1)]
+    #[test_case("2", 1)]
+    #[test_case("3", -2)]
+    #[test_case("4294967294", 2147483647)]
+    #[test_case("4294967295", -2147483648)]
+    fn zigzag_number_formatter_should_read_number(input: &str, expected: i128) {
+        // Act
+        let actual_number = ZigZagNumberFormatter.read(input);
+
+        // Assert
+        assert!(actual_number.is_ok());
+        assert_eq!(expected, actual_number.unwrap());
+    }
+
+    #[test_case(0, "0")]
+    #[test_case(-1, "1")]
+    #[test_case(1, "2")]
+    #[test_case(-64, "127")]
+    #[test_case(i128::MAX, "340282366920938463463374607431768211454")]
+    #[test_case(i128::MIN, "340282366920938463463374607431768211455")]
+    fn zigzag_number_formatter_should_format_i128(input: i128, expected: &str) {
+        // Act
+        let actual_output = ZigZagNumberFormatter.format(input);
+
+        // Assert
+        assert!(actual_output.is_ok());
+        assert_eq!(expected, actual_output.unwrap());
+    }
+
+    #[test_case("-1", NumberFormatterError::InvalidDigit ; "negative")]
+    #[test_case("", NumberFormatterError::Empty ; "empty")]
+    fn zigzag_number_formatter_should_fail_to_read(input: &str, expected: NumberFormatterError) {
+        // Act
+        let actual_number = ZigZagNumberFormatter.read(input);
+
+        // Assert
+        assert_eq!(
+            std::mem::discriminant(&actual_number.unwrap_err()),
+            std::mem::discriminant(&expected)
+        );
+    }
+}